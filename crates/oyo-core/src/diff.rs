@@ -2,6 +2,7 @@
 
 use crate::change::{Change, ChangeKind, ChangeSpan};
 use imara_diff::{Algorithm, Diff, InternedInput, TokenSource};
+use regex::Regex;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::hash::Hash;
 use std::ops::Range;
@@ -58,9 +59,16 @@ pub struct DiffResult {
     pub insertions: usize,
     /// Total number of deletions
     pub deletions: usize,
+    /// IDs of insert/delete changes that belong to a moved block (sorted)
+    pub moved_changes: Vec<usize>,
 }
 
 impl DiffResult {
+    /// Check whether a change was detected as part of a moved block
+    pub fn is_moved(&self, change_id: usize) -> bool {
+        self.moved_changes.binary_search(&change_id).is_ok()
+    }
+
     /// Get only the significant (non-context) changes
     pub fn get_significant_changes(&self) -> Vec<&Change> {
         self.significant_changes
@@ -80,6 +88,73 @@ impl DiffResult {
             .iter()
             .find(|h| h.change_ids.contains(&change_id))
     }
+
+    /// Words added and removed across all change spans, as `(added, removed)`.
+    /// Reflowed prose keeps its words, so this stays small where line counts
+    /// balloon.
+    pub fn word_counts(&self) -> (usize, usize) {
+        let mut added = 0;
+        let mut removed = 0;
+        for span in self.changes.iter().flat_map(|change| &change.spans) {
+            match span.kind {
+                ChangeKind::Insert => added += count_words(&span.text),
+                ChangeKind::Delete => removed += count_words(&span.text),
+                ChangeKind::Replace => {
+                    removed += count_words(&span.text);
+                    added += span.new_text.as_deref().map(count_words).unwrap_or(0);
+                }
+                ChangeKind::Equal => {}
+            }
+        }
+        (added, removed)
+    }
+}
+
+/// Merge each hunk into the previous one when the context between them is
+/// all present (not trimmed away) and has at most `max_gap` non-blank lines.
+fn group_adjacent_hunks(hunks: Vec<Hunk>, changes: &[Change], max_gap: usize) -> Vec<Hunk> {
+    let mut id_to_index = FxHashMap::with_capacity_and_hasher(changes.len(), Default::default());
+    for (idx, change) in changes.iter().enumerate() {
+        id_to_index.insert(change.id, idx);
+    }
+    let mut grouped: Vec<Hunk> = Vec::with_capacity(hunks.len());
+    for hunk in hunks {
+        if let Some(prev) = grouped.last_mut() {
+            let gap = prev
+                .change_ids
+                .last()
+                .zip(hunk.change_ids.first())
+                .and_then(|(last, first)| {
+                    Some((*id_to_index.get(last)?, *id_to_index.get(first)?))
+                });
+            if let Some((last, first)) = gap {
+                let between = &changes[last + 1..first];
+                let contiguous = changes[first].id - changes[last].id == first - last;
+                let non_blank = between
+                    .iter()
+                    .filter(|change| change.spans.iter().any(|span| !span.text.trim().is_empty()))
+                    .count();
+                if contiguous && non_blank <= max_gap {
+                    prev.change_ids.extend(hunk.change_ids);
+                    prev.insertions += hunk.insertions;
+                    prev.deletions += hunk.deletions;
+                    continue;
+                }
+            }
+        }
+        grouped.push(hunk);
+    }
+    for (id, hunk) in grouped.iter_mut().enumerate() {
+        hunk.id = id;
+    }
+    grouped
+}
+
+/// Whitespace-separated runs containing at least one letter or digit.
+fn count_words(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
 }
 
 /// A diff for a single file
@@ -96,8 +171,22 @@ pub struct DiffEngine {
     context_lines: usize,
     /// Whether to do word-level diffing within changed lines
     word_level: bool,
+    /// Whether to pair identical deleted/inserted runs as moved blocks
+    detect_moved: bool,
+    /// Text removed from both sides before lines are compared
+    ignore_matching: Option<Regex>,
+    /// Merge neighbouring hunks separated by at most this many non-blank
+    /// context lines
+    group_adjacent: Option<usize>,
 }
 
+/// Minimum lines in a moved block
+const MOVED_MIN_LINES: usize = 3;
+/// Minimum alphanumeric characters in a moved block (matches git's default)
+const MOVED_MIN_ALNUM: usize = 20;
+/// Lines repeated more often than this are too common to anchor a move
+const MOVED_MAX_CANDIDATES: usize = 32;
+
 fn diff_ranges<I, T>(algorithm: Algorithm, before: I, after: I) -> Vec<(Range<usize>, Range<usize>)>
 where
     I: TokenSource<Token = T>,
@@ -115,11 +204,29 @@ where
         .collect()
 }
 
+/// `text` with every match of `regex` removed, line by line. Line breaks are
+/// kept so line indices still refer to the original text.
+fn strip_matches(text: &str, regex: &Regex) -> String {
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let (body, end) = match line.strip_suffix('\n') {
+            Some(body) => (body, "\n"),
+            None => (line, ""),
+        };
+        out.push_str(&regex.replace_all(body, ""));
+        out.push_str(end);
+    }
+    out
+}
+
 impl Default for DiffEngine {
     fn default() -> Self {
         Self {
             context_lines: 3,
             word_level: true,
+            detect_moved: false,
+            ignore_matching: None,
+            group_adjacent: None,
         }
     }
 }
@@ -139,6 +246,26 @@ impl DiffEngine {
         self
     }
 
+    pub fn with_moved_detection(mut self, enabled: bool) -> Self {
+        self.detect_moved = enabled;
+        self
+    }
+
+    /// Treat lines as equal when they only differ in matches of `regex`.
+    /// Changes still show the original text.
+    pub fn with_ignore_matching(mut self, regex: Option<Regex>) -> Self {
+        self.ignore_matching = regex;
+        self
+    }
+
+    /// Merge hunks whose gap holds at most `max_gap` non-blank context lines,
+    /// so reformats that shuffle blank lines and indentation read as one
+    /// block. The changes shown are the same; only the hunk boundaries move.
+    pub fn with_adjacent_grouping(mut self, max_gap: Option<usize>) -> Self {
+        self.group_adjacent = max_gap;
+        self
+    }
+
     /// Compute diff between two strings
     pub fn diff_strings(&self, old: &str, new: &str) -> DiffResult {
         let mut changes = Vec::new();
@@ -156,7 +283,14 @@ impl DiffEngine {
 
         let old_lines: Vec<&str> = old.lines().collect();
         let new_lines: Vec<&str> = new.lines().collect();
-        let ranges = diff_ranges(Algorithm::Histogram, old, new);
+        let ranges = match &self.ignore_matching {
+            Some(regex) => {
+                let old = strip_matches(old, regex);
+                let new = strip_matches(new, regex);
+                diff_ranges(Algorithm::Histogram, old.as_str(), new.as_str())
+            }
+            None => diff_ranges(Algorithm::Histogram, old, new),
+        };
 
         let mut old_idx = 0usize;
 
@@ -232,6 +366,12 @@ impl DiffEngine {
             &mut deletions,
         );
 
+        let moved_changes = if self.detect_moved {
+            detect_moved_blocks(&changes)
+        } else {
+            Vec::new()
+        };
+
         let (changes, significant_changes) = if self.context_lines != usize::MAX {
             let mut id_to_idx = FxHashMap::default();
             for (idx, change) in changes.iter().enumerate() {
@@ -264,7 +404,10 @@ impl DiffEngine {
         };
 
         // Compute hunks by grouping nearby changes
-        let hunks = Self::compute_hunks(&significant_changes, &changes);
+        let mut hunks = Self::compute_hunks(&significant_changes, &changes);
+        if let Some(max_gap) = self.group_adjacent {
+            hunks = group_adjacent_hunks(hunks, &changes, max_gap);
+        }
 
         DiffResult {
             changes,
@@ -272,6 +415,7 @@ impl DiffEngine {
             hunks,
             insertions,
             deletions,
+            moved_changes,
         }
     }
 
@@ -442,6 +586,99 @@ impl DiffEngine {
     }
 }
 
+/// Pair runs of deleted lines with identical runs of inserted lines elsewhere
+/// in the file (like `git diff --color-moved`). Returns the sorted IDs of
+/// every change in a matched block. Blocks need `MOVED_MIN_LINES` lines and
+/// `MOVED_MIN_ALNUM` alphanumeric characters so braces and blank lines don't
+/// pair up by accident.
+fn detect_moved_blocks(changes: &[Change]) -> Vec<usize> {
+    // Runs of pure deletes / inserts, split wherever anything else appears.
+    let mut delete_runs: Vec<Vec<(usize, &str)>> = Vec::new();
+    let mut insert_runs: Vec<Vec<(usize, &str)>> = Vec::new();
+    let mut last_kind = None;
+    for change in changes {
+        let kind = match change.spans.as_slice() {
+            [span] if matches!(span.kind, ChangeKind::Delete | ChangeKind::Insert) => {
+                Some(span.kind)
+            }
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            let runs = if kind == ChangeKind::Delete {
+                &mut delete_runs
+            } else {
+                &mut insert_runs
+            };
+            if last_kind != Some(kind) {
+                runs.push(Vec::new());
+            }
+            if let Some(run) = runs.last_mut() {
+                run.push((change.id, change.spans[0].text.as_str()));
+            }
+        }
+        last_kind = kind;
+    }
+    if delete_runs.is_empty() || insert_runs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut deleted_at: FxHashMap<&str, Vec<(usize, usize)>> = FxHashMap::default();
+    for (run_idx, run) in delete_runs.iter().enumerate() {
+        for (pos, (_, text)) in run.iter().enumerate() {
+            deleted_at.entry(*text).or_default().push((run_idx, pos));
+        }
+    }
+    let mut used: Vec<Vec<bool>> = delete_runs
+        .iter()
+        .map(|run| vec![false; run.len()])
+        .collect();
+
+    let mut moved = Vec::new();
+    for run in &insert_runs {
+        let mut pos = 0;
+        while pos < run.len() {
+            let best = deleted_at
+                .get(run[pos].1)
+                .filter(|candidates| candidates.len() <= MOVED_MAX_CANDIDATES)
+                .and_then(|candidates| {
+                    candidates
+                        .iter()
+                        .map(|&(run_idx, start)| {
+                            let deleted = &delete_runs[run_idx];
+                            let len = run[pos..]
+                                .iter()
+                                .zip(&deleted[start..])
+                                .zip(&used[run_idx][start..])
+                                .take_while(|((ins, del), used)| ins.1 == del.1 && !**used)
+                                .count();
+                            (run_idx, start, len)
+                        })
+                        .max_by_key(|&(_, _, len)| len)
+                });
+            let Some((run_idx, start, len)) = best else {
+                pos += 1;
+                continue;
+            };
+            let alnum: usize = run[pos..pos + len]
+                .iter()
+                .map(|(_, text)| text.chars().filter(|ch| ch.is_alphanumeric()).count())
+                .sum();
+            if len < MOVED_MIN_LINES || alnum < MOVED_MIN_ALNUM {
+                pos += 1;
+                continue;
+            }
+            for offset in 0..len {
+                used[run_idx][start + offset] = true;
+                moved.push(delete_runs[run_idx][start + offset].0);
+                moved.push(run[pos + offset].0);
+            }
+            pos += len;
+        }
+    }
+    moved.sort_unstable();
+    moved
+}
+
 /// Tokenize code for word-level diffing
 /// Separates identifiers from punctuation for accurate diffs
 fn tokenize_code(line: &str) -> Vec<String> {
@@ -621,6 +858,42 @@ mod tests {
         assert_eq!(result.significant_changes.len(), 1);
     }
 
+    #[test]
+    fn test_word_counts_follow_spans() {
+        let engine = DiffEngine::new().with_word_level(true);
+        let old = "the quick brown fox\njumps over\n";
+        let new = "the slow brown fox\njumps over\nthe lazy dog\n";
+
+        let result = engine.diff_strings(old, new);
+
+        assert_eq!(result.word_counts(), (4, 1));
+    }
+
+    #[test]
+    fn test_adjacent_grouping_merges_hunks_split_by_blank_context() {
+        let old = "a\nb\nc\nd\n\n\n\n\ne\nf\ng\nh\n";
+        let new = "A\nb\nc\nd\n\n\n\n\ne\nf\ng\nH\n";
+
+        let plain = DiffEngine::new()
+            .with_context(usize::MAX)
+            .diff_strings(old, new);
+        assert_eq!(plain.hunks.len(), 2);
+
+        let grouped = DiffEngine::new()
+            .with_context(usize::MAX)
+            .with_adjacent_grouping(Some(6))
+            .diff_strings(old, new);
+        assert_eq!(grouped.hunks.len(), 1);
+        assert_eq!(grouped.hunks[0].change_ids.len(), 2);
+        assert_eq!(grouped.changes.len(), plain.changes.len());
+
+        let strict = DiffEngine::new()
+            .with_context(usize::MAX)
+            .with_adjacent_grouping(Some(5))
+            .diff_strings(old, new);
+        assert_eq!(strict.hunks.len(), 2);
+    }
+
     #[test]
     fn test_tokenize_code_basic() {
         let tokens = tokenize_code("KeyModifiers, MouseEventKind}");
@@ -742,4 +1015,53 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_ignore_matching_skips_lines_that_differ_only_in_matches() {
+        let old = "built 2024-01-01\nkeep\nvalue = 1 at 2024-01-01\n";
+        let new = "built 2025-02-02\nkeep\nvalue = 2 at 2025-02-02\n";
+        let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+
+        let plain = DiffEngine::new().diff_strings(old, new);
+        assert_eq!((plain.insertions, plain.deletions), (2, 2));
+
+        let result = DiffEngine::new()
+            .with_ignore_matching(Some(regex))
+            .diff_strings(old, new);
+        assert_eq!((result.insertions, result.deletions), (1, 1));
+        let first = &result.changes[0];
+        assert!(!first.has_changes());
+        assert_eq!(first.spans[0].text, "built 2024-01-01");
+    }
+
+    #[test]
+    fn test_moved_block_detection() {
+        let block = "fn helper() {\n    compute_value(input)\n}";
+        let old = format!("{block}\nalpha\nbeta\ngamma\ndelta\nepsilon\nzeta\n");
+        let new = format!("alpha\nbeta\ngamma\ndelta\nepsilon\nzeta\n{block}\n");
+
+        let plain = DiffEngine::new().diff_strings(&old, &new);
+        assert!(plain.moved_changes.is_empty());
+
+        let result = DiffEngine::new()
+            .with_moved_detection(true)
+            .diff_strings(&old, &new);
+        assert_eq!(result.moved_changes.len(), 6);
+        for change in &result.changes {
+            let is_change = change.has_changes();
+            assert_eq!(result.is_moved(change.id), is_change);
+        }
+    }
+
+    #[test]
+    fn test_moved_detection_ignores_short_blocks() {
+        let old = "}\n}\nalpha\nbeta\ngamma\ndelta\n";
+        let new = "alpha\nbeta\ngamma\ndelta\n}\n}\n";
+
+        let result = DiffEngine::new()
+            .with_moved_detection(true)
+            .diff_strings(old, new);
+        assert_eq!(result.significant_changes.len(), 4);
+        assert!(result.moved_changes.is_empty());
+    }
 }
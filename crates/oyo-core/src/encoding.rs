@@ -0,0 +1,180 @@
+//! Text encoding detection for files that aren't plain UTF-8
+//!
+//! A byte-order mark decides between UTF-8 and UTF-16. Without one, valid
+//! UTF-8 is taken as is, NUL-interleaved ASCII is read as UTF-16, and other
+//! text is read as Windows-1252 (Latin-1 plus the `0x80..0xA0` punctuation).
+
+/// A detected non-UTF-8 (or BOM-prefixed) encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Windows1252,
+}
+
+impl TextEncoding {
+    /// Short name shown in the top bar
+    pub fn label(self) -> &'static str {
+        match self {
+            TextEncoding::Utf8Bom => "UTF-8 BOM",
+            TextEncoding::Utf16Le => "UTF-16LE",
+            TextEncoding::Utf16Be => "UTF-16BE",
+            TextEncoding::Windows1252 => "Windows-1252",
+        }
+    }
+}
+
+/// Decode `bytes` as text, or `None` when they look binary. The encoding is
+/// `None` for plain UTF-8.
+pub fn decode_text(bytes: &[u8]) -> Option<(String, Option<TextEncoding>)> {
+    if let Some(rest) = bytes.strip_prefix(b"\xEF\xBB\xBF") {
+        let text = std::str::from_utf8(rest).ok()?;
+        return Some((text.to_string(), Some(TextEncoding::Utf8Bom)));
+    }
+    if let Some(rest) = bytes.strip_prefix(b"\xFF\xFE") {
+        return Some((decode_utf16(rest, false)?, Some(TextEncoding::Utf16Le)));
+    }
+    if let Some(rest) = bytes.strip_prefix(b"\xFE\xFF") {
+        return Some((decode_utf16(rest, true)?, Some(TextEncoding::Utf16Be)));
+    }
+    if let Some(big_endian) = utf16_without_bom(bytes) {
+        let encoding = if big_endian {
+            TextEncoding::Utf16Be
+        } else {
+            TextEncoding::Utf16Le
+        };
+        return Some((decode_utf16(bytes, big_endian)?, Some(encoding)));
+    }
+    if bytes.contains(&0) {
+        return None;
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return Some((text.to_string(), None));
+    }
+    let text = bytes
+        .iter()
+        .map(|&byte| windows_1252_char(byte))
+        .collect::<Option<String>>()?;
+    Some((text, Some(TextEncoding::Windows1252)))
+}
+
+#[allow(clippy::manual_is_multiple_of)]
+fn decode_utf16(bytes: &[u8], big_endian: bool) -> Option<String> {
+    if bytes.len() % 2 != 0 {
+        return None;
+    }
+    let units = bytes.chunks_exact(2).map(|pair| {
+        let pair = [pair[0], pair[1]];
+        if big_endian {
+            u16::from_be_bytes(pair)
+        } else {
+            u16::from_le_bytes(pair)
+        }
+    });
+    let text: String = char::decode_utf16(units).collect::<Result<_, _>>().ok()?;
+    text.chars().all(is_text_char).then_some(text)
+}
+
+/// Control characters other than whitespace and escape mark data as binary.
+fn is_text_char(ch: char) -> bool {
+    matches!(ch, '\t' | '\n' | '\r' | '\x0c' | '\x1b') || !ch.is_ascii_control()
+}
+
+/// Mostly-ASCII UTF-16 has a NUL in every other byte: the odd ones for
+/// little-endian, the even ones for big-endian. Returns `Some(big_endian)`.
+#[allow(clippy::manual_is_multiple_of)]
+fn utf16_without_bom(bytes: &[u8]) -> Option<bool> {
+    if bytes.len() < 4 || bytes.len() % 2 != 0 {
+        return None;
+    }
+    let (mut even, mut odd) = (0usize, 0usize);
+    for (idx, byte) in bytes.iter().enumerate() {
+        if *byte == 0 {
+            if idx % 2 == 0 {
+                even += 1;
+            } else {
+                odd += 1;
+            }
+        }
+    }
+    let half = bytes.len() / 2;
+    if even == 0 && odd * 2 >= half {
+        Some(false)
+    } else if odd == 0 && even * 2 >= half {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Windows-1252 decoding; control bytes and the five unassigned bytes mark
+/// the data as binary.
+fn windows_1252_char(byte: u8) -> Option<char> {
+    const HIGH: [Option<char>; 32] = [
+        Some('€'),
+        None,
+        Some('‚'),
+        Some('ƒ'),
+        Some('„'),
+        Some('…'),
+        Some('†'),
+        Some('‡'),
+        Some('ˆ'),
+        Some('‰'),
+        Some('Š'),
+        Some('‹'),
+        Some('Œ'),
+        None,
+        Some('Ž'),
+        None,
+        None,
+        Some('‘'),
+        Some('’'),
+        Some('“'),
+        Some('”'),
+        Some('•'),
+        Some('–'),
+        Some('—'),
+        Some('˜'),
+        Some('™'),
+        Some('š'),
+        Some('›'),
+        Some('œ'),
+        None,
+        Some('ž'),
+        Some('Ÿ'),
+    ];
+    match byte {
+        0x80..=0x9f => HIGH[(byte - 0x80) as usize],
+        _ => Some(byte as char).filter(|ch| is_text_char(*ch)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_text_detects_boms_and_legacy_encodings() {
+        assert_eq!(decode_text(b"plain\n"), Some(("plain\n".to_string(), None)));
+        assert_eq!(
+            decode_text(b"\xEF\xBB\xBFbom"),
+            Some(("bom".to_string(), Some(TextEncoding::Utf8Bom)))
+        );
+        assert_eq!(
+            decode_text(b"\xFF\xFEh\0i\0"),
+            Some(("hi".to_string(), Some(TextEncoding::Utf16Le)))
+        );
+        assert_eq!(
+            decode_text(b"\0h\0i\0!"),
+            Some(("hi!".to_string(), Some(TextEncoding::Utf16Be)))
+        );
+        assert_eq!(
+            decode_text(b"caf\xE9 \x93q\x94"),
+            Some(("café “q”".to_string(), Some(TextEncoding::Windows1252)))
+        );
+        assert_eq!(decode_text(b"\x89PNG\r\n\x1a\n\0\0"), None);
+        assert_eq!(decode_text(b"ab\x81"), None);
+    }
+}
@@ -1,9 +1,17 @@
 //! Git integration for detecting changed files
 
+use ignore::gitignore::GitignoreBuilder;
+use rustc_hash::FxHashSet;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use thiserror::Error;
 
+/// Per-repo exclude file (gitignore syntax) for files that should never show
+/// up in a review, such as lock files or generated code
+pub const OYO_IGNORE_FILE: &str = ".oyoignore";
+/// Git's well-known empty tree, used as the parent of a root commit
+pub const EMPTY_TREE_HASH: &str = "4b825dc642cb6eb9a060e54bf8d69288fbee4904";
+
 #[derive(Error, Debug)]
 pub enum GitError {
     #[error("Not a git repository")]
@@ -51,6 +59,18 @@ pub struct CommitEntry {
     pub author_time: Option<i64>,
     pub summary: String,
     pub stats: Option<CommitStats>,
+    /// A `git notes` entry is attached to the commit
+    pub has_note: bool,
+}
+
+/// Tag metadata for the dashboard tag picker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagEntry {
+    pub name: String,
+    /// Tagger date for annotated tags, commit date otherwise
+    pub time: Option<i64>,
+    /// Tag message subject, or the tagged commit's subject
+    pub summary: String,
 }
 
 /// Check if a directory is a git repository
@@ -99,6 +119,264 @@ pub fn get_repo_root(path: &Path) -> Result<PathBuf, GitError> {
     Ok(PathBuf::from(root))
 }
 
+/// Get the merge base of `reference` and HEAD (where the current branch forked)
+pub fn get_merge_base(repo_path: &Path, reference: &str) -> Result<String, GitError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("merge-base")
+        .arg(reference)
+        .arg("HEAD")
+        .output()?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Get the remote default branch (`origin/HEAD`), e.g. `origin/main`
+pub fn get_default_branch(repo_path: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("symbolic-ref")
+        .arg("--quiet")
+        .arg("--short")
+        .arg("refs/remotes/origin/HEAD")
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!branch.is_empty()).then_some(branch)
+}
+
+/// Get the parent commit ids of `reference`, first parent first
+pub fn get_commit_parents(repo_path: &Path, reference: &str) -> Result<Vec<String>, GitError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("rev-list")
+        .arg("--parents")
+        .arg("-n")
+        .arg("1")
+        .arg(reference)
+        .arg("--")
+        .output()?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .skip(1)
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// Get the full commit message of `reference`
+pub fn get_commit_message(repo_path: &Path, reference: &str) -> Result<String, GitError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("log")
+        .arg("-1")
+        .arg("--format=%B")
+        .arg(reference)
+        .arg("--")
+        .output()?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
+/// Get the subjects of the commits in `from..to`, oldest first
+pub fn get_range_subjects(repo_path: &Path, from: &str, to: &str) -> Result<Vec<String>, GitError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("log")
+        .arg("--reverse")
+        .arg("--format=%s")
+        .arg(format!("{from}..{to}"))
+        .arg("--")
+        .output()?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// Get the `git notes` attached to `reference`, or `None` when it has none
+pub fn get_notes(repo_path: &Path, reference: &str) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("notes")
+        .arg("show")
+        .arg(reference)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let note = String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string();
+    (!note.is_empty()).then_some(note)
+}
+
+/// Get the notes on the commits in `from..to` as (short hash, note), oldest first
+pub fn get_range_notes(
+    repo_path: &Path,
+    from: &str,
+    to: &str,
+) -> Result<Vec<(String, String)>, GitError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("log")
+        .arg("--reverse")
+        .arg("--format=%h%x1f%N%x1e")
+        .arg(format!("{from}..{to}"))
+        .arg("--")
+        .output()?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    Ok(parse_range_notes(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn parse_range_notes(output: &str) -> Vec<(String, String)> {
+    output
+        .split('\u{1e}')
+        .filter_map(|record| {
+            let (hash, note) = record.split_once('\u{1f}')?;
+            let note = note.trim();
+            (!note.is_empty()).then(|| (hash.trim().to_string(), note.to_string()))
+        })
+        .collect()
+}
+
+/// Get the full hashes of every commit with a `git notes` entry
+pub fn get_noted_commits(repo_path: &Path) -> FxHashSet<String> {
+    let Ok(output) = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("notes")
+        .arg("list")
+        .output()
+    else {
+        return FxHashSet::default();
+    };
+    if !output.status.success() {
+        return FxHashSet::default();
+    }
+    // Each line is `<note blob> <annotated object>`.
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1).map(ToString::to_string))
+        .collect()
+}
+
+/// Check whether `name` is a local or remote-tracking branch
+pub fn is_branch(repo_path: &Path, name: &str) -> bool {
+    ["refs/heads/", "refs/remotes/"].iter().any(|prefix| {
+        Command::new("git")
+            .arg("-C")
+            .arg(repo_path)
+            .arg("show-ref")
+            .arg("--verify")
+            .arg("--quiet")
+            .arg(format!("{prefix}{name}"))
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    })
+}
+
+/// Resolve a ref to its full commit id
+pub fn resolve_commit(repo_path: &Path, reference: &str) -> Result<String, GitError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("rev-parse")
+        .arg("--verify")
+        .arg("--quiet")
+        .arg(format!("{reference}^{{commit}}"))
+        .output()?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(format!(
+            "unknown revision {reference}"
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Drop changes matched by the repo root's `.oyoignore`. Renames are matched
+/// on their new path. An invalid pattern skips only its own line; returns a
+/// warning for it, or for a file that can't be read.
+pub fn filter_oyoignore(repo_root: &Path, changes: &mut Vec<ChangedFile>) -> Vec<String> {
+    let ignore_path = repo_root.join(OYO_IGNORE_FILE);
+    if !ignore_path.is_file() {
+        return Vec::new();
+    }
+    let content = match std::fs::read_to_string(&ignore_path) {
+        Ok(content) => content,
+        Err(err) => return vec![format!("Failed to read {OYO_IGNORE_FILE}: {err}")],
+    };
+    let mut warnings = Vec::new();
+    let mut builder = GitignoreBuilder::new(repo_root);
+    for (idx, line) in content.lines().enumerate() {
+        if let Err(err) = builder.add_line(Some(ignore_path.clone()), line) {
+            warnings.push(format!("{OYO_IGNORE_FILE}:{}: {err}", idx + 1));
+        }
+    }
+    let matcher = match builder.build() {
+        Ok(matcher) => matcher,
+        Err(err) => {
+            warnings.push(format!("{OYO_IGNORE_FILE}: {err}"));
+            return warnings;
+        }
+    };
+    changes.retain(|change| {
+        !matcher
+            .matched_path_or_any_parents(&change.path, false)
+            .is_ignore()
+    });
+    warnings
+}
+
 /// Get list of uncommitted changed files (staged and unstaged)
 pub fn get_uncommitted_changes(repo_path: &Path) -> Result<Vec<ChangedFile>, GitError> {
     let mut changes = Vec::new();
@@ -235,15 +513,35 @@ pub fn get_changes_between_index(
 
 /// Get recent commits with short stats
 pub fn get_recent_commits(repo_path: &Path, limit: usize) -> Result<Vec<CommitEntry>, GitError> {
-    let format = "%H%x1f%h%x1f%P%x1f%an%x1f%at%x1f%s";
+    commit_log(repo_path, limit, None)
+}
+
+/// Get recent commits touching `file` (repo-relative), with stats for that file
+pub fn get_file_commits(
+    repo_path: &Path,
+    file: &Path,
+    limit: usize,
+) -> Result<Vec<CommitEntry>, GitError> {
+    commit_log(repo_path, limit, Some(file))
+}
+
+/// Commits in `from..to` touching `file` (repo-relative), oldest first
+pub fn get_range_file_commits(
+    repo_path: &Path,
+    from: &str,
+    to: &str,
+    file: &Path,
+) -> Result<Vec<CommitEntry>, GitError> {
     let output = Command::new("git")
         .arg("-C")
         .arg(repo_path)
         .arg("log")
-        .arg("-n")
-        .arg(limit.to_string())
-        .arg(format!("--pretty=format:{format}"))
+        .arg("--reverse")
+        .arg(format!("--pretty=format:{COMMIT_LOG_FORMAT}"))
         .arg("--shortstat")
+        .arg(format!("{from}..{to}"))
+        .arg("--")
+        .arg(file)
         .output()?;
 
     if !output.status.success() {
@@ -252,10 +550,50 @@ pub fn get_recent_commits(repo_path: &Path, limit: usize) -> Result<Vec<CommitEn
         ));
     }
 
+    Ok(parse_commit_log(&String::from_utf8_lossy(&output.stdout)))
+}
+
+const COMMIT_LOG_FORMAT: &str = "%H%x1f%h%x1f%P%x1f%an%x1f%at%x1f%s";
+
+fn commit_log(
+    repo_path: &Path,
+    limit: usize,
+    file: Option<&Path>,
+) -> Result<Vec<CommitEntry>, GitError> {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C")
+        .arg(repo_path)
+        .arg("log")
+        .arg("-n")
+        .arg(limit.to_string())
+        .arg(format!("--pretty=format:{COMMIT_LOG_FORMAT}"))
+        .arg("--shortstat");
+    if let Some(file) = file {
+        cmd.arg("--").arg(file);
+    }
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    let mut commits = parse_commit_log(&String::from_utf8_lossy(&output.stdout));
+    let noted = get_noted_commits(repo_path);
+    if !noted.is_empty() {
+        for commit in &mut commits {
+            commit.has_note = noted.contains(&commit.id);
+        }
+    }
+    Ok(commits)
+}
+
+fn parse_commit_log(text: &str) -> Vec<CommitEntry> {
     let mut commits = Vec::new();
     let mut last_idx: Option<usize> = None;
 
-    for line in String::from_utf8_lossy(&output.stdout).lines() {
+    for line in text.lines() {
         let line = line.trim();
         if line.is_empty() {
             continue;
@@ -279,6 +617,7 @@ pub fn get_recent_commits(repo_path: &Path, limit: usize) -> Result<Vec<CommitEn
                 author_time,
                 summary: parts[5].to_string(),
                 stats: None,
+                has_note: false,
             });
             last_idx = Some(commits.len() - 1);
             continue;
@@ -291,7 +630,46 @@ pub fn get_recent_commits(repo_path: &Path, limit: usize) -> Result<Vec<CommitEn
         }
     }
 
-    Ok(commits)
+    commits
+}
+
+/// Get the repository's tags, newest first
+pub fn get_tags(repo_path: &Path) -> Result<Vec<TagEntry>, GitError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("for-each-ref")
+        .arg("--sort=-creatordate")
+        .arg("--format=%(refname:short)%1f%(creatordate:unix)%1f%(subject)")
+        .arg("refs/tags")
+        .output()?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+
+    Ok(parse_tags(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn parse_tags(output: &str) -> Vec<TagEntry> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\u{1f}');
+            let name = parts.next()?.trim();
+            if name.is_empty() {
+                return None;
+            }
+            let time = parts.next().and_then(|time| time.trim().parse().ok());
+            Some(TagEntry {
+                name: name.to_string(),
+                time,
+                summary: parts.next().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
 }
 
 /// Get the content of a file at a specific commit
@@ -333,6 +711,35 @@ pub fn get_file_at_commit_bytes(
     Ok(output.stdout)
 }
 
+/// Read a file's blob at `git_ref`.
+/// Returns `Ok(None)` when the ref exists but the file does not exist there,
+/// and for the empty tree a root commit is diffed against.
+pub fn read_file_at_ref(
+    repo_path: &Path,
+    git_ref: &str,
+    file: &Path,
+) -> Result<Option<Vec<u8>>, GitError> {
+    if git_ref == EMPTY_TREE_HASH {
+        return Ok(None);
+    }
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{}^{{commit}}", git_ref))
+        .output()?;
+    if !output.status.success() {
+        return Err(GitError::CommandFailed(format!(
+            "unknown revision: {}",
+            git_ref
+        )));
+    }
+    if get_file_at_commit_size(repo_path, git_ref, file).is_none() {
+        return Ok(None);
+    }
+    get_file_at_commit_bytes(repo_path, git_ref, file).map(Some)
+}
+
 pub fn get_file_at_commit_size(repo_path: &Path, commit: &str, file: &Path) -> Option<u64> {
     let output = Command::new("git")
         .arg("-C")
@@ -493,4 +900,86 @@ mod tests {
         assert_eq!(changes[1].status, FileStatus::Added);
         assert_eq!(changes[2].status, FileStatus::Deleted);
     }
+
+    #[test]
+    fn test_parse_tags() {
+        let output = "v1.1.0\u{1f}1700000100\u{1f}Release 1.1\nv1.0.0\u{1f}\u{1f}\n";
+        let tags = parse_tags(output);
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name, "v1.1.0");
+        assert_eq!(tags[0].time, Some(1_700_000_100));
+        assert_eq!(tags[0].summary, "Release 1.1");
+        assert_eq!(tags[1].time, None);
+        assert_eq!(tags[1].summary, "");
+    }
+
+    #[test]
+    fn test_parse_commit_log_attaches_shortstat() {
+        let output = "aaaa\u{1f}aa\u{1f}bbbb\u{1f}Ann\u{1f}1700000000\u{1f}Edit lib\n \
+                      1 file changed, 3 insertions(+), 1 deletion(-)\n\
+                      bbbb\u{1f}bb\u{1f}\u{1f}Ann\u{1f}1690000000\u{1f}Initial\n";
+        let commits = parse_commit_log(output);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].parents, ["bbbb"]);
+        assert_eq!(commits[0].summary, "Edit lib");
+        let stats = commits[0].stats.expect("shortstat attached");
+        assert_eq!((stats.insertions, stats.deletions), (3, 1));
+        assert!(commits[1].parents.is_empty());
+        assert!(commits[1].stats.is_none());
+    }
+
+    #[test]
+    fn test_parse_range_notes_skips_commits_without_notes() {
+        let output = "aa\u{1f}\u{1e}\nbb\u{1f}ci: passed\nreviewed\n\u{1e}\n";
+        assert_eq!(
+            parse_range_notes(output),
+            [("bb".to_string(), "ci: passed\nreviewed".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_filter_oyoignore() {
+        let root = std::env::temp_dir().join(format!("oyo-core-oyoignore-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(
+            root.join(OYO_IGNORE_FILE),
+            "*.lock\n!keep.lock\ngenerated/\n",
+        )
+        .unwrap();
+        let output = "M\tCargo.lock\nM\tkeep.lock\nA\tgenerated/api.rs\nM\tsrc/main.rs\n";
+        let mut changes = Vec::new();
+        parse_name_status(output, &mut changes);
+
+        assert!(filter_oyoignore(&root, &mut changes).is_empty());
+        let paths: Vec<_> = changes.iter().map(|c| c.path.clone()).collect();
+        assert_eq!(
+            paths,
+            [PathBuf::from("keep.lock"), PathBuf::from("src/main.rs")]
+        );
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn test_filter_oyoignore_skips_only_invalid_lines() {
+        let root =
+            std::env::temp_dir().join(format!("oyo-core-oyoignore-invalid-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(
+            root.join(OYO_IGNORE_FILE),
+            "*.lock\nsrc/[z-a]\ngenerated/\n",
+        )
+        .unwrap();
+        let output = "M\tCargo.lock\nA\tgenerated/api.rs\nM\tsrc/main.rs\n";
+        let mut changes = Vec::new();
+        parse_name_status(output, &mut changes);
+
+        let warnings = filter_oyoignore(&root, &mut changes);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with(".oyoignore:2: "), "{warnings:?}");
+        let paths: Vec<_> = changes.iter().map(|c| c.path.clone()).collect();
+        assert_eq!(paths, [PathBuf::from("src/main.rs")]);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
 }
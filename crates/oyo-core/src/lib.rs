@@ -5,14 +5,19 @@
 
 pub mod change;
 pub mod diff;
+pub mod encoding;
 pub mod git;
 pub mod multi;
+pub mod patch;
 pub mod step;
+pub mod structural;
 
 pub use change::{Change, ChangeKind, ChangeSpan};
 pub use diff::{DiffEngine, DiffResult, FileDiff, Hunk};
+pub use encoding::TextEncoding;
 pub use git::{ChangedFile, FileStatus};
 pub use multi::{DirectoryScanOptions, FileEntry, MultiFileDiff};
+pub use patch::{parse_patch, PatchFile};
 pub use step::{
     AnimationFrame, DiffNavigator, LineKind, StepDirection, StepState, ViewLine, ViewSpan,
     ViewSpanKind,
@@ -2,13 +2,17 @@
 
 use crate::change::{Change, ChangeSpan};
 use crate::diff::{DiffEngine, DiffResult};
-use crate::git::{ChangedFile, FileStatus};
+use crate::encoding::{decode_text, TextEncoding};
+use crate::git::{ChangedFile, CommitEntry, FileStatus};
 use crate::step::{DiffNavigator, StepDirection};
 use ignore::overrides::OverrideBuilder;
 use ignore::WalkBuilder;
+use regex::Regex;
+use std::fs::File;
+use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -31,9 +35,16 @@ pub struct FileEntry {
     pub insertions: usize,
     pub deletions: usize,
     pub binary: bool,
+    /// Detected encoding when either side isn't plain UTF-8
+    pub encoding: Option<TextEncoding>,
+    /// Sides hold the structural rendering (see [`crate::structural`]), so
+    /// line numbers don't match the file on disk
+    pub structural: bool,
+    /// Why a side couldn't be read from disk (the side is left empty)
+    pub read_error: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FileSide {
     Old,
     New,
@@ -70,6 +81,9 @@ pub struct MultiFileDiff {
     precomputed_diffs: Vec<Option<PrecomputedDiff>>,
     /// Diff readiness state per file
     diff_statuses: Vec<DiffStatus>,
+    /// Old/new byte sizes of files whose contents haven't been read yet
+    /// (lazy directory scans); `None` once loaded.
+    unloaded: Vec<Option<(u64, u64)>>,
 }
 
 #[derive(Debug, Clone)]
@@ -105,9 +119,15 @@ enum PrecomputedDiff {
 
 const DEFAULT_DIFF_MAX_BYTES: u64 = 16 * 1024 * 1024;
 const DEFAULT_FULL_CONTEXT_MAX_BYTES: u64 = 2 * 1024 * 1024;
+pub const DEFAULT_LAZY_LOAD_BYTES: u64 = 256 * 1024 * 1024;
 static DIFF_MAX_BYTES: AtomicU64 = AtomicU64::new(DEFAULT_DIFF_MAX_BYTES);
 static FULL_CONTEXT_MAX_BYTES: AtomicU64 = AtomicU64::new(DEFAULT_FULL_CONTEXT_MAX_BYTES);
 static DIFF_DEFER: AtomicBool = AtomicBool::new(true);
+static COLOR_MOVED: AtomicBool = AtomicBool::new(false);
+static STRUCTURAL_DIFF: AtomicBool = AtomicBool::new(false);
+static GROUP_ADJACENT: AtomicBool = AtomicBool::new(false);
+static GROUP_ADJACENT_GAP: AtomicUsize = AtomicUsize::new(0);
+static IGNORE_MATCHING: RwLock<Option<Regex>> = RwLock::new(None);
 
 pub const DEFAULT_SCAN_IGNORE_GLOBS: &[&str] = &[".git/**", ".jj/**", ".hg/**", ".svn/**"];
 
@@ -115,6 +135,14 @@ pub const DEFAULT_SCAN_IGNORE_GLOBS: &[&str] = &[".git/**", ".jj/**", ".hg/**",
 pub struct DirectoryScanOptions {
     pub git_ignore: bool,
     pub ignore_globs: Vec<String>,
+    /// Respect `.oyoignore` files in the scanned directories
+    pub oyo_ignore: bool,
+    /// Deepest directory level to descend into (`None` = unlimited). Symlinks
+    /// are never followed, so cycles can't recurse.
+    pub max_depth: Option<usize>,
+    /// Once the scanned files total more than this many bytes, read each
+    /// file's contents only when it's first selected (`None` = always eager).
+    pub lazy_load_bytes: Option<u64>,
 }
 
 impl Default for DirectoryScanOptions {
@@ -125,6 +153,9 @@ impl Default for DirectoryScanOptions {
                 .iter()
                 .map(|pattern| (*pattern).to_string())
                 .collect(),
+            oyo_ignore: true,
+            max_depth: None,
+            lazy_load_bytes: Some(DEFAULT_LAZY_LOAD_BYTES),
         }
     }
 }
@@ -148,6 +179,38 @@ impl MultiFileDiff {
         DIFF_DEFER.store(enabled, Ordering::Relaxed);
     }
 
+    /// Mark moved blocks in newly computed diffs
+    pub fn set_color_moved(enabled: bool) {
+        COLOR_MOVED.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Diff JSON/YAML files by their parsed structure in newly loaded files
+    /// (see [`crate::structural`])
+    pub fn set_structural_diff(enabled: bool) {
+        STRUCTURAL_DIFF.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Merge hunks separated by at most `max_gap` non-blank context lines in
+    /// newly computed diffs (`None` keeps the usual hunk boundaries)
+    pub fn set_group_adjacent(max_gap: Option<usize>) {
+        GROUP_ADJACENT.store(max_gap.is_some(), Ordering::Relaxed);
+        GROUP_ADJACENT_GAP.store(max_gap.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    /// Ignore matches of `regex` when comparing lines in newly computed diffs
+    pub fn set_ignore_matching(regex: Option<Regex>) {
+        *IGNORE_MATCHING
+            .write()
+            .unwrap_or_else(|err| err.into_inner()) = regex;
+    }
+
+    fn ignore_matching() -> Option<Regex> {
+        IGNORE_MATCHING
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
     fn diff_max_bytes() -> u64 {
         DIFF_MAX_BYTES.load(Ordering::Relaxed)
     }
@@ -160,35 +223,81 @@ impl MultiFileDiff {
         DIFF_DEFER.load(Ordering::Relaxed)
     }
 
-    fn decode_bytes(bytes: Vec<u8>) -> (String, bool) {
+    fn color_moved_enabled() -> bool {
+        COLOR_MOVED.load(Ordering::Relaxed)
+    }
+
+    fn structural_diff_enabled() -> bool {
+        STRUCTURAL_DIFF.load(Ordering::Relaxed)
+    }
+
+    /// Swap both sides for their structural rendering when enabled and both
+    /// parse; otherwise the text stays as loaded. The flag says which.
+    fn structural_contents(
+        path: &Path,
+        old_content: String,
+        new_content: String,
+        binary: bool,
+    ) -> (String, String, bool) {
+        if binary || !Self::structural_diff_enabled() {
+            return (old_content, new_content, false);
+        }
+        match crate::structural::structural_diff(path, &old_content, &new_content) {
+            Some(diff) => (diff.old_text, diff.new_text, true),
+            None => (old_content, new_content, false),
+        }
+    }
+
+    fn group_adjacent_gap() -> Option<usize> {
+        GROUP_ADJACENT
+            .load(Ordering::Relaxed)
+            .then(|| GROUP_ADJACENT_GAP.load(Ordering::Relaxed))
+    }
+
+    /// Decoded text, whether the bytes are binary, and the detected encoding
+    /// when it isn't plain UTF-8.
+    fn decode_bytes(bytes: Vec<u8>) -> (String, bool, Option<TextEncoding>) {
         if bytes.is_empty() {
-            return (String::new(), false);
+            return (String::new(), false, None);
         }
-        if bytes.contains(&0) || std::str::from_utf8(&bytes).is_err() {
-            return (String::new(), true);
+        match decode_text(&bytes) {
+            Some((text, encoding)) => (Self::normalize_text(text), false, encoding),
+            None => (String::new(), true, None),
         }
-        let text = String::from_utf8_lossy(&bytes).to_string();
-        (Self::normalize_text(text), false)
     }
 
     fn text_too_large(size: u64) -> bool {
         size > Self::MAX_TEXT_BYTES
     }
 
-    fn read_text_or_binary(path: &Path) -> (String, bool) {
+    /// Read a file from disk. A failed read leaves the side empty and keeps
+    /// the first failure's reason in `error`.
+    fn read_text_or_binary(
+        path: &Path,
+        error: &mut Option<String>,
+    ) -> (String, bool, Option<TextEncoding>) {
         if let Ok(metadata) = path.metadata() {
             if Self::text_too_large(metadata.len()) {
-                return (String::new(), true);
+                return (String::new(), true, None);
+            }
+        }
+        match std::fs::read(path) {
+            Ok(bytes) => Self::decode_bytes(bytes),
+            Err(err) => {
+                error.get_or_insert_with(|| err.to_string());
+                (String::new(), false, None)
             }
         }
-        let bytes = std::fs::read(path).unwrap_or_default();
-        Self::decode_bytes(bytes)
     }
 
-    fn read_git_commit_or_binary(repo_root: &Path, commit: &str, path: &Path) -> (String, bool) {
+    fn read_git_commit_or_binary(
+        repo_root: &Path,
+        commit: &str,
+        path: &Path,
+    ) -> (String, bool, Option<TextEncoding>) {
         if let Some(size) = crate::git::get_file_at_commit_size(repo_root, commit, path) {
             if Self::text_too_large(size) {
-                return (String::new(), true);
+                return (String::new(), true, None);
             }
         }
         let bytes =
@@ -196,10 +305,13 @@ impl MultiFileDiff {
         Self::decode_bytes(bytes)
     }
 
-    fn read_git_index_or_binary(repo_root: &Path, path: &Path) -> (String, bool) {
+    fn read_git_index_or_binary(
+        repo_root: &Path,
+        path: &Path,
+    ) -> (String, bool, Option<TextEncoding>) {
         if let Some(size) = crate::git::get_staged_content_size(repo_root, path) {
             if Self::text_too_large(size) {
-                return (String::new(), true);
+                return (String::new(), true, None);
             }
         }
         let bytes = crate::git::get_staged_content_bytes(repo_root, path).unwrap_or_default();
@@ -218,6 +330,9 @@ impl MultiFileDiff {
         DiffEngine::new()
             .with_word_level(word_level)
             .with_context(context_lines)
+            .with_moved_detection(Self::color_moved_enabled())
+            .with_ignore_matching(Self::ignore_matching())
+            .with_adjacent_grouping(Self::group_adjacent_gap())
             .diff_strings(old, new)
     }
 
@@ -244,6 +359,7 @@ impl MultiFileDiff {
             hunks: Vec::new(),
             insertions: 0,
             deletions: 0,
+            moved_changes: Vec::new(),
         }
     }
 
@@ -337,21 +453,24 @@ impl MultiFileDiff {
         let mut precomputed_diffs = Vec::new();
         let mut diff_statuses = Vec::new();
         for change in changes {
+            let mut read_error = None;
             // Get old and new content
-            let (old_content, old_binary) = match change.status {
-                FileStatus::Added | FileStatus::Untracked => (String::new(), false),
+            let (old_content, old_binary, old_encoding) = match change.status {
+                FileStatus::Added | FileStatus::Untracked => (String::new(), false, None),
                 _ => Self::read_git_commit_or_binary(&repo_root, "HEAD", &change.path),
             };
 
-            let (new_content, new_binary) = match change.status {
-                FileStatus::Deleted => (String::new(), false),
+            let (new_content, new_binary, new_encoding) = match change.status {
+                FileStatus::Deleted => (String::new(), false, None),
                 _ => {
                     let full_path = repo_root.join(&change.path);
-                    Self::read_text_or_binary(&full_path)
+                    Self::read_text_or_binary(&full_path, &mut read_error)
                 }
             };
 
             let binary = old_binary || new_binary;
+            let (old_content, new_content, structural) =
+                Self::structural_contents(&change.path, old_content, new_content, binary);
             let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
             let (old_content, new_content, precomputed, diff_status) =
                 Self::maybe_defer_diff(old_content, new_content, binary);
@@ -366,6 +485,9 @@ impl MultiFileDiff {
                 insertions,
                 deletions,
                 binary,
+                encoding: new_encoding.or(old_encoding),
+                structural,
+                read_error,
             });
 
             old_contents.push(Arc::from(old_content));
@@ -388,6 +510,7 @@ impl MultiFileDiff {
             old_contents,
             new_contents,
             precomputed_diffs,
+            unloaded: vec![None; diff_statuses.len()],
             diff_statuses,
         })
     }
@@ -407,17 +530,19 @@ impl MultiFileDiff {
                 .old_path
                 .clone()
                 .unwrap_or_else(|| change.path.clone());
-            let (old_content, old_binary) = match change.status {
-                FileStatus::Added | FileStatus::Untracked => (String::new(), false),
+            let (old_content, old_binary, old_encoding) = match change.status {
+                FileStatus::Added | FileStatus::Untracked => (String::new(), false, None),
                 _ => Self::read_git_commit_or_binary(&repo_root, "HEAD", &old_path),
             };
 
-            let (new_content, new_binary) = match change.status {
-                FileStatus::Deleted => (String::new(), false),
+            let (new_content, new_binary, new_encoding) = match change.status {
+                FileStatus::Deleted => (String::new(), false, None),
                 _ => Self::read_git_index_or_binary(&repo_root, &change.path),
             };
 
             let binary = old_binary || new_binary;
+            let (old_content, new_content, structural) =
+                Self::structural_contents(&change.path, old_content, new_content, binary);
             let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
             let (old_content, new_content, precomputed, diff_status) =
                 Self::maybe_defer_diff(old_content, new_content, binary);
@@ -432,6 +557,9 @@ impl MultiFileDiff {
                 insertions,
                 deletions,
                 binary,
+                encoding: new_encoding.or(old_encoding),
+                structural,
+                read_error: None,
             });
 
             old_contents.push(Arc::from(old_content));
@@ -454,6 +582,7 @@ impl MultiFileDiff {
             old_contents,
             new_contents,
             precomputed_diffs,
+            unloaded: vec![None; diff_statuses.len()],
             diff_statuses,
         })
     }
@@ -475,29 +604,46 @@ impl MultiFileDiff {
                 .old_path
                 .clone()
                 .unwrap_or_else(|| change.path.clone());
-            let (old_content, old_binary, new_content, new_binary) = if to_index {
-                let (old_content, old_binary) = match change.status {
-                    FileStatus::Added | FileStatus::Untracked => (String::new(), false),
-                    _ => Self::read_git_commit_or_binary(&repo_root, &from, &old_path),
-                };
-                let (new_content, new_binary) = match change.status {
-                    FileStatus::Deleted => (String::new(), false),
-                    _ => Self::read_git_index_or_binary(&repo_root, &change.path),
-                };
-                (old_content, old_binary, new_content, new_binary)
-            } else {
-                let (old_content, old_binary) = match change.status {
-                    FileStatus::Added | FileStatus::Untracked => (String::new(), false),
-                    _ => Self::read_git_index_or_binary(&repo_root, &old_path),
-                };
-                let (new_content, new_binary) = match change.status {
-                    FileStatus::Deleted => (String::new(), false),
-                    _ => Self::read_git_commit_or_binary(&repo_root, &from, &change.path),
+            let (old_content, old_binary, old_encoding, new_content, new_binary, new_encoding) =
+                if to_index {
+                    let (old_content, old_binary, old_encoding) = match change.status {
+                        FileStatus::Added | FileStatus::Untracked => (String::new(), false, None),
+                        _ => Self::read_git_commit_or_binary(&repo_root, &from, &old_path),
+                    };
+                    let (new_content, new_binary, new_encoding) = match change.status {
+                        FileStatus::Deleted => (String::new(), false, None),
+                        _ => Self::read_git_index_or_binary(&repo_root, &change.path),
+                    };
+                    (
+                        old_content,
+                        old_binary,
+                        old_encoding,
+                        new_content,
+                        new_binary,
+                        new_encoding,
+                    )
+                } else {
+                    let (old_content, old_binary, old_encoding) = match change.status {
+                        FileStatus::Added | FileStatus::Untracked => (String::new(), false, None),
+                        _ => Self::read_git_index_or_binary(&repo_root, &old_path),
+                    };
+                    let (new_content, new_binary, new_encoding) = match change.status {
+                        FileStatus::Deleted => (String::new(), false, None),
+                        _ => Self::read_git_commit_or_binary(&repo_root, &from, &change.path),
+                    };
+                    (
+                        old_content,
+                        old_binary,
+                        old_encoding,
+                        new_content,
+                        new_binary,
+                        new_encoding,
+                    )
                 };
-                (old_content, old_binary, new_content, new_binary)
-            };
 
             let binary = old_binary || new_binary;
+            let (old_content, new_content, structural) =
+                Self::structural_contents(&change.path, old_content, new_content, binary);
             let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
             let (old_content, new_content, precomputed, diff_status) =
                 Self::maybe_defer_diff(old_content, new_content, binary);
@@ -512,6 +658,9 @@ impl MultiFileDiff {
                 insertions,
                 deletions,
                 binary,
+                encoding: new_encoding.or(old_encoding),
+                structural,
+                read_error: None,
             });
 
             old_contents.push(Arc::from(old_content));
@@ -534,6 +683,7 @@ impl MultiFileDiff {
             old_contents,
             new_contents,
             precomputed_diffs,
+            unloaded: vec![None; diff_statuses.len()],
             diff_statuses,
         })
     }
@@ -555,17 +705,19 @@ impl MultiFileDiff {
                 .old_path
                 .clone()
                 .unwrap_or_else(|| change.path.clone());
-            let (old_content, old_binary) = match change.status {
-                FileStatus::Added | FileStatus::Untracked => (String::new(), false),
+            let (old_content, old_binary, old_encoding) = match change.status {
+                FileStatus::Added | FileStatus::Untracked => (String::new(), false, None),
                 _ => Self::read_git_commit_or_binary(&repo_root, &from, &old_path),
             };
 
-            let (new_content, new_binary) = match change.status {
-                FileStatus::Deleted => (String::new(), false),
+            let (new_content, new_binary, new_encoding) = match change.status {
+                FileStatus::Deleted => (String::new(), false, None),
                 _ => Self::read_git_commit_or_binary(&repo_root, &to, &change.path),
             };
 
             let binary = old_binary || new_binary;
+            let (old_content, new_content, structural) =
+                Self::structural_contents(&change.path, old_content, new_content, binary);
             let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
             let (old_content, new_content, precomputed, diff_status) =
                 Self::maybe_defer_diff(old_content, new_content, binary);
@@ -580,6 +732,9 @@ impl MultiFileDiff {
                 insertions,
                 deletions,
                 binary,
+                encoding: new_encoding.or(old_encoding),
+                structural,
+                read_error: None,
             });
 
             old_contents.push(Arc::from(old_content));
@@ -602,10 +757,86 @@ impl MultiFileDiff {
             old_contents,
             new_contents,
             precomputed_diffs,
+            unloaded: vec![None; diff_statuses.len()],
             diff_statuses,
         })
     }
 
+    /// Create a single-file diff of `path` between two git refs.
+    /// A file missing at one ref shows as added/deleted.
+    pub fn from_git_refs(
+        repo_root: PathBuf,
+        path: PathBuf,
+        old_ref: String,
+        new_ref: String,
+    ) -> Result<Self, MultiDiffError> {
+        let old_bytes = crate::git::read_file_at_ref(&repo_root, &old_ref, &path)?;
+        let new_bytes = crate::git::read_file_at_ref(&repo_root, &new_ref, &path)?;
+        let status = match (&old_bytes, &new_bytes) {
+            (None, None) => {
+                return Err(crate::git::GitError::CommandFailed(format!(
+                    "{} not found at {} or {}",
+                    path.display(),
+                    old_ref,
+                    new_ref
+                ))
+                .into());
+            }
+            (None, Some(_)) => FileStatus::Added,
+            (Some(_), None) => FileStatus::Deleted,
+            (Some(_), Some(_)) => FileStatus::Modified,
+        };
+        let decode = |bytes: Option<Vec<u8>>| {
+            let bytes = bytes.unwrap_or_default();
+            if Self::text_too_large(bytes.len() as u64) {
+                (String::new(), true, None)
+            } else {
+                Self::decode_bytes(bytes)
+            }
+        };
+        let (old_content, old_binary, old_encoding) = decode(old_bytes);
+        let (new_content, new_binary, new_encoding) = decode(new_bytes);
+        let binary = old_binary || new_binary;
+        let (old_content, new_content, structural) =
+            Self::structural_contents(&path, old_content, new_content, binary);
+        let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
+        let (old_content, new_content, precomputed, diff_status) =
+            Self::maybe_defer_diff(old_content, new_content, binary);
+
+        let files = vec![FileEntry {
+            display_name: path.display().to_string(),
+            path,
+            old_path: None,
+            old_source_path: None,
+            new_source_path: None,
+            status,
+            insertions,
+            deletions,
+            binary,
+            encoding: new_encoding.or(old_encoding),
+            structural,
+            read_error: None,
+        }];
+
+        Ok(Self {
+            files,
+            selected_index: 0,
+            navigators: vec![None],
+            navigator_is_placeholder: vec![false],
+            repo_root: Some(repo_root),
+            git_mode: Some(GitDiffMode::Range {
+                from: old_ref,
+                to: new_ref,
+            }),
+            source_roots: None,
+            old_contents: vec![Arc::from(old_content)],
+            new_contents: vec![Arc::from(new_content)],
+            precomputed_diffs: vec![precomputed],
+            diff_statuses: vec![diff_status],
+            unloaded: vec![None],
+        })
+    }
+
     /// Create from two directories
     pub fn from_directories(old_dir: &Path, new_dir: &Path) -> Result<Self, MultiDiffError> {
         Self::from_directories_with_options(old_dir, new_dir, &DirectoryScanOptions::default())
@@ -622,18 +853,42 @@ impl MultiFileDiff {
         let mut precomputed_diffs = Vec::new();
         let mut diff_statuses = Vec::new();
         // Collect all files from both directories
-        let mut all_files = std::collections::HashSet::new();
+        let mut old_files = std::collections::HashSet::new();
+        let mut new_files = std::collections::HashSet::new();
 
         if old_dir.is_dir() {
-            collect_files(old_dir, old_dir, &mut all_files, scan_options)?;
+            collect_files(old_dir, old_dir, &mut old_files, scan_options)?;
         }
         if new_dir.is_dir() {
-            collect_files(new_dir, new_dir, &mut all_files, scan_options)?;
+            collect_files(new_dir, new_dir, &mut new_files, scan_options)?;
         }
 
-        let mut all_files: Vec<_> = all_files.into_iter().collect();
+        // A path ignored by either side's rules is dropped from both, so an
+        // ignore file present in only one directory doesn't turn its matches
+        // into spurious adds or deletes.
+        let mut all_files: Vec<_> = old_files
+            .union(&new_files)
+            .filter(|rel| {
+                let old_ok = old_files.contains(*rel) || !old_dir.join(rel).is_file();
+                let new_ok = new_files.contains(*rel) || !new_dir.join(rel).is_file();
+                old_ok && new_ok
+            })
+            .cloned()
+            .collect();
         all_files.sort();
 
+        // Past the budget only metadata is read up front; contents and stats
+        // follow when a file is first selected.
+        let side_len = |path: &Path| path.metadata().map(|meta| meta.len()).unwrap_or(0);
+        let lazy = scan_options.lazy_load_bytes.is_some_and(|limit| {
+            let total: u64 = all_files
+                .iter()
+                .map(|rel| side_len(&old_dir.join(rel)) + side_len(&new_dir.join(rel)))
+                .sum();
+            total > limit
+        });
+        let mut unloaded = Vec::new();
+
         for rel_path in all_files {
             let old_path = old_dir.join(&rel_path);
             let new_path = new_dir.join(&rel_path);
@@ -649,43 +904,50 @@ impl MultiFileDiff {
                 FileStatus::Modified
             };
 
-            let (old_content, old_binary, old_bytes) = if old_exists {
-                if let Ok(metadata) = old_path.metadata() {
-                    if Self::text_too_large(metadata.len()) {
-                        (String::new(), true, Vec::new())
-                    } else {
-                        let bytes = std::fs::read(&old_path).unwrap_or_default();
-                        let (content, binary) = Self::decode_bytes(bytes.clone());
-                        (content, binary, bytes)
-                    }
-                } else {
-                    (String::new(), false, Vec::new())
-                }
+            // Both scan modes list exactly the files whose bytes differ.
+            let (old_len, new_len) = (side_len(&old_path), side_len(&new_path));
+            if !Self::sides_differ(&old_path, &new_path, old_len, new_len) {
+                continue;
+            }
+
+            if lazy {
+                files.push(FileEntry {
+                    display_name: rel_path.display().to_string(),
+                    path: rel_path,
+                    old_path: None,
+                    old_source_path: None,
+                    new_source_path: None,
+                    status,
+                    insertions: 0,
+                    deletions: 0,
+                    binary: false,
+                    encoding: None,
+                    structural: false,
+                    read_error: None,
+                });
+                old_contents.push(Arc::from(""));
+                new_contents.push(Arc::from(""));
+                precomputed_diffs.push(None);
+                diff_statuses.push(DiffStatus::Ready);
+                unloaded.push(Some((old_len, new_len)));
+                continue;
+            }
+
+            let mut read_error = None;
+            let (old_content, old_binary, old_encoding) = if old_exists {
+                Self::read_text_or_binary(&old_path, &mut read_error)
             } else {
-                (String::new(), false, Vec::new())
+                (String::new(), false, None)
             };
-            let (new_content, new_binary, new_bytes) = if new_exists {
-                if let Ok(metadata) = new_path.metadata() {
-                    if Self::text_too_large(metadata.len()) {
-                        (String::new(), true, Vec::new())
-                    } else {
-                        let bytes = std::fs::read(&new_path).unwrap_or_default();
-                        let (content, binary) = Self::decode_bytes(bytes.clone());
-                        (content, binary, bytes)
-                    }
-                } else {
-                    (String::new(), false, Vec::new())
-                }
+            let (new_content, new_binary, new_encoding) = if new_exists {
+                Self::read_text_or_binary(&new_path, &mut read_error)
             } else {
-                (String::new(), false, Vec::new())
+                (String::new(), false, None)
             };
             let binary = old_binary || new_binary;
 
-            // Skip if no changes
-            if !binary && old_bytes == new_bytes {
-                continue;
-            }
-
+            let (old_content, new_content, structural) =
+                Self::structural_contents(&rel_path, old_content, new_content, binary);
             let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
             let (old_content, new_content, precomputed, diff_status) =
                 Self::maybe_defer_diff(old_content, new_content, binary);
@@ -700,18 +962,22 @@ impl MultiFileDiff {
                 insertions,
                 deletions,
                 binary,
+                encoding: new_encoding.or(old_encoding),
+                structural,
+                read_error,
             });
 
             old_contents.push(Arc::from(old_content));
             new_contents.push(Arc::from(new_content));
             precomputed_diffs.push(precomputed);
             diff_statuses.push(diff_status);
+            unloaded.push(None);
         }
 
         let navigators: Vec<Option<DiffNavigator>> = (0..files.len()).map(|_| None).collect();
         let navigator_is_placeholder = vec![false; files.len()];
 
-        Ok(Self {
+        let mut diff = Self {
             files,
             selected_index: 0,
             navigators,
@@ -726,7 +992,39 @@ impl MultiFileDiff {
             new_contents,
             precomputed_diffs,
             diff_statuses,
-        })
+            unloaded,
+        };
+        diff.ensure_loaded(0);
+        Ok(diff)
+    }
+
+    /// Whether two on-disk sides differ: sizes first, then the bytes are
+    /// streamed only when the sizes match. Missing sides count as empty, and
+    /// sides too large to load always count as changed.
+    fn sides_differ(old_path: &Path, new_path: &Path, old_len: u64, new_len: u64) -> bool {
+        if old_len != new_len || Self::text_too_large(old_len) {
+            return true;
+        }
+        if old_len == 0 {
+            return false;
+        }
+        let (Ok(old), Ok(new)) = (File::open(old_path), File::open(new_path)) else {
+            return true;
+        };
+        let mut old = BufReader::new(old);
+        let mut new = BufReader::new(new);
+        let mut old_buf = vec![0u8; 64 * 1024];
+        let mut new_buf = vec![0u8; 64 * 1024];
+        loop {
+            let read = match old.read(&mut old_buf) {
+                Ok(0) => return false,
+                Ok(read) => read,
+                Err(_) => return true,
+            };
+            if new.read_exact(&mut new_buf[..read]).is_err() || old_buf[..read] != new_buf[..read] {
+                return true;
+            }
+        }
     }
 
     /// Create from a single file pair
@@ -757,9 +1055,11 @@ impl MultiFileDiff {
         old_source: Option<PathBuf>,
         new_source: Option<PathBuf>,
     ) -> Self {
-        let (old_content, old_binary) = Self::decode_bytes(old_bytes);
-        let (new_content, new_binary) = Self::decode_bytes(new_bytes);
+        let (old_content, old_binary, old_encoding) = Self::decode_bytes(old_bytes);
+        let (new_content, new_binary, new_encoding) = Self::decode_bytes(new_bytes);
         let binary = old_binary || new_binary;
+        let (old_content, new_content, structural) =
+            Self::structural_contents(&new_path, old_content, new_content, binary);
         let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
         let (old_content, new_content, precomputed, diff_status) =
             Self::maybe_defer_diff(old_content, new_content, binary);
@@ -774,6 +1074,9 @@ impl MultiFileDiff {
             insertions,
             deletions,
             binary,
+            encoding: new_encoding.or(old_encoding),
+            structural,
+            read_error: None,
         }];
 
         Self {
@@ -788,6 +1091,7 @@ impl MultiFileDiff {
             new_contents: vec![Arc::from(new_content)],
             precomputed_diffs: vec![precomputed],
             diff_statuses: vec![diff_status],
+            unloaded: vec![None],
         }
     }
 
@@ -800,9 +1104,13 @@ impl MultiFileDiff {
         let mut diff_statuses = Vec::with_capacity(pairs.len());
 
         for (path, old_content, new_content) in pairs {
-            let (old_content, old_binary) = Self::decode_bytes(old_content.into_bytes());
-            let (new_content, new_binary) = Self::decode_bytes(new_content.into_bytes());
+            let (old_content, old_binary, old_encoding) =
+                Self::decode_bytes(old_content.into_bytes());
+            let (new_content, new_binary, new_encoding) =
+                Self::decode_bytes(new_content.into_bytes());
             let binary = old_binary || new_binary;
+            let (old_content, new_content, structural) =
+                Self::structural_contents(&path, old_content, new_content, binary);
             let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
             let (old_content, new_content, precomputed, diff_status) =
                 Self::maybe_defer_diff(old_content, new_content, binary);
@@ -816,6 +1124,9 @@ impl MultiFileDiff {
                 insertions,
                 deletions,
                 binary,
+                encoding: new_encoding.or(old_encoding),
+                structural,
+                read_error: None,
             });
             old_contents.push(Arc::from(old_content));
             new_contents.push(Arc::from(new_content));
@@ -834,12 +1145,65 @@ impl MultiFileDiff {
             old_contents,
             new_contents,
             precomputed_diffs,
+            unloaded: vec![None; diff_statuses.len()],
             diff_statuses,
         }
     }
 
+    /// One entry per commit that touched `file`, each diffing the file
+    /// against its first parent, so the file panel steps through the commits
+    /// in order.
+    pub fn from_file_commits(repo_root: &Path, file: &Path, commits: &[CommitEntry]) -> Self {
+        let mut pairs = Vec::with_capacity(commits.len());
+        let mut statuses = Vec::with_capacity(commits.len());
+        for commit in commits {
+            let old = crate::git::get_file_at_commit(repo_root, &format!("{}^", commit.id), file);
+            let new = crate::git::get_file_at_commit(repo_root, &commit.id, file);
+            statuses.push(match (&old, &new) {
+                (Err(_), _) => FileStatus::Added,
+                (_, Err(_)) => FileStatus::Deleted,
+                _ => FileStatus::Modified,
+            });
+            pairs.push((
+                file.to_path_buf(),
+                old.unwrap_or_default(),
+                new.unwrap_or_default(),
+            ));
+        }
+        let mut diff = Self::from_file_pairs(pairs);
+        for ((entry, commit), status) in diff.files.iter_mut().zip(commits).zip(statuses) {
+            // The file panel splits names on '/', so keep subjects in one piece.
+            entry.display_name = format!(
+                "{} {}",
+                commit.short_id,
+                commit.summary.replace('/', "\u{2215}")
+            );
+            entry.status = status;
+        }
+        diff
+    }
+
+    /// Create from a unified diff. Lines outside the patch's hunks are blank
+    /// (see [`crate::patch::parse_patch`]).
+    pub fn from_patch(text: &str) -> Self {
+        let mut pairs = Vec::new();
+        let mut meta = Vec::new();
+        for file in crate::patch::parse_patch(text) {
+            pairs.push((file.path, file.old_content, file.new_content));
+            meta.push((file.old_path, file.status, file.binary));
+        }
+        let mut diff = Self::from_file_pairs(pairs);
+        for (entry, (old_path, status, binary)) in diff.files.iter_mut().zip(meta) {
+            entry.old_path = old_path;
+            entry.status = status;
+            entry.binary |= binary;
+        }
+        diff
+    }
+
     /// Get the navigator for the currently selected file
     pub fn current_navigator(&mut self) -> &mut DiffNavigator {
+        self.ensure_loaded(self.selected_index);
         if self.navigators[self.selected_index].is_none() {
             let mut placeholder = false;
             let lazy_maps = self.file_is_large(self.selected_index);
@@ -875,6 +1239,11 @@ impl MultiFileDiff {
         self.navigators[self.selected_index].as_mut().unwrap()
     }
 
+    /// Get the navigator for a file, if it has been built
+    pub fn navigator(&mut self, idx: usize) -> Option<&mut DiffNavigator> {
+        self.navigators.get_mut(idx).and_then(|nav| nav.as_mut())
+    }
+
     /// Get the current file entry
     pub fn current_file(&self) -> Option<&FileEntry> {
         self.files.get(self.selected_index)
@@ -940,6 +1309,59 @@ impl MultiFileDiff {
         path.is_file().then_some(path)
     }
 
+    /// Re-read the raw old/new bytes of a file (used for binary previews).
+    /// Returns None when a side exceeds `max_bytes` or has no readable source.
+    pub fn file_bytes(&self, idx: usize, max_bytes: u64) -> Option<(Vec<u8>, Vec<u8>)> {
+        let file = self.files.get(idx)?;
+        let old = match file.status {
+            FileStatus::Added | FileStatus::Untracked => Vec::new(),
+            _ => self.side_bytes(idx, FileSide::Old, max_bytes)?,
+        };
+        let new = match file.status {
+            FileStatus::Deleted => Vec::new(),
+            _ => self.side_bytes(idx, FileSide::New, max_bytes)?,
+        };
+        Some((old, new))
+    }
+
+    fn side_bytes(&self, idx: usize, side: FileSide, max_bytes: u64) -> Option<Vec<u8>> {
+        let file = self.files.get(idx)?;
+        if let (Some(repo_root), Some((old_source, new_source))) =
+            (self.repo_root.as_ref(), self.blame_sources())
+        {
+            let (source, path) = match side {
+                FileSide::Old => (old_source, file.old_path.as_ref().unwrap_or(&file.path)),
+                FileSide::New => (new_source, &file.path),
+            };
+            return match source {
+                BlameSource::Worktree => Self::read_path_bytes(&repo_root.join(path), max_bytes),
+                BlameSource::Index => {
+                    let size = crate::git::get_staged_content_size(repo_root, path)?;
+                    if size > max_bytes {
+                        return None;
+                    }
+                    crate::git::get_staged_content_bytes(repo_root, path).ok()
+                }
+                BlameSource::Commit(commit) => {
+                    let size = crate::git::get_file_at_commit_size(repo_root, &commit, path)?;
+                    if size > max_bytes {
+                        return None;
+                    }
+                    crate::git::get_file_at_commit_bytes(repo_root, &commit, path).ok()
+                }
+            };
+        }
+        let path = self.existing_source_path(idx, side)?;
+        Self::read_path_bytes(&path, max_bytes)
+    }
+
+    fn read_path_bytes(path: &Path, max_bytes: u64) -> Option<Vec<u8>> {
+        if path.metadata().ok()?.len() > max_bytes {
+            return None;
+        }
+        std::fs::read(path).ok()
+    }
+
     /// Check if the current file is binary
     pub fn current_file_is_binary(&self) -> bool {
         self.files
@@ -1017,6 +1439,7 @@ impl MultiFileDiff {
     }
 
     pub fn ensure_full_navigator(&mut self, idx: usize) {
+        self.ensure_loaded(idx);
         if !matches!(self.diff_status(idx), DiffStatus::Ready) {
             return;
         }
@@ -1062,6 +1485,7 @@ impl MultiFileDiff {
     pub fn next_file(&mut self) -> bool {
         if self.selected_index < self.files.len().saturating_sub(1) {
             self.selected_index += 1;
+            self.ensure_loaded(self.selected_index);
             true
         } else {
             false
@@ -1072,6 +1496,7 @@ impl MultiFileDiff {
     pub fn prev_file(&mut self) -> bool {
         if self.selected_index > 0 {
             self.selected_index -= 1;
+            self.ensure_loaded(self.selected_index);
             true
         } else {
             false
@@ -1082,6 +1507,7 @@ impl MultiFileDiff {
     pub fn select_file(&mut self, index: usize) {
         if index < self.files.len() {
             self.selected_index = index;
+            self.ensure_loaded(index);
         }
     }
 
@@ -1100,6 +1526,14 @@ impl MultiFileDiff {
         self.repo_root.is_some()
     }
 
+    /// The `from`/`to` refs of a commit range diff.
+    pub fn git_range(&self) -> Option<(&str, &str)> {
+        match self.git_mode.as_ref()? {
+            GitDiffMode::Range { from, to } => Some((from, to)),
+            _ => None,
+        }
+    }
+
     /// Return a display-friendly git range for header usage (if applicable).
     pub fn git_range_display(&self) -> Option<(String, String)> {
         let mode = self.git_mode.as_ref()?;
@@ -1213,73 +1647,124 @@ impl MultiFileDiff {
         let mut precomputed_diffs = Vec::new();
         let mut diff_statuses = Vec::new();
         for change in changes {
+            let mut read_error = None;
             let old_path = change
                 .old_path
                 .clone()
                 .unwrap_or_else(|| change.path.clone());
-            let (old_content, old_binary, new_content, new_binary) = match mode {
-                GitDiffMode::Uncommitted => {
-                    let (old_content, old_binary) = match change.status {
-                        FileStatus::Added | FileStatus::Untracked => (String::new(), false),
-                        _ => Self::read_git_commit_or_binary(&repo_root, "HEAD", &old_path),
-                    };
-                    let (new_content, new_binary) = match change.status {
-                        FileStatus::Deleted => (String::new(), false),
-                        _ => {
-                            let full_path = repo_root.join(&change.path);
-                            Self::read_text_or_binary(&full_path)
-                        }
-                    };
-                    (old_content, old_binary, new_content, new_binary)
-                }
-                GitDiffMode::Staged => {
-                    let (old_content, old_binary) = match change.status {
-                        FileStatus::Added | FileStatus::Untracked => (String::new(), false),
-                        _ => Self::read_git_commit_or_binary(&repo_root, "HEAD", &old_path),
-                    };
-                    let (new_content, new_binary) = match change.status {
-                        FileStatus::Deleted => (String::new(), false),
-                        _ => Self::read_git_index_or_binary(&repo_root, &change.path),
-                    };
-                    (old_content, old_binary, new_content, new_binary)
-                }
-                GitDiffMode::Range { ref from, ref to } => {
-                    let (old_content, old_binary) = match change.status {
-                        FileStatus::Added | FileStatus::Untracked => (String::new(), false),
-                        _ => Self::read_git_commit_or_binary(&repo_root, from, &old_path),
-                    };
-                    let (new_content, new_binary) = match change.status {
-                        FileStatus::Deleted => (String::new(), false),
-                        _ => Self::read_git_commit_or_binary(&repo_root, to, &change.path),
-                    };
-                    (old_content, old_binary, new_content, new_binary)
-                }
-                GitDiffMode::IndexRange { ref from, to_index } => {
-                    if to_index {
-                        let (old_content, old_binary) = match change.status {
-                            FileStatus::Added | FileStatus::Untracked => (String::new(), false),
-                            _ => Self::read_git_commit_or_binary(&repo_root, from, &old_path),
+            let (old_content, old_binary, old_encoding, new_content, new_binary, new_encoding) =
+                match mode {
+                    GitDiffMode::Uncommitted => {
+                        let (old_content, old_binary, old_encoding) = match change.status {
+                            FileStatus::Added | FileStatus::Untracked => {
+                                (String::new(), false, None)
+                            }
+                            _ => Self::read_git_commit_or_binary(&repo_root, "HEAD", &old_path),
                         };
-                        let (new_content, new_binary) = match change.status {
-                            FileStatus::Deleted => (String::new(), false),
+                        let (new_content, new_binary, new_encoding) = match change.status {
+                            FileStatus::Deleted => (String::new(), false, None),
+                            _ => {
+                                let full_path = repo_root.join(&change.path);
+                                Self::read_text_or_binary(&full_path, &mut read_error)
+                            }
+                        };
+                        (
+                            old_content,
+                            old_binary,
+                            old_encoding,
+                            new_content,
+                            new_binary,
+                            new_encoding,
+                        )
+                    }
+                    GitDiffMode::Staged => {
+                        let (old_content, old_binary, old_encoding) = match change.status {
+                            FileStatus::Added | FileStatus::Untracked => {
+                                (String::new(), false, None)
+                            }
+                            _ => Self::read_git_commit_or_binary(&repo_root, "HEAD", &old_path),
+                        };
+                        let (new_content, new_binary, new_encoding) = match change.status {
+                            FileStatus::Deleted => (String::new(), false, None),
                             _ => Self::read_git_index_or_binary(&repo_root, &change.path),
                         };
-                        (old_content, old_binary, new_content, new_binary)
-                    } else {
-                        let (old_content, old_binary) = match change.status {
-                            FileStatus::Added | FileStatus::Untracked => (String::new(), false),
-                            _ => Self::read_git_index_or_binary(&repo_root, &old_path),
+                        (
+                            old_content,
+                            old_binary,
+                            old_encoding,
+                            new_content,
+                            new_binary,
+                            new_encoding,
+                        )
+                    }
+                    GitDiffMode::Range { ref from, ref to } => {
+                        let (old_content, old_binary, old_encoding) = match change.status {
+                            FileStatus::Added | FileStatus::Untracked => {
+                                (String::new(), false, None)
+                            }
+                            _ => Self::read_git_commit_or_binary(&repo_root, from, &old_path),
                         };
-                        let (new_content, new_binary) = match change.status {
-                            FileStatus::Deleted => (String::new(), false),
-                            _ => Self::read_git_commit_or_binary(&repo_root, from, &change.path),
+                        let (new_content, new_binary, new_encoding) = match change.status {
+                            FileStatus::Deleted => (String::new(), false, None),
+                            _ => Self::read_git_commit_or_binary(&repo_root, to, &change.path),
                         };
-                        (old_content, old_binary, new_content, new_binary)
+                        (
+                            old_content,
+                            old_binary,
+                            old_encoding,
+                            new_content,
+                            new_binary,
+                            new_encoding,
+                        )
                     }
-                }
-            };
+                    GitDiffMode::IndexRange { ref from, to_index } => {
+                        if to_index {
+                            let (old_content, old_binary, old_encoding) = match change.status {
+                                FileStatus::Added | FileStatus::Untracked => {
+                                    (String::new(), false, None)
+                                }
+                                _ => Self::read_git_commit_or_binary(&repo_root, from, &old_path),
+                            };
+                            let (new_content, new_binary, new_encoding) = match change.status {
+                                FileStatus::Deleted => (String::new(), false, None),
+                                _ => Self::read_git_index_or_binary(&repo_root, &change.path),
+                            };
+                            (
+                                old_content,
+                                old_binary,
+                                old_encoding,
+                                new_content,
+                                new_binary,
+                                new_encoding,
+                            )
+                        } else {
+                            let (old_content, old_binary, old_encoding) = match change.status {
+                                FileStatus::Added | FileStatus::Untracked => {
+                                    (String::new(), false, None)
+                                }
+                                _ => Self::read_git_index_or_binary(&repo_root, &old_path),
+                            };
+                            let (new_content, new_binary, new_encoding) = match change.status {
+                                FileStatus::Deleted => (String::new(), false, None),
+                                _ => {
+                                    Self::read_git_commit_or_binary(&repo_root, from, &change.path)
+                                }
+                            };
+                            (
+                                old_content,
+                                old_binary,
+                                old_encoding,
+                                new_content,
+                                new_binary,
+                                new_encoding,
+                            )
+                        }
+                    }
+                };
 
             let binary = old_binary || new_binary;
+            let (old_content, new_content, structural) =
+                Self::structural_contents(&change.path, old_content, new_content, binary);
             let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
             let (old_content, new_content, precomputed, diff_status) =
                 Self::maybe_defer_diff(old_content, new_content, binary);
@@ -1294,6 +1779,9 @@ impl MultiFileDiff {
                 insertions,
                 deletions,
                 binary,
+                encoding: new_encoding.or(old_encoding),
+                structural,
+                read_error,
             });
 
             old_contents.push(Arc::from(old_content));
@@ -1310,6 +1798,7 @@ impl MultiFileDiff {
         self.new_contents = new_contents;
         self.precomputed_diffs = precomputed_diffs;
         self.diff_statuses = diff_statuses;
+        self.unloaded = vec![None; self.files.len()];
         self.navigators = navigators;
         self.navigator_is_placeholder = navigator_is_placeholder;
 
@@ -1326,85 +1815,187 @@ impl MultiFileDiff {
         let idx = self.selected_index;
         let file = &self.files[idx];
         let old_path = file.old_path.clone().unwrap_or_else(|| file.path.clone());
+        let mut read_error = None;
 
         // Get fresh content based on mode
-        let (old_content, old_binary, new_content, new_binary) =
+        let (old_content, old_binary, old_encoding, new_content, new_binary, new_encoding) =
             match (&self.repo_root, &self.git_mode) {
                 (Some(repo_root), Some(GitDiffMode::Uncommitted)) => {
-                    let (old_content, old_binary) = match file.status {
-                        FileStatus::Added | FileStatus::Untracked => (String::new(), false),
+                    let (old_content, old_binary, old_encoding) = match file.status {
+                        FileStatus::Added | FileStatus::Untracked => (String::new(), false, None),
                         _ => Self::read_git_commit_or_binary(repo_root, "HEAD", &old_path),
                     };
-                    let (new_content, new_binary) = match file.status {
-                        FileStatus::Deleted => (String::new(), false),
+                    let (new_content, new_binary, new_encoding) = match file.status {
+                        FileStatus::Deleted => (String::new(), false, None),
                         _ => {
                             let full_path = repo_root.join(&file.path);
-                            Self::read_text_or_binary(&full_path)
+                            Self::read_text_or_binary(&full_path, &mut read_error)
                         }
                     };
-                    (old_content, old_binary, new_content, new_binary)
+                    (
+                        old_content,
+                        old_binary,
+                        old_encoding,
+                        new_content,
+                        new_binary,
+                        new_encoding,
+                    )
                 }
                 (Some(repo_root), Some(GitDiffMode::Staged)) => {
-                    let (old_content, old_binary) = match file.status {
-                        FileStatus::Added | FileStatus::Untracked => (String::new(), false),
+                    let (old_content, old_binary, old_encoding) = match file.status {
+                        FileStatus::Added | FileStatus::Untracked => (String::new(), false, None),
                         _ => Self::read_git_commit_or_binary(repo_root, "HEAD", &old_path),
                     };
-                    let (new_content, new_binary) = match file.status {
-                        FileStatus::Deleted => (String::new(), false),
+                    let (new_content, new_binary, new_encoding) = match file.status {
+                        FileStatus::Deleted => (String::new(), false, None),
                         _ => Self::read_git_index_or_binary(repo_root, &file.path),
                     };
-                    (old_content, old_binary, new_content, new_binary)
+                    (
+                        old_content,
+                        old_binary,
+                        old_encoding,
+                        new_content,
+                        new_binary,
+                        new_encoding,
+                    )
                 }
                 (Some(repo_root), Some(GitDiffMode::Range { from, to })) => {
-                    let (old_content, old_binary) = match file.status {
-                        FileStatus::Added | FileStatus::Untracked => (String::new(), false),
+                    let (old_content, old_binary, old_encoding) = match file.status {
+                        FileStatus::Added | FileStatus::Untracked => (String::new(), false, None),
                         _ => Self::read_git_commit_or_binary(repo_root, from, &old_path),
                     };
-                    let (new_content, new_binary) = match file.status {
-                        FileStatus::Deleted => (String::new(), false),
+                    let (new_content, new_binary, new_encoding) = match file.status {
+                        FileStatus::Deleted => (String::new(), false, None),
                         _ => Self::read_git_commit_or_binary(repo_root, to, &file.path),
                     };
-                    (old_content, old_binary, new_content, new_binary)
+                    (
+                        old_content,
+                        old_binary,
+                        old_encoding,
+                        new_content,
+                        new_binary,
+                        new_encoding,
+                    )
                 }
                 (Some(repo_root), Some(GitDiffMode::IndexRange { from, to_index })) => {
                     if *to_index {
-                        let (old_content, old_binary) = match file.status {
-                            FileStatus::Added | FileStatus::Untracked => (String::new(), false),
+                        let (old_content, old_binary, old_encoding) = match file.status {
+                            FileStatus::Added | FileStatus::Untracked => {
+                                (String::new(), false, None)
+                            }
                             _ => Self::read_git_commit_or_binary(repo_root, from, &old_path),
                         };
-                        let (new_content, new_binary) = match file.status {
-                            FileStatus::Deleted => (String::new(), false),
+                        let (new_content, new_binary, new_encoding) = match file.status {
+                            FileStatus::Deleted => (String::new(), false, None),
                             _ => Self::read_git_index_or_binary(repo_root, &file.path),
                         };
-                        (old_content, old_binary, new_content, new_binary)
+                        (
+                            old_content,
+                            old_binary,
+                            old_encoding,
+                            new_content,
+                            new_binary,
+                            new_encoding,
+                        )
                     } else {
-                        let (old_content, old_binary) = match file.status {
-                            FileStatus::Added | FileStatus::Untracked => (String::new(), false),
+                        let (old_content, old_binary, old_encoding) = match file.status {
+                            FileStatus::Added | FileStatus::Untracked => {
+                                (String::new(), false, None)
+                            }
                             _ => Self::read_git_index_or_binary(repo_root, &old_path),
                         };
-                        let (new_content, new_binary) = match file.status {
-                            FileStatus::Deleted => (String::new(), false),
+                        let (new_content, new_binary, new_encoding) = match file.status {
+                            FileStatus::Deleted => (String::new(), false, None),
                             _ => Self::read_git_commit_or_binary(repo_root, from, &file.path),
                         };
-                        (old_content, old_binary, new_content, new_binary)
+                        (
+                            old_content,
+                            old_binary,
+                            old_encoding,
+                            new_content,
+                            new_binary,
+                            new_encoding,
+                        )
                     }
                 }
                 _ => {
                     let old_content = self.old_contents[idx].as_ref().to_string();
-                    let (old_content, old_binary) = self
+                    let (old_content, old_binary, old_encoding) = self
                         .source_path(idx, FileSide::Old)
                         .filter(|path| path.is_file())
-                        .map(|path| Self::read_text_or_binary(&path))
-                        .unwrap_or((old_content, false));
+                        .map(|path| Self::read_text_or_binary(&path, &mut read_error))
+                        .unwrap_or((old_content, false, None));
                     let new_path = self
                         .source_path(idx, FileSide::New)
                         .unwrap_or_else(|| file.path.clone());
-                    let (new_content, new_binary) = Self::read_text_or_binary(&new_path);
-                    (old_content, old_binary, new_content, new_binary)
+                    let (new_content, new_binary, new_encoding) =
+                        Self::read_text_or_binary(&new_path, &mut read_error);
+                    (
+                        old_content,
+                        old_binary,
+                        old_encoding,
+                        new_content,
+                        new_binary,
+                        new_encoding,
+                    )
                 }
             };
 
+        self.store_contents(
+            idx,
+            (old_content, old_binary, old_encoding),
+            (new_content, new_binary, new_encoding),
+            read_error,
+        );
+    }
+
+    /// Byte sizes of a file's sides while its contents are still unread
+    /// (lazy directory scans).
+    pub fn unloaded_sizes(&self, idx: usize) -> Option<(u64, u64)> {
+        self.unloaded.get(idx).copied().flatten()
+    }
+
+    /// Changed lines in a file: its diff stats once loaded, otherwise a
+    /// rough count from the side sizes so unread files still carry weight in
+    /// totals and sorting.
+    pub fn change_count(&self, idx: usize) -> usize {
+        const ESTIMATED_LINE_BYTES: u64 = 40;
+        if let Some((old_len, new_len)) = self.unloaded_sizes(idx) {
+            return (old_len + new_len).div_ceil(ESTIMATED_LINE_BYTES).max(1) as usize;
+        }
+        self.files
+            .get(idx)
+            .map_or(0, |file| file.insertions + file.deletions)
+    }
+
+    /// Read a lazily scanned file's contents and stats; no-op once loaded.
+    pub fn ensure_loaded(&mut self, idx: usize) {
+        if self.unloaded_sizes(idx).is_none() {
+            return;
+        }
+        let mut read_error = None;
+        let mut read_side = |side| {
+            self.source_path(idx, side)
+                .filter(|path| path.is_file())
+                .map(|path| Self::read_text_or_binary(&path, &mut read_error))
+                .unwrap_or((String::new(), false, None))
+        };
+        let old = read_side(FileSide::Old);
+        let new = read_side(FileSide::New);
+        self.store_contents(idx, old, new, read_error);
+    }
+
+    /// Install freshly read sides for a file and drop its stale navigator.
+    fn store_contents(
+        &mut self,
+        idx: usize,
+        (old_content, old_binary, old_encoding): (String, bool, Option<TextEncoding>),
+        (new_content, new_binary, new_encoding): (String, bool, Option<TextEncoding>),
+        read_error: Option<String>,
+    ) {
         let binary = old_binary || new_binary;
+        let (old_content, new_content, structural) =
+            Self::structural_contents(&self.files[idx].path, old_content, new_content, binary);
         let (insertions, deletions) = Self::diff_stats(&old_content, &new_content, binary);
         let (old_content, new_content, precomputed, diff_status) =
             Self::maybe_defer_diff(old_content, new_content, binary);
@@ -1412,6 +2003,9 @@ impl MultiFileDiff {
         self.old_contents[idx] = Arc::from(old_content);
         self.new_contents[idx] = Arc::from(new_content);
         self.files[idx].binary = binary;
+        self.files[idx].encoding = new_encoding.or(old_encoding);
+        self.files[idx].structural = structural;
+        self.files[idx].read_error = read_error;
         self.files[idx].insertions = insertions;
         self.files[idx].deletions = deletions;
         if let Some(slot) = self.precomputed_diffs.get_mut(idx) {
@@ -1421,6 +2015,10 @@ impl MultiFileDiff {
             *status = diff_status;
         }
 
+        if let Some(slot) = self.unloaded.get_mut(idx) {
+            *slot = None;
+        }
+
         // Clear the navigator so it gets rebuilt on next access
         self.navigators[idx] = None;
         if let Some(flag) = self.navigator_is_placeholder.get_mut(idx) {
@@ -1444,7 +2042,12 @@ fn collect_files(
         .git_ignore(scan_options.git_ignore)
         .git_global(scan_options.git_ignore)
         .git_exclude(scan_options.git_ignore)
-        .require_git(false);
+        .require_git(false)
+        .follow_links(false)
+        .max_depth(scan_options.max_depth);
+    if scan_options.oyo_ignore {
+        builder.add_custom_ignore_filename(crate::git::OYO_IGNORE_FILE);
+    }
 
     if !scan_options.ignore_globs.is_empty() {
         let mut overrides = OverrideBuilder::new(base);
@@ -1560,6 +2163,9 @@ mod tests {
             &DirectoryScanOptions {
                 git_ignore: true,
                 ignore_globs: Vec::new(),
+                oyo_ignore: true,
+                max_depth: None,
+                lazy_load_bytes: None,
             },
         )
         .unwrap();
@@ -1571,6 +2177,9 @@ mod tests {
             &DirectoryScanOptions {
                 git_ignore: false,
                 ignore_globs: Vec::new(),
+                oyo_ignore: true,
+                max_depth: None,
+                lazy_load_bytes: None,
             },
         )
         .unwrap();
@@ -1579,6 +2188,175 @@ mod tests {
         let _ = std::fs::remove_dir_all(root);
     }
 
+    #[test]
+    fn directory_scan_applies_either_sides_gitignore_to_both() {
+        let root = temp_dir("one-sided-gitignore");
+        let old_dir = root.join("old");
+        let new_dir = root.join("new");
+        write_file(&new_dir.join(".gitignore"), "target/\n");
+        write_file(&old_dir.join("target/debug/out"), "old\n");
+        write_file(&new_dir.join("target/debug/out"), "new\n");
+        write_file(&old_dir.join("stale/build.log"), "old\n");
+        write_file(&old_dir.join("src/lib.rs"), "old\n");
+        write_file(&new_dir.join("src/lib.rs"), "new\n");
+
+        let diff = MultiFileDiff::from_directories(&old_dir, &new_dir).unwrap();
+        let names = display_names(&diff);
+        assert!(!names.iter().any(|name| name.starts_with("target/")));
+        assert!(names.contains(&"stale/build.log".to_string()));
+        assert!(names.contains(&"src/lib.rs".to_string()));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn lazy_directory_scan_loads_files_on_first_visit() {
+        let root = temp_dir("lazy-scan");
+        let old_dir = root.join("old");
+        let new_dir = root.join("new");
+        write_file(&old_dir.join("a.txt"), "one\ntwo\n");
+        write_file(&new_dir.join("a.txt"), "one\nTWO\nthree\n");
+        write_file(&old_dir.join("b.txt"), "same\n");
+        write_file(&new_dir.join("b.txt"), "same\n");
+        write_file(&old_dir.join("c.txt"), "abc\n");
+        write_file(&new_dir.join("c.txt"), "abd\n");
+
+        let options = DirectoryScanOptions {
+            lazy_load_bytes: Some(0),
+            ..DirectoryScanOptions::default()
+        };
+        let mut diff =
+            MultiFileDiff::from_directories_with_options(&old_dir, &new_dir, &options).unwrap();
+        // Same-size files are byte-compared, so only real changes are listed.
+        assert_eq!(display_names(&diff), vec!["a.txt", "c.txt"]);
+        // The first file is loaded up front; the rest only know their sizes.
+        assert_eq!(diff.unloaded_sizes(0), None);
+        assert_eq!((diff.files[0].insertions, diff.files[0].deletions), (2, 1));
+        assert_eq!(diff.unloaded_sizes(1), Some((4, 4)));
+        assert_eq!(diff.files[1].insertions, 0);
+        assert_eq!(diff.change_count(1), 1);
+
+        diff.select_file(1);
+        assert_eq!(diff.unloaded_sizes(1), None);
+        assert_eq!((diff.files[1].insertions, diff.files[1].deletions), (1, 1));
+        assert_eq!(diff.file_contents(1), Some(("abc\n", "abd\n")));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn lazy_and_eager_directory_scans_list_the_same_files() {
+        let root = temp_dir("lazy-eager");
+        let old_dir = root.join("old");
+        let new_dir = root.join("new");
+        let cases: [(&str, &[u8], &[u8]); 7] = [
+            ("same.txt", b"same\n", b"same\n"),
+            ("bom.txt", b"text\n", b"\xef\xbb\xbftext\n"),
+            ("crlf.txt", b"a\r\nb\r\n", b"a\nb\n\n"),
+            ("empty.txt", b"", b""),
+            ("binary.bin", b"\0\x01\x02", b"\0\x01\x02"),
+            ("binary_changed.bin", b"\0\x01\x02", b"\0\x01\x03"),
+            ("edit.txt", b"one\n", b"two\n"),
+        ];
+        for (name, old, new) in cases {
+            std::fs::create_dir_all(&old_dir).unwrap();
+            std::fs::create_dir_all(&new_dir).unwrap();
+            std::fs::write(old_dir.join(name), old).unwrap();
+            std::fs::write(new_dir.join(name), new).unwrap();
+        }
+        write_file(&old_dir.join("gone_empty.txt"), "");
+
+        let eager = MultiFileDiff::from_directories(&old_dir, &new_dir).unwrap();
+        let options = DirectoryScanOptions {
+            lazy_load_bytes: Some(0),
+            ..DirectoryScanOptions::default()
+        };
+        let lazy =
+            MultiFileDiff::from_directories_with_options(&old_dir, &new_dir, &options).unwrap();
+        assert_eq!(display_names(&lazy), display_names(&eager));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn unreadable_side_is_recorded_without_failing_the_scan() {
+        let root = temp_dir("read-error");
+        let old_dir = root.join("old");
+        let new_dir = root.join("new");
+        write_file(&old_dir.join("a.txt"), "one\n");
+        write_file(&new_dir.join("a.txt/inner.txt"), "inner\n");
+        write_file(&old_dir.join("b.txt"), "old\n");
+        write_file(&new_dir.join("b.txt"), "new\n");
+
+        let diff = MultiFileDiff::from_directories(&old_dir, &new_dir).unwrap();
+        let unreadable = diff
+            .files
+            .iter()
+            .find(|file| file.display_name == "a.txt")
+            .unwrap();
+        assert!(unreadable.read_error.is_some());
+        let healthy = diff
+            .files
+            .iter()
+            .find(|file| file.display_name == "b.txt")
+            .unwrap();
+        assert_eq!(healthy.read_error, None);
+        assert_eq!((healthy.insertions, healthy.deletions), (1, 1));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn directory_scan_stops_at_max_depth() {
+        let root = temp_dir("max-depth");
+        let old_dir = root.join("old");
+        let new_dir = root.join("new");
+        std::fs::create_dir_all(&old_dir).unwrap();
+        write_file(&new_dir.join("top.txt"), "top\n");
+        write_file(&new_dir.join("a/mid.txt"), "mid\n");
+        write_file(&new_dir.join("a/b/deep.txt"), "deep\n");
+
+        let options = DirectoryScanOptions {
+            max_depth: Some(2),
+            ..DirectoryScanOptions::default()
+        };
+        let diff =
+            MultiFileDiff::from_directories_with_options(&old_dir, &new_dir, &options).unwrap();
+        assert_eq!(display_names(&diff), vec!["a/mid.txt", "top.txt"]);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn directory_scan_respects_oyoignore_with_negation() {
+        let root = temp_dir("oyoignore");
+        let old_dir = root.join("old");
+        let new_dir = root.join("new");
+        std::fs::create_dir_all(&old_dir).unwrap();
+        write_file(&new_dir.join(".oyoignore"), "*.lock\n!keep.lock\n");
+        write_file(&new_dir.join("Cargo.lock"), "lock\n");
+        write_file(&new_dir.join("keep.lock"), "keep\n");
+        write_file(&new_dir.join("src/main.rs"), "fn main() {}\n");
+
+        let options = DirectoryScanOptions::default();
+        let diff =
+            MultiFileDiff::from_directories_with_options(&old_dir, &new_dir, &options).unwrap();
+        let names = display_names(&diff);
+        assert!(!names.contains(&"Cargo.lock".to_string()));
+        assert!(names.contains(&"keep.lock".to_string()));
+        assert!(names.contains(&"src/main.rs".to_string()));
+
+        let options = DirectoryScanOptions {
+            oyo_ignore: false,
+            ..DirectoryScanOptions::default()
+        };
+        let diff =
+            MultiFileDiff::from_directories_with_options(&old_dir, &new_dir, &options).unwrap();
+        assert!(display_names(&diff).contains(&"Cargo.lock".to_string()));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
     #[test]
     fn directory_scan_skips_vcs_metadata_by_default() {
         let root = temp_dir("vcs-metadata");
@@ -1640,6 +2418,76 @@ mod tests {
         let _ = std::fs::remove_dir_all(root);
     }
 
+    #[test]
+    fn file_bytes_reads_binary_sources_within_cap() {
+        let root = temp_dir("file-bytes");
+        let old_path = root.join("old.bin");
+        let new_path = root.join("new.bin");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(&old_path, [0u8, 1, 2, 3]).unwrap();
+        std::fs::write(&new_path, [0u8, 1, 9, 3, 4]).unwrap();
+
+        let diff = MultiFileDiff::from_file_pair_with_sources(
+            PathBuf::from("blob.bin"),
+            vec![0, 1, 2, 3],
+            vec![0, 1, 9, 3, 4],
+            Some(old_path),
+            Some(new_path),
+        );
+        assert!(diff.files[0].binary);
+        assert_eq!(
+            diff.file_bytes(0, 64),
+            Some((vec![0, 1, 2, 3], vec![0, 1, 9, 3, 4]))
+        );
+        assert_eq!(diff.file_bytes(0, 4), None);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn directory_scan_decodes_legacy_encodings() {
+        let root = temp_dir("encodings");
+        let old_dir = root.join("old");
+        let new_dir = root.join("new");
+        std::fs::create_dir_all(&old_dir).unwrap();
+        std::fs::create_dir_all(&new_dir).unwrap();
+        std::fs::write(old_dir.join("latin1.txt"), b"caf\xE9\n").unwrap();
+        std::fs::write(new_dir.join("latin1.txt"), b"caf\xE9 au lait\n").unwrap();
+
+        let diff = MultiFileDiff::from_directories(&old_dir, &new_dir).unwrap();
+        assert!(!diff.files[0].binary);
+        assert_eq!(diff.files[0].encoding, Some(TextEncoding::Windows1252));
+        assert_eq!(diff.file_contents(0).unwrap().1, "café au lait\n");
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn structural_diff_ignores_reordered_json_keys() {
+        let _guard = DIFF_SETTINGS_LOCK.lock().unwrap();
+        MultiFileDiff::set_structural_diff(true);
+        let diff = MultiFileDiff::from_file_pairs(vec![
+            (
+                PathBuf::from("conf.json"),
+                r#"{"a": 1, "b": 2}"#.to_string(),
+                r#"{"b": 2, "a": 1, "c": 3}"#.to_string(),
+            ),
+            (
+                PathBuf::from("broken.json"),
+                r#"{"a": 1"#.to_string(),
+                r#"{"a": 2"#.to_string(),
+            ),
+        ]);
+        MultiFileDiff::set_structural_diff(false);
+
+        assert_eq!((diff.files[0].insertions, diff.files[0].deletions), (1, 0));
+        assert_eq!(diff.file_contents(0).unwrap().1, "a: 1\nb: 2\nc: 3\n");
+        assert!(diff.files[0].structural);
+        // Unparsable files keep their text diff.
+        assert_eq!(diff.file_contents(1).unwrap().1, r#"{"a": 2"#);
+        assert!(!diff.files[1].structural);
+    }
+
     #[test]
     fn deferred_diff_upgrades_to_ready() {
         let _guard = DIFF_SETTINGS_LOCK.lock().unwrap();
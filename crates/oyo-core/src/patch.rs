@@ -0,0 +1,267 @@
+//! Unified diff (patch) parsing
+//!
+//! A patch only carries the lines around each change, so both sides of a
+//! file are rebuilt from its hunks. Lines the patch leaves out are filled
+//! with blank context so line numbers still match the real files.
+
+use crate::git::FileStatus;
+use std::path::PathBuf;
+
+/// One file's changes as reconstructed from a patch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchFile {
+    pub path: PathBuf,
+    /// Previous path for renames
+    pub old_path: Option<PathBuf>,
+    pub status: FileStatus,
+    pub old_content: String,
+    pub new_content: String,
+    pub binary: bool,
+}
+
+#[derive(Default)]
+struct PatchFileBuilder {
+    old_path: Option<String>,
+    new_path: Option<String>,
+    rename_from: Option<String>,
+    rename_to: Option<String>,
+    added: bool,
+    deleted: bool,
+    binary: bool,
+    old_lines: Vec<String>,
+    new_lines: Vec<String>,
+}
+
+impl PatchFileBuilder {
+    fn finish(self) -> Option<PatchFile> {
+        let new_path = self.rename_to.or(self.new_path);
+        let old_path = self.rename_from.or(self.old_path);
+        let path = new_path.clone().or_else(|| old_path.clone())?;
+        let status = if self.added {
+            FileStatus::Added
+        } else if self.deleted {
+            FileStatus::Deleted
+        } else if old_path.is_some() && new_path.is_some() && old_path != new_path {
+            FileStatus::Renamed
+        } else {
+            FileStatus::Modified
+        };
+        let old_path = (status == FileStatus::Renamed)
+            .then(|| old_path.map(PathBuf::from))
+            .flatten();
+        Some(PatchFile {
+            path: PathBuf::from(path),
+            old_path,
+            status,
+            old_content: join_lines(&self.old_lines),
+            new_content: join_lines(&self.new_lines),
+            binary: self.binary,
+        })
+    }
+}
+
+fn join_lines(lines: &[String]) -> String {
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+/// Parse a `git diff` or `diff -u` style patch into per-file old/new contents.
+pub fn parse_patch(text: &str) -> Vec<PatchFile> {
+    let mut files = Vec::new();
+    let mut current: Option<PatchFileBuilder> = None;
+    // Lines left in the current hunk as (old, new).
+    let mut remaining = (0usize, 0usize);
+
+    for line in text.lines() {
+        if remaining.0 > 0 || remaining.1 > 0 {
+            if let Some(file) = current.as_mut() {
+                let (old, new) = &mut remaining;
+                match line.chars().next() {
+                    Some('-') => {
+                        file.old_lines.push(line[1..].to_string());
+                        *old = old.saturating_sub(1);
+                    }
+                    Some('+') => {
+                        file.new_lines.push(line[1..].to_string());
+                        *new = new.saturating_sub(1);
+                    }
+                    Some('\\') => {}
+                    _ => {
+                        let content = line.get(1..).unwrap_or_default().to_string();
+                        file.old_lines.push(content.clone());
+                        file.new_lines.push(content);
+                        *old = old.saturating_sub(1);
+                        *new = new.saturating_sub(1);
+                    }
+                }
+                continue;
+            }
+        }
+
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            files.extend(current.take().and_then(PatchFileBuilder::finish));
+            let mut file = PatchFileBuilder::default();
+            if let Some((old, new)) = rest.rsplit_once(" b/") {
+                file.old_path = Some(old.strip_prefix("a/").unwrap_or(old).to_string());
+                file.new_path = Some(new.to_string());
+            }
+            current = Some(file);
+        } else if let Some(rest) = line.strip_prefix("--- ") {
+            // Plain `diff -u` output has no `diff --git` line between files.
+            if current
+                .as_ref()
+                .is_none_or(|file| !file.old_lines.is_empty() || !file.new_lines.is_empty())
+            {
+                files.extend(current.take().and_then(PatchFileBuilder::finish));
+                current = Some(PatchFileBuilder::default());
+            }
+            if let Some(file) = current.as_mut() {
+                match header_path(rest, "a/") {
+                    Some(path) => file.old_path = Some(path),
+                    None => file.added = true,
+                }
+            }
+        } else if let Some(rest) = line.strip_prefix("+++ ") {
+            if let Some(file) = current.as_mut() {
+                match header_path(rest, "b/") {
+                    Some(path) => file.new_path = Some(path),
+                    None => file.deleted = true,
+                }
+            }
+        } else if let Some(file) = current.as_mut() {
+            if line.starts_with("new file mode") {
+                file.added = true;
+            } else if line.starts_with("deleted file mode") {
+                file.deleted = true;
+            } else if let Some(path) = line.strip_prefix("rename from ") {
+                file.rename_from = Some(path.to_string());
+            } else if let Some(path) = line.strip_prefix("rename to ") {
+                file.rename_to = Some(path.to_string());
+            } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+                file.binary = true;
+            } else if let Some((old_start, old_len, new_start, new_len)) = parse_hunk_header(line) {
+                pad_to(&mut file.old_lines, old_start, old_len);
+                pad_to(&mut file.new_lines, new_start, new_len);
+                remaining = (old_len, new_len);
+            }
+        }
+    }
+    files.extend(current.take().and_then(PatchFileBuilder::finish));
+    files
+}
+
+/// Path from a `---`/`+++` header, or `None` for `/dev/null`.
+fn header_path(rest: &str, prefix: &str) -> Option<String> {
+    let path = rest.split('\t').next().unwrap_or(rest).trim_end();
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(path.strip_prefix(prefix).unwrap_or(path).to_string())
+}
+
+/// Parse `@@ -a,b +c,d @@` (counts default to 1 when omitted).
+fn parse_hunk_header(line: &str) -> Option<(usize, usize, usize, usize)> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, _) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let parse = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_len) = parse(old)?;
+    let (new_start, new_len) = parse(new)?;
+    Some((old_start, old_len, new_start, new_len))
+}
+
+/// Fill unknown lines so the hunk starts at its real line number. An empty
+/// range names the line before it rather than the first line of the hunk.
+fn pad_to(lines: &mut Vec<String>, start: usize, len: usize) {
+    let before = if len == 0 {
+        start
+    } else {
+        start.saturating_sub(1)
+    };
+    if lines.len() < before {
+        lines.resize(before, String::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_patch_rebuilds_sides_with_line_numbers() {
+        let patch = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -2,2 +2,2 @@ fn main() {
+ keep
+-old
++new
+@@ -10,1 +10,2 @@
+ tail
++added
+diff --git a/new.txt b/new.txt
+new file mode 100644
+--- /dev/null
++++ b/new.txt
+@@ -0,0 +1 @@
++hello
+diff --git a/old name.txt b/new name.txt
+similarity index 100%
+rename from old name.txt
+rename to new name.txt
+";
+        let files = parse_patch(patch);
+        assert_eq!(files.len(), 3);
+
+        let lib = &files[0];
+        assert_eq!(lib.path, PathBuf::from("src/lib.rs"));
+        assert_eq!(lib.status, FileStatus::Modified);
+        let old: Vec<&str> = lib.old_content.lines().collect();
+        let new: Vec<&str> = lib.new_content.lines().collect();
+        assert_eq!(old.len(), 10);
+        assert_eq!((old[1], old[2], old[9]), ("keep", "old", "tail"));
+        assert_eq!(new.len(), 11);
+        assert_eq!((new[2], new[9], new[10]), ("new", "tail", "added"));
+
+        assert_eq!(files[1].status, FileStatus::Added);
+        assert_eq!(files[1].old_content, "");
+        assert_eq!(files[1].new_content, "hello\n");
+
+        assert_eq!(files[2].status, FileStatus::Renamed);
+        assert_eq!(files[2].path, PathBuf::from("new name.txt"));
+        assert_eq!(files[2].old_path, Some(PathBuf::from("old name.txt")));
+    }
+
+    #[test]
+    fn parse_patch_handles_plain_unified_diffs() {
+        let patch = "\
+--- a.txt\t2024-01-01 00:00:00
++++ a.txt\t2024-01-02 00:00:00
+@@ -1 +1 @@
+-one
++two
+--- b.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-gone
+";
+        let files = parse_patch(patch);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, PathBuf::from("a.txt"));
+        assert_eq!(files[0].old_content, "one\n");
+        assert_eq!(files[0].new_content, "two\n");
+        assert_eq!(files[1].status, FileStatus::Deleted);
+        assert_eq!(files[1].new_content, "");
+    }
+}
@@ -1237,6 +1237,17 @@ impl DiffNavigator {
         self.view_for_changes(self.diff.changes.iter(), frame)
     }
 
+    /// View with every change applied and nothing active, without touching
+    /// the current step state
+    pub fn final_view(&mut self) -> Vec<ViewLine> {
+        let saved = self.state.clone();
+        self.goto_end();
+        self.clear_active_change();
+        let view = self.current_view();
+        self.state = saved;
+        view
+    }
+
     pub fn view_line_for_change(
         &self,
         frame: AnimationFrame,
@@ -1675,6 +1686,7 @@ impl DiffNavigator {
             change_id: change.id,
             hunk_index,
             has_changes,
+            moved: self.diff.is_moved(change.id),
         })
     }
 
@@ -1774,6 +1786,7 @@ impl DiffNavigator {
             change_id,
             hunk_index,
             has_changes,
+            moved: self.diff.is_moved(change_id),
         })
     }
 
@@ -1830,6 +1843,8 @@ pub struct ViewLine {
     pub hunk_index: Option<usize>,
     /// True if the underlying change contains any non-equal spans
     pub has_changes: bool,
+    /// Part of a block moved elsewhere in the file (see `DiffEngine::with_moved_detection`)
+    pub moved: bool,
 }
 
 /// The kind of line in the view
@@ -1885,6 +1900,7 @@ mod tests {
             hunks,
             insertions,
             deletions,
+            moved_changes: Vec::new(),
         }
     }
 
@@ -1943,6 +1959,23 @@ mod tests {
         assert!(nav.state().is_at_start());
     }
 
+    #[test]
+    fn test_final_view_keeps_step_state() {
+        let old = "foo\nbar\nbaz";
+        let new = "foo\nqux\nbaz\nend";
+
+        let engine = DiffEngine::new();
+        let diff = engine.diff_strings(old, new);
+        let mut nav = DiffNavigator::new(diff, Arc::from(old), Arc::from(new), false);
+        nav.next();
+        let step = nav.state().current_step;
+
+        let view = nav.final_view();
+        assert!(view.iter().any(|line| line.content == "end"));
+        assert!(view.iter().all(|line| !line.is_active_change));
+        assert_eq!(nav.state().current_step, step);
+    }
+
     #[test]
     fn test_progress() {
         let old = "a\nb\nc\nd";
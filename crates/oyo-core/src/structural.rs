@@ -0,0 +1,218 @@
+//! Structural diffs for JSON and YAML files
+//!
+//! Both sides are parsed and rendered back as canonical text: keys sorted,
+//! one leaf value per line, nested maps indented. Reordered keys then render
+//! identically, and the regular line diff over that text shows only added,
+//! removed and changed keys. Either side failing to parse leaves the file to
+//! the normal text diff.
+
+use std::path::Path;
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// Config formats with a structural diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuralFormat {
+    Json,
+    Yaml,
+}
+
+impl StructuralFormat {
+    /// Format by file extension
+    pub fn for_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Canonical text for both sides
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuralDiff {
+    pub old_text: String,
+    pub new_text: String,
+}
+
+/// Parsed document, independent of the source format
+#[derive(Debug, Clone)]
+enum Node {
+    Scalar(String),
+    /// Entries sorted by key
+    Map(Vec<(String, Node)>),
+    List(Vec<Node>),
+}
+
+/// Structural diff of `path`'s two sides, or `None` when the format isn't
+/// supported, a side is empty (added or deleted file) or fails to parse.
+pub fn structural_diff(path: &Path, old: &str, new: &str) -> Option<StructuralDiff> {
+    let format = StructuralFormat::for_path(path)?;
+    if old.trim().is_empty() || new.trim().is_empty() {
+        return None;
+    }
+    let old = parse(format, old)?;
+    let new = parse(format, new)?;
+
+    Some(StructuralDiff {
+        old_text: render(&old),
+        new_text: render(&new),
+    })
+}
+
+fn parse(format: StructuralFormat, text: &str) -> Option<Node> {
+    match format {
+        StructuralFormat::Json => {
+            let value: serde_json::Value = serde_json::from_str(text).ok()?;
+            Some(from_json(value))
+        }
+        StructuralFormat::Yaml => {
+            let mut docs = YamlLoader::load_from_str(text).ok()?;
+            let mut nodes = Vec::with_capacity(docs.len());
+            for doc in docs.drain(..) {
+                nodes.push(from_yaml(doc)?);
+            }
+            match nodes.len() {
+                1 => nodes.pop(),
+                _ => Some(Node::List(nodes)),
+            }
+        }
+    }
+}
+
+fn from_json(value: serde_json::Value) -> Node {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<(String, Node)> =
+                map.into_iter().map(|(k, v)| (k, from_json(v))).collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Node::Map(entries)
+        }
+        serde_json::Value::Array(items) => Node::List(items.into_iter().map(from_json).collect()),
+        scalar => Node::Scalar(scalar.to_string()),
+    }
+}
+
+fn from_yaml(value: Yaml) -> Option<Node> {
+    Some(match value {
+        Yaml::Hash(map) => {
+            let mut entries = Vec::with_capacity(map.len());
+            for (key, value) in map {
+                let key = match key {
+                    Yaml::String(key) => key,
+                    other => yaml_scalar(&other)?,
+                };
+                entries.push((key, from_yaml(value)?));
+            }
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Node::Map(entries)
+        }
+        Yaml::Array(items) => Node::List(
+            items
+                .into_iter()
+                .map(from_yaml)
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        scalar => Node::Scalar(yaml_scalar(&scalar)?),
+    })
+}
+
+/// Scalars render as JSON would, so `"1"` and `1` stay distinct.
+fn yaml_scalar(value: &Yaml) -> Option<String> {
+    Some(match value {
+        Yaml::String(text) => serde_json::Value::String(text.clone()).to_string(),
+        Yaml::Integer(number) => number.to_string(),
+        Yaml::Real(number) => number.clone(),
+        Yaml::Boolean(flag) => flag.to_string(),
+        Yaml::Null => "null".to_string(),
+        Yaml::Hash(_) | Yaml::Array(_) | Yaml::Alias(_) | Yaml::BadValue => return None,
+    })
+}
+
+fn render(node: &Node) -> String {
+    let mut lines = Vec::new();
+    render_into(node, 0, &mut lines);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+fn render_into(node: &Node, depth: usize, lines: &mut Vec<String>) {
+    let pad = "  ".repeat(depth);
+    match node {
+        Node::Scalar(value) => lines.push(format!("{pad}{value}")),
+        Node::Map(entries) if entries.is_empty() => lines.push(format!("{pad}{{}}")),
+        Node::List(items) if items.is_empty() => lines.push(format!("{pad}[]")),
+        Node::Map(entries) => {
+            for (key, value) in entries {
+                match inline(value) {
+                    Some(value) => lines.push(format!("{pad}{key}: {value}")),
+                    None => {
+                        lines.push(format!("{pad}{key}:"));
+                        render_into(value, depth + 1, lines);
+                    }
+                }
+            }
+        }
+        Node::List(items) => {
+            for value in items {
+                match inline(value) {
+                    Some(value) => lines.push(format!("{pad}- {value}")),
+                    None => {
+                        lines.push(format!("{pad}-"));
+                        render_into(value, depth + 1, lines);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Text for values that fit after their key on one line.
+fn inline(node: &Node) -> Option<&str> {
+    match node {
+        Node::Scalar(value) => Some(value),
+        Node::Map(entries) if entries.is_empty() => Some("{}"),
+        Node::List(items) if items.is_empty() => Some("[]"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reordered_json_keys_render_identically() {
+        let old = r#"{"b": 1, "a": {"y": true, "x": [1, 2]}}"#;
+        let new = r#"{"a": {"x": [1, 2], "y": true}, "b": 1}"#;
+        let diff = structural_diff(Path::new("conf.json"), old, new).unwrap();
+        assert_eq!(diff.old_text, diff.new_text);
+        assert_eq!(
+            diff.old_text,
+            "a:\n  x:\n    - 1\n    - 2\n  y: true\nb: 1\n"
+        );
+    }
+
+    #[test]
+    fn yaml_key_changes_become_line_changes() {
+        let old = "server:\n  port: 80\n  host: a\nname: x\n";
+        let new = "name: x\nserver:\n  port: 8080\ndebug: true\n";
+        let diff = structural_diff(Path::new("conf.yml"), old, new).unwrap();
+        assert_eq!(
+            diff.old_text,
+            "name: \"x\"\nserver:\n  host: \"a\"\n  port: 80\n"
+        );
+        assert_eq!(
+            diff.new_text,
+            "debug: true\nname: \"x\"\nserver:\n  port: 8080\n"
+        );
+    }
+
+    #[test]
+    fn unsupported_or_unparsable_sides_fall_back() {
+        assert!(structural_diff(Path::new("a.txt"), "{}", "{}").is_none());
+        assert!(structural_diff(Path::new("a.json"), "{", "{}").is_none());
+        assert!(structural_diff(Path::new("a.json"), "", "{}").is_none());
+    }
+}
@@ -0,0 +1,32 @@
+//! Records the git commit oy is built from (`OYO_BUILD_COMMIT`), or
+//! `unknown` outside a checkout (e.g. crates.io builds)
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    let git_dir = Path::new("../../.git");
+    let head = git_dir.join("HEAD");
+    if head.exists() {
+        println!("cargo:rerun-if-changed={}", head.display());
+        // HEAD only names the branch; the branch ref moves on each commit.
+        if let Some(reference) = std::fs::read_to_string(&head)
+            .ok()
+            .and_then(|text| text.strip_prefix("ref: ").map(|r| r.trim().to_string()))
+        {
+            println!(
+                "cargo:rerun-if-changed={}",
+                git_dir.join(reference).display()
+            );
+        }
+    }
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=OYO_BUILD_COMMIT={commit}");
+}
@@ -1,16 +1,17 @@
 use super::types::{
     BlameCacheKey, BlamePrefetchKey, BlamePrefetchRange, BlameRequest, BlameResponse, BlameStepHint,
 };
-use super::App;
+use super::{App, PeekMode};
 use crate::blame::{
-    blame_line, blame_range, format_blame_github_text, format_blame_hint_text, load_git_user_name,
-    BlameInfo,
+    blame_line, blame_range, format_blame_github_text, format_blame_hint_text, git_show,
+    load_git_user_name, BlameInfo,
 };
 use crate::color;
 use crate::config::BlameMode;
-use oyo_core::multi::BlameSource;
-use oyo_core::{LineKind, ViewLine};
+use oyo_core::multi::{BlameSource, FileSide};
+use oyo_core::{ChangeKind, LineKind, ViewLine};
 use ratatui::style::Color;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::path::PathBuf;
 use std::sync::mpsc;
 use std::thread;
@@ -37,6 +38,79 @@ impl App {
         self.blame_user_name = load_git_user_name(root);
     }
 
+    /// `g a`: dim hunks that have no line by the git user.
+    pub fn toggle_mine_only(&mut self) {
+        self.mine_only = !self.mine_only;
+    }
+
+    /// Hunks of the current file with at least one new-side line blamed on
+    /// the git user. Uncommitted lines count as the user's, and so does every
+    /// hunk of a worktree or index diff. Pure deletions can't be attributed
+    /// and count as the user's too. `None` when ownership can't be told (no
+    /// repo, no `user.name`, blame failed), so nothing gets dimmed.
+    pub(crate) fn mine_hunks(&mut self) -> Option<FxHashSet<usize>> {
+        if !self.mine_only || !self.current_file_diff_ready() {
+            return None;
+        }
+        let file_idx = self.multi_diff.selected_index;
+        if let Some(cached) = self.mine_hunk_cache.get(&file_idx) {
+            return cached.clone();
+        }
+        let mine = self.compute_mine_hunks();
+        self.mine_hunk_cache.insert(file_idx, mine.clone());
+        mine
+    }
+
+    fn compute_mine_hunks(&mut self) -> Option<FxHashSet<usize>> {
+        let (_, new_source) = self.multi_diff.blame_sources()?;
+        self.ensure_blame_user_name();
+        let user = self.blame_user_name.clone()?;
+        let root = self.multi_diff.repo_root()?.to_path_buf();
+        let path = self.multi_diff.current_file()?.path.clone();
+        let nav = self.multi_diff.current_navigator();
+        let changes = &nav.diff().changes;
+        let hunk_lines: Vec<(usize, Vec<usize>)> = nav
+            .hunks()
+            .iter()
+            .map(|hunk| {
+                let lines = hunk
+                    .change_ids
+                    .iter()
+                    .filter_map(|id| nav.change_index_for(*id))
+                    .filter_map(|idx| changes.get(idx))
+                    .flat_map(|change| change.spans.iter())
+                    .filter(|span| matches!(span.kind, ChangeKind::Insert | ChangeKind::Replace))
+                    .filter_map(|span| span.new_line)
+                    .collect();
+                (hunk.id, lines)
+            })
+            .collect();
+        if matches!(new_source, BlameSource::Worktree | BlameSource::Index) {
+            return Some(hunk_lines.into_iter().map(|(id, _)| id).collect());
+        }
+        let first = hunk_lines.iter().flat_map(|(_, lines)| lines).min();
+        let last = hunk_lines.iter().flat_map(|(_, lines)| lines).max();
+        let authors: FxHashMap<usize, bool> = match (first, last) {
+            (Some(&first), Some(&last)) => blame_range(&root, &path, first, last, &new_source)?
+                .into_iter()
+                .map(|(line, info)| (line, info.uncommitted || info.author == user))
+                .collect(),
+            _ => FxHashMap::default(),
+        };
+        Some(
+            hunk_lines
+                .into_iter()
+                .filter(|(_, lines)| {
+                    lines.is_empty()
+                        || lines
+                            .iter()
+                            .any(|line| authors.get(line).copied().unwrap_or(false))
+                })
+                .map(|(id, _)| id)
+                .collect(),
+        )
+    }
+
     fn active_view_line(&mut self) -> Option<ViewLine> {
         let frame = oyo_core::AnimationFrame::Idle;
         let nav = self.multi_diff.current_navigator();
@@ -66,51 +140,49 @@ impl App {
         nav.view_line_for_change(frame, change_id)
     }
 
-    fn blame_cache_key_for_line(&self, view_line: &ViewLine) -> Option<BlameCacheKey> {
+    /// True when the line is peeked to its old content, so blame should follow the old side.
+    fn blame_peeks_old(&mut self, view_line: &ViewLine) -> bool {
+        view_line.old_line.is_some() && self.peek_mode_for_line(view_line) == Some(PeekMode::Old)
+    }
+
+    fn blame_cache_key_for_line(&mut self, view_line: &ViewLine) -> Option<BlameCacheKey> {
         let (old_source, new_source) = self.multi_diff.blame_sources()?;
+        let peek_old = self.blame_peeks_old(view_line);
         let file = self.multi_diff.current_file()?;
         let old_path = file.old_path.as_ref().unwrap_or(&file.path);
-        if view_line.new_line.is_none() {
-            if let Some(old_line) = view_line.old_line {
-                return Some(BlameCacheKey {
-                    path: old_path.to_path_buf(),
-                    line: old_line,
-                    source: old_source,
-                });
-            }
-        }
-        let (line, path, source) = match view_line.kind {
-            LineKind::Deleted | LineKind::PendingDelete => {
-                (view_line.old_line?, old_path, old_source)
-            }
-            LineKind::Inserted | LineKind::PendingInsert => {
-                (view_line.new_line?, &file.path, new_source)
-            }
-            LineKind::Modified | LineKind::PendingModify => (
-                view_line.new_line.or(view_line.old_line)?,
-                &file.path,
-                new_source,
-            ),
-            LineKind::Context => {
-                if view_line.has_changes {
-                    if let Some(old_line) = view_line.old_line {
-                        (old_line, old_path, old_source)
+        let (line, side) = if view_line.new_line.is_none() || peek_old {
+            (view_line.old_line?, FileSide::Old)
+        } else {
+            match view_line.kind {
+                LineKind::Deleted | LineKind::PendingDelete => (view_line.old_line?, FileSide::Old),
+                LineKind::Inserted | LineKind::PendingInsert => {
+                    (view_line.new_line?, FileSide::New)
+                }
+                LineKind::Modified | LineKind::PendingModify => {
+                    (view_line.new_line.or(view_line.old_line)?, FileSide::New)
+                }
+                LineKind::Context => {
+                    if view_line.has_changes {
+                        if let Some(old_line) = view_line.old_line {
+                            (old_line, FileSide::Old)
+                        } else {
+                            (view_line.new_line?, FileSide::New)
+                        }
                     } else {
-                        (view_line.new_line?, &file.path, new_source)
+                        (view_line.new_line.or(view_line.old_line)?, FileSide::New)
                     }
-                } else {
-                    (
-                        view_line.new_line.or(view_line.old_line)?,
-                        &file.path,
-                        new_source,
-                    )
                 }
             }
         };
+        let (path, source) = match side {
+            FileSide::Old => (old_path, old_source),
+            FileSide::New => (&file.path, new_source),
+        };
         Some(BlameCacheKey {
             path: path.to_path_buf(),
             line,
             source,
+            side,
         })
     }
 
@@ -142,7 +214,7 @@ impl App {
         }
     }
 
-    fn should_force_uncommitted_blame(&self, view_line: &ViewLine) -> bool {
+    fn should_force_uncommitted_blame(&mut self, view_line: &ViewLine) -> bool {
         let (_, new_source) = match self.multi_diff.blame_sources() {
             Some(sources) => sources,
             None => return false,
@@ -150,6 +222,10 @@ impl App {
         if !matches!(new_source, BlameSource::Worktree | BlameSource::Index) {
             return false;
         }
+        // Peeked old content resolves against the old source (HEAD for uncommitted diffs).
+        if self.blame_peeks_old(view_line) {
+            return false;
+        }
         matches!(
             view_line.kind,
             LineKind::Inserted
@@ -168,6 +244,7 @@ impl App {
         let range_key = BlamePrefetchKey {
             path: key.path.clone(),
             source: key.source.clone(),
+            side: key.side,
         };
         let entry = self.blame_time_ranges.entry(range_key).or_insert((ts, ts));
         if ts < entry.0 {
@@ -179,9 +256,11 @@ impl App {
     }
 
     fn blame_info_for_line(&mut self, view_line: &ViewLine, allow_sync: bool) -> Option<BlameInfo> {
-        let repo_root = self.multi_diff.repo_root()?;
         let key = self.blame_cache_key_for_line(view_line)?;
-        let BlameCacheKey { path, line, source } = key.clone();
+        let repo_root = self.multi_diff.repo_root()?;
+        let BlameCacheKey {
+            path, line, source, ..
+        } = key.clone();
         let info = if let Some(info) = self.blame_cache.get(&key) {
             info.clone()
         } else {
@@ -222,7 +301,7 @@ impl App {
     }
 
     fn blame_text_for_line(&mut self, view_line: &ViewLine) -> Option<String> {
-        if !self.blame_enabled {
+        if !self.blame_enabled || self.current_file_structural() {
             return None;
         }
         if self.should_force_uncommitted_blame(view_line) {
@@ -267,7 +346,7 @@ impl App {
         view_line: &ViewLine,
         now: i64,
     ) -> Option<super::BlameDisplay> {
-        if !self.blame_enabled {
+        if !self.blame_enabled || self.current_file_structural() {
             return None;
         }
         if self.should_force_uncommitted_blame(view_line) {
@@ -299,6 +378,7 @@ impl App {
         let range_key = BlamePrefetchKey {
             path: key.path.clone(),
             source: key.source.clone(),
+            side: key.side,
         };
         let range = self.blame_time_ranges.get(&range_key).copied();
         let computed = display.and_then(|display| {
@@ -350,6 +430,7 @@ impl App {
                 let _ = resp_tx.send(BlameResponse {
                     path: req.path,
                     source: req.source,
+                    side: req.side,
                     start: req.start,
                     end: req.end,
                     entries,
@@ -369,12 +450,14 @@ impl App {
             let range_key = BlamePrefetchKey {
                 path: resp.path.clone(),
                 source: resp.source.clone(),
+                side: resp.side,
             };
             for (line_num, info) in resp.entries {
                 let key = BlameCacheKey {
                     path: resp.path.clone(),
                     line: line_num,
                     source: resp.source.clone(),
+                    side: resp.side,
                 };
                 if let Some(ts) = info.author_time {
                     let entry = self
@@ -411,6 +494,7 @@ impl App {
         repo_root: &std::path::Path,
         path: &std::path::Path,
         source: &BlameSource,
+        side: FileSide,
         start: usize,
         end: usize,
     ) {
@@ -418,6 +502,7 @@ impl App {
         let key = BlamePrefetchKey {
             path: path.to_path_buf(),
             source: source.clone(),
+            side,
         };
         if let Some(range) = self.blame_prefetch.get(&key) {
             if start >= range.start && end <= range.end {
@@ -434,6 +519,7 @@ impl App {
                 repo_root: repo_root.to_path_buf(),
                 path: path.to_path_buf(),
                 source: source.clone(),
+                side,
                 start,
                 end,
             });
@@ -448,7 +534,7 @@ impl App {
         visible_indices: &[usize],
         visible_height: usize,
     ) {
-        if !self.blame_enabled || visible_indices.is_empty() {
+        if !self.blame_enabled || visible_indices.is_empty() || self.current_file_structural() {
             return;
         }
         if self.animation_phase != super::AnimationPhase::Idle {
@@ -482,6 +568,7 @@ impl App {
 
         for idx in visible_indices {
             let line = &view_lines[*idx];
+            let peek_old = self.blame_peeks_old(line);
             match line.kind {
                 LineKind::Deleted | LineKind::PendingDelete => {
                     if let Some(old_line) = line.old_line {
@@ -489,6 +576,7 @@ impl App {
                             path: old_path_buf.clone(),
                             line: old_line,
                             source: old_source.clone(),
+                            side: FileSide::Old,
                         };
                         if !self.blame_cache.contains_key(&key) {
                             missing_old.push(old_line);
@@ -504,6 +592,7 @@ impl App {
                             path: file_path.clone(),
                             line: new_line,
                             source: new_source.clone(),
+                            side: FileSide::New,
                         };
                         if !self.blame_cache.contains_key(&key) {
                             missing_new.push(new_line);
@@ -514,12 +603,13 @@ impl App {
                     }
                 }
                 LineKind::Modified | LineKind::PendingModify | LineKind::Context => {
-                    if line.new_line.is_none() {
+                    if line.new_line.is_none() || peek_old {
                         if let Some(old_line) = line.old_line {
                             let key = BlameCacheKey {
                                 path: old_path_buf.clone(),
                                 line: old_line,
                                 source: old_source.clone(),
+                                side: FileSide::Old,
                             };
                             if !self.blame_cache.contains_key(&key) {
                                 missing_old.push(old_line);
@@ -533,6 +623,7 @@ impl App {
                             path: file_path.clone(),
                             line: line_num,
                             source: new_source.clone(),
+                            side: FileSide::New,
                         };
                         if !self.blame_cache.contains_key(&key) {
                             missing_new.push(line_num);
@@ -597,55 +688,94 @@ impl App {
             }
         }
         let mut did_prefetch = false;
-        let mut schedule_range = |lines: &Vec<usize>, path: &PathBuf, source: &BlameSource| {
-            if lines.is_empty() {
-                return;
-            }
-            let min_line = *lines.iter().min().unwrap();
-            let max_line_found = *lines.iter().max().unwrap();
-            let start = min_line.saturating_sub(margin).max(1);
-            let end = max_line_found.saturating_add(margin);
-            let mut fetch_start = start;
-            let mut fetch_end = end;
-            let key = BlamePrefetchKey {
-                path: path.clone(),
-                source: source.clone(),
-            };
-            if let Some(prev) = self.blame_prefetch.get(&key) {
-                if start >= prev.start && end <= prev.end {
+        let mut schedule_range =
+            |lines: &Vec<usize>, path: &PathBuf, source: &BlameSource, side: FileSide| {
+                if lines.is_empty() {
                     return;
                 }
-                let union_start = start.min(prev.start);
-                let union_end = end.max(prev.end);
-                if union_end.saturating_sub(union_start) <= max_range {
-                    fetch_start = union_start;
-                    fetch_end = union_end;
-                }
-            }
-            self.queue_blame_range(&repo_root, path, source, fetch_start, fetch_end);
-            self.blame_prefetch.insert(
-                BlamePrefetchKey {
+                let min_line = *lines.iter().min().unwrap();
+                let max_line_found = *lines.iter().max().unwrap();
+                let start = min_line.saturating_sub(margin).max(1);
+                let end = max_line_found.saturating_add(margin);
+                let mut fetch_start = start;
+                let mut fetch_end = end;
+                let key = BlamePrefetchKey {
                     path: path.clone(),
                     source: source.clone(),
-                },
-                BlamePrefetchRange {
-                    start: fetch_start,
-                    end: fetch_end,
-                },
-            );
-            did_prefetch = true;
-        };
+                    side,
+                };
+                if let Some(prev) = self.blame_prefetch.get(&key) {
+                    if start >= prev.start && end <= prev.end {
+                        return;
+                    }
+                    let union_start = start.min(prev.start);
+                    let union_end = end.max(prev.end);
+                    if union_end.saturating_sub(union_start) <= max_range {
+                        fetch_start = union_start;
+                        fetch_end = union_end;
+                    }
+                }
+                self.queue_blame_range(&repo_root, path, source, side, fetch_start, fetch_end);
+                self.blame_prefetch.insert(
+                    BlamePrefetchKey {
+                        path: path.clone(),
+                        source: source.clone(),
+                        side,
+                    },
+                    BlamePrefetchRange {
+                        start: fetch_start,
+                        end: fetch_end,
+                    },
+                );
+                did_prefetch = true;
+            };
 
-        schedule_range(&missing_old, &old_path_buf, &old_source);
-        schedule_range(&missing_new, &file_path, &new_source);
+        schedule_range(&missing_old, &old_path_buf, &old_source, FileSide::Old);
+        schedule_range(&missing_new, &file_path, &new_source, FileSide::New);
 
         if did_prefetch {
             self.blame_prefetch_at = Some(Instant::now());
         }
     }
 
+    /// Copy `git show` of the commit that last touched the active line. The
+    /// outcome shows as the line's blame hint.
+    pub fn yank_blame_commit(&mut self) {
+        if !self.blame_enabled || self.skip_structural_lines() {
+            return;
+        }
+        let Some(line) = self.active_view_line() else {
+            return;
+        };
+        let info = if self.should_force_uncommitted_blame(&line) {
+            None
+        } else {
+            self.blame_info_for_line(&line, true)
+        };
+        let text = match info {
+            None => "Uncommitted: no commit to copy".to_string(),
+            Some(info) if info.uncommitted => "Uncommitted: no commit to copy".to_string(),
+            Some(info) => {
+                let short = &info.commit[..info.commit.len().min(7)];
+                let shown = self
+                    .multi_diff
+                    .repo_root()
+                    .and_then(|root| git_show(root, &info.commit));
+                match shown {
+                    Some(shown) if self.copy_text(&shown) => format!("Copied git show {short}"),
+                    _ => format!("Could not copy git show {short}"),
+                }
+            }
+        };
+        self.clear_blame_hunk_hint();
+        self.blame_step_hint = Some(BlameStepHint {
+            change_id: line.change_id,
+            text,
+        });
+    }
+
     pub fn trigger_blame_hint(&mut self) {
-        if !self.blame_enabled {
+        if !self.blame_enabled || self.skip_structural_lines() {
             return;
         }
         self.clear_blame_hunk_hint();
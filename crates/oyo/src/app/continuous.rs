@@ -0,0 +1,277 @@
+//! Continuous mode: every file in one no-step scroll, separated by headers
+
+use super::types::{ContinuousCache, ContinuousRow};
+use super::utils::{fold_context_view, line_has_query};
+use super::{App, ViewMode};
+use crate::views::view_spans_to_text;
+use oyo_core::multi::DiffStatus;
+use oyo_core::LineKind;
+use regex::Regex;
+use std::sync::Arc;
+
+impl App {
+    pub fn toggle_continuous_view(&mut self) {
+        if self.continuous_view {
+            self.exit_continuous_view();
+        } else {
+            self.enter_continuous_view();
+        }
+    }
+
+    /// Switch to continuous mode (no-step, unified) anchored at the current file.
+    pub fn enter_continuous_view(&mut self) {
+        if self.continuous_view || !self.is_multi_file() {
+            return;
+        }
+        if self.stepping {
+            self.toggle_stepping();
+        }
+        if self.view_mode != ViewMode::UnifiedPane {
+            self.set_view_mode(ViewMode::UnifiedPane);
+        }
+        let idx = self.multi_diff.selected_index;
+        self.save_no_step_state_snapshot(idx);
+        self.save_scroll_position_for(idx);
+        self.continuous_view = true;
+        self.continuous_cache = None;
+        self.horizontal_scroll = 0;
+        self.reset_search_for_file_switch();
+        self.scroll_offset = self.continuous_header_row(idx).unwrap_or(0);
+    }
+
+    /// Leave continuous mode, landing on the file that was at the top.
+    pub(crate) fn exit_continuous_view(&mut self) {
+        if !self.continuous_view {
+            return;
+        }
+        self.sync_continuous_selection();
+        self.continuous_view = false;
+        self.continuous_header_rows.clear();
+        self.reset_search_for_file_switch();
+        let idx = self.multi_diff.selected_index;
+        self.restore_scroll_position_for(idx);
+        self.centered_once = false;
+        self.handle_file_enter();
+    }
+
+    pub fn toggle_continuous_file_collapsed(&mut self, idx: usize) {
+        if idx >= self.multi_diff.file_count() {
+            return;
+        }
+        if self.continuous_collapsed.len() < self.multi_diff.file_count() {
+            self.continuous_collapsed
+                .resize(self.multi_diff.file_count(), false);
+        }
+        self.continuous_collapsed[idx] = !self.continuous_collapsed[idx];
+        self.continuous_cache = None;
+        if let Some(row) = self.continuous_header_row(idx) {
+            if row < self.scroll_offset {
+                self.scroll_offset = row;
+            }
+        }
+    }
+
+    pub(crate) fn continuous_file_collapsed(&self, idx: usize) -> bool {
+        self.continuous_collapsed.get(idx).copied().unwrap_or(false)
+    }
+
+    /// Rows of the concatenated view, rebuilt when folding or file contents change.
+    pub(crate) fn continuous_rows(&mut self) -> Arc<Vec<ContinuousRow>> {
+        if let Some(cache) = &self.continuous_cache {
+            if cache.fold_context == self.fold_context {
+                return cache.rows.clone();
+            }
+        }
+        let mut rows = Vec::new();
+        for idx in 0..self.multi_diff.file_count() {
+            rows.push(ContinuousRow::Header(idx));
+            if self.continuous_file_collapsed(idx) {
+                continue;
+            }
+            if self.multi_diff.files[idx].read_error.is_some() {
+                rows.push(ContinuousRow::Notice(idx, "failed to read"));
+                continue;
+            }
+            if self.multi_diff.files[idx].binary {
+                rows.push(ContinuousRow::Notice(idx, "binary file"));
+                continue;
+            }
+            match self.multi_diff.diff_status(idx) {
+                DiffStatus::Ready => {}
+                DiffStatus::Deferred | DiffStatus::Computing => {
+                    self.queue_diff_for_file(idx);
+                    rows.push(ContinuousRow::Notice(idx, "diffing…"));
+                    continue;
+                }
+                DiffStatus::Failed => {
+                    rows.push(ContinuousRow::Notice(idx, "diff failed"));
+                    continue;
+                }
+                DiffStatus::Disabled => {
+                    rows.push(ContinuousRow::Notice(idx, "diff disabled (file too large)"));
+                    continue;
+                }
+            }
+            self.multi_diff.ensure_full_navigator(idx);
+            let Some(nav) = self.multi_diff.navigator(idx) else {
+                continue;
+            };
+            let view = fold_context_view(nav.final_view(), self.fold_context, |_| (0, 0));
+            if view.is_empty() {
+                rows.push(ContinuousRow::Notice(idx, "empty file"));
+            }
+            rows.extend(view.into_iter().map(|line| ContinuousRow::Line(idx, line)));
+        }
+        let rows = Arc::new(rows);
+        self.continuous_cache = Some(ContinuousCache {
+            fold_context: self.fold_context,
+            rows: rows.clone(),
+        });
+        rows
+    }
+
+    fn continuous_header_row(&mut self, file_idx: usize) -> Option<usize> {
+        self.continuous_rows()
+            .iter()
+            .position(|row| matches!(row, ContinuousRow::Header(idx) if *idx == file_idx))
+    }
+
+    /// Keep the selected file in step with the file at the top of the viewport.
+    pub(crate) fn sync_continuous_selection(&mut self) {
+        let rows = self.continuous_rows();
+        let top = self.scroll_offset.min(rows.len().saturating_sub(1));
+        let Some(file_idx) = rows.get(top).map(ContinuousRow::file_index) else {
+            return;
+        };
+        if file_idx != self.multi_diff.selected_index {
+            self.multi_diff.select_file(file_idx);
+            self.update_file_list_scroll();
+        }
+    }
+
+    /// Scroll so the header of `file_idx` is at the top.
+    pub(crate) fn continuous_scroll_to_file(&mut self, file_idx: usize) {
+        if let Some(row) = self.continuous_header_row(file_idx) {
+            self.scroll_offset = row;
+            self.centered_once = false;
+            self.multi_diff.select_file(file_idx);
+            self.update_file_list_scroll();
+        }
+    }
+
+    /// Row indices where a run of changed lines starts.
+    fn continuous_hunk_starts(&mut self) -> Vec<usize> {
+        let rows = self.continuous_rows();
+        let mut starts = Vec::new();
+        let mut prev_changed = false;
+        for (row_idx, row) in rows.iter().enumerate() {
+            let changed =
+                matches!(row, ContinuousRow::Line(_, line) if line.kind != LineKind::Context);
+            if changed && !prev_changed {
+                starts.push(row_idx);
+            }
+            prev_changed = changed;
+        }
+        starts
+    }
+
+    pub(crate) fn continuous_next_hunk(&mut self) {
+        let context = self.no_step_hunk_context;
+        let current = self.scroll_offset;
+        if let Some(start) = self
+            .continuous_hunk_starts()
+            .into_iter()
+            .map(|start| start.saturating_sub(context))
+            .find(|&target| target > current)
+        {
+            self.scroll_offset = start;
+            self.centered_once = false;
+        }
+    }
+
+    pub(crate) fn continuous_prev_hunk(&mut self) {
+        let context = self.no_step_hunk_context;
+        let current = self.scroll_offset;
+        if let Some(start) = self
+            .continuous_hunk_starts()
+            .into_iter()
+            .map(|start| start.saturating_sub(context))
+            .rev()
+            .find(|&target| target < current)
+        {
+            self.scroll_offset = start;
+            self.centered_once = false;
+        }
+    }
+
+    /// `:<line>` jumps within the file at the top; `:h<num>` counts hunks across all files.
+    pub(crate) fn continuous_goto(&mut self, query: &str) {
+        if let Some(rest) = query.strip_prefix(['h', 'H']) {
+            let rest = rest.trim_start_matches(|c: char| c == ':' || c.is_whitespace());
+            if let Ok(num) = rest.parse::<usize>() {
+                let starts = self.continuous_hunk_starts();
+                let idx = num.saturating_sub(1).min(starts.len().saturating_sub(1));
+                if let Some(&start) = starts.get(idx) {
+                    self.scroll_offset = start.saturating_sub(self.no_step_hunk_context);
+                    self.centered_once = false;
+                    self.flash_locate(start);
+                }
+            }
+            return;
+        }
+        let Ok(line_number) = query.parse::<usize>() else {
+            return;
+        };
+        self.sync_continuous_selection();
+        let file_idx = self.multi_diff.selected_index;
+        let rows = self.continuous_rows();
+        let target = rows.iter().position(|row| match row {
+            ContinuousRow::Line(idx, line) => {
+                *idx == file_idx && line.new_line.is_some_and(|n| n >= line_number)
+            }
+            _ => false,
+        });
+        if let Some(row) = target {
+            let half = self.last_viewport_height / 2;
+            self.scroll_offset = row.saturating_sub(half);
+            self.centered_once = false;
+            self.flash_locate(row);
+        }
+    }
+
+    pub(super) fn continuous_search_matches(&mut self, regex: &Regex) -> Vec<usize> {
+        self.continuous_rows()
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| match row {
+                ContinuousRow::Line(_, line) => {
+                    line_has_query(&view_spans_to_text(&line.spans), regex)
+                }
+                _ => false,
+            })
+            .map(|(row_idx, _)| row_idx)
+            .collect()
+    }
+
+    /// Collapse or expand a file when its header is clicked.
+    pub fn handle_continuous_click(&mut self, column: u16, row: u16) -> bool {
+        if !self.continuous_view {
+            return false;
+        }
+        let Some((x, _, width, _)) = self.diff_view_area else {
+            return false;
+        };
+        if column < x || column >= x.saturating_add(width) {
+            return false;
+        }
+        let Some(&(_, file_idx)) = self
+            .continuous_header_rows
+            .iter()
+            .find(|(header_row, _)| *header_row == row)
+        else {
+            return false;
+        };
+        self.toggle_continuous_file_collapsed(file_idx);
+        true
+    }
+}
@@ -99,11 +99,13 @@ impl App {
             match resp.diff {
                 Ok(diff) => {
                     self.multi_diff.apply_diff_result(resp.file_index, diff);
+                    self.continuous_cache = None;
                     if resp.file_index == self.multi_diff.selected_index {
                         self.multi_diff.ensure_full_navigator(resp.file_index);
                     }
                     if resp.file_index == self.multi_diff.selected_index {
                         self.reset_current_max_line_width();
+                        self.apply_auto_fold();
                         if !self.files_visited[resp.file_index]
                             || !self.no_step_visited[resp.file_index]
                         {
@@ -113,6 +115,7 @@ impl App {
                 }
                 Err(_) => {
                     self.multi_diff.mark_diff_failed(resp.file_index);
+                    self.continuous_cache = None;
                 }
             }
         }
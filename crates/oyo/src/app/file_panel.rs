@@ -1,6 +1,7 @@
-use super::{App, DIFF_VIEW_MIN_WIDTH, FILE_PANEL_MIN_WIDTH};
+use super::{App, FILE_PANEL_MIN_WIDTH};
+use oyo_core::{FileStatus, MultiFileDiff};
 
-fn point_in_rect(rect: (u16, u16, u16, u16), column: u16, row: u16) -> bool {
+pub(super) fn point_in_rect(rect: (u16, u16, u16, u16), column: u16, row: u16) -> bool {
     let (x, y, width, height) = rect;
     let end_x = x.saturating_add(width);
     let end_y = y.saturating_add(height);
@@ -64,6 +65,91 @@ impl App {
             .unwrap_or(false)
     }
 
+    /// Whether the file panel fits beside the diff in `width` columns. It
+    /// auto-hides when too narrow unless the user toggled it, and a running
+    /// file filter brings it back so the matches stay visible.
+    pub(crate) fn update_file_panel_auto_hide(&mut self, width: u16) -> bool {
+        if !self.is_multi_file() || !self.file_panel_visible {
+            self.file_panel_auto_hidden = false;
+            return false;
+        }
+        if self.file_panel_manually_set {
+            self.file_panel_auto_hidden = false;
+            return true;
+        }
+        let fits = width >= FILE_PANEL_MIN_WIDTH + self.diff_view_min_width();
+        // Track if panel is auto-hidden (for toggle behavior)
+        self.file_panel_auto_hidden = !fits && !self.file_filter_active;
+        !self.file_panel_auto_hidden
+    }
+
+    /// Mark or unmark the selected file for batch operations.
+    pub fn toggle_file_mark(&mut self) {
+        let idx = self.multi_diff.selected_index;
+        if !self.marked_files.remove(&idx) {
+            self.marked_files.insert(idx);
+        }
+    }
+
+    pub fn is_file_marked(&self, idx: usize) -> bool {
+        self.marked_files.contains(&idx)
+    }
+
+    /// Paths of the marked files in panel order, one per line, or the
+    /// selected file's path when nothing is marked.
+    pub(crate) fn marked_paths_text(&self) -> Option<String> {
+        let files = &self.multi_diff.files;
+        if self.marked_files.is_empty() {
+            let file = files.get(self.multi_diff.selected_index)?;
+            return Some(file.path.display().to_string());
+        }
+        let paths: Vec<String> = files
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| self.marked_files.contains(idx))
+            .map(|(_, file)| file.path.display().to_string())
+            .collect();
+        Some(paths.join("\n"))
+    }
+
+    pub fn yank_marked_paths(&mut self) {
+        if let Some(text) = self.marked_paths_text() {
+            self.copy_text(&text);
+        }
+    }
+
+    /// With exactly two files marked, diff the first one's new side against
+    /// the second's in a transient viewer. Returns false otherwise.
+    pub fn compare_marked_files(&mut self) -> bool {
+        let marked: Vec<usize> = self.marked_files.iter().copied().collect();
+        let [first, second] = marked[..] else {
+            return false;
+        };
+        // Panel order: the earlier file is the old side.
+        let (old_idx, new_idx) = (first.min(second), first.max(second));
+        self.multi_diff.ensure_loaded(old_idx);
+        self.multi_diff.ensure_loaded(new_idx);
+        let files = &self.multi_diff.files;
+        let (Some((_, old)), Some((_, new))) = (
+            self.multi_diff.file_contents(old_idx),
+            self.multi_diff.file_contents(new_idx),
+        ) else {
+            return false;
+        };
+        let old_path = files[old_idx].path.clone();
+        let mut diff = MultiFileDiff::from_file_pair(
+            old_path.clone(),
+            files[new_idx].path.clone(),
+            old.to_string(),
+            new.to_string(),
+        );
+        // Shown like a rename so both names stay visible.
+        diff.files[0].old_path = Some(old_path);
+        diff.files[0].status = FileStatus::Renamed;
+        self.open_compare = Some(diff);
+        true
+    }
+
     pub fn toggle_file_panel(&mut self) {
         if self.file_panel_manually_set {
             // Already manually controlled, just toggle
@@ -86,7 +172,7 @@ impl App {
 
     pub fn clamp_file_panel_width(&self, viewport_width: u16) -> u16 {
         let max_panel = viewport_width
-            .saturating_sub(DIFF_VIEW_MIN_WIDTH)
+            .saturating_sub(self.diff_view_min_width())
             .max(FILE_PANEL_MIN_WIDTH);
         self.file_panel_width.clamp(FILE_PANEL_MIN_WIDTH, max_panel)
     }
@@ -99,6 +185,11 @@ impl App {
         self.file_panel_manually_set = true;
     }
 
+    pub fn reset_file_panel_width(&mut self, viewport_width: u16) {
+        self.file_panel_width = self.file_panel_default_width;
+        self.file_panel_width = self.clamp_file_panel_width(viewport_width);
+    }
+
     pub fn start_file_panel_resize(&mut self, column: u16, row: u16) -> bool {
         let (x, y, width, height) = match self.file_panel_rect {
             Some(rect) => rect,
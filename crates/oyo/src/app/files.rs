@@ -1,11 +1,21 @@
-use super::{AnimationPhase, App, FileDiskStamp, ViewMode};
+use super::utils::{file_list_group, normalize_path, path_tokens};
+use super::{
+    AnimationPhase, App, FileDiskStamp, FilePathStyle, GotoPathHint, ViewMode, GOTO_PATH_HINT_MS,
+};
+use crate::config::{EmptyStepMode, FileSortOrder};
 use oyo_core::multi::FileSide;
+use oyo_core::FileStatus;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+/// Rows above the bottom that still count as "at the bottom" for `watch.follow`
+const FOLLOW_SLACK_LINES: usize = 2;
+
 impl App {
     // File navigation methods
     pub fn next_file(&mut self) {
-        if !self.file_filter.is_empty() {
+        if !self.file_order_is_default() {
             let indices = self.filtered_file_indices();
             if indices.is_empty() {
                 return;
@@ -29,7 +39,7 @@ impl App {
     }
 
     pub fn prev_file(&mut self) {
-        if !self.file_filter.is_empty() {
+        if !self.file_order_is_default() {
             let indices = self.filtered_file_indices();
             if indices.is_empty() {
                 return;
@@ -52,7 +62,7 @@ impl App {
     }
 
     pub(super) fn next_file_wrapped(&mut self) -> bool {
-        if !self.file_filter.is_empty() {
+        if !self.file_order_is_default() {
             let indices = self.filtered_file_indices();
             if indices.is_empty() {
                 return false;
@@ -85,7 +95,7 @@ impl App {
     }
 
     pub(super) fn prev_file_wrapped(&mut self) -> bool {
-        if !self.file_filter.is_empty() {
+        if !self.file_order_is_default() {
             let indices = self.filtered_file_indices();
             if indices.is_empty() {
                 return false;
@@ -118,7 +128,14 @@ impl App {
     }
 
     pub fn select_file(&mut self, index: usize) {
+        if self.continuous_view {
+            self.continuous_scroll_to_file(index);
+            return;
+        }
         let old_index = self.multi_diff.selected_index;
+        if index != old_index {
+            self.record_jump();
+        }
         self.clear_step_edge_hint();
         self.clear_hunk_edge_hint();
         self.clear_blame_step_hint();
@@ -127,6 +144,11 @@ impl App {
             self.save_no_step_state_snapshot(old_index);
         }
         self.save_scroll_position_for(old_index);
+        let sync_line = if self.sync_line_across_files && index != old_index {
+            self.focused_source_line()
+        } else {
+            None
+        };
         self.multi_diff.select_file(index);
         self.restore_scroll_position_for(self.multi_diff.selected_index);
         self.animation_phase = AnimationPhase::Idle;
@@ -135,13 +157,98 @@ impl App {
         self.view_build_pending = false;
         self.reset_search_for_file_switch();
         self.centered_once = false;
+        self.split_new_skew = 0;
+        self.peek_next = None;
         self.update_file_list_scroll();
         self.handle_file_enter();
+        if let Some(line) = sync_line {
+            // Lines past the end of the new file clamp to its last line.
+            let paused = std::mem::replace(&mut self.jump_recording_paused, true);
+            self.goto_line_number(line);
+            self.jump_recording_paused = paused;
+        }
+    }
+
+    /// `gf`: find a path on the cursor line, resolved against the current
+    /// file's directory and then the repo root. A file in this diff is
+    /// selected; a file only on disk is returned for the caller to open in
+    /// the editor; otherwise a "not found" hint is shown.
+    pub fn goto_path_under_cursor(&mut self) -> Option<PathBuf> {
+        let frame = self.animation_frame();
+        let line = self
+            .current_view_with_frame(frame)
+            .iter()
+            .find(|line| line.is_primary_active)?
+            .content
+            .clone();
+        let tokens = path_tokens(&line);
+        let first = tokens.first()?.to_string();
+
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let absolute = |path: &Path| normalize_path(&cwd.join(path));
+        // Without a repo or directory roots, file paths are relative to the cwd.
+        let diff_paths: Vec<PathBuf> = self
+            .multi_diff
+            .files
+            .iter()
+            .enumerate()
+            .map(|(idx, file)| {
+                let path = self.multi_diff.source_path(idx, FileSide::New);
+                absolute(path.as_deref().unwrap_or(&file.path))
+            })
+            .collect();
+        let current = self.multi_diff.selected_index;
+        let file_dir = diff_paths
+            .get(current)
+            .and_then(|path| path.parent().map(Path::to_path_buf));
+        let root = self.multi_diff.repo_root().map(absolute);
+
+        for token in &tokens {
+            let token = token.strip_prefix("~/").map_or_else(
+                || PathBuf::from(token),
+                |rest| dirs::home_dir().unwrap_or_default().join(rest),
+            );
+            let bases = [file_dir.as_ref(), root.as_ref(), Some(&cwd)];
+            for base in bases.into_iter().flatten() {
+                let candidate = absolute(&base.join(&token));
+                if let Some(idx) = diff_paths.iter().position(|path| *path == candidate) {
+                    if idx != current {
+                        self.select_file(idx);
+                    }
+                    return None;
+                }
+                if candidate.is_file() {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        self.goto_path_hint = Some(GotoPathHint {
+            path: first,
+            until: Instant::now() + Duration::from_millis(GOTO_PATH_HINT_MS),
+        });
+        None
+    }
+
+    /// `:f<num>`: select the Nth file as the panel lists it (filtered and
+    /// sorted); out-of-range numbers show a "not found" hint.
+    pub(super) fn goto_file_number(&mut self, number: usize) {
+        let indices = self.filtered_file_indices();
+        match number.checked_sub(1).and_then(|idx| indices.get(idx)) {
+            Some(&index) => self.select_file(index),
+            None => {
+                self.goto_path_hint = Some(GotoPathHint {
+                    path: format!("file {number}"),
+                    until: Instant::now() + Duration::from_millis(GOTO_PATH_HINT_MS),
+                });
+            }
+        }
     }
 
     pub fn start_file_filter(&mut self) {
         self.file_filter_active = true;
         self.file_filter.clear();
+        self.file_filter_cache = None;
         self.file_list_scroll = 0;
         self.ensure_selection_matches_filter();
         self.update_file_list_scroll();
@@ -149,6 +256,30 @@ impl App {
 
     pub fn stop_file_filter(&mut self) {
         self.file_filter_active = false;
+        self.file_filter_return = None;
+    }
+
+    /// Jump to the file panel and start filtering; Esc restores the prior focus.
+    pub fn focus_file_filter(&mut self) {
+        if !self.is_multi_file() {
+            return;
+        }
+        let restore = (self.file_list_focused, self.file_panel_visible);
+        self.file_panel_visible = true;
+        self.file_list_focused = true;
+        self.start_file_filter();
+        self.file_filter_return = Some(restore);
+    }
+
+    /// Esc in the file filter: stop filtering and return focus to where it was
+    /// when `focus_file_filter` opened it.
+    pub fn cancel_file_filter(&mut self) {
+        let restore = self.file_filter_return.take();
+        self.stop_file_filter();
+        if let Some((focused, visible)) = restore {
+            self.file_list_focused = focused;
+            self.file_panel_visible = visible;
+        }
     }
 
     pub fn push_file_filter_char(&mut self, ch: char) {
@@ -183,6 +314,7 @@ impl App {
 
     pub(crate) fn finish_file_enter(&mut self) {
         let idx = self.multi_diff.selected_index;
+        self.apply_auto_fold();
 
         if !self.stepping {
             if !self.files_visited[idx] {
@@ -233,10 +365,44 @@ impl App {
             self.next_step();
             return;
         }
+        if self.empty_step != EmptyStepMode::Message && self.step_is_blank() {
+            self.settle_empty_step(true);
+            return;
+        }
 
         // Regular auto-step on enter (not for Evolution mode)
         if self.auto_step_on_enter && self.view_mode != ViewMode::Evolution {
-            self.next_step();
+            if self.auto_step_delay.is_zero() {
+                self.next_step();
+            } else {
+                self.pending_auto_step = Some((idx, Instant::now() + self.auto_step_delay));
+            }
+        }
+    }
+
+    /// Drop a delayed auto-step once the user navigates on their own.
+    pub fn cancel_pending_auto_step(&mut self) {
+        self.pending_auto_step = None;
+    }
+
+    /// Ask to browse the commits touching the current file (git mode only).
+    pub fn request_file_history(&mut self) {
+        if !self.multi_diff.is_git_mode() {
+            return;
+        }
+        if let Some(file) = self.multi_diff.current_file() {
+            self.open_file_history = Some(file.path.clone());
+        }
+    }
+
+    /// Ask to step through the range's commits that touched the current file
+    /// (commit ranges only).
+    pub fn request_commit_walk(&mut self) {
+        if self.multi_diff.git_range().is_none() {
+            return;
+        }
+        if let Some(file) = self.multi_diff.current_file() {
+            self.open_commit_walk = Some(file.path.clone());
         }
     }
 
@@ -244,7 +410,7 @@ impl App {
         self.multi_diff.is_multi_file()
     }
 
-    fn update_file_list_scroll(&mut self) {
+    pub(super) fn update_file_list_scroll(&mut self) {
         let indices = self.filtered_file_indices();
         if indices.is_empty() {
             self.file_list_scroll = 0;
@@ -254,22 +420,85 @@ impl App {
         // Keep selected file visible in the file list
         let selected = self.multi_diff.selected_index;
         let selected_pos = indices.iter().position(|&i| i == selected).unwrap_or(0);
+        // Rows available in the last rendered list (fallback before first draw)
+        let visible_rows = self
+            .file_list_area
+            .map(|(_, _, _, height)| height.saturating_sub(2) as usize)
+            .filter(|rows| *rows > 0)
+            .unwrap_or(20);
+        if self.file_list_center {
+            // Half the rows above the selection, but never scroll past the
+            // point where the last file reaches the bottom.
+            let centered =
+                self.file_list_top_fitting(&indices, selected_pos, visible_rows / 2 + 1, 0);
+            let max_top = self.file_list_top_fitting(&indices, indices.len() - 1, visible_rows, 0);
+            self.file_list_scroll = centered.min(max_top);
+            return;
+        }
         if selected_pos < self.file_list_scroll {
             self.file_list_scroll = selected_pos;
+            return;
         }
-        // Assume roughly 20 visible files
-        let visible_files = 20;
-        if selected_pos >= self.file_list_scroll + visible_files {
-            self.file_list_scroll = selected_pos.saturating_sub(visible_files - 1);
+        self.file_list_scroll =
+            self.file_list_top_fitting(&indices, selected_pos, visible_rows, self.file_list_scroll);
+    }
+
+    /// Topmost list position from which `pos` still fits in `rows`, not above
+    /// `floor`. When grouped, the top file gets a group header, and every group
+    /// change below it costs a blank separator plus a header.
+    fn file_list_top_fitting(
+        &self,
+        indices: &[usize],
+        pos: usize,
+        rows: usize,
+        floor: usize,
+    ) -> usize {
+        let files = &self.multi_diff.files;
+        let grouped = self.file_path_style == FilePathStyle::Grouped;
+        let mut top = pos;
+        let mut used = if grouped { 2 } else { 1 };
+        while top > floor {
+            let above = file_list_group(&files[indices[top - 1]].display_name);
+            let current = file_list_group(&files[indices[top]].display_name);
+            let cost = if !grouped || above == current { 1 } else { 3 };
+            if used + cost > rows {
+                break;
+            }
+            used += cost;
+            top -= 1;
         }
+        top
     }
 
     fn on_filter_changed(&mut self) {
+        self.refresh_file_filter_cache();
         self.file_list_scroll = 0;
         self.ensure_selection_matches_filter();
         self.update_file_list_scroll();
     }
 
+    /// Rebuild the filter cache, narrowing the previous result when the query only grew.
+    fn refresh_file_filter_cache(&mut self) {
+        if self.file_filter.is_empty() {
+            self.file_filter_cache = None;
+            return;
+        }
+        let query = self.file_filter.to_ascii_lowercase();
+        let indices = match self.file_filter_cache.take() {
+            Some((prev, prev_indices)) if self.file_filter.starts_with(&prev) => prev_indices
+                .into_iter()
+                .filter(|&idx| {
+                    self.multi_diff
+                        .files
+                        .get(idx)
+                        .is_some_and(|file| file_matches_query(&file.display_name, &query))
+                })
+                .collect(),
+            _ => self.file_indices_for_query(&self.file_filter),
+        };
+        self.file_filter_cache = Some((self.file_filter.clone(), indices));
+    }
+
     fn ensure_selection_matches_filter(&mut self) {
         if self.file_filter.is_empty() {
             return;
@@ -283,8 +512,40 @@ impl App {
         }
     }
 
+    /// File indices in file panel order: filtered by the query, then sorted
+    /// by `files.sort`. The indices still address `multi_diff.files`.
     pub fn filtered_file_indices(&self) -> Vec<usize> {
-        self.file_indices_for_query(&self.file_filter)
+        let mut indices = match &self.file_filter_cache {
+            Some((query, indices)) if *query == self.file_filter => indices.clone(),
+            _ => self.file_indices_for_query(&self.file_filter),
+        };
+        self.sort_file_indices(&mut indices);
+        indices
+    }
+
+    /// True when the file panel lists every file in diff order.
+    pub(super) fn file_order_is_default(&self) -> bool {
+        self.file_filter.is_empty() && self.file_sort == FileSortOrder::None
+    }
+
+    pub(super) fn sort_file_indices(&self, indices: &mut [usize]) {
+        let diff = &self.multi_diff;
+        let files = &diff.files;
+        match self.file_sort {
+            FileSortOrder::None => {}
+            FileSortOrder::Path => {
+                indices.sort_by(|&a, &b| files[a].display_name.cmp(&files[b].display_name))
+            }
+            FileSortOrder::Changes => {
+                indices.sort_by_key(|&idx| std::cmp::Reverse(diff.change_count(idx)))
+            }
+            FileSortOrder::Status => indices.sort_by_key(|&idx| match files[idx].status {
+                FileStatus::Added | FileStatus::Untracked => 0,
+                FileStatus::Modified => 1,
+                FileStatus::Renamed => 2,
+                FileStatus::Deleted => 3,
+            }),
+        }
     }
 
     pub(super) fn file_indices_for_query(&self, query: &str) -> Vec<usize> {
@@ -296,11 +557,31 @@ impl App {
             .files
             .iter()
             .enumerate()
-            .filter(|(_, file)| file.display_name.to_ascii_lowercase().contains(&query))
+            .filter(|(_, file)| file_matches_query(&file.display_name, &query))
             .map(|(idx, _)| idx)
             .collect()
     }
 
+    /// Raw old/new bytes of the current binary file for the hex view, if within
+    /// `hex_max_bytes`. Cached per file until the next refresh.
+    pub(crate) fn current_binary_bytes(&mut self) -> Option<Arc<(Vec<u8>, Vec<u8>)>> {
+        if self.hex_max_bytes == 0 {
+            return None;
+        }
+        let idx = self.multi_diff.selected_index;
+        if let Some((cached_idx, bytes)) = &self.binary_bytes_cache {
+            if *cached_idx == idx {
+                return bytes.clone();
+            }
+        }
+        let bytes = self
+            .multi_diff
+            .file_bytes(idx, self.hex_max_bytes)
+            .map(Arc::new);
+        self.binary_bytes_cache = Some((idx, bytes.clone()));
+        bytes
+    }
+
     /// Get current file path for display
     pub fn current_file_path(&self) -> String {
         self.multi_diff
@@ -309,6 +590,34 @@ impl App {
             .unwrap_or_default()
     }
 
+    /// The old path of a renamed file while `show_old_path` is on.
+    pub(crate) fn displayed_old_path(&self, idx: usize) -> Option<String> {
+        if !self.show_old_path {
+            return None;
+        }
+        let file = self.multi_diff.files.get(idx)?;
+        if file.status != FileStatus::Renamed {
+            return None;
+        }
+        file.old_path
+            .as_ref()
+            .map(|path| path.display().to_string())
+    }
+
+    /// Name to show for file `idx`: `display_name`, or the old path of a
+    /// rename while `show_old_path` is on. Content lookups keep using
+    /// `current_file_path`.
+    pub fn file_display_name(&self, idx: usize) -> String {
+        self.displayed_old_path(idx)
+            .or_else(|| {
+                self.multi_diff
+                    .files
+                    .get(idx)
+                    .map(|file| file.display_name.clone())
+            })
+            .unwrap_or_default()
+    }
+
     fn disk_stamp_for_index(&self, idx: usize) -> FileDiskStamp {
         let Some(file) = self.multi_diff.files.get(idx) else {
             return FileDiskStamp::default();
@@ -393,6 +702,9 @@ impl App {
 
     /// Refresh current file from disk
     pub fn refresh_current_file(&mut self) {
+        self.mine_hunk_cache.remove(&self.multi_diff.selected_index);
+        // Scrolling up pauses the follow, like `less +F`.
+        let follow = self.watch_follow && self.scrolled_near_bottom();
         // Preserve no-step hunk scope/cursor context when possible.
         let preserve_no_step_hunk = if !self.stepping {
             let nav = self.multi_diff.current_navigator();
@@ -417,6 +729,8 @@ impl App {
         };
 
         self.multi_diff.refresh_current_file();
+        self.binary_bytes_cache = None;
+        self.continuous_cache = None;
 
         // The navigator is rebuilt at step 0 after refresh; jump to the end
         // so all changes remain visible.
@@ -469,11 +783,32 @@ impl App {
 
         self.refresh_file_disk_baseline_for(idx);
         self.recompute_file_change_state();
+
+        if follow {
+            self.scroll_offset = usize::MAX;
+            self.centered_once = false;
+            self.needs_scroll_to_active = false;
+            if !self.stepping {
+                self.multi_diff.current_navigator().clear_cursor_change();
+                self.multi_diff.current_navigator().set_hunk_scope(false);
+            }
+        }
+    }
+
+    /// The last rows of the view are on screen (within `FOLLOW_SLACK_LINES`).
+    fn scrolled_near_bottom(&mut self) -> bool {
+        let viewport = self.last_viewport_height.max(1);
+        let total = self.total_lines();
+        self.scroll_offset
+            .saturating_add(viewport)
+            .saturating_add(FOLLOW_SLACK_LINES)
+            >= total
     }
 
     /// Refresh all files from git (re-scan for uncommitted changes)
     pub fn refresh_all_files(&mut self) {
         if self.multi_diff.refresh_all_from_git() {
+            self.mine_hunk_cache.clear();
             // Reset scroll states for all files
             let file_count = self.multi_diff.file_count();
             self.scroll_offsets_step = vec![0; file_count];
@@ -484,6 +819,8 @@ impl App {
             self.max_line_widths_no_step = vec![0; file_count];
             self.no_step_visited = vec![false; file_count];
             self.files_visited = vec![false; file_count];
+            self.visited_hunks.clear();
+            self.marked_files.clear();
             self.syntax_caches = vec![None; file_count];
             self.step_state_snapshots = vec![None; file_count];
             self.no_step_state_snapshots = vec![None; file_count];
@@ -493,6 +830,10 @@ impl App {
             self.centered_once = false;
             self.handle_file_enter();
 
+            self.file_filter_cache = None;
+            self.refresh_file_filter_cache();
+            self.binary_bytes_cache = None;
+            self.continuous_cache = None;
             self.rebuild_file_disk_baseline();
             self.files_changed_on_disk = false;
             self.invalidate_review_repo_file_cache();
@@ -500,7 +841,6 @@ impl App {
     }
 
     /// Get the total number of lines in the current view
-    #[allow(dead_code)]
     pub fn total_lines(&mut self) -> usize {
         let frame = self.animation_frame();
         self.current_view_with_frame(frame).len()
@@ -515,7 +855,38 @@ impl App {
         (diff.insertions, diff.deletions)
     }
 
+    /// Words added and removed in the current file, for prose files only
+    /// (line counts are misleading once paragraphs get reflowed).
+    pub fn word_stats(&mut self) -> Option<(usize, usize)> {
+        let file = self.multi_diff.current_file()?;
+        if !is_prose_path(&file.path) || self.current_file_is_binary() {
+            return None;
+        }
+        Some(self.multi_diff.current_navigator().diff().word_counts())
+    }
+
     pub fn current_file_is_binary(&self) -> bool {
         self.multi_diff.current_file_is_binary()
     }
+
+    /// Why the current file couldn't be read from disk, if it couldn't.
+    pub fn current_read_error(&self) -> Option<String> {
+        self.multi_diff
+            .current_file()
+            .and_then(|file| file.read_error.clone())
+    }
+}
+
+fn is_prose_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            ["md", "markdown", "txt"]
+                .iter()
+                .any(|prose| ext.eq_ignore_ascii_case(prose))
+        })
+}
+
+fn file_matches_query(name: &str, lowered_query: &str) -> bool {
+    name.to_ascii_lowercase().contains(lowered_query)
 }
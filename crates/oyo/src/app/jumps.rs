@@ -0,0 +1,111 @@
+//! Jump list: `ctrl-o`/`ctrl-i` walk back and forward through the positions
+//! that hunk, goto, search and file jumps left from
+
+use super::types::{AnimationPhase, JumpPosition};
+use super::App;
+
+/// Oldest entries drop off past this many
+const JUMP_LIST_LIMIT: usize = 100;
+
+impl App {
+    fn jump_position(&mut self) -> Option<JumpPosition> {
+        if self.continuous_view
+            || self.multi_diff.file_count() == 0
+            || !self.current_file_diff_ready()
+        {
+            return None;
+        }
+        Some(JumpPosition {
+            file_idx: self.multi_diff.selected_index,
+            stepping: self.stepping,
+            step_state: self.multi_diff.current_navigator().state().clone(),
+            scroll_offset: self.scroll_offset,
+            horizontal_scroll: self.horizontal_scroll,
+        })
+    }
+
+    /// Remember the current position before a jump. Jumping from the middle
+    /// of the list drops the forward entries, like a browser.
+    pub(super) fn record_jump(&mut self) {
+        if self.jump_recording_paused {
+            return;
+        }
+        let Some(here) = self.jump_position() else {
+            return;
+        };
+        self.jump_list.truncate(self.jump_index);
+        if !self
+            .jump_list
+            .last()
+            .is_some_and(|last| last.same_place(&here))
+        {
+            self.jump_list.push(here);
+        }
+        if self.jump_list.len() > JUMP_LIST_LIMIT {
+            let excess = self.jump_list.len() - JUMP_LIST_LIMIT;
+            self.jump_list.drain(..excess);
+        }
+        self.jump_index = self.jump_list.len();
+    }
+
+    /// `ctrl-o`: go back to where the last jump left from.
+    pub fn jump_back(&mut self) -> bool {
+        if self.jump_index >= self.jump_list.len() {
+            // Leaving the head of the list: keep this spot so forward returns here.
+            let Some(here) = self.jump_position() else {
+                return false;
+            };
+            match self.jump_list.last() {
+                Some(last) if last.same_place(&here) => {}
+                _ => self.jump_list.push(here),
+            }
+            self.jump_index = self.jump_list.len() - 1;
+        }
+        if self.jump_index == 0 {
+            return false;
+        }
+        self.jump_index -= 1;
+        let target = self.jump_list[self.jump_index].clone();
+        self.restore_jump(target);
+        true
+    }
+
+    /// `ctrl-i`: redo a jump undone by [`App::jump_back`].
+    pub fn jump_forward(&mut self) -> bool {
+        if self.jump_index + 1 >= self.jump_list.len() {
+            return false;
+        }
+        self.jump_index += 1;
+        let target = self.jump_list[self.jump_index].clone();
+        self.restore_jump(target);
+        true
+    }
+
+    fn restore_jump(&mut self, target: JumpPosition) {
+        if target.file_idx >= self.multi_diff.file_count() || self.continuous_view {
+            return;
+        }
+        self.jump_recording_paused = true;
+        if target.file_idx != self.multi_diff.selected_index {
+            self.select_file(target.file_idx);
+        }
+        self.jump_recording_paused = false;
+        self.multi_diff.ensure_full_navigator(target.file_idx);
+        self.clear_peek();
+        self.clear_blame_step_hint();
+        self.clear_blame_hunk_hint();
+        if target.stepping == self.stepping {
+            self.multi_diff
+                .current_navigator()
+                .set_state(target.step_state);
+        }
+        self.scroll_offset = target.scroll_offset;
+        self.split_new_skew = 0;
+        self.horizontal_scroll = target.horizontal_scroll;
+        self.animation_phase = AnimationPhase::Idle;
+        self.animation_progress = 1.0;
+        self.needs_scroll_to_active = false;
+        self.needs_scroll_to_search = false;
+        self.refresh_blame_toggle_hint();
+    }
+}
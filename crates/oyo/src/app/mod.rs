@@ -2,29 +2,37 @@
 
 use crate::blame::BlameInfo;
 use crate::config::{
-    BlameMode, DiffExtentMarkerMode, DiffExtentMarkerScope, DiffForegroundMode, DiffHighlightMode,
-    FileCountMode, FoldContextMode, HunkWrapMode, MentionFileScope, MentionFinder,
-    ModifiedStepMode, ResolvedTheme, StepWrapMode, SyntaxMode,
+    AutoplayEndMode, BlameMode, DeletionStyle, DiffExtentMarkerMode, DiffExtentMarkerScope,
+    DiffForegroundMode, DiffHighlightMode, EmptyStepMode, FileCountMode, FilePathStyle,
+    FileSortOrder, FoldContextMode, GutterSide, HunkWrapMode, MentionFileScope, MentionFinder,
+    ModifiedStepMode, ResolvedTheme, ReviewOutputFormat, ShowTimeMode, SpaceAction,
+    SplitOrientation, StepWrapMode, SyntaxMode,
 };
 use crate::keybindings::Keybindings;
+use crate::notes::PresenterNotes;
 use crate::syntax::{SyntaxCache, SyntaxEngine};
-use crate::time_format::TimeFormatter;
+use crate::time_format::{format_elapsed, TimeFormatter};
 use oyo_core::{
-    multi::DiffStatus, AnimationFrame, LineKind, MultiFileDiff, StepDirection, StepState, ViewLine,
+    multi::{DiffStatus, FileSide},
+    AnimationFrame, LineKind, MultiFileDiff, StepDirection, StepState, ViewLine,
 };
 use ratatui::style::Color;
 use regex::Regex;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 use std::path::PathBuf;
 use std::sync::mpsc;
 use std::time::{Duration, Instant, SystemTime};
 
 mod blame;
+mod continuous;
 mod diff_worker;
 mod file_panel;
 mod files;
+mod jumps;
 mod navigation;
+mod notes;
+mod overview;
 mod palette;
 mod playback;
 mod review;
@@ -33,18 +41,26 @@ mod syntax;
 mod types;
 mod utils;
 
+use file_panel::point_in_rect;
+pub(crate) use overview::stat_bar_cells;
 pub(crate) use types::{
-    AnimationPhase, BlameDisplay, BlameRenderCache, BlameRenderKey, PeekMode, PeekScope, PeekState,
-    UnifiedRenderKey, UnifiedRenderModel, ViewMode, DIFF_VIEW_MIN_WIDTH, FILE_PANEL_MIN_WIDTH,
+    AnimationPhase, BlameDisplay, BlameRenderCache, BlameRenderKey, CommitHeader, ContinuousRow,
+    PeekMode, PeekScope, PeekState, SnapshotFormat, UnifiedRenderKey, UnifiedRenderModel, ViewMode,
+    DIFF_VIEW_MIN_HEIGHT, DIFF_VIEW_MIN_WIDTH, FILE_PANEL_MIN_WIDTH,
 };
 use types::{
     BlameCacheKey, BlamePrefetchKey, BlamePrefetchRange, BlameRequest, BlameResponse,
-    BlameStepHint, DiffRequest, DiffResponse, HunkBounds, HunkEdge, HunkEdgeHint, HunkStart,
-    NoStepState, StepEdge, StepEdgeHint, SyntaxScopeCache,
+    BlameStepHint, ConfigReloadHint, ContinuousCache, DiffRequest, DiffResponse, GotoPathHint,
+    HintKind, HunkBounds, HunkEdge, HunkEdgeHint, HunkStart, HunkSubset, JumpPosition, KeyLogEntry,
+    LineLimit, NoStepState, StepEdge, StepEdgeHint, SyntaxScopeCache,
 };
 use utils::{allow_overscroll_state, max_scroll};
-pub(crate) use utils::{display_metrics, is_conflict_marker, is_fold_line};
+pub(crate) use utils::{
+    apply_highlight_spans, display_metrics, file_list_group, is_conflict_marker, is_fold_line,
+    smart_path_labels,
+};
 
+type BinaryBytesCache = Option<(usize, Option<std::sync::Arc<(Vec<u8>, Vec<u8>)>>)>;
 type UnifiedHunkCacheKey = (usize, ViewMode, FoldContextMode, bool, usize, usize, usize);
 type SplitHunkCacheKey = (usize, FoldContextMode, bool, bool, usize, usize, usize);
 type UnifiedHunkStartsCache = Option<(UnifiedHunkCacheKey, Vec<Option<HunkStart>>)>;
@@ -70,6 +86,8 @@ pub struct App {
     pub autoplay: bool,
     /// True when autoplay is running in reverse
     pub autoplay_reverse: bool,
+    /// Show autoplay direction/remaining steps next to the step counter
+    pub autoplay_indicator: bool,
     /// Current scroll offset
     pub scroll_offset: usize,
     /// Per-file scroll offsets when stepping
@@ -86,6 +104,24 @@ pub struct App {
     pub(crate) keybindings: Keybindings,
     /// Whether to open the commit picker dashboard
     pub open_dashboard: bool,
+    /// Repo-relative file whose commit history to open in the picker
+    pub open_file_history: Option<PathBuf>,
+    /// Step through the range's commits touching this repo-relative file
+    pub open_commit_walk: Option<PathBuf>,
+    /// Transient diff of two marked files to open over this one
+    pub open_compare: Option<MultiFileDiff>,
+    /// All files in one no-step scroll with header rows between them
+    pub continuous_view: bool,
+    /// Files collapsed to their header in continuous mode
+    continuous_collapsed: Vec<bool>,
+    /// Cached rows for continuous mode
+    continuous_cache: Option<ContinuousCache>,
+    /// Screen rows of visible continuous-mode file headers (for click handling)
+    pub(crate) continuous_header_rows: Vec<(u16, usize)>,
+    /// Whether to reload the config file on the next loop iteration
+    pub config_reload_requested: bool,
+    /// Switch between the light and dark theme (needs the config to resolve)
+    pub theme_mode_toggle_requested: bool,
     /// Current animation phase
     pub animation_phase: AnimationPhase,
     /// Animation progress (0.0 to 1.0)
@@ -100,10 +136,14 @@ pub struct App {
     pub file_panel_visible: bool,
     /// File panel width (in columns)
     pub file_panel_width: u16,
+    /// Configured file panel width (restored by reset)
+    pub file_panel_default_width: u16,
     /// File panel full area (x, y, width, height)
     pub file_panel_rect: Option<(u16, u16, u16, u16)>,
     /// Diff content area (x, y, width, height)
     pub diff_view_area: Option<(u16, u16, u16, u16)>,
+    /// Snapshot of the diff view to copy once the next frame is drawn
+    pending_snapshot: Option<SnapshotFormat>,
     /// True when dragging the file panel separator
     pub file_panel_resizing: bool,
     /// File list scroll offset
@@ -116,14 +156,36 @@ pub struct App {
     pub file_filter_area: Option<(u16, u16, u16, u16)>,
     /// When to show per-file +/- counts in the file panel
     pub file_count_mode: FileCountMode,
+    /// `--plain`: no colors or mouse capture, for dumb terminals and logs
+    pub plain: bool,
+    /// Capture mouse events; off leaves the terminal's own text selection
+    pub mouse_capture: bool,
+    /// File panel order (`files.sort`)
+    pub file_sort: FileSortOrder,
+    /// Keep the selected file centered in the panel (`files.center_selection`)
+    pub file_list_center: bool,
+    /// How the panel labels files (`files.path_style`)
+    pub file_path_style: FilePathStyle,
     /// File list filter text
     pub file_filter: String,
+    /// Filtered file indices keyed by the filter text they were built for
+    file_filter_cache: Option<(String, Vec<usize>)>,
+    /// Largest binary file (bytes) shown as a hex diff; 0 disables the hex view
+    pub hex_max_bytes: u64,
+    /// Raw bytes for the hex view, keyed by file index
+    binary_bytes_cache: BinaryBytesCache,
     /// True when filter input is active
     pub file_filter_active: bool,
+    /// Focus and panel visibility to restore when Esc closes a `focus_file_filter`
+    file_filter_return: Option<(bool, bool)>,
     /// Whether animations are enabled (false = instant transitions)
     pub animation_enabled: bool,
     /// Zen mode - hide UI chrome (top bar, progress bar, help bar)
     pub zen_mode: bool,
+    /// Focus mode: dim every line outside the current hunk
+    pub focus_mode: bool,
+    /// Show renamed files under their old path (display only)
+    pub show_old_path: bool,
     /// Flag to scroll to active change on next render (after stepping)
     pub needs_scroll_to_active: bool,
     /// Whether to show the help popover
@@ -132,10 +194,32 @@ pub struct App {
     pub help_scroll: usize,
     /// Max scroll for help popover (computed during render)
     pub help_max_scroll: usize,
+    /// Whether the full-screen file overview (diff --stat) is shown
+    pub show_overview: bool,
+    /// Selected row in the file overview
+    pub overview_selection: usize,
+    /// Visible overview rows (computed during render, used for paging)
+    pub overview_page_rows: usize,
+    /// Esc in the viewer returns to the overview (file was opened from it)
+    pub overview_return: bool,
     /// Git branch name (if in a git repo)
     pub git_branch: Option<String>,
+    /// Position in a sequence of git external-diff invocations (1-based index, total)
+    pub external_diff_progress: Option<(usize, usize)>,
+    /// Short note about how a range was resolved (e.g. merge vs first parent)
+    pub range_note: Option<String>,
+    /// Commit message or range summary for git ranges
+    pub(crate) commit_header: Option<CommitHeader>,
+    /// Show `commit_header` above the first file
+    pub show_commit_header: bool,
+    /// Scroll offset within a long commit header
+    pub(crate) commit_header_scroll: usize,
+    /// Commit header area for mouse scrolling (x, y, width, height)
+    pub(crate) commit_header_area: Option<(u16, u16, u16, u16)>,
     /// Auto-center on active change after stepping (like vim's zz)
     pub auto_center: bool,
+    /// Anchor the first change near the top on launch (cleared once applied)
+    pub startup_anchor_top: bool,
     /// Allow overscroll near EOF when centering
     pub overscroll: bool,
     /// Show top bar in diff view
@@ -168,48 +252,117 @@ pub struct App {
     max_line_widths_no_step: Vec<usize>,
     /// Line wrap mode (when true, horizontal scroll is ignored)
     pub line_wrap: bool,
+    /// Fixed column to wrap at (0 = viewport width)
+    pub wrap_column: usize,
+    /// Poll interval when nothing animates or runs in the background
+    pub idle_tick: Duration,
+    /// Column the length guideline rule is drawn after (0 = off)
+    pub color_column: usize,
     /// Collapse long unchanged (context) blocks
     pub fold_context: FoldContextMode,
     /// Default fold context mode (restored when toggling)
     fold_context_default: FoldContextMode,
+    /// Fold context on entering files with more hunks than this (0 = off)
+    pub auto_fold_threshold: usize,
+    /// The current fold was switched on by `auto_fold_threshold`
+    auto_folded: bool,
+    /// Files unfolded by hand after an auto fold; left alone from then on
+    auto_fold_dismissed: FxHashSet<usize>,
+    /// Source line range the current file is restricted to (`:limit`)
+    line_limit: Option<LineLimit>,
     /// Cached wrapped display length (for line wrap centering)
     last_wrap_display_len: Option<usize>,
     /// Cached wrapped active display index (for line wrap centering)
     last_wrap_active_idx: Option<usize>,
     /// Show scrollbar
     pub scrollbar_visible: bool,
-    /// Show strikethrough on deleted text
-    pub strikethrough_deletions: bool,
+    /// Show a horizontal scrollbar when long lines overflow
+    pub hscrollbar: bool,
+    /// Faint guides at each indent level of leading whitespace
+    pub indent_guides: bool,
+    /// Columns between indent guides on space-indented lines
+    pub indent_guide_width: usize,
+    /// Text width the last horizontal scroll clamp used
+    horizontal_viewport_width: usize,
+    /// How deleted text is marked (strikethrough, dim, background)
+    pub deletion_style: DeletionStyle,
     /// Show +/- sign column in the gutter (unified/evolution)
     pub gutter_signs: bool,
+    /// Edge of each pane the gutter sits on
+    pub gutter_side: GutterSide,
+    /// Highlight trailing whitespace on added lines
+    pub highlight_trailing_ws: bool,
     /// Whether user has manually toggled the file panel (overrides auto-hide)
     pub file_panel_manually_set: bool,
     /// Whether to show the file path popup (Ctrl+G)
     pub show_path_popup: bool,
+    /// Presenter notes loaded from the `.oyo-notes.toml` sidecar
+    pub presenter_notes: Option<PresenterNotes>,
+    /// Whether to show the presenter note popup
+    pub show_note_popup: bool,
+    /// Show the version/build info popup
+    pub show_build_info: bool,
+    /// Rows for the build info popup (see `build_info::build_info`)
+    pub build_info: Vec<(&'static str, String)>,
+    /// When quitting asks for confirmation
+    pub confirm_quit: crate::config::ConfirmQuitMode,
+    /// The index was changed from inside oyo (hunk staging)
+    pub index_modified: bool,
+    /// A quit is waiting for `y` or another quit key
+    pub quit_confirm_pending: bool,
+    quit_confirmed: bool,
     /// Whether the file panel is currently auto-hidden due to narrow viewport
     pub file_panel_auto_hidden: bool,
     /// Auto-step to first change when entering a file at step 0
     pub auto_step_on_enter: bool,
+    /// Pause on step 0 before the auto-step on enter
+    pub auto_step_delay: Duration,
+    /// Delayed auto-step as (file index, due time); any key cancels it
+    pending_auto_step: Option<(usize, Instant)>,
     /// Auto-step when file would be blank at step 0 (new files)
     pub auto_step_blank_files: bool,
+    /// What a step with nothing to show does
+    pub empty_step: EmptyStepMode,
+    /// What autoplay does when it runs out of steps
+    pub autoplay_end: AutoplayEndMode,
     /// Auto-jump to first hunk when entering a file in no-step mode
     pub no_step_auto_jump_on_enter: bool,
+    /// Context lines shown above the hunk start on no-step hunk jumps
+    pub no_step_hunk_context: usize,
     /// Manual center was requested (zz), enables overscroll until manual scroll
     pub centered_once: bool,
+    /// Extra rows the new split pane is scrolled past the old one (`z s`)
+    pub(crate) split_new_skew: isize,
     /// Marker for primary active line (left pane / unified pane)
     pub primary_marker: String,
     /// Marker for right pane primary line
     pub primary_marker_right: String,
+    /// Split view: primary marker on the left pane while it lacks focus
+    pub primary_marker_inactive: Option<String>,
+    /// Split view: primary marker on the right pane while it lacks focus
+    pub primary_marker_right_inactive: Option<String>,
+    /// Split pane with focus (`ctrl-w` switches)
+    pub(crate) split_focus: FileSide,
     /// Marker for hunk extent lines (left pane / unified pane)
     pub extent_marker: String,
     /// Marker for right pane extent lines
     pub extent_marker_right: String,
+    /// Extent marker override for inserted lines
+    pub extent_marker_insert: Option<String>,
+    /// Extent marker override for deleted lines
+    pub extent_marker_delete: Option<String>,
     /// Clear active change after next render (for one-frame animation styling)
     pub clear_active_on_next_render: bool,
     /// Resolved theme (colors, gradients)
     pub theme: ResolvedTheme,
     /// Time formatting rules
     pub time_format: TimeFormatter,
+    /// Status bar clock and/or elapsed review time
+    pub show_time: ShowTimeMode,
+    /// When oy started, for the elapsed review time
+    started_at: Instant,
+    /// Last clock text drawn, so `tick` redraws only when it changes
+    last_time_text: Option<String>,
     /// Whether the UI theme is in light mode
     pub theme_is_light: bool,
     /// Whether stepping is enabled (false = no-step diff view)
@@ -218,8 +371,53 @@ pub struct App {
     pub hunk_wrap: HunkWrapMode,
     /// Wrap stepping across files (j at end goes to next file, k at start goes to previous file)
     pub step_wrap: StepWrapMode,
+    /// Land on the same source line when switching files instead of restoring scroll
+    pub sync_line_across_files: bool,
+    /// Step past the last change into the next file's first change (no wrap)
+    pub auto_advance_file: bool,
+    /// Fold each hunk once hunk navigation moves past it
+    pub collapse_reviewed_hunks: bool,
+    /// What the autoplay key does in no-step mode
+    pub space_action: SpaceAction,
+    /// Hunks folded as reviewed, as (file index, hunk index)
+    collapsed_hunks: FxHashSet<(usize, usize)>,
+    /// Hunks restricted to by `--hunks`; the rest are folded and skipped
+    hunk_subset: Option<HunkSubset>,
+    /// Files marked in the panel with `space` for batch operations
+    marked_files: FxHashSet<usize>,
+    /// Folded context revealed around hunks as (file index, hunk index) -> (above, below)
+    fold_expand: FxHashMap<(usize, usize), (usize, usize)>,
+    /// Show review progress (visited share of changed lines) in the top bar
+    pub review_progress: bool,
+    /// Show steps applied in the current hunk ("step 2/5 in hunk") in the top bar
+    pub hunk_step_progress: bool,
+    /// Visited hunks as (file index, hunk index) -> changed lines in the hunk
+    visited_hunks: FxHashMap<(usize, usize), usize>,
+    /// Flash the landing line after large jumps
+    pub locate_flash_enabled: bool,
     /// Diff background (full-line) toggle
     pub diff_bg: bool,
+    /// Row text drawn between hunks in no-step mode
+    pub hunk_separator: Option<String>,
+    /// `@@ -a,b +c,d @@ (+i -d)` above each hunk in no-step mode, and for
+    /// the current hunk in the top bar while stepping
+    pub hunk_headers: bool,
+    /// Tint the active line's row across the whole viewport
+    pub cursorline: bool,
+    /// `ui.line_wrap` from config, for views without their own default
+    line_wrap_default: bool,
+    /// Per-view `line_wrap` defaults from config
+    line_wrap_by_view: Vec<(ViewMode, bool)>,
+    /// `w` was pressed this session, so view switches keep the wrap setting
+    line_wrap_overridden: bool,
+    /// View mode `line_wrap` was last resolved for
+    line_wrap_view: Option<ViewMode>,
+    /// `diff_bg` from config, for views without a runtime toggle
+    diff_bg_default: bool,
+    /// `diff_bg` toggled at runtime, per view mode
+    diff_bg_by_view: Vec<(ViewMode, bool)>,
+    /// View mode `diff_bg` was last resolved for
+    diff_bg_view: Option<ViewMode>,
     /// Diff foreground rendering mode
     pub diff_fg: DiffForegroundMode,
     /// Inline diff highlight mode
@@ -236,10 +434,16 @@ pub struct App {
     pub blame_mode: BlameMode,
     /// Show blame hint when jumping to a hunk
     pub blame_hunk_hint_enabled: bool,
+    /// Blame view gutter width in columns (0 = percentage of the view)
+    pub blame_width: u16,
     /// True when blame toggle is active
     blame_toggle: bool,
     /// Cached git user name for blame display
     blame_user_name: Option<String>,
+    /// Dim hunks without a line authored by the git user
+    pub mine_only: bool,
+    /// Per file: hunks holding a line by the git user (`None` = can't tell)
+    mine_hunk_cache: FxHashMap<usize, Option<FxHashSet<usize>>>,
     /// Cached blame entries
     blame_cache: FxHashMap<BlameCacheKey, BlameInfo>,
     /// Cached blame display text (used as fallback while loading)
@@ -293,6 +497,8 @@ pub struct App {
     pub split_align_lines: bool,
     /// Fill character for aligned blank rows in split view
     pub split_align_fill: String,
+    /// Split view pane layout (side-by-side or top/bottom)
+    pub split_orientation: SplitOrientation,
     /// Syntax scope in evolution view
     pub evo_syntax: crate::config::EvoSyntaxMode,
     /// Syntax highlighting mode
@@ -323,16 +529,26 @@ pub struct App {
     syntax_caches: Vec<Option<SyntaxCache>>,
     /// Show syntax scope debug label in the status bar
     show_syntax_scopes: bool,
+    /// Append change id, hunk and kind to each line (`--debug-lines`)
+    pub show_line_debug: bool,
     /// Cached syntax scope label for the active line
     syntax_scope_cache: Option<SyntaxScopeCache>,
     /// Peek old/new state (stepping-only)
     peek_state: Option<PeekState>,
     /// Saved peek state for stepping mode (when toggled off)
     step_peek_state: Option<PeekState>,
+    /// Change previewed ahead of the current step (`g p`), stepping-only
+    peek_next: Option<usize>,
     /// Saved step state per file (to restore after toggling off)
     step_state_snapshots: Vec<Option<StepState>>,
     /// Saved no-step cursor/marker state per file
     no_step_state_snapshots: Vec<Option<NoStepState>>,
+    /// Positions left by jumps (hunk, goto, search, file switch), oldest first
+    jump_list: Vec<JumpPosition>,
+    /// Entry the view is at while walking the jump list; `jump_list.len()` otherwise
+    jump_index: usize,
+    /// Set while a jump is being replayed so nested jumps don't record
+    jump_recording_paused: bool,
     /// View mode to restore when stepping is enabled
     step_view_mode: ViewMode,
     /// Search query (diff pane)
@@ -389,6 +605,11 @@ pub struct App {
     review_next_comment_id: u64,
     /// Review output prepared on submit+quit
     review_submission_output: Option<String>,
+    /// Format of the quit output and `g E` exports (`review.output_format`)
+    pub review_output_format: ReviewOutputFormat,
+    /// Where `g E` writes comments (`--review-output-file`); defaults to
+    /// `oyo-review.<ext>` in the repository root
+    pub review_export_path: Option<PathBuf>,
     /// Click hitboxes for rendered review comment previews
     review_preview_boxes: Vec<review::ReviewPreviewBox>,
     /// Active inline mention picker state for comment editor
@@ -397,6 +618,17 @@ pub struct App {
     pub review_mention_file_scope: MentionFileScope,
     /// Finder backend for @ mention file candidates.
     pub review_mention_finder: MentionFinder,
+    /// Template for the hunk review comment yank ({path}, {lines}, {quote})
+    pub review_comment_template: String,
+    /// Labels for the old/new sides of a before/after copy
+    pub review_old_header: String,
+    pub review_new_header: String,
+    /// When yanks use the terminal's OSC 52 clipboard
+    pub osc52: crate::config::Osc52Mode,
+    /// Keep a bottom-scrolled view on the newest lines across refreshes
+    pub watch_follow: bool,
+    /// Transient status bar hint and when it expires
+    status_hint: Option<(HintKind, Instant)>,
     /// Cached fzf availability probe result.
     review_mention_fzf_available: Option<bool>,
     /// Cached git-aware repository file list for @ mention candidates.
@@ -411,10 +643,18 @@ pub struct App {
     search_target: Option<usize>,
     /// Cached search regex (case-insensitive)
     search_regex: Option<Regex>,
+    /// `review.keywords` as one pattern, highlighted in added lines
+    review_keywords: Option<Regex>,
+    /// Search matches whole words only
+    search_whole_word: bool,
+    /// `n`/`N` past the last match continue in the next matching file
+    pub search_wrap_files: bool,
     /// Goto query (":" command)
     goto_query: String,
     /// True when goto input is active
     goto_active: bool,
+    /// Last `:biggest` jump as (file index, rank), so a repeat goes to the next-biggest hunk
+    biggest_hunk_rank: Option<(usize, usize)>,
     /// Snap animation frame when animations are disabled
     snap_frame: Option<AnimationFrame>,
     /// Start time of the current snap frame
@@ -425,6 +665,15 @@ pub struct App {
     step_edge_hint: Option<StepEdgeHint>,
     /// Edge-of-hunks hint (shown briefly after trying to go past ends)
     hunk_edge_hint: Option<HunkEdgeHint>,
+    /// Result of the last config reload (shown briefly in the status bar)
+    config_reload_hint: Option<ConfigReloadHint>,
+    goto_path_hint: Option<GotoPathHint>,
+    /// Landing line (display index) of the last jump, highlighted until the instant
+    locate_flash: Option<(usize, Instant)>,
+    /// Show recent keypresses in a corner overlay
+    pub show_keys: bool,
+    /// Recent keypresses for the keystroke overlay (oldest first)
+    key_log: VecDeque<KeyLogEntry>,
     /// Last known viewport height for the diff area
     pub last_viewport_height: usize,
     /// Cached view lines for the current state/frame
@@ -438,6 +687,15 @@ pub struct App {
 }
 
 const SNAP_PHASE_MS: u64 = 50;
+const CONFIG_RELOAD_HINT_MS: u64 = 2000;
+const GOTO_PATH_HINT_MS: u64 = 2000;
+const DEFAULT_IDLE_TICK_MS: u64 = 250;
+/// One animation frame; the idle tick never polls faster than this.
+pub(crate) const MIN_IDLE_TICK_MS: u64 = 33;
+const KEY_LOG_LEN: usize = 6;
+/// Folded context lines revealed per expand-context press
+const FOLD_EXPAND_STEP: usize = 5;
+pub(crate) const KEY_LOG_TTL_MS: u64 = 2500;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct ViewCacheKey {
@@ -456,6 +714,9 @@ struct ViewCacheKey {
     show_hunk_extent_while_stepping: bool,
     placeholder_view: bool,
     fold_context: FoldContextMode,
+    hunk_separator: bool,
+    hunk_headers: bool,
+    line_limit: Option<(usize, usize)>,
     viewport_height: usize,
     windowed: bool,
     window_start: usize,
@@ -510,6 +771,7 @@ impl App {
             animation_speed,
             autoplay,
             autoplay_reverse: false,
+            autoplay_indicator: true,
             scroll_offset: 0,
             scroll_offsets_step: vec![0; file_count],
             scroll_offsets_no_step: vec![0; file_count],
@@ -518,6 +780,15 @@ impl App {
             should_quit: false,
             keybindings: Keybindings::default(),
             open_dashboard: false,
+            open_file_history: None,
+            open_commit_walk: None,
+            open_compare: None,
+            continuous_view: false,
+            continuous_collapsed: Vec::new(),
+            continuous_cache: None,
+            continuous_header_rows: Vec::new(),
+            config_reload_requested: false,
+            theme_mode_toggle_requested: false,
             animation_phase: AnimationPhase::Idle,
             animation_progress: 1.0,
             last_animation_tick: Instant::now(),
@@ -525,24 +796,48 @@ impl App {
             file_list_focused: false,
             file_panel_visible: true,
             file_panel_width: 30,
+            file_panel_default_width: 30,
             file_panel_rect: None,
             diff_view_area: None,
+            pending_snapshot: None,
             file_panel_resizing: false,
             file_list_scroll: 0,
             file_list_area: None,
             file_list_rows: Vec::new(),
             file_filter_area: None,
             file_count_mode: FileCountMode::Active,
+            plain: false,
+            mouse_capture: true,
+            file_sort: FileSortOrder::None,
+            file_list_center: false,
+            file_path_style: FilePathStyle::Grouped,
             file_filter: String::new(),
+            file_filter_cache: None,
+            hex_max_bytes: 64 * 1024,
+            binary_bytes_cache: None,
             file_filter_active: false,
+            file_filter_return: None,
             animation_enabled: false,
             zen_mode: false,
+            focus_mode: false,
+            show_old_path: false,
             needs_scroll_to_active: true, // Scroll to first change on startup
             show_help: false,
             help_scroll: 0,
             help_max_scroll: 0,
+            show_overview: false,
+            overview_selection: 0,
+            overview_page_rows: 0,
+            overview_return: false,
             git_branch,
+            external_diff_progress: None,
+            range_note: None,
+            commit_header: None,
+            show_commit_header: true,
+            commit_header_scroll: 0,
+            commit_header_area: None,
             auto_center: true,
+            startup_anchor_top: false,
             overscroll: false,
             topbar: true,
             animation_duration: 150,
@@ -559,32 +854,89 @@ impl App {
             max_line_widths_step: vec![0; file_count],
             max_line_widths_no_step: vec![0; file_count],
             line_wrap: false,
+            wrap_column: 0,
+            idle_tick: Duration::from_millis(DEFAULT_IDLE_TICK_MS),
+            color_column: 0,
             fold_context: FoldContextMode::Off,
             fold_context_default: FoldContextMode::Off,
+            auto_fold_threshold: 0,
+            auto_folded: false,
+            auto_fold_dismissed: FxHashSet::default(),
+            line_limit: None,
             last_wrap_display_len: None,
             last_wrap_active_idx: None,
             scrollbar_visible: false,
-            strikethrough_deletions: false,
+            hscrollbar: false,
+            indent_guides: false,
+            indent_guide_width: 4,
+            horizontal_viewport_width: 0,
+            deletion_style: DeletionStyle::Plain,
             gutter_signs: true,
+            gutter_side: GutterSide::Left,
+            highlight_trailing_ws: false,
             file_panel_manually_set: false,
             show_path_popup: false,
+            presenter_notes: None,
+            show_note_popup: false,
+            show_build_info: false,
+            confirm_quit: crate::config::ConfirmQuitMode::Never,
+            index_modified: false,
+            quit_confirm_pending: false,
+            quit_confirmed: false,
+            build_info: Vec::new(),
             file_panel_auto_hidden: false,
             auto_step_on_enter: true,
+            auto_step_delay: Duration::ZERO,
+            pending_auto_step: None,
             auto_step_blank_files: true,
+            empty_step: EmptyStepMode::Message,
+            autoplay_end: AutoplayEndMode::Stop,
             no_step_auto_jump_on_enter: true,
+            no_step_hunk_context: 0,
             centered_once: false,
+            split_new_skew: 0,
             primary_marker: "▶".to_string(),
             primary_marker_right: "◀".to_string(),
+            primary_marker_inactive: None,
+            primary_marker_right_inactive: None,
+            split_focus: FileSide::New,
             extent_marker: "▌".to_string(),
             extent_marker_right: "▐".to_string(),
+            extent_marker_insert: None,
+            extent_marker_delete: None,
             clear_active_on_next_render: false,
             theme: ResolvedTheme::default(),
             time_format: TimeFormatter::default(),
+            show_time: ShowTimeMode::Off,
+            started_at: Instant::now(),
+            last_time_text: None,
             theme_is_light: false,
             stepping: true,
             hunk_wrap: HunkWrapMode::None,
             step_wrap: StepWrapMode::None,
+            sync_line_across_files: false,
+            auto_advance_file: false,
+            collapse_reviewed_hunks: false,
+            space_action: SpaceAction::Autoplay,
+            collapsed_hunks: FxHashSet::default(),
+            hunk_subset: None,
+            marked_files: FxHashSet::default(),
+            fold_expand: FxHashMap::default(),
+            review_progress: true,
+            hunk_step_progress: true,
+            visited_hunks: FxHashMap::default(),
+            locate_flash_enabled: true,
             diff_bg: false,
+            hunk_separator: None,
+            hunk_headers: false,
+            cursorline: false,
+            diff_bg_default: false,
+            diff_bg_by_view: Vec::new(),
+            line_wrap_default: false,
+            line_wrap_by_view: Vec::new(),
+            line_wrap_overridden: false,
+            line_wrap_view: None,
+            diff_bg_view: None,
             diff_fg: DiffForegroundMode::Theme,
             diff_highlight: DiffHighlightMode::Text,
             diff_extent_marker: DiffExtentMarkerMode::Neutral,
@@ -593,8 +945,11 @@ impl App {
             blame_enabled: false,
             blame_mode: BlameMode::OneShot,
             blame_hunk_hint_enabled: true,
+            blame_width: 0,
             blame_toggle: false,
             blame_user_name: None,
+            mine_only: false,
+            mine_hunk_cache: FxHashMap::default(),
             blame_cache: FxHashMap::default(),
             blame_display_cache: FxHashMap::default(),
             blame_bar_cache: FxHashMap::default(),
@@ -622,6 +977,7 @@ impl App {
             blame_hunk_hint: None,
             unified_modified_step_mode: ModifiedStepMode::Mixed,
             split_align_lines: false,
+            split_orientation: SplitOrientation::SideBySide,
             split_align_fill: "╱".to_string(),
             evo_syntax: crate::config::EvoSyntaxMode::Context,
             syntax_mode: SyntaxMode::On,
@@ -638,11 +994,16 @@ impl App {
             syntax_engine: None,
             syntax_caches: vec![None; file_count],
             show_syntax_scopes: false,
+            show_line_debug: false,
             syntax_scope_cache: None,
             peek_state: None,
             step_peek_state: None,
+            peek_next: None,
             step_state_snapshots: vec![None; file_count],
             no_step_state_snapshots: vec![None; file_count],
+            jump_list: Vec::new(),
+            jump_index: 0,
+            jump_recording_paused: false,
             step_view_mode: view_mode,
             search_query: String::new(),
             search_active: false,
@@ -671,10 +1032,18 @@ impl App {
             review_session_created_at: 0,
             review_next_comment_id: 1,
             review_submission_output: None,
+            review_output_format: ReviewOutputFormat::Text,
+            review_export_path: None,
             review_preview_boxes: Vec::new(),
             review_mention_picker: None,
             review_mention_file_scope: MentionFileScope::default(),
             review_mention_finder: MentionFinder::default(),
+            review_comment_template: crate::config::DEFAULT_COMMENT_TEMPLATE.to_string(),
+            osc52: crate::config::Osc52Mode::Auto,
+            watch_follow: false,
+            status_hint: None,
+            review_old_header: crate::config::DEFAULT_OLD_HEADER.to_string(),
+            review_new_header: crate::config::DEFAULT_NEW_HEADER.to_string(),
             review_mention_fzf_available: None,
             review_repo_file_cache: None,
             review_revision: 0,
@@ -682,13 +1051,22 @@ impl App {
             needs_scroll_to_search: false,
             search_target: None,
             search_regex: None,
+            review_keywords: None,
+            search_whole_word: false,
+            search_wrap_files: false,
             goto_query: String::new(),
             goto_active: false,
+            biggest_hunk_rank: None,
             snap_frame: None,
             snap_frame_started_at: None,
             autoplay_remaining: None,
             step_edge_hint: None,
             hunk_edge_hint: None,
+            config_reload_hint: None,
+            goto_path_hint: None,
+            locate_flash: None,
+            show_keys: false,
+            key_log: VecDeque::new(),
             last_viewport_height: 0,
             view_cache: None,
             unified_render_cache: None,
@@ -732,6 +1110,10 @@ impl App {
         self.help_scroll = (self.help_scroll + 1).min(self.help_max_scroll);
     }
 
+    pub fn toggle_build_info(&mut self) {
+        self.show_build_info = !self.show_build_info && !self.build_info.is_empty();
+    }
+
     pub fn toggle_path_popup(&mut self) {
         self.show_path_popup = !self.show_path_popup;
     }
@@ -740,6 +1122,47 @@ impl App {
         self.zen_mode = !self.zen_mode;
     }
 
+    pub fn toggle_focus_mode(&mut self) {
+        self.focus_mode = !self.focus_mode;
+    }
+
+    pub fn toggle_old_path(&mut self) {
+        self.show_old_path = !self.show_old_path;
+    }
+
+    /// Cycle file panel counts: active, focused, always, off.
+    pub fn cycle_file_count_mode(&mut self) {
+        self.file_count_mode = self.file_count_mode.next();
+    }
+
+    /// Cycle file panel labels: grouped, full, basename, smart.
+    pub fn cycle_file_path_style(&mut self) {
+        self.file_path_style = self.file_path_style.next();
+        self.update_file_list_scroll();
+    }
+
+    /// The reviewed commit or range carries `git notes`.
+    pub(crate) fn has_commit_notes(&self) -> bool {
+        self.commit_header
+            .as_ref()
+            .is_some_and(|header| header.has_notes)
+    }
+
+    pub fn toggle_commit_header(&mut self) {
+        self.show_commit_header = !self.show_commit_header;
+        self.commit_header_scroll = 0;
+    }
+
+    pub fn mouse_over_commit_header(&self, column: u16, row: u16) -> bool {
+        self.commit_header_area
+            .is_some_and(|rect| point_in_rect(rect, column, row))
+    }
+
+    /// Scroll a long commit header; the renderer clamps the offset.
+    pub fn scroll_commit_header(&mut self, delta: isize) {
+        self.commit_header_scroll = self.commit_header_scroll.saturating_add_signed(delta);
+    }
+
     pub fn toggle_syntax(&mut self) {
         self.syntax_mode = match self.syntax_mode {
             SyntaxMode::On => SyntaxMode::Off,
@@ -751,6 +1174,230 @@ impl App {
         }
     }
 
+    /// Cycle inline highlighting: text, word, none.
+    pub fn cycle_diff_highlight(&mut self) {
+        self.diff_highlight = self.diff_highlight.next();
+        self.show_status_hint(HintKind::DiffHighlight);
+    }
+
+    /// Show `kind` in the status bar for its duration, replacing any other hint.
+    pub(crate) fn show_status_hint(&mut self, kind: HintKind) {
+        let until = Instant::now() + kind.duration();
+        self.status_hint = Some((kind, until));
+    }
+
+    fn status_hint_is(&self, kind: &HintKind) -> bool {
+        self.status_hint
+            .as_ref()
+            .is_some_and(|(shown, _)| shown == kind)
+    }
+
+    /// Cycle how the active modified line renders while stepping: mixed,
+    /// modified, old. A change peek is relative to the old default, so it's
+    /// dropped.
+    pub fn cycle_modified_step_mode(&mut self) {
+        self.unified_modified_step_mode = self.unified_modified_step_mode.next();
+        if self
+            .peek_state
+            .is_some_and(|peek| peek.scope == PeekScope::Change)
+        {
+            self.peek_state = None;
+        }
+        self.show_status_hint(HintKind::ModifiedStep);
+    }
+
+    /// Modified step mode, while the hint after cycling it is visible.
+    pub(crate) fn modified_step_hint(&self) -> Option<ModifiedStepMode> {
+        self.status_hint_is(&HintKind::ModifiedStep)
+            .then_some(self.unified_modified_step_mode)
+    }
+
+    /// Turn mouse capture on or off; the run loop applies it to the terminal.
+    pub fn toggle_mouse_capture(&mut self) {
+        self.mouse_capture = !self.mouse_capture;
+        self.show_status_hint(HintKind::MouseCapture);
+    }
+
+    /// Mouse capture state, while the hint after toggling it is visible.
+    pub(crate) fn mouse_capture_hint(&self) -> Option<bool> {
+        self.status_hint_is(&HintKind::MouseCapture)
+            .then_some(self.mouse_capture)
+    }
+
+    /// Inline highlight mode, while the hint after cycling it is visible.
+    pub(crate) fn diff_highlight_hint(&self) -> Option<DiffHighlightMode> {
+        self.status_hint_is(&HintKind::DiffHighlight)
+            .then_some(self.diff_highlight)
+    }
+
+    /// Swap in the theme resolved for the other mode. The syntax engine and
+    /// caches are rebuilt since the syntax theme depends on the mode too.
+    pub fn set_theme(&mut self, theme: ResolvedTheme, light: bool) {
+        self.theme = theme;
+        self.theme_is_light = light;
+        self.clear_render_caches();
+    }
+
+    fn clear_render_caches(&mut self) {
+        self.syntax_engine = None;
+        self.syntax_caches = vec![None; self.multi_diff.file_count()];
+        self.view_cache = None;
+        self.unified_render_cache = None;
+        self.blame_render_cache = None;
+        self.last_wrap_display_len = None;
+        self.last_wrap_active_idx = None;
+    }
+
+    /// Record the outcome of a config reload: its warnings, or the error that
+    /// kept the previous config. On success, drop the syntax engine and render
+    /// caches so the new theme and settings take effect.
+    pub fn finish_config_reload(&mut self, result: Result<Vec<String>, String>) {
+        let (ok, detail) = match result {
+            Ok(warnings) => {
+                self.clear_render_caches();
+                let detail = warnings.first().map(|first| match warnings.len() {
+                    1 => first.clone(),
+                    n => format!("{first} (+{} more)", n - 1),
+                });
+                (true, detail)
+            }
+            Err(error) => (false, Some(error)),
+        };
+        // TOML errors span several lines; the status bar has room for one.
+        let detail = detail.map(|text| text.lines().next().unwrap_or_default().to_string());
+        self.config_reload_hint = Some(ConfigReloadHint {
+            ok,
+            detail,
+            until: Instant::now() + Duration::from_millis(CONFIG_RELOAD_HINT_MS),
+        });
+    }
+
+    pub fn toggle_show_keys(&mut self) {
+        self.show_keys = !self.show_keys;
+        self.key_log.clear();
+    }
+
+    /// Remember a keypress for the keystroke overlay. Repeats of the last
+    /// key bump its count instead of taking a new row.
+    pub(crate) fn record_key(&mut self, label: String) {
+        let now = Instant::now();
+        if let Some(last) = self.key_log.back_mut() {
+            if last.label == label {
+                last.count += 1;
+                last.at = now;
+                return;
+            }
+        }
+        if self.key_log.len() == KEY_LOG_LEN {
+            self.key_log.pop_front();
+        }
+        self.key_log.push_back(KeyLogEntry {
+            label,
+            count: 1,
+            at: now,
+        });
+    }
+
+    /// Visible keystroke overlay entries with their age in milliseconds.
+    pub(crate) fn key_log_entries(&self) -> impl Iterator<Item = (&str, usize, u64)> {
+        let now = Instant::now();
+        self.key_log.iter().map(move |entry| {
+            let age = now.duration_since(entry.at).as_millis() as u64;
+            (entry.label.as_str(), entry.count, age)
+        })
+    }
+
+    /// Outcome of a recent config reload and its error or warning, while its
+    /// hint is visible.
+    pub(crate) fn config_reload_hint(&self) -> Option<(bool, Option<&str>)> {
+        self.config_reload_hint
+            .as_ref()
+            .map(|hint| (hint.ok, hint.detail.as_deref()))
+    }
+
+    /// Path `gf` last failed to resolve, while its hint is showing.
+    pub(crate) fn goto_path_hint(&self) -> Option<&str> {
+        self.goto_path_hint.as_ref().map(|hint| hint.path.as_str())
+    }
+
+    /// Whether a recent OSC 52 yank was truncated, while its hint is showing.
+    pub(crate) fn clipboard_truncated_hint(&self) -> bool {
+        self.status_hint_is(&HintKind::ClipboardTruncated)
+    }
+
+    /// Whether the current file shows its structural rendering, whose line
+    /// numbers don't match the file on disk.
+    pub(crate) fn current_file_structural(&self) -> bool {
+        self.multi_diff
+            .current_file()
+            .is_some_and(|file| file.structural)
+    }
+
+    /// True (with a hint) when a line-based action must skip the current
+    /// file because it is shown structurally.
+    pub(crate) fn skip_structural_lines(&mut self) -> bool {
+        let structural = self.current_file_structural();
+        if structural {
+            self.show_status_hint(HintKind::StructuralLines);
+        }
+        structural
+    }
+
+    /// Whether a line-based action was just skipped on a structural file.
+    pub(crate) fn structural_lines_hint(&self) -> bool {
+        self.status_hint_is(&HintKind::StructuralLines)
+    }
+
+    /// Turn a requested quit into a confirmation prompt when
+    /// `ui.confirm_quit` asks for one. Returns true while the quit is held.
+    pub fn hold_quit_for_confirmation(&mut self) -> bool {
+        if self.quit_confirmed
+            || self.confirm_quit != crate::config::ConfirmQuitMode::IfModified
+            || !self.index_modified
+        {
+            return false;
+        }
+        self.should_quit = false;
+        self.quit_confirm_pending = true;
+        true
+    }
+
+    /// Answer the quit prompt: confirm, or go back to the diff.
+    pub fn resolve_quit_confirmation(&mut self, confirmed: bool) {
+        self.quit_confirm_pending = false;
+        if confirmed {
+            self.quit_confirmed = true;
+            self.submit_review_and_quit();
+        }
+    }
+
+    pub fn toggle_split_orientation(&mut self) {
+        self.split_orientation = match self.split_orientation {
+            SplitOrientation::SideBySide => SplitOrientation::TopBottom,
+            SplitOrientation::TopBottom => SplitOrientation::SideBySide,
+        };
+    }
+
+    /// True when split view should stack panes for a diff area of `height` rows.
+    /// Falls back to side-by-side when there is not enough room for two panes.
+    pub(crate) fn split_stacked(&self, height: u16) -> bool {
+        self.view_mode == ViewMode::Split
+            && self.split_orientation == SplitOrientation::TopBottom
+            && height >= DIFF_VIEW_MIN_HEIGHT
+    }
+
+    /// Minimum diff view width before the file panel auto-hides.
+    /// Stacked split panes use the full width, so they need half the columns.
+    pub(crate) fn diff_view_min_width(&self) -> u16 {
+        if self.view_mode == ViewMode::Split
+            && self.split_orientation == SplitOrientation::TopBottom
+        {
+            DIFF_VIEW_MIN_WIDTH / 2
+        } else {
+            DIFF_VIEW_MIN_WIDTH
+        }
+    }
+
     pub fn toggle_evo_syntax(&mut self) {
         self.evo_syntax = match self.evo_syntax {
             crate::config::EvoSyntaxMode::Context => crate::config::EvoSyntaxMode::Full,
@@ -803,6 +1450,11 @@ impl App {
         self.scroll_offset += half;
     }
 
+    pub fn scroll_page_down(&mut self, viewport_height: usize) {
+        self.centered_once = false;
+        self.scroll_offset += viewport_height.max(1);
+    }
+
     pub fn scroll_left(&mut self) {
         if !self.line_wrap {
             self.horizontal_scroll = self.horizontal_scroll.saturating_sub(4);
@@ -830,6 +1482,7 @@ impl App {
 
     /// Clamp horizontal scroll so we don't scroll too far right
     pub fn clamp_horizontal_scroll(&mut self, max_line_width: usize, viewport_width: usize) {
+        self.horizontal_viewport_width = viewport_width;
         if !self.line_wrap {
             let max_scroll = max_line_width.saturating_sub(viewport_width);
             self.horizontal_scroll = self.horizontal_scroll.min(max_scroll);
@@ -837,6 +1490,7 @@ impl App {
     }
 
     pub fn clamp_horizontal_scroll_cached(&mut self, viewport_width: usize) {
+        self.horizontal_viewport_width = viewport_width;
         if self.line_wrap {
             return;
         }
@@ -881,6 +1535,23 @@ impl App {
         }
     }
 
+    /// Horizontal scroll, its maximum and the text width, when wrapping is
+    /// off and the longest line is wider than the text area.
+    pub(crate) fn horizontal_overflow(&self) -> Option<(usize, usize, usize)> {
+        if self.line_wrap || self.horizontal_viewport_width == 0 {
+            return None;
+        }
+        let max_scroll = self
+            .current_max_line_width()
+            .checked_sub(self.horizontal_viewport_width)
+            .filter(|max| *max > 0)?;
+        Some((
+            self.horizontal_scroll.min(max_scroll),
+            max_scroll,
+            self.horizontal_viewport_width,
+        ))
+    }
+
     fn current_max_line_width(&self) -> usize {
         let idx = self.multi_diff.selected_index;
         if self.stepping {
@@ -891,7 +1562,12 @@ impl App {
     }
 
     pub fn toggle_line_wrap(&mut self) {
-        self.line_wrap = !self.line_wrap;
+        self.line_wrap_overridden = true;
+        self.set_line_wrap(!self.line_wrap);
+    }
+
+    fn set_line_wrap(&mut self, wrap: bool) {
+        self.line_wrap = wrap;
         // Reset horizontal scroll when enabling wrap
         if self.line_wrap {
             self.horizontal_scroll = 0;
@@ -900,16 +1576,102 @@ impl App {
         self.last_wrap_active_idx = None;
         self.needs_scroll_to_active = true;
         self.centered_once = false;
+        self.split_new_skew = 0;
+    }
+
+    /// Set the config wrap defaults. They apply right away unless `w` was
+    /// pressed this session, which a config reload keeps.
+    pub fn set_line_wrap_defaults(&mut self, default: bool, by_view: Vec<(ViewMode, bool)>) {
+        self.line_wrap_default = default;
+        self.line_wrap_by_view = by_view;
+        if !self.line_wrap_overridden {
+            self.line_wrap_view = None;
+            self.sync_line_wrap_to_view();
+        }
+    }
+
+    /// After a view switch, use that view's configured wrap default unless
+    /// `w` was pressed this session.
+    pub(crate) fn sync_line_wrap_to_view(&mut self) {
+        let mode = self.view_mode;
+        if self.line_wrap_overridden || self.line_wrap_view == Some(mode) {
+            return;
+        }
+        self.line_wrap_view = Some(mode);
+        let wrap = self
+            .line_wrap_by_view
+            .iter()
+            .find(|(view, _)| *view == mode)
+            .map(|(_, wrap)| *wrap)
+            .unwrap_or(self.line_wrap_default);
+        if wrap != self.line_wrap {
+            self.set_line_wrap(wrap);
+        }
+    }
+
+    /// Flip full-line diff backgrounds for the current view mode only.
+    pub fn toggle_diff_bg(&mut self) {
+        self.diff_bg = !self.diff_bg;
+        let mode = self.view_mode;
+        self.diff_bg_view = Some(mode);
+        self.diff_bg_by_view.retain(|(view, _)| *view != mode);
+        self.diff_bg_by_view.push((mode, self.diff_bg));
+    }
+
+    /// Set the config `diff_bg` default. Views toggled this session keep
+    /// their setting across a config reload.
+    pub fn set_diff_bg_default(&mut self, default: bool) {
+        self.diff_bg_default = default;
+        let mode = self.view_mode;
+        if !self.diff_bg_by_view.iter().any(|(view, _)| *view == mode) {
+            self.diff_bg = default;
+        }
+    }
+
+    /// After a view switch, use that view's toggled `diff_bg` or the config
+    /// default.
+    pub(crate) fn sync_diff_bg_to_view(&mut self) {
+        let mode = self.view_mode;
+        match self.diff_bg_view {
+            Some(view) if view == mode => {}
+            None => self.diff_bg_view = Some(mode),
+            Some(_) => {
+                self.diff_bg_view = Some(mode);
+                self.diff_bg = self
+                    .diff_bg_by_view
+                    .iter()
+                    .find(|(view, _)| *view == mode)
+                    .map(|(_, bg)| *bg)
+                    .unwrap_or(self.diff_bg_default);
+            }
+        }
+    }
+
+    /// The terminal changed size: bring the active change back into view on
+    /// the next render, which also re-clamps both scroll offsets.
+    pub fn handle_resize(&mut self) {
+        self.last_wrap_display_len = None;
+        self.last_wrap_active_idx = None;
+        self.needs_scroll_to_active = true;
+        self.centered_once = false;
     }
 
     pub fn toggle_fold_context(&mut self) {
         if self.fold_context.is_enabled() {
             self.fold_context = FoldContextMode::Off;
+            if std::mem::take(&mut self.auto_folded) {
+                self.auto_fold_dismissed
+                    .insert(self.multi_diff.selected_index);
+            }
         } else if self.fold_context_default.is_enabled() {
             self.fold_context = self.fold_context_default;
         } else {
             self.fold_context = FoldContextMode::On;
         }
+        if !self.fold_expand.is_empty() {
+            self.fold_expand.clear();
+            self.view_cache = None;
+        }
         self.last_wrap_display_len = None;
         self.last_wrap_active_idx = None;
         self.needs_scroll_to_active = true;
@@ -917,17 +1679,284 @@ impl App {
         self.blame_render_cache = None;
     }
 
+    /// Unfold `count` more steps of context above or below the current hunk
+    /// while context folding is on, until the gap to the next hunk closes.
+    pub fn expand_hunk_context(&mut self, above: bool, count: usize) {
+        if !self.fold_context.is_enabled() || !self.current_file_diff_ready() {
+            return;
+        }
+        let file_idx = self.multi_diff.selected_index;
+        let state = self.multi_diff.current_navigator().state();
+        if state.total_hunks == 0 {
+            return;
+        }
+        let key = (file_idx, state.current_hunk);
+        let lines = FOLD_EXPAND_STEP.saturating_mul(count.max(1));
+        let entry = self.fold_expand.entry(key).or_default();
+        if above {
+            entry.0 = entry.0.saturating_add(lines);
+        } else {
+            entry.1 = entry.1.saturating_add(lines);
+        }
+        self.invalidate_view_layout();
+    }
+
+    /// Fold context when the current file has more hunks than
+    /// `auto_fold_threshold`, and undo that fold again on files that don't.
+    pub(super) fn apply_auto_fold(&mut self) {
+        if self.auto_fold_threshold == 0 || !self.current_file_diff_ready() {
+            return;
+        }
+        let idx = self.multi_diff.selected_index;
+        let total_hunks = self.multi_diff.current_navigator().state().total_hunks;
+        let wants_fold =
+            total_hunks > self.auto_fold_threshold && !self.auto_fold_dismissed.contains(&idx);
+        if wants_fold && !self.fold_context.is_enabled() {
+            self.toggle_fold_context();
+            self.auto_folded = true;
+        } else if !wants_fold && self.auto_folded {
+            self.auto_folded = false;
+            self.toggle_fold_context();
+        }
+    }
+
     pub fn set_fold_context_mode(&mut self, mode: FoldContextMode) {
         self.fold_context = mode;
         self.fold_context_default = mode;
     }
 
-    pub fn toggle_strikethrough_deletions(&mut self) {
-        self.strikethrough_deletions = !self.strikethrough_deletions;
+    /// Hide everything in the current file outside source lines `start..=end`.
+    pub fn set_line_limit(&mut self, start: usize, end: usize) {
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let total = self
+            .multi_diff
+            .current_navigator()
+            .diff()
+            .changes
+            .iter()
+            .flat_map(|change| change.spans.iter())
+            .filter_map(|span| span.new_line.or(span.old_line))
+            .max()
+            .unwrap_or(0);
+        self.line_limit = Some(LineLimit {
+            file_index: self.multi_diff.selected_index,
+            start: start.max(1),
+            end: end.max(1),
+            total,
+        });
+        self.reset_after_line_limit_change();
+    }
+
+    pub fn clear_line_limit(&mut self) {
+        if self.line_limit.take().is_some() {
+            self.reset_after_line_limit_change();
+        }
+    }
+
+    /// Collapsed hunk indices of the selected file, sorted.
+    pub(crate) fn current_collapsed_hunks(&self) -> Vec<usize> {
+        let file_idx = self.multi_diff.selected_index;
+        let mut hunks: Vec<usize> = self
+            .collapsed_hunks
+            .iter()
+            .filter(|(file, _)| *file == file_idx)
+            .map(|(_, hunk)| *hunk)
+            .collect();
+        hunks.sort_unstable();
+        hunks
+    }
+
+    /// Restrict the review to `(file index, hunk indices)`. Files not listed
+    /// keep all their hunks unless `others_hidden` is set.
+    pub fn set_hunk_subset(&mut self, listed: Vec<(usize, Vec<usize>)>, others_hidden: bool) {
+        let mut subset = HunkSubset {
+            others_hidden,
+            ..HunkSubset::default()
+        };
+        for (file_idx, hunks) in listed {
+            subset.listed.entry(file_idx).or_default().extend(hunks);
+        }
+        self.hunk_subset = Some(subset);
+        self.invalidate_view_layout();
+    }
+
+    /// Whether `--hunks` leaves this hunk of the selected file out of the review.
+    pub(crate) fn hunk_skipped(&self, hunk_idx: usize) -> bool {
+        self.hunk_subset
+            .as_ref()
+            .is_some_and(|subset| subset.skips(self.multi_diff.selected_index, hunk_idx))
+    }
+
+    /// Fold or unfold a hunk of the selected file; returns whether it changed.
+    pub(crate) fn set_hunk_collapsed(&mut self, hunk_idx: usize, collapsed: bool) -> bool {
+        let key = (self.multi_diff.selected_index, hunk_idx);
+        let changed = if collapsed {
+            self.collapsed_hunks.insert(key)
+        } else {
+            self.collapsed_hunks.remove(&key)
+        };
+        if changed {
+            self.invalidate_view_layout();
+        }
+        changed
+    }
+
+    /// Count the selected file's current hunk as visited for review progress.
+    /// In stepping mode a hunk only counts once a step has been applied.
+    pub(crate) fn record_hunk_visit(&mut self) {
+        let file_idx = self.multi_diff.selected_index;
+        let stepping = self.stepping;
+        let Some(nav) = self.multi_diff.navigator(file_idx) else {
+            return;
+        };
+        let state = nav.state();
+        if state.total_hunks == 0 || (stepping && state.current_step == 0) {
+            return;
+        }
+        let hunk_idx = state.current_hunk;
+        if let Some(hunk) = nav.hunks().get(hunk_idx) {
+            let changed = hunk.insertions + hunk.deletions;
+            self.visited_hunks.insert((file_idx, hunk_idx), changed);
+        }
+    }
+
+    /// Percentage of all changed lines that sit in visited hunks.
+    pub fn review_progress_percent(&self) -> usize {
+        let total: usize = (0..self.multi_diff.file_count())
+            .map(|idx| self.multi_diff.change_count(idx))
+            .sum();
+        if total == 0 {
+            return 0;
+        }
+        let reviewed: usize = self.visited_hunks.values().sum();
+        (reviewed * 100 / total).min(100)
+    }
+
+    /// Top bar text: `File 3/7 · 45% reviewed`, or just the percentage for a
+    /// single file.
+    pub(crate) fn review_progress_text(&self) -> Option<String> {
+        if !self.review_progress {
+            return None;
+        }
+        let percent = self.review_progress_percent();
+        if self.is_multi_file() {
+            Some(format!(
+                "File {}/{} · {}% reviewed",
+                self.multi_diff.selected_index + 1,
+                self.multi_diff.file_count(),
+                percent
+            ))
+        } else {
+            Some(format!("{percent}% reviewed"))
+        }
+    }
+
+    /// Status bar text for `ui.show_time`: "14:05", "12:34" elapsed, or both.
+    pub(crate) fn time_status_text(&self) -> Option<String> {
+        let clock = || {
+            let now = time::OffsetDateTime::now_utc().unix_timestamp();
+            self.time_format.clock(now)
+        };
+        let elapsed = || format_elapsed(self.started_at.elapsed());
+        match self.show_time {
+            ShowTimeMode::Off => None,
+            ShowTimeMode::Elapsed => Some(elapsed()),
+            ShowTimeMode::Clock => Some(clock()),
+            ShowTimeMode::Both => Some(format!("{} · {}", clock(), elapsed())),
+        }
+    }
+
+    /// Re-expand the hunk the cursor is on after moving backward.
+    pub(crate) fn expand_current_hunk(&mut self) {
+        if self.collapsed_hunks.is_empty() {
+            return;
+        }
+        let hunk_idx = self.multi_diff.current_navigator().state().current_hunk;
+        self.set_hunk_collapsed(hunk_idx, false);
+    }
+
+    /// `(start, end)` of the line limit when it applies to the selected file.
+    pub(crate) fn active_line_limit(&self) -> Option<(usize, usize)> {
+        self.line_limit_info().map(|(start, end, _)| (start, end))
+    }
+
+    /// `(start, end, total)` for the "lines 100–200 of N" indicator.
+    pub(crate) fn line_limit_info(&self) -> Option<(usize, usize, usize)> {
+        self.line_limit
+            .filter(|limit| limit.file_index == self.multi_diff.selected_index)
+            .map(|limit| (limit.start, limit.end, limit.total))
+    }
+
+    /// Whether lines are hidden from the view (line limit, collapsed or skipped hunks).
+    pub(crate) fn view_filtered(&self) -> bool {
+        let file_idx = self.multi_diff.selected_index;
+        self.active_line_limit().is_some()
+            || self
+                .collapsed_hunks
+                .iter()
+                .any(|(file, _)| *file == file_idx)
+            || self
+                .hunk_subset
+                .as_ref()
+                .is_some_and(|subset| subset.restricts(file_idx))
+    }
+
+    /// Whether the view no longer maps 1:1 onto diff changes (folding or filtering).
+    pub(crate) fn view_reshaped(&self) -> bool {
+        self.fold_context.is_enabled() || self.view_filtered()
+    }
+
+    fn reset_after_line_limit_change(&mut self) {
+        self.invalidate_view_layout();
+        self.scroll_offset = 0;
+        self.needs_scroll_to_active = true;
+        self.centered_once = false;
+    }
+
+    /// Drop cached views and hunk positions after the visible line set changes.
+    fn invalidate_view_layout(&mut self) {
+        self.view_cache = None;
+        self.unified_render_cache = None;
+        self.blame_render_cache = None;
+        self.hunk_starts_unified_cache = None;
+        self.hunk_bounds_unified_cache = None;
+        self.hunk_starts_split_cache = None;
+        self.hunk_bounds_split_cache = None;
+        self.last_wrap_display_len = None;
+        self.last_wrap_active_idx = None;
+    }
+
+    pub fn cycle_deletion_style(&mut self) {
+        self.deletion_style = self.deletion_style.next();
+    }
+
+    pub(crate) fn strikethrough_deletions(&self) -> bool {
+        self.deletion_style == DeletionStyle::Strikethrough
+    }
+
+    pub fn toggle_gutter_signs(&mut self) {
+        self.gutter_signs = !self.gutter_signs;
+    }
+
+    pub fn toggle_highlight_trailing_ws(&mut self) {
+        self.highlight_trailing_ws = !self.highlight_trailing_ws;
+    }
+
+    pub fn set_review_keywords(&mut self, keywords: &[String]) {
+        self.review_keywords = utils::keyword_regex(keywords);
+    }
+
+    /// The keyword pattern, for render cache keys.
+    pub(crate) fn review_keywords_pattern(&self) -> Option<&str> {
+        self.review_keywords.as_ref().map(Regex::as_str)
     }
 
     fn wrap_to_file_hunk(&mut self, forward: bool, stepping: bool) -> bool {
-        let indices = if !self.file_filter.is_empty() {
+        let indices = if !self.file_order_is_default() {
             self.filtered_file_indices()
         } else {
             (0..self.multi_diff.file_count()).collect()
@@ -1128,6 +2157,9 @@ impl App {
             show_hunk_extent_while_stepping: state.show_hunk_extent_while_stepping,
             placeholder_view: self.multi_diff.current_navigator_is_placeholder(),
             fold_context: self.fold_context,
+            hunk_separator: self.hunk_separator_text().is_some(),
+            hunk_headers: self.hunk_headers && !self.stepping,
+            line_limit: self.active_line_limit(),
             viewport_height: self.last_viewport_height,
             windowed,
             window_start,
@@ -1138,10 +2170,19 @@ impl App {
         self.scroll_offset.saturating_sub(self.view_window_start)
     }
 
+    /// Scroll offset for the new split pane, shifted by the sync-center skew.
+    pub(crate) fn split_new_scroll_offset(&self, scroll_offset: usize) -> usize {
+        scroll_offset.saturating_add_signed(self.split_new_skew)
+    }
+
     pub(crate) fn peek_state(&self) -> Option<PeekState> {
         self.peek_state
     }
 
+    pub(crate) fn peek_next_change(&self) -> Option<usize> {
+        self.peek_next
+    }
+
     pub(crate) fn view_window_start(&self) -> usize {
         self.view_window_start
     }
@@ -1180,7 +2221,7 @@ impl App {
     }
 
     fn compute_view_window(&mut self) -> Option<ViewWindow> {
-        if self.line_wrap {
+        if self.line_wrap || self.view_filtered() {
             return None;
         }
         if !self.multi_diff.current_file_is_large() {
@@ -1264,6 +2305,19 @@ impl App {
         &mut self,
         frame: AnimationFrame,
     ) -> std::sync::Arc<Vec<ViewLine>> {
+        if self.peek_next.is_none() {
+            return self.build_view_with_frame(frame);
+        }
+        // Build the view one step ahead, then put the real state back.
+        let nav = self.multi_diff.current_navigator();
+        let saved = nav.state().clone();
+        nav.next();
+        let view = self.build_view_with_frame(frame);
+        self.multi_diff.current_navigator().set_state(saved);
+        view
+    }
+
+    fn build_view_with_frame(&mut self, frame: AnimationFrame) -> std::sync::Arc<Vec<ViewLine>> {
         let window = self.compute_view_window();
         let windowed = window.is_some();
         let window_start = window.map(|w| w.start).unwrap_or(0);
@@ -1381,7 +2435,32 @@ impl App {
                 }
             }
         }
-        let view = utils::fold_context_view(view, self.fold_context);
+        let view = utils::limit_lines_view(view, self.active_line_limit());
+        let view = utils::collapse_hunks_view(view, &self.current_collapsed_hunks(), |hunk| {
+            self.hunk_skipped(hunk)
+        });
+        let file_idx = self.multi_diff.selected_index;
+        let view = utils::fold_context_view(view, self.fold_context, |hunk| {
+            self.fold_expand
+                .get(&(file_idx, hunk))
+                .copied()
+                .unwrap_or_default()
+        });
+        let view = match self.hunk_separator_text() {
+            Some(text) => utils::hunk_separator_view(view, text),
+            None => view,
+        };
+        let view = if self.hunk_headers && !self.stepping {
+            let diff = self.multi_diff.current_navigator().diff();
+            let headers: Vec<String> = diff
+                .hunks
+                .iter()
+                .map(|hunk| utils::hunk_header_text(hunk, &diff.changes))
+                .collect();
+            utils::hunk_header_view(view, &headers)
+        } else {
+            view
+        };
         let lines = std::sync::Arc::new(view);
         let applied_start = window_start_override.unwrap_or(window_start);
         let applied_total = window_total_override.or(window.map(|w| w.total_len));
@@ -1396,6 +2475,21 @@ impl App {
         lines
     }
 
+    /// Top bar header for the current hunk while stepping.
+    pub(crate) fn current_hunk_header_text(&mut self) -> Option<String> {
+        if !self.hunk_headers || !self.stepping {
+            return None;
+        }
+        let nav = self.multi_diff.current_navigator();
+        let hunk = nav.current_hunk()?;
+        Some(utils::hunk_header_text(hunk, &nav.diff().changes))
+    }
+
+    /// The separator between hunks, shown only in no-step mode.
+    fn hunk_separator_text(&self) -> Option<&str> {
+        self.hunk_separator.as_deref().filter(|_| !self.stepping)
+    }
+
     pub(crate) fn is_backward_animation(&self) -> bool {
         if self.snap_frame.is_some() {
             return self.multi_diff.current_step_direction() == StepDirection::Backward;
@@ -1411,6 +2505,26 @@ impl App {
         !self.is_backward_animation()
     }
 
+    /// Display row of the active line, for the cursorline tint. Wrapped views
+    /// record it while rendering; otherwise it comes from the current view.
+    pub(crate) fn cursorline_display_idx(&mut self) -> Option<usize> {
+        if self.line_wrap {
+            return self.last_wrap_active_idx;
+        }
+        let frame = self.animation_frame();
+        let view = self.current_view_with_frame(frame);
+        let step_direction = self.multi_diff.current_step_direction();
+        display_metrics(
+            &view,
+            self.view_mode,
+            self.animation_phase,
+            self.render_scroll_offset(),
+            step_direction,
+            self.split_align_lines,
+        )
+        .1
+    }
+
     pub(crate) fn cursor_visible_in_wrap(&self, viewport_height: usize) -> bool {
         self.last_wrap_active_idx
             .map(|idx| {
@@ -1441,7 +2555,8 @@ impl App {
         }
 
         let step_direction = self.multi_diff.current_step_direction();
-        let auto_center = self.auto_center;
+        let startup_anchor_top = std::mem::take(&mut self.startup_anchor_top);
+        let auto_center = self.auto_center && !startup_anchor_top;
         // If auto_center is enabled, always center on active change
         if auto_center {
             self.center_on_active(viewport_height);
@@ -1462,9 +2577,10 @@ impl App {
 
         if let Some(idx) = display_idx {
             let margin = 3.min(viewport_height / 4);
+            let scroll_before = self.scroll_offset;
 
-            // Check if active line is above viewport
-            if idx < scroll_offset.saturating_add(margin) {
+            // Startup top anchor: place the first change just below the margin
+            if startup_anchor_top || idx < scroll_offset.saturating_add(margin) {
                 self.scroll_offset = view_start.saturating_add(idx.saturating_sub(margin));
             }
             // Check if active line is below viewport
@@ -1472,6 +2588,9 @@ impl App {
                 self.scroll_offset = view_start
                     .saturating_add(idx.saturating_sub(viewport_height.saturating_sub(margin + 1)));
             }
+            if self.scroll_offset != scroll_before {
+                self.split_new_skew = 0;
+            }
         } else if display_len > 0 {
             let state = self.multi_diff.current_navigator().state();
             if self.view_mode == ViewMode::Evolution && self.stepping && state.current_step > 0 {
@@ -1502,8 +2621,9 @@ impl App {
             return;
         }
         self.needs_scroll_to_active = false;
+        let startup_anchor_top = std::mem::take(&mut self.startup_anchor_top);
 
-        if self.auto_center {
+        if self.auto_center && !startup_anchor_top {
             self.center_with_display_idx(viewport_height, display_len, display_idx);
             return;
         }
@@ -1511,7 +2631,7 @@ impl App {
         if let Some(idx) = display_idx {
             let margin = 3.min(viewport_height / 4);
 
-            if idx < self.scroll_offset.saturating_add(margin) {
+            if startup_anchor_top || idx < self.scroll_offset.saturating_add(margin) {
                 self.scroll_offset = idx.saturating_sub(margin);
             } else if idx
                 >= self
@@ -1535,6 +2655,7 @@ impl App {
         display_len: usize,
         display_idx: Option<usize>,
     ) {
+        self.split_new_skew = 0;
         if let Some(idx) = display_idx {
             let half_viewport = viewport_height / 2;
             self.scroll_offset = idx.saturating_sub(half_viewport);
@@ -1552,6 +2673,7 @@ impl App {
 
     /// Center the viewport on the active change (like Vim's zz)
     pub fn center_on_active(&mut self, viewport_height: usize) {
+        self.split_new_skew = 0;
         if self.line_wrap {
             if let Some(display_len) = self.last_wrap_display_len {
                 let display_idx = self.last_wrap_active_idx;
@@ -1560,7 +2682,10 @@ impl App {
             }
         }
 
-        if self.multi_diff.current_file_is_large() && self.view_mode != ViewMode::Split {
+        if self.multi_diff.current_file_is_large()
+            && self.view_mode != ViewMode::Split
+            && !self.view_filtered()
+        {
             let nav = self.multi_diff.current_navigator();
             let state = nav.state();
             let primary_change = if state.cursor_change.is_some()
@@ -1614,17 +2739,21 @@ impl App {
 
     pub fn redraw_interval(&self) -> Duration {
         if self.animation_phase != AnimationPhase::Idle || self.snap_frame.is_some() {
-            Duration::from_millis(33)
+            Duration::from_millis(MIN_IDLE_TICK_MS)
         } else if self.autoplay
             || self.diff_inflight.is_some()
             || !self.diff_queue.is_empty()
             || self.syntax_warmup_pending()
             || self.step_edge_hint.is_some()
             || self.hunk_edge_hint.is_some()
+            || self.locate_flash.is_some()
+            || self.status_hint.is_some()
+            || !self.key_log.is_empty()
+            || self.pending_auto_step.is_some()
         {
             Duration::from_millis(100)
         } else {
-            Duration::from_millis(250)
+            self.idle_tick
         }
     }
 
@@ -1633,6 +2762,12 @@ impl App {
         let now = Instant::now();
         let mut dirty = false;
 
+        let time_text = self.time_status_text();
+        if time_text != self.last_time_text {
+            self.last_time_text = time_text;
+            dirty = true;
+        }
+
         if let Some(hint) = self.step_edge_hint {
             if now >= hint.until {
                 self.step_edge_hint = None;
@@ -1645,6 +2780,51 @@ impl App {
                 dirty = true;
             }
         }
+        if self
+            .config_reload_hint
+            .as_ref()
+            .is_some_and(|hint| now >= hint.until)
+        {
+            self.config_reload_hint = None;
+            dirty = true;
+        }
+        if self
+            .goto_path_hint
+            .as_ref()
+            .is_some_and(|hint| now >= hint.until)
+        {
+            self.goto_path_hint = None;
+            dirty = true;
+        }
+        if self
+            .status_hint
+            .as_ref()
+            .is_some_and(|(_, until)| now >= *until)
+        {
+            self.status_hint = None;
+            dirty = true;
+        }
+        if self.locate_flash.is_some_and(|(_, until)| now >= until) {
+            self.locate_flash = None;
+            dirty = true;
+        }
+        if let Some((idx, due)) = self.pending_auto_step {
+            if now >= due {
+                self.pending_auto_step = None;
+                let at_step_0 = self.multi_diff.current_navigator().state().current_step == 0;
+                if self.stepping && idx == self.multi_diff.selected_index && at_step_0 {
+                    self.next_step();
+                    dirty = true;
+                }
+            }
+        }
+        if !self.key_log.is_empty() {
+            // Redraw while entries fade out
+            dirty = true;
+            let ttl = Duration::from_millis(KEY_LOG_TTL_MS);
+            self.key_log
+                .retain(|entry| now.duration_since(entry.at) < ttl);
+        }
 
         dirty |= self.poll_diff_responses();
         dirty |= self.maybe_queue_idle_diff();
@@ -1714,6 +2894,9 @@ impl App {
                 } else {
                     self.step_forward()
                 };
+                if moved {
+                    self.settle_empty_step(!self.autoplay_reverse);
+                }
                 if let Some(remaining) = self.autoplay_remaining.as_mut() {
                     if moved && *remaining > 0 {
                         *remaining = remaining.saturating_sub(1);
@@ -1722,7 +2905,7 @@ impl App {
                         self.autoplay_remaining = None;
                         self.autoplay = false;
                     }
-                } else if !moved {
+                } else if !moved && !self.continue_autoplay_at_end() {
                     self.autoplay = false;
                 }
                 self.last_autoplay_tick = now;
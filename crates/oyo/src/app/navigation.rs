@@ -1,22 +1,30 @@
 use super::utils::{
-    copy_to_clipboard, inline_text_for_change, is_conflict_marker, is_fold_line,
-    modified_only_text_for_change, old_text_for_change,
+    copy_to_clipboard, hunk_ranges, in_ssh_session, inline_text_for_change, is_added_line,
+    is_conflict_marker, is_fold_line, markdown_code_block, markdown_language_tag,
+    modified_only_text_for_change, old_text_for_change, osc52_sequence, write_osc52,
 };
 use super::{
-    display_metrics, AnimationPhase, App, HunkBounds, HunkEdge, HunkEdgeHint, HunkStart, PeekMode,
-    PeekScope, PeekState, StepEdge, StepEdgeHint, ViewMode,
+    display_metrics, AnimationPhase, App, HintKind, HunkBounds, HunkEdge, HunkEdgeHint, HunkStart,
+    PeekMode, PeekScope, PeekState, SnapshotFormat, StepEdge, StepEdgeHint, ViewMode,
 };
-use crate::config::{FoldContextMode, HunkWrapMode, ModifiedStepMode, StepWrapMode};
+use crate::config::{
+    AutoplayEndMode, EmptyStepMode, FoldContextMode, HunkWrapMode, ModifiedStepMode, Osc52Mode,
+    StepWrapMode,
+};
+use crate::syntax::SyntaxEngine;
 use oyo_core::{
-    git::FileStatus, AnimationFrame, ChangeKind, DiffNavigator, LineKind, StepState, ViewLine,
+    git::FileStatus, multi::FileSide, AnimationFrame, ChangeKind, DiffNavigator, LineKind,
+    StepDirection, StepState, ViewLine,
 };
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 const STEP_EDGE_HINT_MS: u64 = 700;
+const LOCATE_FLASH_MS: u64 = 350;
 
+/// A view line that conflict or keyword jumps can land on.
 #[derive(Debug, Clone, Copy)]
-struct ConflictMarker {
+struct LineMarker {
     display_idx: usize,
     change_id: usize,
 }
@@ -93,14 +101,36 @@ impl App {
 
     pub fn toggle_peek_old_change(&mut self) {
         self.cycle_peek_change();
+        self.refresh_blame_toggle_hint();
     }
 
     pub fn toggle_peek_old_hunk(&mut self) {
         self.toggle_peek_hunk();
+        self.refresh_blame_toggle_hint();
     }
 
-    fn clear_peek(&mut self) {
+    pub(super) fn clear_peek(&mut self) {
         self.peek_state = None;
+        self.peek_next = None;
+    }
+
+    /// Show the next change as it will look once applied, without stepping.
+    pub fn toggle_peek_next(&mut self) {
+        if !self.stepping {
+            return;
+        }
+        self.needs_scroll_to_active = true;
+        if self.peek_next.take().is_some() {
+            return;
+        }
+        let nav = self.multi_diff.current_navigator();
+        let saved = nav.state().clone();
+        if nav.next() {
+            self.peek_next = nav.state().active_change;
+        } else {
+            self.trigger_step_edge_hint(StepEdge::End);
+        }
+        self.multi_diff.current_navigator().set_state(saved);
     }
 
     fn cycle_peek_change(&mut self) {
@@ -146,10 +176,10 @@ impl App {
     }
 
     fn base_modified_view_mode(&self) -> PeekMode {
-        if self.unified_modified_step_mode == ModifiedStepMode::Modified {
-            PeekMode::Modified
-        } else {
-            PeekMode::Mixed
+        match self.unified_modified_step_mode {
+            ModifiedStepMode::Mixed => PeekMode::Mixed,
+            ModifiedStepMode::Modified => PeekMode::Modified,
+            ModifiedStepMode::Old => PeekMode::Old,
         }
     }
 
@@ -197,6 +227,27 @@ impl App {
         None
     }
 
+    /// Copy text with a clipboard tool, or through the terminal with OSC 52
+    /// as `clipboard.osc52` allows.
+    pub(crate) fn copy_text(&mut self, text: &str) -> bool {
+        if text.is_empty() {
+            return false;
+        }
+        let try_tools = match self.osc52 {
+            Osc52Mode::Off => return copy_to_clipboard(text),
+            Osc52Mode::On => false,
+            Osc52Mode::Auto => !in_ssh_session(),
+        };
+        if try_tools && copy_to_clipboard(text) {
+            return true;
+        }
+        let (sequence, truncated) = osc52_sequence(text, std::env::var_os("TMUX").is_some());
+        if truncated {
+            self.show_status_hint(HintKind::ClipboardTruncated);
+        }
+        write_osc52(&sequence)
+    }
+
     pub fn yank_current_change(&mut self) {
         let frame = self.animation_frame();
         let view_lines = self.current_view_with_frame(frame);
@@ -204,7 +255,7 @@ impl App {
             return;
         };
         if let Some(text) = self.text_for_yank(line) {
-            copy_to_clipboard(&text);
+            self.copy_text(&text);
         }
     }
 
@@ -224,7 +275,120 @@ impl App {
         if lines.is_empty() {
             return;
         }
-        copy_to_clipboard(&lines.join("\n"));
+        self.copy_text(&lines.join("\n"));
+    }
+
+    /// Copy the visible diff view after the next draw, when the rendered
+    /// buffer is available.
+    pub fn yank_view_snapshot(&mut self, format: SnapshotFormat) {
+        self.pending_snapshot = Some(format);
+    }
+
+    /// Serialize the diff view area of a drawn frame and copy it, if a
+    /// snapshot was requested.
+    pub(crate) fn copy_pending_snapshot(&mut self, buf: &ratatui::buffer::Buffer) {
+        let Some(format) = self.pending_snapshot.take() else {
+            return;
+        };
+        let Some((x, y, width, height)) = self.diff_view_area else {
+            return;
+        };
+        let area = ratatui::layout::Rect::new(x, y, width, height);
+        let ansi = format == SnapshotFormat::Ansi;
+        let text = crate::snapshot::buffer_region_text(buf, area, ansi, self.theme.background);
+        if !text.is_empty() {
+            self.copy_text(&text);
+        }
+    }
+
+    pub fn yank_comment_template(&mut self) {
+        if let Some(text) = self.comment_template_for_hunk() {
+            self.copy_text(&text);
+        }
+    }
+
+    pub fn yank_hunk_header(&mut self) {
+        if let Some(text) = self.hunk_location_header() {
+            self.copy_text(&text);
+        }
+    }
+
+    /// `@@ -a,b +c,d @@` for the current hunk, followed by the nearest
+    /// declaration above it in the old file as git's function context.
+    pub(super) fn hunk_location_header(&mut self) -> Option<String> {
+        if self.current_file_is_binary() {
+            return None;
+        }
+        let ((old_start, old_count), (new_start, new_count)) = {
+            let nav = self.multi_diff.current_navigator();
+            let hunk = nav.current_hunk()?;
+            hunk_ranges(hunk, &nav.diff().changes)
+        };
+        let header = format!("@@ -{old_start},{old_count} +{new_start},{new_count} @@");
+        // Lines above the hunk; an empty old side names the line it follows.
+        let before = if old_count > 0 {
+            old_start.saturating_sub(1)
+        } else {
+            old_start
+        };
+        let file_name = self.current_file_path();
+        let (old_content, _) = self
+            .multi_diff
+            .file_contents_arc(self.multi_diff.selected_index)?;
+        let engine = self
+            .syntax_engine
+            .get_or_insert_with(|| SyntaxEngine::new(&self.syntax_theme, self.theme_is_light));
+        Some(
+            match engine.declaration_before(&old_content, &file_name, before) {
+                Some(context) => format!("{header} {context}"),
+                None => header,
+            },
+        )
+    }
+
+    pub(super) fn comment_template_for_hunk(&mut self) -> Option<String> {
+        if self.current_file_is_binary() {
+            return None;
+        }
+        let (diff, current_hunk) = {
+            let nav = self.multi_diff.current_navigator();
+            (nav.diff().clone(), nav.state().current_hunk)
+        };
+        let hunk = diff.hunks.get(current_hunk)?;
+        let indices: Vec<usize> = hunk
+            .change_ids
+            .iter()
+            .filter_map(|id| diff.changes.iter().position(|c| c.id == *id))
+            .collect();
+        let start_idx = *indices.iter().min()?;
+        let end_idx = *indices.iter().max()?;
+        let (lines, old_start, new_start, old_count, new_count) =
+            self.build_unified_hunk_lines(&diff.changes[start_idx..=end_idx])?;
+        // Quote the new side, falling back to the old side for pure deletions.
+        let (start, count, side) = if new_count > 0 {
+            (new_start, new_count, '+')
+        } else {
+            (old_start, old_count, '-')
+        };
+        let end = start + count.saturating_sub(1);
+        let range = if end > start {
+            format!("{}-{}", start, end)
+        } else {
+            start.to_string()
+        };
+        let path = self.multi_diff.current_file()?.path.display().to_string();
+        let quote = lines
+            .iter()
+            .filter(|line| line.starts_with(side) || line.starts_with(' '))
+            .map(|line| format!("> {}", &line[1..]))
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(
+            self.review_comment_template
+                .replace("{path}", &path)
+                .replace("{lines}", &range)
+                .replace("{quote}", &quote),
+        )
     }
 
     pub fn yank_current_change_patch(&mut self) {
@@ -234,40 +398,144 @@ impl App {
             return;
         };
         if let Some(text) = self.patch_for_hunk(Some(line.change_id)) {
-            copy_to_clipboard(&text);
+            self.copy_text(&text);
         }
     }
 
     pub fn yank_current_hunk_patch(&mut self) {
         if let Some(text) = self.patch_for_hunk(None) {
-            copy_to_clipboard(&text);
+            self.copy_text(&text);
         }
     }
 
-    fn patch_for_hunk(&mut self, change_filter: Option<usize>) -> Option<String> {
+    pub fn yank_current_change_before_after(&mut self) {
+        let frame = self.animation_frame();
+        let view_lines = self.current_view_with_frame(frame);
+        let Some(line) = view_lines.iter().find(|line| line.is_primary_active) else {
+            return;
+        };
+        if let Some(text) = self.before_after_text(Some(line.change_id)) {
+            self.copy_text(&text);
+        }
+    }
+
+    pub fn yank_current_hunk_before_after(&mut self) {
+        if let Some(text) = self.before_after_text(None) {
+            self.copy_text(&text);
+        }
+    }
+
+    pub fn yank_current_change_markdown(&mut self) {
+        let frame = self.animation_frame();
+        let view_lines = self.current_view_with_frame(frame);
+        let Some(line) = view_lines.iter().find(|line| line.is_primary_active) else {
+            return;
+        };
+        if let Some(text) = self.markdown_block_text(Some(line.change_id)) {
+            self.copy_text(&text);
+        }
+    }
+
+    pub fn yank_current_hunk_markdown(&mut self) {
+        if let Some(text) = self.markdown_block_text(None) {
+            self.copy_text(&text);
+        }
+    }
+
+    /// The new side fenced as a markdown code block tagged with the file's
+    /// language, or the old side for a pure deletion.
+    pub(super) fn markdown_block_text(&mut self, change_filter: Option<usize>) -> Option<String> {
+        let changes = self.changes_for_copy(change_filter)?;
+        let has_new = changes.iter().any(|change| {
+            change.has_changes() && change.spans.iter().any(|span| span.new_line.is_some())
+        });
+        let lines: Vec<String> = changes
+            .iter()
+            .filter_map(|change| {
+                if has_new {
+                    change
+                        .spans
+                        .iter()
+                        .any(|span| span.new_line.is_some())
+                        .then(|| modified_only_text_for_change(change))
+                } else {
+                    change
+                        .spans
+                        .iter()
+                        .any(|span| span.old_line.is_some())
+                        .then(|| old_text_for_change(change))
+                }
+            })
+            .collect();
+        if lines.is_empty() {
+            return None;
+        }
+        let file_name = self.current_file_path();
+        let engine = self
+            .syntax_engine
+            .get_or_insert_with(|| SyntaxEngine::new(&self.syntax_theme, self.theme_is_light));
+        let tag = markdown_language_tag(engine.syntax_name_for_file(&file_name));
+        Some(markdown_code_block(&tag, &lines.join("\n")))
+    }
+
+    /// Changes spanning one change, or the current hunk when `change_filter`
+    /// is `None`, including the context between them.
+    fn changes_for_copy(&mut self, change_filter: Option<usize>) -> Option<Vec<oyo_core::Change>> {
         if self.current_file_is_binary() {
             return None;
         }
-        let (diff, current_hunk) = {
-            let nav = self.multi_diff.current_navigator();
-            (nav.diff().clone(), nav.state().current_hunk)
-        };
-        let mut indices = Vec::new();
+        let nav = self.multi_diff.current_navigator();
+        let diff = nav.diff();
         let change_ids: Vec<usize> = match change_filter {
             Some(change_id) => vec![change_id],
-            None => {
-                let hunk = diff.hunks.get(current_hunk)?;
-                hunk.change_ids.clone()
-            }
+            None => diff.hunks.get(nav.state().current_hunk)?.change_ids.clone(),
         };
-        for change_id in change_ids {
-            if let Some(idx) = diff.changes.iter().position(|c| c.id == change_id) {
-                indices.push(idx);
-            }
-        }
+        let indices: Vec<usize> = change_ids
+            .iter()
+            .filter_map(|id| diff.changes.iter().position(|c| c.id == *id))
+            .collect();
         let start_idx = *indices.iter().min()?;
         let end_idx = *indices.iter().max()?;
-        let changes = &diff.changes[start_idx..=end_idx];
+        Some(diff.changes[start_idx..=end_idx].to_vec())
+    }
+
+    /// The old lines then the new lines under the `[review]` headers. A pure
+    /// insertion or deletion has only one side, which is copied bare.
+    pub(super) fn before_after_text(&mut self, change_filter: Option<usize>) -> Option<String> {
+        let changes = self.changes_for_copy(change_filter)?;
+        let mut old: Vec<String> = Vec::new();
+        let mut new: Vec<String> = Vec::new();
+        for change in &changes {
+            if change.spans.iter().any(|span| span.old_line.is_some()) {
+                old.push(old_text_for_change(change));
+            }
+            if change.spans.iter().any(|span| span.new_line.is_some()) {
+                new.push(modified_only_text_for_change(change));
+            }
+        }
+        let has_old = changes.iter().any(|change| {
+            change.has_changes() && change.spans.iter().any(|span| span.old_line.is_some())
+        });
+        let has_new = changes.iter().any(|change| {
+            change.has_changes() && change.spans.iter().any(|span| span.new_line.is_some())
+        });
+        match (has_old, has_new) {
+            (true, true) => Some(format!(
+                "{}\n{}\n{}\n{}",
+                self.review_old_header,
+                old.join("\n"),
+                self.review_new_header,
+                new.join("\n")
+            )),
+            (true, false) => Some(old.join("\n")),
+            (false, true) => Some(new.join("\n")),
+            (false, false) => None,
+        }
+    }
+
+    fn patch_for_hunk(&mut self, change_filter: Option<usize>) -> Option<String> {
+        let changes = self.changes_for_copy(change_filter)?;
+        let changes = changes.as_slice();
 
         let file = self.multi_diff.current_file()?;
         let (old_path, new_path) = match file.status {
@@ -446,14 +714,94 @@ impl App {
 
     pub fn next_step(&mut self) {
         let moved = self.step_forward();
+        if moved {
+            self.settle_empty_step(true);
+        }
         crate::views::log_view_nav_event(self, "step_down", moved);
     }
 
     pub fn prev_step(&mut self) {
         let moved = self.step_backward();
+        if moved {
+            self.settle_empty_step(false);
+            self.expand_current_hunk();
+        }
         crate::views::log_view_nav_event(self, "step_up", moved);
     }
 
+    /// Apply `playback.empty_step` when the step just taken shows nothing,
+    /// such as step 0 of a new file or the last step of a deleted one.
+    pub(super) fn settle_empty_step(&mut self, forward: bool) {
+        if self.empty_step == EmptyStepMode::Message
+            || !self.stepping
+            || !self.current_file_diff_ready()
+            || !self.step_is_blank()
+        {
+            return;
+        }
+        let start = self.multi_diff.current_navigator().state().current_step;
+        let directions: &[bool] = match self.empty_step {
+            EmptyStepMode::Neighbor => &[forward, !forward],
+            _ => &[forward],
+        };
+        for &direction in directions {
+            self.multi_diff.current_navigator().goto(start);
+            loop {
+                let nav = self.multi_diff.current_navigator();
+                let moved = if direction { nav.next() } else { nav.prev() };
+                if !moved {
+                    break;
+                }
+                if !self.step_is_blank() {
+                    self.needs_scroll_to_active = true;
+                    return;
+                }
+            }
+        }
+        self.multi_diff.current_navigator().goto(start);
+    }
+
+    /// Nothing to show once the step settles: lines still waiting to be
+    /// inserted disappear when a backward step's animation ends.
+    pub(super) fn step_is_blank(&mut self) -> bool {
+        let backward =
+            self.multi_diff.current_navigator().state().step_direction == StepDirection::Backward;
+        self.current_view_with_frame(AnimationFrame::Idle)
+            .iter()
+            .all(|line| backward && line.kind == LineKind::PendingInsert)
+    }
+
+    /// Step forward `count` changes, applying all but the last instantly so
+    /// only the final transition animates.
+    pub fn next_steps(&mut self, count: usize) {
+        if count > 1 && self.current_file_diff_ready() {
+            self.multi_diff
+                .ensure_full_navigator(self.multi_diff.selected_index);
+            let nav = self.multi_diff.current_navigator();
+            for _ in 1..count {
+                if !nav.next() {
+                    break;
+                }
+            }
+        }
+        self.next_step();
+    }
+
+    /// Step backward `count` changes, animating only the final transition.
+    pub fn prev_steps(&mut self, count: usize) {
+        if count > 1 && self.current_file_diff_ready() {
+            self.multi_diff
+                .ensure_full_navigator(self.multi_diff.selected_index);
+            let nav = self.multi_diff.current_navigator();
+            for _ in 1..count {
+                if !nav.prev() {
+                    break;
+                }
+            }
+        }
+        self.prev_step();
+    }
+
     pub fn replay_step(&mut self) {
         if !self.stepping {
             return;
@@ -504,6 +852,57 @@ impl App {
         self.hunk_edge_hint.is_some()
     }
 
+    /// Briefly highlight the line a jump landed on (a display index, as for
+    /// search targets) so the eye can find it.
+    pub(crate) fn flash_locate(&mut self, idx: usize) {
+        if self.locate_flash_enabled {
+            let until = Instant::now() + Duration::from_millis(LOCATE_FLASH_MS);
+            self.locate_flash = Some((idx, until));
+        }
+    }
+
+    /// Flash the last line, where `G` lands.
+    pub(crate) fn flash_locate_end(&mut self) {
+        if !self.locate_flash_enabled {
+            return;
+        }
+        if let Some(idx) = self.last_display_index() {
+            self.flash_locate(idx);
+        }
+    }
+
+    pub(crate) fn locate_flash_line(&self) -> Option<usize> {
+        self.locate_flash
+            .filter(|(_, until)| Instant::now() < *until)
+            .map(|(idx, _)| idx)
+    }
+
+    fn last_display_index(&mut self) -> Option<usize> {
+        if self.continuous_view {
+            return self.continuous_rows().len().checked_sub(1);
+        }
+        let view = self.current_view_with_frame(AnimationFrame::Idle);
+        let len = match self.view_mode {
+            ViewMode::Split => {
+                let old = view
+                    .iter()
+                    .filter(|line| line.old_line.is_some() || is_fold_line(line))
+                    .count();
+                let new = view
+                    .iter()
+                    .filter(|line| line.new_line.is_some() || is_fold_line(line))
+                    .count();
+                old.max(new)
+            }
+            ViewMode::Evolution => view
+                .iter()
+                .filter(|line| !matches!(line.kind, LineKind::Deleted | LineKind::PendingDelete))
+                .count(),
+            _ => view.len(),
+        };
+        len.checked_sub(1)
+    }
+
     pub(crate) fn step_edge_hint_for_change(&self, change_id: usize) -> Option<&'static str> {
         let hint = self.step_edge_hint?;
         if Instant::now() > hint.until {
@@ -513,6 +912,7 @@ impl App {
             Some(match hint.edge {
                 StepEdge::Start => "No more steps",
                 StepEdge::End => "No more steps",
+                StepEdge::ReviewEnd => "End of review",
             })
         } else {
             None
@@ -527,6 +927,7 @@ impl App {
         Some(match hint.edge {
             HunkEdge::First => "First hunk",
             HunkEdge::Last => "Last hunk",
+            HunkEdge::ReviewComplete => "Review complete",
         })
     }
 
@@ -584,6 +985,36 @@ impl App {
         self.goto_conflict(false);
     }
 
+    pub fn next_keyword(&mut self) {
+        self.goto_keyword(true);
+    }
+
+    pub fn prev_keyword(&mut self) {
+        self.goto_keyword(false);
+    }
+
+    /// Jump to the next or previous added line with a `review.keywords` word:
+    /// by step while stepping, otherwise by scrolling.
+    fn goto_keyword(&mut self, forward: bool) {
+        let Some(regex) = self.review_keywords.clone() else {
+            return;
+        };
+        if self.stepping {
+            let steps = self.collect_steps_where(|change| {
+                change.has_changes() && regex.is_match(&modified_only_text_for_change(change))
+            });
+            if !steps.is_empty() {
+                self.goto_marked_step(forward, steps);
+                return;
+            }
+        }
+        let markers =
+            self.collect_line_markers(|line| is_added_line(line) && regex.is_match(&line.content));
+        if !markers.is_empty() {
+            self.goto_marked_line(forward, markers);
+        }
+    }
+
     fn goto_conflict(&mut self, forward: bool) {
         if self.stepping {
             let steps = self.collect_conflict_steps();
@@ -592,20 +1023,20 @@ impl App {
                 if markers.is_empty() {
                     return;
                 }
-                self.goto_conflict_scroll(forward, markers);
+                self.goto_marked_line(forward, markers);
             } else {
-                self.goto_conflict_step(forward, steps);
+                self.goto_marked_step(forward, steps);
             }
         } else {
             let markers = self.collect_conflict_markers();
             if markers.is_empty() {
                 return;
             }
-            self.goto_conflict_scroll(forward, markers);
+            self.goto_marked_line(forward, markers);
         }
     }
 
-    fn goto_conflict_step(&mut self, forward: bool, mut steps: Vec<usize>) {
+    fn goto_marked_step(&mut self, forward: bool, mut steps: Vec<usize>) {
         steps.sort_unstable();
         let current_step = self.multi_diff.current_navigator().state().current_step;
         let target_step = if forward {
@@ -626,7 +1057,7 @@ impl App {
         self.jump_to_step(target_step);
     }
 
-    fn goto_conflict_scroll(&mut self, forward: bool, mut markers: Vec<ConflictMarker>) {
+    fn goto_marked_line(&mut self, forward: bool, mut markers: Vec<LineMarker>) {
         markers.sort_by_key(|marker| marker.display_idx);
         let frame = self.animation_frame();
         let view = self.current_view_with_frame(frame);
@@ -671,7 +1102,12 @@ impl App {
         self.clear_step_edge_hint();
     }
 
-    fn collect_conflict_markers(&mut self) -> Vec<ConflictMarker> {
+    fn collect_conflict_markers(&mut self) -> Vec<LineMarker> {
+        self.collect_line_markers(is_conflict_marker)
+    }
+
+    /// Display positions of view lines matching `is_match`, per view mode.
+    fn collect_line_markers(&mut self, is_match: impl Fn(&ViewLine) -> bool) -> Vec<LineMarker> {
         let frame = self.animation_frame();
         let view = self.current_view_with_frame(frame);
         let mut matches = Vec::new();
@@ -679,8 +1115,8 @@ impl App {
         match self.view_mode {
             ViewMode::UnifiedPane | ViewMode::Blame => {
                 for (display_idx, line) in view.iter().enumerate() {
-                    if is_conflict_marker(line) {
-                        matches.push(ConflictMarker {
+                    if is_match(line) {
+                        matches.push(LineMarker {
                             display_idx,
                             change_id: line.change_id,
                         });
@@ -700,8 +1136,8 @@ impl App {
                     if !visible {
                         continue;
                     }
-                    if is_conflict_marker(line) {
-                        matches.push(ConflictMarker {
+                    if is_match(line) {
+                        matches.push(LineMarker {
                             display_idx,
                             change_id: line.change_id,
                         });
@@ -717,16 +1153,16 @@ impl App {
                         && !matches!(line.kind, LineKind::Deleted | LineKind::PendingDelete);
                     let has_old = line.old_line.is_some();
                     if has_new {
-                        if is_conflict_marker(line) {
-                            matches.push(ConflictMarker {
+                        if is_match(line) {
+                            matches.push(LineMarker {
                                 display_idx: new_idx,
                                 change_id: line.change_id,
                             });
                         }
                         new_idx += 1;
                     } else if has_old {
-                        if is_conflict_marker(line) {
-                            matches.push(ConflictMarker {
+                        if is_match(line) {
+                            matches.push(LineMarker {
                                 display_idx: old_idx,
                                 change_id: line.change_id,
                             });
@@ -741,6 +1177,11 @@ impl App {
     }
 
     fn collect_conflict_steps(&mut self) -> Vec<usize> {
+        self.collect_steps_where(change_has_conflict_marker)
+    }
+
+    /// Steps (1-based) whose change matches `is_match`.
+    fn collect_steps_where(&mut self, is_match: impl Fn(&oyo_core::Change) -> bool) -> Vec<usize> {
         let nav = self.multi_diff.current_navigator();
         let diff = nav.diff();
         let mut out = Vec::new();
@@ -749,7 +1190,7 @@ impl App {
                 continue;
             };
             let change = &diff.changes[change_idx];
-            if change_has_conflict_marker(change) {
+            if is_match(change) {
                 out.push(idx + 1);
             }
         }
@@ -809,7 +1250,7 @@ impl App {
     fn trigger_step_edge_hint(&mut self, edge: StepEdge) {
         let state = self.multi_diff.current_navigator().state();
         let change_id = match edge {
-            StepEdge::End => state
+            StepEdge::End | StepEdge::ReviewEnd => state
                 .applied_changes
                 .last()
                 .copied()
@@ -827,6 +1268,43 @@ impl App {
         });
     }
 
+    /// Apply `playback.on_autoplay_end` once autoplay can't step further.
+    /// Returns true when autoplay should keep running.
+    pub(super) fn continue_autoplay_at_end(&mut self) -> bool {
+        match self.autoplay_end {
+            AutoplayEndMode::Stop => false,
+            AutoplayEndMode::Loop => {
+                if self.multi_diff.current_navigator().state().total_steps < 2 {
+                    return false;
+                }
+                let paused = std::mem::replace(&mut self.jump_recording_paused, true);
+                if self.autoplay_reverse {
+                    self.goto_last_step();
+                } else {
+                    self.goto_first_step();
+                }
+                self.jump_recording_paused = paused;
+                true
+            }
+            AutoplayEndMode::NextFile => {
+                if self.autoplay_reverse {
+                    return false;
+                }
+                let current = self.multi_diff.selected_index;
+                self.next_file();
+                if self.multi_diff.selected_index == current {
+                    return false;
+                }
+                self.goto_first_step();
+                true
+            }
+            AutoplayEndMode::Quit => {
+                self.should_quit = true;
+                false
+            }
+        }
+    }
+
     pub(super) fn step_forward(&mut self) -> bool {
         if !self.current_file_diff_ready() {
             return false;
@@ -848,6 +1326,16 @@ impl App {
             self.refresh_blame_toggle_hint();
             true
         } else {
+            if self.auto_advance_file {
+                let current = self.multi_diff.selected_index;
+                self.next_file();
+                if self.multi_diff.selected_index != current {
+                    self.goto_first_step();
+                    return true;
+                }
+                self.trigger_step_edge_hint(StepEdge::ReviewEnd);
+                return false;
+            }
             match self.step_wrap {
                 StepWrapMode::File => {
                     if self.next_file_wrapped() {
@@ -926,7 +1414,7 @@ impl App {
     }
 
     fn compute_hunk_starts_unified_uncached(&mut self) -> Vec<Option<HunkStart>> {
-        if self.multi_diff.current_file_is_large() && !self.fold_context.is_enabled() {
+        if self.multi_diff.current_file_is_large() && !self.view_reshaped() {
             return self.compute_hunk_starts_unified_fast();
         }
         let view = self.current_view_with_frame(AnimationFrame::Idle);
@@ -972,7 +1460,7 @@ impl App {
     }
 
     fn compute_hunk_bounds_unified_uncached(&mut self) -> Vec<Option<HunkBounds>> {
-        if self.multi_diff.current_file_is_large() && !self.fold_context.is_enabled() {
+        if self.multi_diff.current_file_is_large() && !self.view_reshaped() {
             return self.compute_hunk_bounds_unified_fast();
         }
         let view = self.current_view_with_frame(AnimationFrame::Idle);
@@ -1020,7 +1508,7 @@ impl App {
                 return starts.clone();
             }
         }
-        let starts = if self.multi_diff.current_file_is_large() && !self.fold_context.is_enabled() {
+        let starts = if self.multi_diff.current_file_is_large() && !self.view_reshaped() {
             self.compute_hunk_starts_split_fast()
         } else {
             let view = self.current_view_with_frame(AnimationFrame::Idle);
@@ -1071,7 +1559,7 @@ impl App {
                 return bounds.clone();
             }
         }
-        let bounds = if self.multi_diff.current_file_is_large() && !self.fold_context.is_enabled() {
+        let bounds = if self.multi_diff.current_file_is_large() && !self.view_reshaped() {
             self.compute_hunk_bounds_split_fast()
         } else {
             let view = self.current_view_with_frame(AnimationFrame::Idle);
@@ -1367,6 +1855,70 @@ impl App {
         }
     }
 
+    /// Move focus to the other split pane. Only the markers follow it: the
+    /// pane without focus shows its inactive marker when one is configured.
+    pub fn toggle_split_focus(&mut self) {
+        if self.view_mode != ViewMode::Split {
+            return;
+        }
+        self.split_focus = match self.split_focus {
+            FileSide::Old => FileSide::New,
+            FileSide::New => FileSide::Old,
+        };
+    }
+
+    /// Primary marker for a split pane, honoring the inactive variant on the
+    /// pane without focus.
+    pub(crate) fn split_primary_marker(&self, side: FileSide) -> String {
+        let (active, inactive) = match side {
+            FileSide::Old => (&self.primary_marker, &self.primary_marker_inactive),
+            FileSide::New => (
+                &self.primary_marker_right,
+                &self.primary_marker_right_inactive,
+            ),
+        };
+        match inactive {
+            Some(marker) if side != self.split_focus => marker.clone(),
+            _ => active.clone(),
+        }
+    }
+
+    /// Center the current hunk in both split panes at once (`z s`).
+    /// The new pane is skewed against the old one so a block that sits
+    /// at different heights on each side lines up in the middle of both.
+    pub fn sync_center_split(&mut self, viewport_height: usize) {
+        if self.view_mode != ViewMode::Split || self.line_wrap || self.continuous_view {
+            self.center_on_active(viewport_height);
+            return;
+        }
+        let hunk_idx = self.multi_diff.current_navigator().state().current_hunk;
+        let (old_bounds, new_bounds) = self.compute_hunk_bounds_split();
+        let old = old_bounds.get(hunk_idx).copied().flatten();
+        let new = new_bounds.get(hunk_idx).copied().flatten();
+        if old.is_none() && new.is_none() {
+            self.center_on_active(viewport_height);
+            return;
+        }
+        let top_for = |bounds: HunkBounds| {
+            let len = bounds.end.idx.saturating_sub(bounds.start.idx) + 1;
+            if len >= viewport_height {
+                // Taller than the viewport: keep the hunk start in view.
+                bounds.start.idx
+            } else {
+                ((bounds.start.idx + bounds.end.idx) / 2).saturating_sub(viewport_height / 2)
+            }
+        };
+        let old_top = old.map(top_for);
+        let new_top = new.map(top_for);
+        self.scroll_offset = old_top.or(new_top).unwrap_or(0);
+        self.split_new_skew = match (old_top, new_top) {
+            (Some(old_top), Some(new_top)) => new_top as isize - old_top as isize,
+            _ => 0,
+        };
+        self.centered_once = true;
+        self.needs_scroll_to_active = false;
+    }
+
     fn pick_split_bounds(
         &self,
         old: Option<HunkBounds>,
@@ -1451,6 +2003,18 @@ impl App {
             .find_map(|(idx, start)| start.map(|s| (idx, s)))
     }
 
+    /// Drop the starts of hunks `--hunks` leaves out so navigation passes them.
+    fn mask_skipped_hunks(&self, mut starts: Vec<Option<HunkStart>>) -> Vec<Option<HunkStart>> {
+        if self.hunk_subset.is_some() {
+            for (idx, start) in starts.iter_mut().enumerate() {
+                if self.hunk_skipped(idx) {
+                    *start = None;
+                }
+            }
+        }
+        starts
+    }
+
     fn unified_hunk_fallback(&self, starts: &[Option<HunkStart>]) -> Option<(usize, HunkStart)> {
         let mut only: Option<(usize, HunkStart)> = None;
         for (idx, start) in starts.iter().enumerate() {
@@ -1550,6 +2114,7 @@ impl App {
 
     /// Scroll to the next hunk (no-step mode)
     pub fn next_hunk_scroll(&mut self) {
+        self.record_jump();
         let mut moved = false;
         if !self.current_file_diff_ready() {
             crate::views::log_view_nav_event(self, "hunk_down", moved);
@@ -1570,6 +2135,10 @@ impl App {
             .last_nav_was_hunk;
         let use_cursor = auto_center && cursor_set && in_hunk_scope;
         let inclusive = in_hunk_scope;
+        // Fold the hunk being left before measuring so target starts account for it.
+        let collapsed = self.collapse_reviewed_hunks
+            && in_hunk_scope
+            && self.set_hunk_collapsed(current_hunk, true);
         let target = match self.view_mode {
             ViewMode::Split => {
                 let (old_starts, new_starts) = self.compute_hunk_starts_split();
@@ -1578,6 +2147,7 @@ impl App {
                     .zip(new_starts)
                     .map(|(old, new)| self.pick_split_start(old, new))
                     .collect();
+                let effective = self.mask_skipped_hunks(effective);
                 let mut target = if use_cursor && current_hunk < effective.len() {
                     self.next_hunk_from_index(&effective, current_hunk)
                 } else {
@@ -1593,6 +2163,7 @@ impl App {
             }
             _ => {
                 let hunk_starts = self.compute_hunk_starts_unified();
+                let hunk_starts = self.mask_skipped_hunks(hunk_starts);
                 let mut target = if use_cursor && current_hunk < hunk_starts.len() {
                     self.next_hunk_from_index(&hunk_starts, current_hunk)
                 } else {
@@ -1615,6 +2186,7 @@ impl App {
                 .current_navigator()
                 .set_cursor_hunk(hidx, start.change_id);
             self.multi_diff.current_navigator().set_hunk_scope(true);
+            self.expand_current_hunk();
             if self.auto_center {
                 self.needs_scroll_to_active = true;
             }
@@ -1632,11 +2204,15 @@ impl App {
         } else {
             self.trigger_hunk_edge_hint(HunkEdge::Last);
         }
+        if collapsed && !moved {
+            self.set_hunk_collapsed(current_hunk, false);
+        }
         crate::views::log_view_nav_event(self, "hunk_down", moved);
     }
 
     /// Scroll to the previous hunk (no-step mode)
     pub fn prev_hunk_scroll(&mut self) {
+        self.record_jump();
         let mut moved = false;
         if !self.current_file_diff_ready() {
             crate::views::log_view_nav_event(self, "hunk_up", moved);
@@ -1664,6 +2240,7 @@ impl App {
                     .zip(new_starts)
                     .map(|(old, new)| self.pick_split_start(old, new))
                     .collect();
+                let effective = self.mask_skipped_hunks(effective);
                 let mut target = if use_cursor && current_hunk < effective.len() {
                     self.prev_hunk_from_index(&effective, current_hunk)
                 } else {
@@ -1679,6 +2256,7 @@ impl App {
             }
             _ => {
                 let hunk_starts = self.compute_hunk_starts_unified();
+                let hunk_starts = self.mask_skipped_hunks(hunk_starts);
                 let mut target = if use_cursor && current_hunk < hunk_starts.len() {
                     self.prev_hunk_from_index(&hunk_starts, current_hunk)
                 } else {
@@ -1701,6 +2279,7 @@ impl App {
                 .current_navigator()
                 .set_cursor_hunk(hidx, start.change_id);
             self.multi_diff.current_navigator().set_hunk_scope(true);
+            self.expand_current_hunk();
             if self.auto_center {
                 self.needs_scroll_to_active = true;
             }
@@ -1723,6 +2302,7 @@ impl App {
 
     /// Move to the next hunk (group of related changes)
     pub fn next_hunk(&mut self) {
+        self.record_jump();
         let mut moved = false;
         if !self.current_file_diff_ready() {
             crate::views::log_view_nav_event(self, "hunk_down", moved);
@@ -1734,7 +2314,12 @@ impl App {
         self.clear_peek();
         self.clear_blame_step_hint();
         self.clear_blame_hunk_hint();
-        if self.multi_diff.current_navigator().next_hunk() {
+        let left_hunk = self.multi_diff.current_navigator().state().current_hunk;
+        if self.step_to_listed_hunk(true) {
+            let current_hunk = self.multi_diff.current_navigator().state().current_hunk;
+            if self.collapse_reviewed_hunks && current_hunk > left_hunk {
+                self.set_hunk_collapsed(left_hunk, true);
+            }
             if self.animation_enabled {
                 self.start_animation();
             }
@@ -1747,8 +2332,8 @@ impl App {
             match self.hunk_wrap {
                 HunkWrapMode::Hunk => {
                     let total = self.multi_diff.current_navigator().state().total_hunks;
-                    if total > 0 {
-                        self.goto_hunk_index(0);
+                    if let Some(first) = (0..total).find(|&idx| !self.hunk_skipped(idx)) {
+                        self.goto_hunk_index(first);
                         self.clear_hunk_edge_hint();
                         moved = true;
                     } else {
@@ -1773,6 +2358,7 @@ impl App {
 
     /// Move to the previous hunk (group of related changes)
     pub fn prev_hunk(&mut self) {
+        self.record_jump();
         let mut moved = false;
         if !self.current_file_diff_ready() {
             crate::views::log_view_nav_event(self, "hunk_up", moved);
@@ -1784,7 +2370,8 @@ impl App {
         self.clear_peek();
         self.clear_blame_step_hint();
         self.clear_blame_hunk_hint();
-        if self.multi_diff.current_navigator().prev_hunk() {
+        if self.step_to_listed_hunk(false) {
+            self.expand_current_hunk();
             if self.animation_enabled {
                 self.start_animation();
             } else {
@@ -1799,8 +2386,8 @@ impl App {
             match self.hunk_wrap {
                 HunkWrapMode::Hunk => {
                     let total = self.multi_diff.current_navigator().state().total_hunks;
-                    if total > 0 {
-                        self.goto_hunk_index(total.saturating_sub(1));
+                    if let Some(last) = (0..total).rev().find(|&idx| !self.hunk_skipped(idx)) {
+                        self.goto_hunk_index(last);
                         self.clear_hunk_edge_hint();
                         moved = true;
                     } else {
@@ -1823,6 +2410,31 @@ impl App {
         crate::views::log_view_nav_event(self, "hunk_up", moved);
     }
 
+    /// Step to the next or previous hunk, passing hunks `--hunks` leaves out.
+    /// Keeps the current state when no listed hunk is left that way.
+    fn step_to_listed_hunk(&mut self, forward: bool) -> bool {
+        let saved = self.multi_diff.current_navigator().state().clone();
+        loop {
+            let nav = self.multi_diff.current_navigator();
+            let moved = if forward {
+                nav.next_hunk()
+            } else {
+                nav.prev_hunk()
+            };
+            if !moved {
+                break;
+            }
+            let hunk = self.multi_diff.current_navigator().state().current_hunk;
+            if !self.hunk_skipped(hunk) {
+                return true;
+            }
+        }
+        if self.hunk_subset.is_some() {
+            self.multi_diff.current_navigator().set_state(saved);
+        }
+        false
+    }
+
     /// Get current hunk info (current hunk index, total hunks)
     pub fn hunk_info(&mut self) -> (usize, usize) {
         let state = self.multi_diff.current_navigator().state();
@@ -1840,6 +2452,19 @@ impl App {
         Some((applied, total))
     }
 
+    /// Top bar text: `step 2/5 in hunk` while stepping through a hunk with
+    /// more than one change.
+    pub(crate) fn hunk_step_progress_text(&mut self) -> Option<String> {
+        if !self.hunk_step_progress || !self.stepping {
+            return None;
+        }
+        let (applied, total) = self.hunk_step_info()?;
+        if applied == 0 || total < 2 {
+            return None;
+        }
+        Some(format!("step {applied}/{total} in hunk"))
+    }
+
     pub fn pending_insert_only_in_current_hunk(&mut self) -> usize {
         let nav = self.multi_diff.current_navigator();
         let state = nav.state();
@@ -1961,7 +2586,7 @@ impl App {
         };
 
         if let Some((hidx, bound)) = target {
-            self.scroll_offset = bound.start.idx;
+            self.scroll_offset = bound.start.idx.saturating_sub(self.no_step_hunk_context);
             self.centered_once = false;
             self.multi_diff
                 .current_navigator()
@@ -2080,12 +2705,14 @@ impl App {
     }
 
     pub fn toggle_stepping(&mut self) {
+        self.exit_continuous_view();
         let current_index = self.multi_diff.selected_index;
         if self.stepping {
             // Turning OFF stepping: snapshot state and scroll, then enter no-step.
             self.save_scroll_position_for(current_index);
             self.save_step_state_snapshot(current_index);
             self.step_peek_state = self.peek_state.take();
+            self.peek_next = None;
             self.step_view_mode = self.view_mode;
             self.stepping = false;
             self.clear_step_edge_hint();
@@ -2120,6 +2747,7 @@ impl App {
     }
 
     pub fn goto_start(&mut self) {
+        self.record_jump();
         if self.stepping && !self.current_file_diff_ready() {
             return;
         }
@@ -2156,6 +2784,7 @@ impl App {
     }
 
     pub fn goto_end(&mut self) {
+        self.record_jump();
         if self.stepping && !self.current_file_diff_ready() {
             return;
         }
@@ -2192,6 +2821,7 @@ impl App {
     }
 
     pub fn goto_first_step(&mut self) {
+        self.record_jump();
         if !self.current_file_diff_ready() {
             return;
         }
@@ -2209,6 +2839,7 @@ impl App {
     }
 
     pub fn goto_last_step(&mut self) {
+        self.record_jump();
         if !self.current_file_diff_ready() {
             return;
         }
@@ -2226,6 +2857,7 @@ impl App {
     }
 
     pub(super) fn goto_step_number(&mut self, step_number: usize) {
+        self.record_jump();
         if !self.current_file_diff_ready() {
             return;
         }
@@ -2252,6 +2884,7 @@ impl App {
     }
 
     pub(super) fn goto_hunk_number(&mut self, hunk_number: usize) {
+        self.record_jump();
         if !self.current_file_diff_ready() {
             return;
         }
@@ -2270,6 +2903,99 @@ impl App {
         }
     }
 
+    /// Jump to the next hunk not yet visited or folded as reviewed, moving on
+    /// through later files and wrapping around to the earlier ones.
+    pub fn goto_next_unreviewed_hunk(&mut self) {
+        self.record_jump();
+        let file_count = self.multi_diff.file_count();
+        if file_count == 0 {
+            return;
+        }
+        let current_file = self.multi_diff.selected_index;
+        let current_hunk = self
+            .multi_diff
+            .navigator(current_file)
+            .map(|nav| nav.state().current_hunk);
+        for offset in 0..=file_count {
+            let file_idx = (current_file + offset) % file_count;
+            let range = match (offset, current_hunk) {
+                (0, Some(hunk)) => hunk + 1..usize::MAX,
+                (0, None) => continue,
+                (_, Some(hunk)) if offset == file_count => 0..hunk + 1,
+                _ => 0..usize::MAX,
+            };
+            let Some(target) = self.unreviewed_hunk_in(file_idx, range) else {
+                continue;
+            };
+            if file_idx != current_file {
+                self.select_file(file_idx);
+            }
+            if let Some(hunk_idx) = target {
+                self.goto_hunk_number(hunk_idx + 1);
+            }
+            return;
+        }
+        self.trigger_hunk_edge_hint(HunkEdge::ReviewComplete);
+    }
+
+    /// First unreviewed hunk of a file within `range`. A file whose diff is
+    /// not ready yet counts as unreviewed until one of its hunks is visited,
+    /// with no hunk known (`Some(None)`).
+    fn unreviewed_hunk_in(
+        &mut self,
+        file_idx: usize,
+        range: std::ops::Range<usize>,
+    ) -> Option<Option<usize>> {
+        self.multi_diff.ensure_full_navigator(file_idx);
+        let Some(nav) = self.multi_diff.navigator(file_idx) else {
+            self.multi_diff.files.get(file_idx)?;
+            let untouched = self.multi_diff.change_count(file_idx) > 0
+                && !self.visited_hunks.keys().any(|(idx, _)| *idx == file_idx);
+            return untouched.then_some(None);
+        };
+        let total_hunks = nav.state().total_hunks;
+        (range.start..range.end.min(total_hunks))
+            .find(|&hunk_idx| {
+                let key = (file_idx, hunk_idx);
+                !self.visited_hunks.contains_key(&key) && !self.collapsed_hunks.contains(&key)
+            })
+            .map(Some)
+    }
+
+    /// Jump to the hunk with the most changed lines; repeating from there walks
+    /// down to the next-biggest. Ties keep file order.
+    pub(super) fn goto_biggest_hunk(&mut self) {
+        self.record_jump();
+        if !self.current_file_diff_ready() {
+            return;
+        }
+        let file_idx = self.multi_diff.selected_index;
+        self.multi_diff.ensure_full_navigator(file_idx);
+        let nav = self.multi_diff.current_navigator();
+        let mut ranking: Vec<(usize, usize)> = nav
+            .hunks()
+            .iter()
+            .enumerate()
+            .map(|(idx, hunk)| (idx, hunk.insertions + hunk.deletions))
+            .collect();
+        if ranking.is_empty() {
+            return;
+        }
+        ranking.sort_by_key(|(_, size)| std::cmp::Reverse(*size));
+        let current_hunk = nav.state().current_hunk;
+        let rank = match self.biggest_hunk_rank {
+            Some((file, rank))
+                if file == file_idx
+                    && ranking.get(rank).map(|(idx, _)| *idx) == Some(current_hunk) =>
+            {
+                (rank + 1) % ranking.len()
+            }
+            _ => 0,
+        };
+        self.goto_hunk_number(ranking[rank].0 + 1);
+        self.biggest_hunk_rank = Some((file_idx, rank));
+    }
+
     pub(super) fn goto_hunk_index(&mut self, hunk_idx: usize) {
         if !self.current_file_diff_ready() {
             return;
@@ -2342,7 +3068,7 @@ impl App {
         };
 
         if let Some((hidx, bound)) = target {
-            self.scroll_offset = bound.start.idx;
+            self.scroll_offset = bound.start.idx.saturating_sub(self.no_step_hunk_context);
             self.centered_once = false;
             self.multi_diff
                 .current_navigator()
@@ -2356,7 +3082,11 @@ impl App {
         }
     }
 
-    pub(super) fn goto_line_number(&mut self, line_number: usize) {
+    pub fn goto_line_number(&mut self, line_number: usize) {
+        if self.skip_structural_lines() {
+            return;
+        }
+        self.record_jump();
         if self.stepping && !self.current_file_diff_ready() {
             return;
         }
@@ -2475,24 +3205,197 @@ impl App {
         };
 
         if let Some(idx) = target_idx {
-            let viewport_height = self.last_viewport_height.max(1);
-            if self.auto_center {
-                let half_viewport = viewport_height / 2;
-                self.scroll_offset = idx.saturating_sub(half_viewport);
-                self.centered_once = true;
+            self.scroll_to_goto_index(idx);
+        }
+    }
+
+    /// Jump to the Nth changed (non-context) line in display order.
+    pub(super) fn goto_changed_line_number(&mut self, changed_number: usize) {
+        self.record_jump();
+        if self.stepping && !self.current_file_diff_ready() {
+            return;
+        }
+        if self.stepping {
+            self.multi_diff
+                .ensure_full_navigator(self.multi_diff.selected_index);
+        }
+        self.clear_peek();
+        let view = self.current_view_with_frame(AnimationFrame::Idle);
+        let mut targets = Vec::new();
+        match self.view_mode {
+            ViewMode::Split => {
+                let mut old_idx = 0usize;
+                let mut new_idx = 0usize;
+                for line in view.iter() {
+                    let fold_line = is_fold_line(line);
+                    let old_match = (line.old_line.is_some() || fold_line).then_some(old_idx);
+                    let new_match = (line.new_line.is_some() || fold_line).then_some(new_idx);
+                    if old_match.is_some() {
+                        old_idx += 1;
+                    }
+                    if new_match.is_some() {
+                        new_idx += 1;
+                    }
+                    if line.kind != LineKind::Context {
+                        if let Some(idx) = self.pick_split_index(old_match, new_match) {
+                            targets.push(idx);
+                        }
+                    }
+                }
+            }
+            ViewMode::Evolution => {
+                targets.extend(
+                    view.iter()
+                        .filter(|line| {
+                            !matches!(line.kind, LineKind::Deleted | LineKind::PendingDelete)
+                        })
+                        .enumerate()
+                        .filter(|(_, line)| line.kind != LineKind::Context)
+                        .map(|(idx, _)| idx),
+                );
+            }
+            _ => {
+                targets.extend(
+                    view.iter()
+                        .enumerate()
+                        .filter(|(_, line)| line.kind != LineKind::Context)
+                        .map(|(idx, _)| idx),
+                );
+            }
+        }
+        if targets.is_empty() {
+            return;
+        }
+        let clamped = changed_number.max(1).min(targets.len());
+        self.scroll_to_goto_index(targets[clamped - 1]);
+    }
+
+    fn scroll_to_goto_index(&mut self, idx: usize) {
+        let viewport_height = self.last_viewport_height.max(1);
+        if self.auto_center {
+            let half_viewport = viewport_height / 2;
+            self.scroll_offset = idx.saturating_sub(half_viewport);
+            self.centered_once = true;
+        } else {
+            self.scroll_offset = idx;
+            self.centered_once = false;
+        }
+        self.needs_scroll_to_active = false;
+        self.multi_diff.current_navigator().set_hunk_scope(false);
+        if !self.stepping {
+            self.set_cursor_for_current_scroll();
+        }
+        self.flash_locate(idx);
+    }
+
+    /// Changed line under the cursor (1-indexed, 0 before the first change) and
+    /// the total number of changed lines in the current file.
+    pub fn changed_line_info(&mut self) -> Option<(usize, usize)> {
+        let view = self.current_view_with_frame(self.animation_frame());
+        if self.view_windowed() {
+            // Counts would only cover the loaded window.
+            return None;
+        }
+        let evolution = self.view_mode == ViewMode::Evolution;
+        let visible: Vec<&ViewLine> = view
+            .iter()
+            .filter(|line| {
+                !evolution || !matches!(line.kind, LineKind::Deleted | LineKind::PendingDelete)
+            })
+            .collect();
+        let total = visible
+            .iter()
+            .filter(|line| line.kind != LineKind::Context)
+            .count();
+        if total == 0 {
+            return None;
+        }
+        let focus_idx = visible
+            .iter()
+            .position(|line| line.is_primary_active)
+            .or_else(|| visible.iter().position(|line| line.is_active))
+            .unwrap_or_else(|| self.display_row_view_index(&visible, self.scroll_offset));
+        let current = visible
+            .iter()
+            .take(focus_idx + 1)
+            .filter(|line| line.kind != LineKind::Context)
+            .count();
+        Some((current, total))
+    }
+
+    /// Source line at the viewport focus (centered when auto-center is on).
+    /// Used to land on the same line when switching files.
+    pub(super) fn focused_source_line(&mut self) -> Option<usize> {
+        let view = self.current_view_with_frame(AnimationFrame::Idle);
+        let offset = if self.view_windowed() {
+            self.render_scroll_offset()
+        } else {
+            self.scroll_offset
+        };
+        let row = if self.auto_center {
+            offset + self.last_viewport_height.max(1) / 2
+        } else {
+            offset
+        };
+        let evolution = self.view_mode == ViewMode::Evolution;
+        let visible: Vec<&ViewLine> = view
+            .iter()
+            .filter(|line| {
+                !evolution || !matches!(line.kind, LineKind::Deleted | LineKind::PendingDelete)
+            })
+            .collect();
+        if visible.is_empty() {
+            return None;
+        }
+        let idx = self.display_row_view_index(&visible, row);
+        // Search outward from the focus row for the nearest numbered line (skips folds).
+        let line_number = |line: &ViewLine| {
+            if evolution {
+                line.new_line.or(line.old_line)
             } else {
-                self.scroll_offset = idx;
-                self.centered_once = false;
+                line.old_line.or(line.new_line)
             }
-            self.needs_scroll_to_active = false;
-            self.multi_diff.current_navigator().set_hunk_scope(false);
-            if !self.stepping {
-                self.set_cursor_for_current_scroll();
+        };
+        (0..visible.len()).find_map(|dist| {
+            let after = visible.get(idx + dist).and_then(|line| line_number(line));
+            after.or_else(|| {
+                idx.checked_sub(dist)
+                    .and_then(|i| visible.get(i))
+                    .and_then(|line| line_number(line))
+            })
+        })
+    }
+
+    /// Map a display row back to an index into `visible`.
+    /// Split panes count rows per side, so walk both sides to find the row.
+    fn display_row_view_index(&self, visible: &[&ViewLine], row: usize) -> usize {
+        let last = visible.len().saturating_sub(1);
+        if self.view_mode != ViewMode::Split {
+            return row.min(last);
+        }
+        let mut old_idx = 0usize;
+        let mut new_idx = 0usize;
+        for (idx, line) in visible.iter().enumerate() {
+            let fold_line = is_fold_line(line);
+            if line.old_line.is_some() || fold_line {
+                if old_idx >= row {
+                    return idx;
+                }
+                old_idx += 1;
+            }
+            if line.new_line.is_some() || fold_line {
+                if new_idx >= row {
+                    return idx;
+                }
+                new_idx += 1;
             }
         }
+        last
     }
 
     pub fn toggle_view_mode(&mut self) {
+        self.exit_continuous_view();
+        self.split_new_skew = 0;
         let allow_blame = self.blame_enabled;
         if !self.stepping {
             // In no-step mode, skip Evolution view as it requires stepping
@@ -2524,6 +3427,8 @@ impl App {
     }
 
     pub fn set_view_mode(&mut self, target: ViewMode) {
+        self.exit_continuous_view();
+        self.split_new_skew = 0;
         if target == ViewMode::Blame && !self.blame_enabled {
             return;
         }
@@ -2549,6 +3454,8 @@ impl App {
     }
 
     pub fn toggle_view_mode_reverse(&mut self) {
+        self.exit_continuous_view();
+        self.split_new_skew = 0;
         let allow_blame = self.blame_enabled;
         if !self.stepping {
             // In no-step mode, skip Evolution view as it requires stepping
@@ -2621,4 +3528,36 @@ mod tests {
         let state = app.multi_diff.current_navigator().state();
         assert_eq!(state.current_step, steps[0]);
     }
+
+    #[test]
+    fn test_keyword_navigation_skips_context_and_deleted_lines() {
+        let _guard = DiffSettingsGuard::default();
+        let old = "// TODO: kept\na\n// FIXME: removed\nb\nc\n".to_string();
+        let new = "// TODO: kept\na\nb\nc\n// FIXME: added\n".to_string();
+        let multi_diff = MultiFileDiff::from_file_pair(
+            std::path::PathBuf::from("a.txt"),
+            std::path::PathBuf::from("a.txt"),
+            old,
+            new,
+        );
+        let mut app = App::new(multi_diff, ViewMode::UnifiedPane, 0, false, None);
+
+        app.next_keyword();
+        let state = app.multi_diff.current_navigator().state();
+        assert_eq!(state.current_step, 0, "no keywords configured");
+
+        app.set_review_keywords(&["TODO".to_string(), " FIXME ".to_string()]);
+        let steps = app.collect_steps_where(|change| {
+            change.has_changes() && modified_only_text_for_change(change).contains("FIXME")
+        });
+        assert_eq!(steps.len(), 1);
+
+        app.next_keyword();
+        let state = app.multi_diff.current_navigator().state();
+        assert_eq!(state.current_step, steps[0]);
+
+        app.prev_keyword();
+        let state = app.multi_diff.current_navigator().state();
+        assert_eq!(state.current_step, steps[0]);
+    }
 }
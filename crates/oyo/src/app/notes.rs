@@ -0,0 +1,95 @@
+use super::{AnimationFrame, App, ViewMode};
+use crate::notes::PresenterNote;
+use oyo_core::{LineKind, ViewLine};
+use rustc_hash::FxHashSet;
+use std::path::{Path, PathBuf};
+
+impl App {
+    pub fn has_presenter_notes(&self) -> bool {
+        self.presenter_notes.is_some()
+    }
+
+    pub fn toggle_note_popup(&mut self) {
+        if self.show_note_popup {
+            self.show_note_popup = false;
+            return;
+        }
+        self.show_note_popup = self.current_presenter_note().is_some();
+    }
+
+    fn current_note_file_path(&self) -> Option<PathBuf> {
+        self.multi_diff.current_file().map(|file| file.path.clone())
+    }
+
+    /// Every file path in the diff, to tell whether a note's path is unique.
+    fn note_diff_paths(&self) -> impl Iterator<Item = &Path> + Clone {
+        self.multi_diff.files.iter().map(|file| file.path.as_path())
+    }
+
+    /// Note for the line under the cursor (or nearest noted line in the file).
+    pub(crate) fn current_presenter_note(&mut self) -> Option<(usize, PresenterNote)> {
+        self.presenter_notes.as_ref()?;
+        let path = self.current_note_file_path()?;
+        let line = self.presenter_note_focus_line()?;
+        let note = self
+            .presenter_notes
+            .as_ref()?
+            .note_for_line(&path, line, self.note_diff_paths())?
+            .clone();
+        Some((line, note))
+    }
+
+    fn presenter_note_focus_line(&mut self) -> Option<usize> {
+        let target_offset = if self.view_windowed() {
+            self.render_scroll_offset()
+        } else {
+            self.scroll_offset
+        };
+        let view = self.current_view_with_frame(AnimationFrame::Idle);
+        let evolution = self.view_mode == ViewMode::Evolution;
+        let visible: Vec<&ViewLine> = view
+            .iter()
+            .filter(|line| {
+                !evolution || !matches!(line.kind, LineKind::Deleted | LineKind::PendingDelete)
+            })
+            .collect();
+        let focus = visible
+            .iter()
+            .find(|line| line.is_primary_active)
+            .or_else(|| visible.iter().find(|line| line.is_active))
+            .or_else(|| visible.get(target_offset.min(visible.len().saturating_sub(1))))?;
+        focus.new_line.or(focus.old_line)
+    }
+
+    /// Line numbers in the current file that should show a note indicator.
+    /// Notes whose range no longer exists anchor to the nearest present line.
+    pub(crate) fn presenter_note_lines(&self, view_lines: &[ViewLine]) -> FxHashSet<usize> {
+        let mut out = FxHashSet::default();
+        let Some(notes) = self.presenter_notes.as_ref() else {
+            return out;
+        };
+        let Some(path) = self.current_note_file_path() else {
+            return out;
+        };
+        let present: Vec<usize> = view_lines
+            .iter()
+            .filter_map(|line| line.new_line.or(line.old_line))
+            .collect();
+        if present.is_empty() {
+            return out;
+        }
+        for note in notes.notes_for_file(&path, self.note_diff_paths()) {
+            let mut hit = false;
+            for line in present.iter().filter(|line| note.contains(**line)) {
+                out.insert(*line);
+                hit = true;
+            }
+            if !hit {
+                if let Some(nearest) = present.iter().min_by_key(|line| note.distance(**line)) {
+                    out.insert(*nearest);
+                }
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,87 @@
+//! Full-screen file overview, a `git diff --stat` style landing screen
+
+use super::App;
+
+impl App {
+    pub fn toggle_overview(&mut self) {
+        if self.show_overview {
+            self.close_overview();
+        } else {
+            self.open_overview();
+        }
+    }
+
+    pub fn open_overview(&mut self) {
+        if !self.is_multi_file() {
+            return;
+        }
+        self.show_overview = true;
+        self.overview_return = false;
+        self.overview_selection = self.multi_diff.selected_index;
+    }
+
+    pub fn close_overview(&mut self) {
+        self.show_overview = false;
+    }
+
+    /// Open the selected file in the viewer; Esc there returns to the overview.
+    pub fn overview_accept(&mut self) {
+        let idx = self.overview_selection;
+        self.show_overview = false;
+        if idx != self.multi_diff.selected_index {
+            self.select_file(idx);
+        }
+        self.overview_return = true;
+    }
+
+    pub fn overview_move(&mut self, delta: isize) {
+        let last = self.multi_diff.file_count().saturating_sub(1);
+        self.overview_selection = self
+            .overview_selection
+            .saturating_add_signed(delta)
+            .min(last);
+    }
+
+    pub fn overview_page(&mut self, down: bool) {
+        let step = self.overview_page_rows.max(1) as isize;
+        self.overview_move(if down { step } else { -step });
+    }
+
+    pub fn overview_select_first(&mut self) {
+        self.overview_selection = 0;
+    }
+
+    pub fn overview_select_last(&mut self) {
+        self.overview_selection = self.multi_diff.file_count().saturating_sub(1);
+    }
+}
+
+/// Split `width` columns of a stat bar into (plus, minus) cells, scaled
+/// against the largest change like `git diff --stat`. Any non-zero side
+/// gets at least one cell.
+pub(crate) fn stat_bar_cells(
+    insertions: usize,
+    deletions: usize,
+    max_total: usize,
+    width: usize,
+) -> (usize, usize) {
+    let total = insertions + deletions;
+    if total == 0 || max_total == 0 || width == 0 {
+        return (0, 0);
+    }
+    let cells = if max_total <= width {
+        total
+    } else {
+        (total * width).div_ceil(max_total).max(1)
+    };
+    let mut plus = (insertions * cells) / total;
+    if insertions > 0 && plus == 0 {
+        plus = 1;
+    }
+    let mut minus = cells.saturating_sub(plus);
+    if deletions > 0 && minus == 0 && plus > 1 {
+        plus -= 1;
+        minus = 1;
+    }
+    (plus, minus)
+}
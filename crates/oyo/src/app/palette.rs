@@ -6,17 +6,43 @@ pub(crate) enum PaletteAction {
     ToggleViewMode,
     SetViewMode(ViewMode),
     ToggleLineWrap,
+    ToggleDiffBg,
+    ToggleThemeMode,
+    ToggleSplitOrientation,
+    ToggleGutterSigns,
+    ToggleTrailingWhitespace,
     ToggleFoldContext,
+    ClearLineLimit,
     ToggleSyntax,
+    CycleDiffHighlight,
+    CycleModifiedStep,
     ToggleHelp,
     ToggleZen,
+    SyncCenterSplit,
+    ToggleSplitFocus,
+    ToggleMouseCapture,
+    ToggleFocusMode,
+    ToggleMineOnly,
+    ToggleCommitHeader,
+    ToggleOldPath,
+    CycleFileCounts,
+    CyclePathStyle,
     ToggleFilePanel,
+    ToggleContinuous,
+    ToggleShowKeys,
+    OpenOverview,
     ToggleAutoplay,
     ToggleAutoplayReverse,
     OpenDashboard,
+    OpenFileHistory,
+    WalkFileCommits,
     Quit,
     RefreshCurrentFile,
     RefreshAllFiles,
+    ReloadConfig,
+    ExportComments,
+    ShowNote,
+    ShowBuildInfo,
 }
 
 #[derive(Clone, Debug)]
@@ -175,6 +201,26 @@ impl App {
                 label: "Toggle line wrap".to_string(),
                 action: PaletteAction::ToggleLineWrap,
             },
+            PaletteEntry {
+                label: "Toggle line backgrounds (this view)".to_string(),
+                action: PaletteAction::ToggleDiffBg,
+            },
+            PaletteEntry {
+                label: "Switch light/dark theme".to_string(),
+                action: PaletteAction::ToggleThemeMode,
+            },
+            PaletteEntry {
+                label: "Toggle split orientation".to_string(),
+                action: PaletteAction::ToggleSplitOrientation,
+            },
+            PaletteEntry {
+                label: "Toggle gutter signs".to_string(),
+                action: PaletteAction::ToggleGutterSigns,
+            },
+            PaletteEntry {
+                label: "Toggle trailing whitespace highlight".to_string(),
+                action: PaletteAction::ToggleTrailingWhitespace,
+            },
             PaletteEntry {
                 label: "Toggle context folding".to_string(),
                 action: PaletteAction::ToggleFoldContext,
@@ -183,6 +229,20 @@ impl App {
                 label: "Toggle syntax highlight".to_string(),
                 action: PaletteAction::ToggleSyntax,
             },
+            PaletteEntry {
+                label: format!(
+                    "Cycle inline highlight (now {})",
+                    self.diff_highlight.label()
+                ),
+                action: PaletteAction::CycleDiffHighlight,
+            },
+            PaletteEntry {
+                label: format!(
+                    "Cycle modified line rendering (now {})",
+                    self.unified_modified_step_mode.label()
+                ),
+                action: PaletteAction::CycleModifiedStep,
+            },
             PaletteEntry {
                 label: "Toggle help".to_string(),
                 action: PaletteAction::ToggleHelp,
@@ -191,13 +251,74 @@ impl App {
                 label: "Toggle zen mode".to_string(),
                 action: PaletteAction::ToggleZen,
             },
+            PaletteEntry {
+                label: "Center hunk in both split panes".to_string(),
+                action: PaletteAction::SyncCenterSplit,
+            },
+            PaletteEntry {
+                label: "Switch focused split pane".to_string(),
+                action: PaletteAction::ToggleSplitFocus,
+            },
+            PaletteEntry {
+                label: format!(
+                    "Toggle mouse capture (now {})",
+                    if self.mouse_capture { "on" } else { "off" }
+                ),
+                action: PaletteAction::ToggleMouseCapture,
+            },
+            PaletteEntry {
+                label: "Toggle focus mode".to_string(),
+                action: PaletteAction::ToggleFocusMode,
+            },
+            PaletteEntry {
+                label: "Toggle only my changes (dim others)".to_string(),
+                action: PaletteAction::ToggleMineOnly,
+            },
+            PaletteEntry {
+                label: "Toggle commit message".to_string(),
+                action: PaletteAction::ToggleCommitHeader,
+            },
+            PaletteEntry {
+                label: "Toggle old/new name for renames".to_string(),
+                action: PaletteAction::ToggleOldPath,
+            },
+            PaletteEntry {
+                label: format!("Cycle file counts (now {})", self.file_count_mode.label()),
+                action: PaletteAction::CycleFileCounts,
+            },
+            PaletteEntry {
+                label: format!(
+                    "Cycle panel path style (now {})",
+                    self.file_path_style.label()
+                ),
+                action: PaletteAction::CyclePathStyle,
+            },
+            PaletteEntry {
+                label: "Toggle keystroke overlay".to_string(),
+                action: PaletteAction::ToggleShowKeys,
+            },
         ]);
 
+        if self.line_limit_info().is_some() {
+            entries.push(PaletteEntry {
+                label: "Clear line range limit".to_string(),
+                action: PaletteAction::ClearLineLimit,
+            });
+        }
+
         if self.is_multi_file() {
             entries.push(PaletteEntry {
                 label: "Toggle file panel".to_string(),
                 action: PaletteAction::ToggleFilePanel,
             });
+            entries.push(PaletteEntry {
+                label: "File overview (diff --stat)".to_string(),
+                action: PaletteAction::OpenOverview,
+            });
+            entries.push(PaletteEntry {
+                label: "Toggle continuous scroll (all files)".to_string(),
+                action: PaletteAction::ToggleContinuous,
+            });
             entries.push(PaletteEntry {
                 label: "Refresh all files".to_string(),
                 action: PaletteAction::RefreshAllFiles,
@@ -208,12 +329,36 @@ impl App {
             label: "Pick commit".to_string(),
             action: PaletteAction::OpenDashboard,
         });
+        if self.multi_diff.is_git_mode() {
+            entries.push(PaletteEntry {
+                label: "File history".to_string(),
+                action: PaletteAction::OpenFileHistory,
+            });
+        }
+        if self.multi_diff.git_range().is_some() {
+            entries.push(PaletteEntry {
+                label: "Step through file's commits in range".to_string(),
+                action: PaletteAction::WalkFileCommits,
+            });
+        }
 
         entries.push(PaletteEntry {
             label: "Refresh current file".to_string(),
             action: PaletteAction::RefreshCurrentFile,
         });
 
+        entries.push(PaletteEntry {
+            label: "Reload config".to_string(),
+            action: PaletteAction::ReloadConfig,
+        });
+
+        if self.review_comment_count() > 0 {
+            entries.push(PaletteEntry {
+                label: "Export review comments".to_string(),
+                action: PaletteAction::ExportComments,
+            });
+        }
+
         if self.stepping {
             entries.push(PaletteEntry {
                 label: "Toggle autoplay".to_string(),
@@ -225,6 +370,18 @@ impl App {
             });
         }
 
+        if self.has_presenter_notes() {
+            entries.push(PaletteEntry {
+                label: "Show presenter note".to_string(),
+                action: PaletteAction::ShowNote,
+            });
+        }
+
+        entries.push(PaletteEntry {
+            label: "About (version and build info)".to_string(),
+            action: PaletteAction::ShowBuildInfo,
+        });
+
         entries.push(PaletteEntry {
             label: "Quit".to_string(),
             action: PaletteAction::Quit,
@@ -239,17 +396,45 @@ impl App {
             PaletteAction::ToggleViewMode => self.toggle_view_mode(),
             PaletteAction::SetViewMode(mode) => self.set_view_mode(mode),
             PaletteAction::ToggleLineWrap => self.toggle_line_wrap(),
+            PaletteAction::ToggleDiffBg => self.toggle_diff_bg(),
+            PaletteAction::ToggleThemeMode => self.theme_mode_toggle_requested = true,
+            PaletteAction::ToggleSplitOrientation => self.toggle_split_orientation(),
+            PaletteAction::ToggleGutterSigns => self.toggle_gutter_signs(),
+            PaletteAction::ToggleTrailingWhitespace => self.toggle_highlight_trailing_ws(),
             PaletteAction::ToggleFoldContext => self.toggle_fold_context(),
+            PaletteAction::ClearLineLimit => self.clear_line_limit(),
             PaletteAction::ToggleSyntax => self.toggle_syntax(),
+            PaletteAction::CycleDiffHighlight => self.cycle_diff_highlight(),
+            PaletteAction::CycleModifiedStep => self.cycle_modified_step_mode(),
             PaletteAction::ToggleHelp => self.toggle_help(),
             PaletteAction::ToggleZen => self.toggle_zen(),
+            PaletteAction::SyncCenterSplit => {
+                self.sync_center_split(self.last_viewport_height.max(1))
+            }
+            PaletteAction::ToggleSplitFocus => self.toggle_split_focus(),
+            PaletteAction::ToggleMouseCapture => self.toggle_mouse_capture(),
+            PaletteAction::ToggleFocusMode => self.toggle_focus_mode(),
+            PaletteAction::ToggleMineOnly => self.toggle_mine_only(),
+            PaletteAction::ToggleCommitHeader => self.toggle_commit_header(),
+            PaletteAction::ToggleOldPath => self.toggle_old_path(),
+            PaletteAction::CycleFileCounts => self.cycle_file_count_mode(),
+            PaletteAction::CyclePathStyle => self.cycle_file_path_style(),
             PaletteAction::ToggleFilePanel => self.toggle_file_panel(),
             PaletteAction::ToggleAutoplay => self.toggle_autoplay(),
             PaletteAction::ToggleAutoplayReverse => self.toggle_autoplay_reverse(),
+            PaletteAction::ToggleContinuous => self.toggle_continuous_view(),
+            PaletteAction::ToggleShowKeys => self.toggle_show_keys(),
+            PaletteAction::OpenOverview => self.open_overview(),
             PaletteAction::OpenDashboard => self.open_dashboard = true,
+            PaletteAction::OpenFileHistory => self.request_file_history(),
+            PaletteAction::WalkFileCommits => self.request_commit_walk(),
+            PaletteAction::ReloadConfig => self.config_reload_requested = true,
+            PaletteAction::ExportComments => self.export_review_comments(),
             PaletteAction::Quit => self.should_quit = true,
             PaletteAction::RefreshCurrentFile => self.refresh_current_file(),
             PaletteAction::RefreshAllFiles => self.refresh_all_files(),
+            PaletteAction::ShowNote => self.toggle_note_popup(),
+            PaletteAction::ShowBuildInfo => self.toggle_build_info(),
         }
     }
 
@@ -23,6 +23,21 @@ impl App {
         self.last_autoplay_tick = Instant::now();
     }
 
+    /// Status bar label for running autoplay: steps left in a replay (`r`),
+    /// reverse, or plain forward autoplay.
+    pub(crate) fn autoplay_status(&self) -> Option<String> {
+        if !self.autoplay || !self.autoplay_indicator {
+            return None;
+        }
+        Some(if self.autoplay_reverse {
+            "reverse".to_string()
+        } else if let Some(remaining) = self.autoplay_remaining {
+            format!("autoplay {remaining} left")
+        } else {
+            "autoplay".to_string()
+        })
+    }
+
     pub fn toggle_animation(&mut self) {
         self.animation_enabled = !self.animation_enabled;
         if !self.animation_enabled {
@@ -1,11 +1,12 @@
-use super::{AnimationFrame, App, ViewMode};
-use crate::config::{MentionFileScope, MentionFinder};
+use super::{AnimationFrame, App, HintKind, ViewMode};
+use crate::config::{MentionFileScope, MentionFinder, ReviewOutputFormat};
 use oyo_core::{ChangeKind, LineKind, ViewLine};
 use serde::{Deserialize, Serialize};
 use std::collections::{hash_map::DefaultHasher, BTreeSet};
 use std::fs;
 use std::hash::{Hash, Hasher};
 use std::io::Write;
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -795,7 +796,7 @@ impl App {
     }
 
     pub fn start_line_comment(&mut self) {
-        if !self.review_mode {
+        if !self.review_mode || self.skip_structural_lines() {
             return;
         }
         let Some(anchor) = self.resolve_line_review_anchor() else {
@@ -805,7 +806,7 @@ impl App {
     }
 
     pub fn start_hunk_comment(&mut self) {
-        if !self.review_mode {
+        if !self.review_mode || self.skip_structural_lines() {
             return;
         }
         let Some(anchor) = self.resolve_hunk_review_anchor() else {
@@ -1085,6 +1086,57 @@ impl App {
         self.review_submission_output.take()
     }
 
+    /// Write all review comments to the export file without quitting.
+    pub fn export_review_comments(&mut self) {
+        if self.review_editor.is_some() {
+            self.review_save_editor();
+        }
+        let count = self.review_comments.len();
+        let (message, ok) = if count == 0 {
+            ("no review comments".to_string(), false)
+        } else {
+            let path = self.review_export_file();
+            let output = self.format_review_output();
+            let written = path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| fs::write(&path, format!("{output}\n")));
+            match written {
+                Ok(()) => {
+                    let noun = if count == 1 { "comment" } else { "comments" };
+                    (format!("{count} {noun} → {}", path.display()), true)
+                }
+                Err(err) => (format!("export failed: {err}"), false),
+            }
+        };
+        self.show_status_hint(HintKind::ReviewExport { message, ok });
+    }
+
+    /// Status bar text for the last export and whether it succeeded.
+    pub(crate) fn review_export_hint(&self) -> Option<(&str, bool)> {
+        match self.status_hint.as_ref() {
+            Some((HintKind::ReviewExport { message, ok }, _)) => Some((message.as_str(), *ok)),
+            _ => None,
+        }
+    }
+
+    fn review_export_file(&self) -> PathBuf {
+        if let Some(path) = &self.review_export_path {
+            return path.clone();
+        }
+        let root = self
+            .review_repo_root
+            .as_ref()
+            .map(PathBuf::from)
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        root.join(format!(
+            "oyo-review.{}",
+            self.review_output_format.extension()
+        ))
+    }
+
     fn touch_review_state(&mut self) {
         self.review_revision = self.review_revision.saturating_add(1);
     }
@@ -1898,6 +1950,21 @@ impl App {
     }
 
     fn format_review_output(&self) -> String {
+        let comments = self.sorted_review_comments();
+        match self.review_output_format {
+            ReviewOutputFormat::Text => comments
+                .iter()
+                .enumerate()
+                .map(|(idx, comment)| Self::format_review_comment(comment, idx + 1))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            ReviewOutputFormat::Markdown => Self::format_review_markdown(&comments),
+            ReviewOutputFormat::Json => Self::format_review_json(&comments),
+        }
+    }
+
+    /// Comments by file, line comments before hunk comments, then by line.
+    fn sorted_review_comments(&self) -> Vec<ReviewComment> {
         let mut comments = self.review_comments.clone();
         comments.sort_by(|a, b| {
             a.anchor
@@ -1924,13 +1991,69 @@ impl App {
                     a_line.cmp(&b_line)
                 })
         });
-
         comments
+    }
+
+    /// `## file` sections with one bullet per comment.
+    fn format_review_markdown(comments: &[ReviewComment]) -> String {
+        let mut out = vec!["# Review comments".to_string()];
+        let mut current_file: Option<&str> = None;
+        for comment in comments {
+            let anchor = &comment.anchor;
+            if current_file != Some(anchor.file_path.as_str()) {
+                current_file = Some(anchor.file_path.as_str());
+                out.push(String::new());
+                out.push(format!("## `{}`", anchor.file_path));
+                out.push(String::new());
+            }
+            let target = match anchor.kind {
+                ReviewTargetKind::Line => {
+                    let side = anchor.side.unwrap_or(ReviewSide::New);
+                    let range = match side {
+                        ReviewSide::Old => anchor.old_range,
+                        ReviewSide::New => anchor.new_range,
+                    };
+                    format!(
+                        "Line {} ({})",
+                        format_opt_range_display(range),
+                        side.as_str()
+                    )
+                }
+                ReviewTargetKind::Hunk => format!(
+                    "Hunk old {} · new {}",
+                    format_opt_range_display(anchor.old_range),
+                    format_opt_range_display(anchor.new_range)
+                ),
+            };
+            let body = comment.body.trim_end();
+            let mut lines = body.lines();
+            let first = lines.next().unwrap_or("(empty)");
+            out.push(format!("- **{target}**: {first}"));
+            out.extend(lines.map(|line| format!("  {line}")));
+        }
+        out.join("\n")
+    }
+
+    fn format_review_json(comments: &[ReviewComment]) -> String {
+        let range = |range: Option<ReviewRange>| range.map(|r| serde_json::json!([r.start, r.end]));
+        let items: Vec<serde_json::Value> = comments
             .iter()
-            .enumerate()
-            .map(|(idx, comment)| Self::format_review_comment(comment, idx + 1))
-            .collect::<Vec<_>>()
-            .join("\n\n")
+            .map(|comment| {
+                let anchor = &comment.anchor;
+                serde_json::json!({
+                    "file": anchor.file_path,
+                    "kind": match anchor.kind {
+                        ReviewTargetKind::Line => "line",
+                        ReviewTargetKind::Hunk => "hunk",
+                    },
+                    "side": anchor.side.map(ReviewSide::as_str),
+                    "old_lines": range(anchor.old_range),
+                    "new_lines": range(anchor.new_range),
+                    "body": comment.body.trim_end(),
+                })
+            })
+            .collect();
+        serde_json::to_string_pretty(&items).unwrap_or_default()
     }
 
     fn format_review_comment(comment: &ReviewComment, index: usize) -> String {
@@ -1,6 +1,6 @@
 use super::utils::{
-    apply_highlight_spans, inline_text_for_change, line_has_query, match_ranges,
-    old_text_for_change,
+    apply_highlight_spans, inline_text_for_change, is_added_line, is_fold_line, line_has_query,
+    match_ranges, old_text_for_change, whole_word_pattern,
 };
 use super::{AnimationPhase, App, PeekMode, ViewMode};
 use crate::color;
@@ -102,28 +102,49 @@ impl App {
             self.search_regex = None;
             return;
         }
-        let regex = RegexBuilder::new(query)
-            .case_insensitive(true)
-            .build()
-            .or_else(|_| {
-                RegexBuilder::new(&regex::escape(query))
-                    .case_insensitive(true)
-                    .build()
-            })
-            .ok();
+        let whole_word = self.search_whole_word;
+        let build = |pattern: &str| {
+            let pattern = if whole_word {
+                whole_word_pattern(pattern)
+            } else {
+                pattern.to_string()
+            };
+            RegexBuilder::new(&pattern).case_insensitive(true).build()
+        };
+        let regex = build(query).or_else(|_| build(&regex::escape(query))).ok();
         self.search_regex = regex;
     }
 
+    /// Match the search query only as a whole word (`set` skips `reset`).
+    pub fn toggle_search_whole_word(&mut self) {
+        self.search_whole_word = !self.search_whole_word;
+        self.search_last_target = None;
+        self.update_search_regex();
+    }
+
+    pub fn search_whole_word(&self) -> bool {
+        self.search_whole_word
+    }
+
     pub fn search_target(&self) -> Option<usize> {
         self.search_target
     }
 
     pub fn search_next(&mut self) {
         let matches = self.collect_search_matches();
+        let start = self.search_last_target.unwrap_or(self.scroll_offset);
+        // Only a file without matches, or `n` from its last match, moves on.
+        let past_last = match self.search_last_target {
+            Some(last) => matches.iter().all(|idx| *idx <= last),
+            None => matches.is_empty(),
+        };
+        if self.search_wrap_files && past_last && self.search_other_file(true) {
+            return;
+        }
         if matches.is_empty() {
             return;
         }
-        let start = self.search_last_target.unwrap_or(self.scroll_offset);
+        self.record_jump();
         let target = matches
             .iter()
             .copied()
@@ -132,14 +153,23 @@ impl App {
         self.search_last_target = Some(target);
         self.search_target = Some(target);
         self.needs_scroll_to_search = true;
+        self.flash_locate(target);
     }
 
     pub fn search_prev(&mut self) {
         let matches = self.collect_search_matches();
+        let start = self.search_last_target.unwrap_or(self.scroll_offset);
+        let before_first = match self.search_last_target {
+            Some(last) => matches.iter().all(|idx| *idx >= last),
+            None => matches.is_empty(),
+        };
+        if self.search_wrap_files && before_first && self.search_other_file(false) {
+            return;
+        }
         if matches.is_empty() {
             return;
         }
-        let start = self.search_last_target.unwrap_or(self.scroll_offset);
+        self.record_jump();
         let target = matches
             .iter()
             .copied()
@@ -149,6 +179,72 @@ impl App {
         self.search_last_target = Some(target);
         self.search_target = Some(target);
         self.needs_scroll_to_search = true;
+        self.flash_locate(target);
+    }
+
+    /// `search.wrap_files`: past the last (or before the first) match, move
+    /// to the next (previous) file in panel order with a visible match and
+    /// land on its first (last) one, wrapping around the file list. Files
+    /// are only switched to when their text contains the query; if none
+    /// shows a match the current file is restored. False when no other file
+    /// matched, so the caller wraps within the current file.
+    fn search_other_file(&mut self, forward: bool) -> bool {
+        if self.continuous_view || !self.multi_diff.is_multi_file() {
+            return false;
+        }
+        let Some(regex) = self.search_regex.clone() else {
+            return false;
+        };
+        let order = self.filtered_file_indices();
+        let current = self.multi_diff.selected_index;
+        let Some(pos) = order.iter().position(|&idx| idx == current) else {
+            return false;
+        };
+        let count = order.len();
+        let candidates: Vec<usize> = (1..count)
+            .map(|step| {
+                let offset = if forward { step } else { count - step };
+                order[(pos + offset) % count]
+            })
+            .collect();
+
+        self.record_jump();
+        let paused = std::mem::replace(&mut self.jump_recording_paused, true);
+        let mut landed = false;
+        for idx in candidates {
+            self.multi_diff.ensure_loaded(idx);
+            let has_text = self
+                .multi_diff
+                .file_contents(idx)
+                .is_some_and(|(old, new)| {
+                    old.lines()
+                        .chain(new.lines())
+                        .any(|line| line_has_query(line, &regex))
+                });
+            if !has_text {
+                continue;
+            }
+            self.select_file(idx);
+            let matches = self.collect_search_matches();
+            let target = if forward {
+                matches.first()
+            } else {
+                matches.last()
+            };
+            if let Some(&target) = target {
+                self.search_last_target = Some(target);
+                self.search_target = Some(target);
+                self.needs_scroll_to_search = true;
+                self.flash_locate(target);
+                landed = true;
+                break;
+            }
+        }
+        if !landed && self.multi_diff.selected_index != current {
+            self.select_file(current);
+        }
+        self.jump_recording_paused = paused;
+        landed
     }
 
     pub fn apply_goto(&mut self) {
@@ -156,6 +252,31 @@ impl App {
         if query.is_empty() {
             return;
         }
+        if query == "biggest" {
+            if !self.continuous_view {
+                self.goto_biggest_hunk();
+            }
+            return;
+        }
+        if let Some(range) = query.strip_prefix("limit") {
+            if !self.continuous_view {
+                let range = range.to_string();
+                self.apply_line_limit_query(&range);
+            }
+            return;
+        }
+        if let Some(rest) = query.strip_prefix(['f', 'F']) {
+            let rest = rest.trim_start_matches(|c: char| c == ':' || c.is_whitespace());
+            if let Ok(num) = rest.parse::<usize>() {
+                self.goto_file_number(num);
+            }
+            return;
+        }
+        if self.continuous_view {
+            let query = query.to_string();
+            self.continuous_goto(&query);
+            return;
+        }
 
         let mut chars = query.chars();
         let first = match chars.next() {
@@ -180,6 +301,14 @@ impl App {
                     self.goto_step_number(num);
                 }
             }
+            'c' | 'C' => {
+                let rest = chars
+                    .as_str()
+                    .trim_start_matches(|c: char| c == ':' || c.is_whitespace());
+                if let Ok(num) = rest.parse::<usize>() {
+                    self.goto_changed_line_number(num);
+                }
+            }
             _ => {
                 if query.chars().all(|c| c.is_ascii_digit()) {
                     if let Ok(num) = query.parse::<usize>() {
@@ -190,6 +319,21 @@ impl App {
         }
     }
 
+    /// `:limit <start>,<end>` (or `<start>-<end>`) restricts the view; a bare `:limit` clears it.
+    fn apply_line_limit_query(&mut self, range: &str) {
+        let range = range.trim();
+        if range.is_empty() {
+            self.clear_line_limit();
+            return;
+        }
+        let Some((start, end)) = range.split_once([',', '-']) else {
+            return;
+        };
+        if let (Ok(start), Ok(end)) = (start.trim().parse(), end.trim().parse()) {
+            self.set_line_limit(start, end);
+        }
+    }
+
     pub fn highlight_search_spans(
         &self,
         spans: Vec<Span<'static>>,
@@ -216,6 +360,24 @@ impl App {
         apply_highlight_spans(spans, &ranges, highlight_bg, highlight_fg)
     }
 
+    /// Mark `review.keywords` in added and modified lines.
+    pub fn highlight_keyword_spans(
+        &self,
+        spans: Vec<Span<'static>>,
+        line: &ViewLine,
+        text: &str,
+    ) -> Vec<Span<'static>> {
+        let Some(regex) = self.review_keywords.as_ref() else {
+            return spans;
+        };
+        if !is_added_line(line) {
+            return spans;
+        }
+        let ranges = match_ranges(text, regex);
+        let bg = self.theme.warning;
+        apply_highlight_spans(spans, &ranges, bg, self.search_highlight_fg(bg))
+    }
+
     fn search_highlight_fg(&self, bg: Color) -> Option<Color> {
         let text = self.theme.text;
         let mut best_color = text;
@@ -239,6 +401,9 @@ impl App {
             Some(regex) => regex.clone(),
             None => return Vec::new(),
         };
+        if self.continuous_view {
+            return self.continuous_search_matches(&regex);
+        }
         let frame = self.animation_frame();
         let view = self.current_view_with_frame(frame);
         let mut matches = Vec::new();
@@ -246,6 +411,9 @@ impl App {
         match self.view_mode {
             ViewMode::UnifiedPane | ViewMode::Blame => {
                 for (display_idx, line) in view.iter().enumerate() {
+                    if is_fold_line(line) {
+                        continue;
+                    }
                     let text = self.search_text_unified(line);
                     if line_has_query(&text, &regex) {
                         matches.push(display_idx);
@@ -265,9 +433,11 @@ impl App {
                     if !visible {
                         continue;
                     }
-                    let text = self.search_text_unified(line);
-                    if line_has_query(&text, &regex) {
-                        matches.push(display_idx);
+                    if !is_fold_line(line) {
+                        let text = self.search_text_unified(line);
+                        if line_has_query(&text, &regex) {
+                            matches.push(display_idx);
+                        }
                     }
                     display_idx += 1;
                 }
@@ -1,9 +1,11 @@
 use super::utils::{
-    allow_overscroll_state, evolution_display_metrics, max_scroll, split_display_metrics,
+    allow_overscroll_state, evolution_display_metrics, is_fold_line, markdown_code_block,
+    markdown_language_tag, max_scroll, osc52_sequence, path_tokens, smart_path_labels,
+    split_display_metrics, whole_word_pattern, OSC52_MAX_PAYLOAD,
 };
 use super::*;
 use crate::test_utils::{DiffSettingsGuard, TestApp};
-use oyo_core::{LineKind, MultiFileDiff, StepDirection, ViewLine};
+use oyo_core::{FileStatus, LineKind, MultiFileDiff, StepDirection, ViewLine};
 use std::sync::{Mutex, MutexGuard};
 use std::time::{Duration, Instant};
 
@@ -104,6 +106,7 @@ fn make_view_line(
         change_id: 0,
         hunk_index: None,
         has_changes: kind != LineKind::Context,
+        moved: false,
     }
 }
 
@@ -317,6 +320,101 @@ fn test_no_step_next_hunk_after_goto_start() {
     assert!(state.last_nav_was_hunk);
 }
 
+#[test]
+fn test_no_step_hunk_context_keeps_lines_above_hunk() {
+    let mut app = make_app_with_two_hunks();
+    app.auto_center = false;
+    app.goto_hunk_index_scroll(1);
+    let hunk_start = app.scroll_offset;
+    assert!(hunk_start >= 3);
+
+    app.no_step_hunk_context = 3;
+    app.goto_hunk_index_scroll(0);
+    assert_eq!(app.scroll_offset, 0);
+    app.goto_hunk_index_scroll(1);
+    assert_eq!(app.scroll_offset, hunk_start - 3);
+    assert_eq!(app.multi_diff.current_navigator().state().current_hunk, 1);
+}
+
+#[test]
+fn test_comment_template_fills_path_lines_and_quote() {
+    let mut app = make_app_with_unified_hunk_two_changes();
+    app.next_hunk_scroll();
+    let text = app.comment_template_for_hunk().unwrap();
+    assert_eq!(text, "a.txt:1-2:\n> ONE\n> TWO\n\n");
+
+    app.review_comment_template = "{path}#L{lines} {unknown}".to_string();
+    let text = app.comment_template_for_hunk().unwrap();
+    assert_eq!(text, "a.txt#L1-2 {unknown}");
+}
+
+#[test]
+fn test_hunk_location_header_includes_enclosing_function() {
+    let _guard = DiffSettingsGuard::default();
+    let multi_diff = MultiFileDiff::from_file_pair(
+        std::path::PathBuf::from("lib.rs"),
+        std::path::PathBuf::from("lib.rs"),
+        "fn run() {\n    let a = 1;\n    let b = 2;\n}\n".to_string(),
+        "fn run() {\n    let a = 1;\n    let b = 3;\n}\n".to_string(),
+    );
+    let mut app = App::new(multi_diff, ViewMode::UnifiedPane, 0, false, None);
+    app.next_hunk_scroll();
+    assert_eq!(
+        app.hunk_location_header().as_deref(),
+        Some("@@ -3,1 +3,1 @@ fn run() {")
+    );
+}
+
+#[test]
+fn test_before_after_copies_labeled_sides() {
+    let mut app = make_app_with_unified_hunk_two_changes();
+    app.next_hunk_scroll();
+    let text = app.before_after_text(None).unwrap();
+    assert_eq!(text, "--- old\none\ntwo\n+++ new\nONE\nTWO");
+}
+
+#[test]
+fn test_before_after_copies_only_side_of_insertion() {
+    let mut app = TestApp::new_default(|| {
+        let multi_diff = MultiFileDiff::from_file_pair(
+            std::path::PathBuf::from("a.txt"),
+            std::path::PathBuf::from("a.txt"),
+            "one\n".to_string(),
+            "one\nadded\n".to_string(),
+        );
+        App::new(multi_diff, ViewMode::UnifiedPane, 0, false, None)
+    });
+    app.next_hunk_scroll();
+    assert_eq!(app.before_after_text(None).as_deref(), Some("added"));
+}
+
+#[test]
+fn test_markdown_block_fences_new_side_with_language_tag() {
+    let mut app = TestApp::new_default(|| {
+        let multi_diff = MultiFileDiff::from_file_pair(
+            std::path::PathBuf::from("lib.rs"),
+            std::path::PathBuf::from("lib.rs"),
+            "fn a() {}\nfn b() {}\n".to_string(),
+            "fn a() {}\nfn c() {}\n".to_string(),
+        );
+        App::new(multi_diff, ViewMode::UnifiedPane, 0, false, None)
+    });
+    app.next_hunk_scroll();
+    assert_eq!(
+        app.markdown_block_text(None).as_deref(),
+        Some("```rust\nfn c() {}\n```")
+    );
+}
+
+#[test]
+fn test_markdown_language_tag_and_fence() {
+    assert_eq!(markdown_language_tag("Rust"), "rust");
+    assert_eq!(markdown_language_tag("C++"), "cpp");
+    assert_eq!(markdown_language_tag("Plain Text"), "");
+    assert_eq!(markdown_language_tag("Some Odd Syntax"), "");
+    assert_eq!(markdown_code_block("", "a ``` b"), "````\na ``` b\n````");
+}
+
 #[test]
 fn test_unified_hunk_jump_sets_cursor() {
     let mut app = make_app_with_unified_hunk();
@@ -328,6 +426,311 @@ fn test_unified_hunk_jump_sets_cursor() {
     assert!(state.last_nav_was_hunk);
 }
 
+#[test]
+fn test_goto_changed_line_prefix_jumps_to_nth_change() {
+    let mut app = make_app_with_two_hunks();
+    app.auto_center = false;
+    let (_, total) = app.changed_line_info().expect("changes counted");
+    assert!(total >= 2);
+
+    app.goto_query = "c2".to_string();
+    app.apply_goto();
+    let view = app.current_view_with_frame(AnimationFrame::Idle);
+    let target = &view[app.scroll_offset];
+    assert_ne!(target.kind, LineKind::Context);
+    assert_eq!(app.changed_line_info(), Some((2, total)));
+
+    app.goto_query = "c999".to_string();
+    app.apply_goto();
+    assert_eq!(app.changed_line_info(), Some((total, total)));
+}
+
+#[test]
+fn test_goto_biggest_walks_hunks_by_size() {
+    let mut app = TestApp::new_default(|| {
+        let old_lines: Vec<String> = (1..=40).map(|i| format!("line{}", i)).collect();
+        let mut new_lines = old_lines.clone();
+        new_lines[1] = "line2-new".to_string();
+        for (idx, line) in new_lines.iter_mut().enumerate().take(17).skip(14) {
+            *line = format!("line{}-new", idx + 1);
+        }
+        new_lines[29] = "line30-new".to_string();
+        let multi_diff = MultiFileDiff::from_file_pair(
+            std::path::PathBuf::from("a.txt"),
+            std::path::PathBuf::from("a.txt"),
+            old_lines.join("\n"),
+            new_lines.join("\n"),
+        );
+        let mut app = App::new(multi_diff, ViewMode::UnifiedPane, 0, false, None);
+        app.stepping = false;
+        app.enter_no_step_mode();
+        app
+    });
+    let current_hunk = |app: &mut App| app.multi_diff.current_navigator().state().current_hunk;
+
+    app.goto_query = "biggest".to_string();
+    app.apply_goto();
+    assert_eq!(current_hunk(&mut app), 1);
+    app.apply_goto();
+    assert_eq!(current_hunk(&mut app), 0, "ties break by first occurrence");
+    app.apply_goto();
+    assert_eq!(current_hunk(&mut app), 2);
+    app.apply_goto();
+    assert_eq!(current_hunk(&mut app), 1);
+}
+
+#[test]
+fn test_locate_flash_marks_goto_landing_line() {
+    let mut app = make_app_with_two_hunks();
+    app.goto_query = "20".to_string();
+    app.apply_goto();
+    assert_eq!(app.locate_flash_line(), Some(19));
+
+    app.locate_flash = app.locate_flash.map(|(idx, _)| (idx, Instant::now()));
+    assert!(app.tick());
+    assert_eq!(app.locate_flash_line(), None);
+
+    app.locate_flash_enabled = false;
+    app.goto_query = "2".to_string();
+    app.apply_goto();
+    assert_eq!(app.locate_flash_line(), None);
+}
+
+#[test]
+fn test_review_progress_counts_visited_hunks() {
+    let mut app = make_app_with_two_hunks();
+    app.multi_diff.ensure_full_navigator(0);
+    assert_eq!(app.review_progress_percent(), 0);
+
+    app.record_hunk_visit();
+    assert_eq!(app.review_progress_percent(), 50);
+    assert_eq!(app.review_progress_text().as_deref(), Some("50% reviewed"));
+
+    app.goto_hunk_index_scroll(1);
+    app.record_hunk_visit();
+    assert_eq!(app.review_progress_percent(), 100);
+
+    app.review_progress = false;
+    assert_eq!(app.review_progress_text(), None);
+}
+
+#[test]
+fn test_review_progress_counts_unloaded_files_of_lazy_scans() {
+    let _guard = DiffSettingsGuard::default();
+    let root = std::env::temp_dir().join(format!("oyo_lazy_progress_{}", std::process::id()));
+    let (old_dir, new_dir) = (root.join("old"), root.join("new"));
+    std::fs::create_dir_all(&old_dir).unwrap();
+    std::fs::create_dir_all(&new_dir).unwrap();
+    std::fs::write(old_dir.join("a.txt"), "one\n").unwrap();
+    std::fs::write(new_dir.join("a.txt"), "two\n").unwrap();
+    std::fs::write(old_dir.join("b.txt"), "b\n".repeat(50)).unwrap();
+    std::fs::write(new_dir.join("b.txt"), "c\n".repeat(50)).unwrap();
+    let options = oyo_core::DirectoryScanOptions {
+        lazy_load_bytes: Some(0),
+        ..Default::default()
+    };
+    let multi = MultiFileDiff::from_directories_with_options(&old_dir, &new_dir, &options).unwrap();
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.multi_diff.ensure_full_navigator(0);
+    app.record_hunk_visit();
+    let percent = app.review_progress_percent();
+    assert!(percent > 0 && percent < 100, "{percent}");
+
+    app.file_sort = FileSortOrder::Changes;
+    let mut order = vec![0, 1];
+    app.sort_file_indices(&mut order);
+    assert_eq!(order, vec![1, 0]);
+    let _ = std::fs::remove_dir_all(root);
+}
+
+#[test]
+fn test_next_unreviewed_hunk_crosses_files_until_review_complete() {
+    let _guard = DiffSettingsGuard::default();
+    let old_lines: Vec<String> = (1..=25).map(|i| format!("line{}", i)).collect();
+    let mut new_lines = old_lines.clone();
+    new_lines[1] = "line2-new".to_string();
+    new_lines[19] = "line20-new".to_string();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.txt"),
+            old_lines.join("\n"),
+            new_lines.join("\n"),
+        ),
+        (
+            std::path::PathBuf::from("b.txt"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.multi_diff.ensure_full_navigator(0);
+    app.record_hunk_visit();
+
+    app.goto_next_unreviewed_hunk();
+    assert_eq!(app.multi_diff.selected_index, 0);
+    assert_eq!(app.multi_diff.current_navigator().state().current_hunk, 1);
+    app.record_hunk_visit();
+
+    app.goto_next_unreviewed_hunk();
+    assert_eq!(app.multi_diff.selected_index, 1);
+    app.record_hunk_visit();
+    assert_eq!(app.hunk_edge_hint_text(), None);
+
+    app.goto_next_unreviewed_hunk();
+    assert_eq!(app.multi_diff.selected_index, 1);
+    assert_eq!(app.hunk_edge_hint_text(), Some("Review complete"));
+}
+
+#[test]
+fn test_jump_back_and_forward_walk_the_jump_list() {
+    let _guard = DiffSettingsGuard::default();
+    let old_lines: Vec<String> = (1..=25).map(|i| format!("line{}", i)).collect();
+    let mut new_lines = old_lines.clone();
+    new_lines[1] = "line2-new".to_string();
+    new_lines[19] = "line20-new".to_string();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.txt"),
+            old_lines.join("\n"),
+            new_lines.join("\n"),
+        ),
+        (
+            std::path::PathBuf::from("b.txt"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.multi_diff.ensure_full_navigator(0);
+    let place = |app: &mut App| {
+        let file = app.multi_diff.selected_index;
+        (
+            file,
+            app.multi_diff.current_navigator().state().current_hunk,
+        )
+    };
+    let start = place(&mut app);
+
+    app.goto_next_unreviewed_hunk();
+    let second_hunk = place(&mut app);
+    assert_eq!(second_hunk, (0, 1));
+    app.goto_next_unreviewed_hunk();
+    assert_eq!(app.multi_diff.selected_index, 1);
+
+    assert!(app.jump_back());
+    assert_eq!(place(&mut app), second_hunk);
+    assert!(app.jump_back());
+    assert_eq!(place(&mut app), start);
+    assert!(!app.jump_back());
+
+    assert!(app.jump_forward());
+    assert_eq!(place(&mut app), second_hunk);
+    assert!(app.jump_forward());
+    assert_eq!(app.multi_diff.selected_index, 1);
+    assert!(!app.jump_forward());
+
+    // A new jump from the middle of the list drops the forward entries.
+    assert!(app.jump_back());
+    app.select_file(1);
+    assert!(!app.jump_forward());
+    assert!(app.jump_back());
+    assert_eq!(place(&mut app), second_hunk);
+}
+
+#[test]
+fn test_mine_only_dims_nothing_without_git_blame() {
+    let mut app = make_app_with_two_hunks();
+    app.multi_diff.ensure_full_navigator(0);
+    assert_eq!(app.mine_hunks(), None);
+
+    app.toggle_mine_only();
+    assert!(app.mine_only);
+    // A plain file pair has no repo to blame, so ownership is unknown.
+    assert_eq!(app.mine_hunks(), None);
+}
+
+#[test]
+fn test_goto_limit_restricts_view_to_line_range() {
+    let mut app = make_app_with_two_hunks();
+    app.goto_query = "limit 18,22".to_string();
+    app.apply_goto();
+    assert_eq!(app.line_limit_info(), Some((18, 22, 25)));
+
+    let view = app.current_view_with_frame(AnimationFrame::Idle);
+    assert!(!view.is_empty());
+    assert!(view.iter().all(|line| {
+        [line.old_line, line.new_line]
+            .into_iter()
+            .flatten()
+            .any(|n| (18..=22).contains(&n))
+    }));
+    assert!(view.iter().any(|line| line.content == "line20-new"));
+    assert!(!view.iter().any(|line| line.content == "line2-new"));
+
+    app.goto_query = "limit".to_string();
+    app.apply_goto();
+    assert_eq!(app.line_limit_info(), None);
+    let view = app.current_view_with_frame(AnimationFrame::Idle);
+    assert!(view.iter().any(|line| line.content == "line2-new"));
+}
+
+#[test]
+fn test_collapse_reviewed_hunks_folds_on_next_and_expands_on_prev() {
+    let mut app = make_app_with_two_hunks();
+    app.collapse_reviewed_hunks = true;
+    app.auto_center = false;
+    let has_line = |app: &mut App, text: &str| {
+        app.current_view_with_frame(AnimationFrame::Idle)
+            .iter()
+            .any(|line| line.content.contains(text))
+    };
+
+    app.goto_hunk_index_scroll(0);
+    assert_eq!(app.multi_diff.current_navigator().state().current_hunk, 0);
+    assert!(has_line(&mut app, "line2-new"));
+
+    app.next_hunk_scroll();
+    assert_eq!(app.multi_diff.current_navigator().state().current_hunk, 1);
+    assert!(!has_line(&mut app, "line2-new"));
+    assert!(has_line(&mut app, "hunk 1 reviewed"));
+    let view = app.current_view_with_frame(AnimationFrame::Idle);
+    assert!(view[app.scroll_offset].content.contains("line20"));
+
+    app.prev_hunk_scroll();
+    assert_eq!(app.multi_diff.current_navigator().state().current_hunk, 0);
+    assert!(has_line(&mut app, "line2-new"));
+    assert!(!has_line(&mut app, "reviewed"));
+}
+
+#[test]
+fn test_hunk_subset_folds_and_skips_unlisted_hunks() {
+    let mut app = make_app_with_two_hunks();
+    app.auto_center = false;
+    app.set_hunk_subset(vec![(0, vec![1])], false);
+
+    let view = app.current_view_with_frame(AnimationFrame::Idle);
+    assert!(view
+        .iter()
+        .any(|line| line.content.contains("hunk 1 not under review")));
+    assert!(!view.iter().any(|line| line.content.contains("line2-new")));
+    assert!(view.iter().any(|line| line.content.contains("line20-new")));
+
+    app.goto_hunk_index_scroll(1);
+    app.prev_hunk_scroll();
+    assert_eq!(app.multi_diff.current_navigator().state().current_hunk, 1);
+
+    app.stepping = true;
+    app.goto_start();
+    app.next_hunk();
+    assert_eq!(app.multi_diff.current_navigator().state().current_hunk, 1);
+}
+
 #[test]
 fn test_goto_start_clears_hunk_scope_in_no_step() {
     let mut app = make_app_with_two_hunks();
@@ -389,84 +792,1045 @@ fn test_toggle_stepping_restores_no_step_cursor_scope() {
 }
 
 #[test]
-fn test_hunk_step_info_counts_applied_changes() {
-    let mut app = make_app_with_unified_hunk_two_changes();
-    assert_eq!(app.hunk_step_info(), Some((0, 2)));
+fn test_hunk_step_info_counts_applied_changes() {
+    let mut app = make_app_with_unified_hunk_two_changes();
+    assert_eq!(app.hunk_step_info(), Some((0, 2)));
+
+    app.next_step();
+    assert_eq!(app.hunk_step_info(), Some((1, 2)));
+
+    app.next_step();
+    assert_eq!(app.hunk_step_info(), Some((2, 2)));
+}
+
+#[test]
+fn test_hunk_step_progress_text_tracks_steps() {
+    let mut app = make_app_with_unified_hunk_two_changes();
+    assert_eq!(app.hunk_step_progress_text(), None);
+
+    app.next_step();
+    assert_eq!(
+        app.hunk_step_progress_text().as_deref(),
+        Some("step 1/2 in hunk")
+    );
+
+    app.next_step();
+    assert_eq!(
+        app.hunk_step_progress_text().as_deref(),
+        Some("step 2/2 in hunk")
+    );
+
+    app.hunk_step_progress = false;
+    assert_eq!(app.hunk_step_progress_text(), None);
+}
+
+#[test]
+fn test_peek_next_previews_without_stepping() {
+    let mut app = make_app_with_unified_hunk_two_changes();
+    let shows_new = |app: &mut TestApp| {
+        app.current_view_with_frame(AnimationFrame::Idle)
+            .iter()
+            .any(|line| line.content.contains("ONE"))
+    };
+    assert!(!shows_new(&mut app));
+
+    app.toggle_peek_next();
+    let peeked = app.peek_next_change().expect("next change previewed");
+    assert!(shows_new(&mut app));
+    let state = app.multi_diff.current_navigator().state();
+    assert_eq!(state.current_step, 0);
+    assert!(!state.is_applied(peeked));
+
+    app.toggle_peek_next();
+    assert_eq!(app.peek_next_change(), None);
+    assert!(!shows_new(&mut app));
+
+    app.toggle_peek_next();
+    app.next_step();
+    assert_eq!(app.peek_next_change(), None);
+}
+
+#[test]
+fn test_hunk_step_progress_text_omits_single_change_hunks() {
+    let mut app = make_app_with_unified_hunk();
+    app.next_step();
+    assert_eq!(app.hunk_step_progress_text(), None);
+}
+
+#[test]
+fn test_counted_steps_apply_at_once_and_animate_last() {
+    let mut app = make_large_step_app(20, &[2, 6, 10, 14]);
+    app.animation_enabled = true;
+
+    app.next_steps(3);
+    let state = app.multi_diff.current_navigator().state().clone();
+    assert_eq!(state.current_step, 3);
+    assert_eq!(state.applied_changes.len(), 3);
+    assert_eq!(state.active_change, state.applied_changes.last().copied());
+    assert_eq!(app.animation_phase, AnimationPhase::FadeOut);
+
+    app.animation_phase = AnimationPhase::Idle;
+    app.prev_steps(2);
+    assert_eq!(app.multi_diff.current_navigator().state().current_step, 1);
+    assert_eq!(app.animation_phase, AnimationPhase::FadeOut);
+}
+
+#[test]
+fn test_no_step_snapshot_restores_cursor_or_jumps() {
+    let _guard = DiffSettingsGuard::default();
+    let old_lines: Vec<String> = (1..=25).map(|i| format!("line{}", i)).collect();
+    let mut new_lines = old_lines.clone();
+    new_lines[1] = "line2-new".to_string();
+    new_lines[19] = "line20-new".to_string();
+    let old = old_lines.join("\n");
+    let new = new_lines.join("\n");
+
+    let multi_diff = MultiFileDiff::from_file_pair(
+        std::path::PathBuf::from("a.txt"),
+        std::path::PathBuf::from("a.txt"),
+        old,
+        new,
+    );
+    let mut app = App::new(multi_diff, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.no_step_auto_jump_on_enter = true;
+    app.enter_no_step_mode();
+
+    let idx = app.multi_diff.selected_index;
+    app.save_no_step_state_snapshot(idx);
+    app.multi_diff.current_navigator().clear_cursor_change();
+    app.multi_diff.current_navigator().set_hunk_scope(false);
+
+    assert!(app.restore_no_step_state_snapshot(idx));
+    let cursor_id = app
+        .multi_diff
+        .current_navigator()
+        .state()
+        .cursor_change
+        .expect("cursor change expected");
+    assert!(cursor_id > 0);
+}
+
+#[test]
+fn test_no_step_cursor_stable_through_file_cycles() {
+    let _guard = DiffSettingsGuard::default();
+    let old_lines: Vec<String> = (1..=25).map(|i| format!("line{}", i)).collect();
+    let mut new_lines = old_lines.clone();
+    new_lines[1] = "line2-new".to_string();
+    new_lines[19] = "line20-new".to_string();
+    let old = old_lines.join("\n");
+    let new = new_lines.join("\n");
+
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (std::path::PathBuf::from("a.txt"), old.clone(), new.clone()),
+        (std::path::PathBuf::from("b.txt"), old.clone(), new.clone()),
+        (std::path::PathBuf::from("c.txt"), old.clone(), new.clone()),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.no_step_auto_jump_on_enter = true;
+    app.enter_no_step_mode();
+
+    app.goto_hunk_start_scroll();
+    let first_cursor = app.multi_diff.current_navigator().state().cursor_change;
+
+    app.next_file();
+    app.next_file();
+    app.prev_file();
+    app.prev_file();
+
+    let cursor_after = app.multi_diff.current_navigator().state().cursor_change;
+
+    assert_eq!(first_cursor, cursor_after);
+}
+
+#[test]
+fn test_file_list_scroll_uses_panel_height_and_group_headers() {
+    let _guard = DiffSettingsGuard::default();
+    let pairs = (0..60)
+        .map(|i| {
+            let dir = if i < 30 { "a" } else { "b" };
+            (
+                std::path::PathBuf::from(format!("{}/f{:02}.txt", dir, i)),
+                "old".to_string(),
+                "new".to_string(),
+            )
+        })
+        .collect();
+    let mut app = App::new(
+        MultiFileDiff::from_file_pairs(pairs),
+        ViewMode::UnifiedPane,
+        0,
+        false,
+        None,
+    );
+    // 10 list rows: one group header plus nine files.
+    app.file_list_area = Some((0, 0, 30, 12));
+
+    app.select_file(20);
+    assert_eq!(app.file_list_scroll, 12);
+
+    // Crossing into group "b" costs a blank row and a second header.
+    app.select_file(31);
+    assert_eq!(app.file_list_scroll, 25);
+
+    app.select_file(5);
+    assert_eq!(app.file_list_scroll, 5);
+}
+
+#[test]
+fn test_file_list_center_selection_keeps_selection_mid_panel() {
+    let _guard = DiffSettingsGuard::default();
+    let pairs = (0..60)
+        .map(|i| {
+            (
+                std::path::PathBuf::from(format!("a/f{:02}.txt", i)),
+                "old".to_string(),
+                "new".to_string(),
+            )
+        })
+        .collect();
+    let mut app = App::new(
+        MultiFileDiff::from_file_pairs(pairs),
+        ViewMode::UnifiedPane,
+        0,
+        false,
+        None,
+    );
+    app.file_list_center = true;
+    app.file_list_area = Some((0, 0, 30, 12));
+
+    // Header plus four files above the selection.
+    app.select_file(20);
+    assert_eq!(app.file_list_scroll, 16);
+    app.select_file(21);
+    assert_eq!(app.file_list_scroll, 17);
+
+    // Near the ends the list stays pinned instead of leaving blank rows.
+    app.select_file(2);
+    assert_eq!(app.file_list_scroll, 0);
+    app.select_file(59);
+    assert_eq!(app.file_list_scroll, 51);
+}
+
+#[test]
+fn test_search_wrap_files_continues_in_next_matching_file() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.txt"),
+            "one\nfoo\n".to_string(),
+            "one\nfoo\ntwo\n".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.txt"),
+            "x\n".to_string(),
+            "y\n".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("c.txt"),
+            "bar\n".to_string(),
+            "bar\nfoo\n".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.search_wrap_files = true;
+    app.start_search();
+    for ch in "foo".chars() {
+        app.push_search_char(ch);
+    }
+    app.stop_search();
+
+    app.search_next();
+    assert_eq!(app.multi_diff.selected_index, 0);
+    // Past the last match in a.txt: b.txt has none, so c.txt is next.
+    app.search_next();
+    assert_eq!(app.multi_diff.selected_index, 2);
+    assert!(app.search_target().is_some());
+    // The last match of the last file wraps to the first file.
+    app.search_next();
+    assert_eq!(app.multi_diff.selected_index, 0);
+    app.search_prev();
+    assert_eq!(app.multi_diff.selected_index, 2);
+    assert_eq!(app.search_query(), "foo");
+}
+
+#[test]
+fn test_smart_path_labels_disambiguate_only_collisions() {
+    let labels = smart_path_labels(&[
+        "src/app/mod.rs",
+        "src/views/mod.rs",
+        "a/x/lib.rs",
+        "b/x/lib.rs",
+        "README.md",
+        "docs/main.rs",
+    ]);
+    assert_eq!(
+        labels,
+        vec![
+            "app/mod.rs",
+            "views/mod.rs",
+            "a/x/lib.rs",
+            "b/x/lib.rs",
+            "README.md",
+            "main.rs",
+        ]
+    );
+}
+
+#[test]
+fn test_flat_path_style_scrolls_without_group_headers() {
+    let _guard = DiffSettingsGuard::default();
+    let pairs = (0..30)
+        .map(|i| {
+            (
+                std::path::PathBuf::from(format!("a/f{:02}.txt", i)),
+                "old".to_string(),
+                "new".to_string(),
+            )
+        })
+        .collect();
+    let mut app = App::new(
+        MultiFileDiff::from_file_pairs(pairs),
+        ViewMode::UnifiedPane,
+        0,
+        false,
+        None,
+    );
+    app.file_list_area = Some((0, 0, 30, 12));
+
+    // Grouped: the directory header takes one of the ten rows.
+    app.select_file(20);
+    assert_eq!(app.file_list_scroll, 12);
+
+    app.cycle_file_path_style();
+    assert_eq!(app.file_path_style, FilePathStyle::Full);
+    // Flat: all ten rows hold files.
+    app.select_file(29);
+    assert_eq!(app.file_list_scroll, 20);
+    app.cycle_file_path_style();
+    app.cycle_file_path_style();
+    assert_eq!(app.file_path_style, FilePathStyle::Smart);
+    app.cycle_file_path_style();
+    assert_eq!(app.file_path_style, FilePathStyle::Grouped);
+}
+
+#[test]
+fn test_continuous_view_spans_files_and_collapses() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.txt"),
+            "one\ntwo".to_string(),
+            "one\nTWO".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.txt"),
+            "x\ny".to_string(),
+            "x\ny\nz".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, true, None);
+    app.enter_continuous_view();
+    assert!(app.continuous_view);
+    assert!(!app.stepping);
+
+    let rows = app.continuous_rows();
+    let headers: Vec<usize> = rows
+        .iter()
+        .filter_map(|row| match row {
+            ContinuousRow::Header(idx) => Some(*idx),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(headers, vec![0, 1]);
+    let b_header = rows
+        .iter()
+        .position(|row| matches!(row, ContinuousRow::Header(1)))
+        .unwrap();
+
+    app.select_file(1);
+    assert_eq!(app.scroll_offset, b_header);
+    assert_eq!(app.multi_diff.selected_index, 1);
+
+    let full_len = rows.len();
+    app.toggle_continuous_file_collapsed(0);
+    let collapsed = app.continuous_rows();
+    assert!(collapsed.len() < full_len);
+    assert!(matches!(collapsed[1], ContinuousRow::Header(1)));
+    // The collapsed header is pulled back into view.
+    assert_eq!(app.scroll_offset, 0);
+
+    app.toggle_continuous_view();
+    assert!(!app.continuous_view);
+    assert_eq!(app.multi_diff.selected_index, 0);
+}
+
+#[test]
+fn test_overview_opens_file_and_esc_returns() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.txt"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.txt"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("c.txt"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.toggle_overview();
+    assert!(app.show_overview);
+    assert_eq!(app.overview_selection, 0);
+
+    app.overview_move(5);
+    assert_eq!(app.overview_selection, 2);
+    app.overview_move(-1);
+    app.overview_accept();
+    assert!(!app.show_overview);
+    assert!(app.overview_return);
+    assert_eq!(app.multi_diff.selected_index, 1);
+
+    app.open_overview();
+    assert!(app.show_overview);
+    assert!(!app.overview_return);
+    assert_eq!(app.overview_selection, 1);
+}
+
+#[test]
+fn test_stat_bar_cells_scale_to_largest_change() {
+    assert_eq!(stat_bar_cells(10, 0, 10, 40), (10, 0));
+    assert_eq!(stat_bar_cells(50, 50, 200, 20), (5, 5));
+    assert_eq!(stat_bar_cells(1, 0, 1000, 10), (1, 0));
+    assert_eq!(stat_bar_cells(3, 1, 400, 10), (1, 0));
+    assert_eq!(stat_bar_cells(0, 0, 10, 10), (0, 0));
+}
+
+#[test]
+fn test_file_filter_cache_narrows_as_query_grows() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("src/main.rs"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("src/lib.rs"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("README.md"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.start_file_filter();
+    app.push_file_filter_char('r');
+    assert_eq!(app.filtered_file_indices(), vec![0, 1, 2]);
+    app.push_file_filter_char('s');
+    assert_eq!(app.filtered_file_indices(), vec![0, 1]);
+    app.pop_file_filter_char();
+    app.push_file_filter_char('e');
+    assert_eq!(app.filtered_file_indices(), vec![2]);
+    assert_eq!(app.multi_diff.selected_index, 2);
+    app.clear_file_filter();
+    assert_eq!(app.filtered_file_indices(), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_goto_file_number_follows_panel_order() {
+    let _guard = DiffSettingsGuard::default();
+    let pair = |path: &str| {
+        (
+            std::path::PathBuf::from(path),
+            "a\n".to_string(),
+            "b\n".to_string(),
+        )
+    };
+    let multi = MultiFileDiff::from_file_pairs(vec![pair("src/main.rs"), pair("README.md")]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.file_sort = FileSortOrder::Path;
+
+    app.goto_query = "f1".to_string();
+    app.apply_goto();
+    assert_eq!(app.multi_diff.selected_index, 1);
+
+    app.goto_query = "f 2".to_string();
+    app.apply_goto();
+    assert_eq!(app.multi_diff.selected_index, 0);
+
+    app.goto_query = "f9".to_string();
+    app.apply_goto();
+    assert_eq!(app.multi_diff.selected_index, 0);
+    assert_eq!(app.goto_path_hint(), Some("file 9"));
+}
+
+#[test]
+fn test_file_sort_orders_panel_without_moving_files() {
+    let _guard = DiffSettingsGuard::default();
+    let pair = |path: &str, old: &str, new: &str| {
+        (
+            std::path::PathBuf::from(path),
+            old.to_string(),
+            new.to_string(),
+        )
+    };
+    let mut multi = MultiFileDiff::from_file_pairs(vec![
+        pair("src/main.rs", "a\n", "b\n"),
+        pair("README.md", "a\nb\nc\n", "x\ny\nz\n"),
+        pair("src/lib.rs", "a\n", "a\nb\n"),
+    ]);
+    multi.files[2].status = FileStatus::Added;
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+
+    app.file_sort = FileSortOrder::Path;
+    assert_eq!(app.filtered_file_indices(), vec![1, 2, 0]);
+    app.file_sort = FileSortOrder::Changes;
+    assert_eq!(app.filtered_file_indices(), vec![1, 0, 2]);
+    app.file_sort = FileSortOrder::Status;
+    assert_eq!(app.filtered_file_indices(), vec![2, 0, 1]);
+
+    app.next_file();
+    assert_eq!(app.multi_diff.selected_index, 1);
+    assert_eq!(app.multi_diff.files[1].display_name, "README.md");
+    app.file_sort = FileSortOrder::None;
+    assert_eq!(app.filtered_file_indices(), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_autoplay_end_mode_loops_or_quits() {
+    let mut app = make_app_with_unified_hunk_two_changes();
+    app.goto_last_step();
+    assert!(!app.continue_autoplay_at_end());
+
+    app.autoplay_end = AutoplayEndMode::NextFile;
+    assert!(
+        !app.continue_autoplay_at_end(),
+        "no next file to advance to"
+    );
+
+    app.autoplay_end = AutoplayEndMode::Loop;
+    assert!(app.continue_autoplay_at_end());
+    // Back on the first change.
+    assert_eq!(app.multi_diff.current_navigator().state().current_step, 1);
+
+    app.autoplay_reverse = true;
+    assert!(app.continue_autoplay_at_end());
+    let state = app.multi_diff.current_navigator().state();
+    assert_eq!(state.current_step, state.total_steps - 1);
+
+    app.autoplay_end = AutoplayEndMode::Quit;
+    assert!(!app.continue_autoplay_at_end());
+    assert!(app.should_quit);
+}
+
+#[test]
+fn test_autoplay_status_reports_replay_and_reverse() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pair(
+        std::path::PathBuf::from("a.txt"),
+        std::path::PathBuf::from("a.txt"),
+        "a\nb\nc\n".to_string(),
+        "A\nB\nC\n".to_string(),
+    );
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.animation_enabled = false;
+    assert_eq!(app.autoplay_status(), None);
+
+    app.next_step();
+    app.next_step();
+    app.pending_count = Some(2);
+    app.replay_step();
+    assert_eq!(app.autoplay_status().as_deref(), Some("autoplay 2 left"));
+
+    app.toggle_autoplay_reverse();
+    assert_eq!(app.autoplay_status().as_deref(), Some("reverse"));
+    app.toggle_autoplay_reverse();
+    assert_eq!(app.autoplay_status(), None);
+
+    app.toggle_autoplay();
+    app.autoplay_indicator = false;
+    assert_eq!(app.autoplay_status(), None);
+}
+
+#[test]
+fn test_export_review_comments_writes_markdown_and_json() {
+    let mut app = make_app_with_two_hunks();
+    app.set_review_persist_enabled(false);
+    app.enable_review_mode();
+    app.export_review_comments();
+    assert_eq!(
+        app.review_export_hint(),
+        Some(("no review comments", false))
+    );
+
+    app.goto_hunk_index_scroll(0);
+    app.start_line_comment();
+    for ch in "rename this".chars() {
+        app.review_insert_char(ch);
+    }
+    app.review_save_editor();
+    assert_eq!(app.review_comment_count(), 1);
+
+    let path = std::env::temp_dir().join(format!(
+        "oyo_review_export_test_{}_{}.md",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("time")
+            .as_nanos()
+    ));
+    app.review_export_path = Some(path.clone());
+    app.review_output_format = crate::config::ReviewOutputFormat::Markdown;
+    app.export_review_comments();
+    let markdown = std::fs::read_to_string(&path).expect("export");
+    assert!(markdown.starts_with("# Review comments\n\n## `a.txt`\n"));
+    assert!(markdown.contains("**: rename this"));
+    assert!(app.review_export_hint().is_some_and(|(_, ok)| ok));
+
+    app.review_output_format = crate::config::ReviewOutputFormat::Json;
+    app.export_review_comments();
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("export")).expect("json");
+    assert_eq!(json[0]["file"], "a.txt");
+    assert_eq!(json[0]["body"], "rename this");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_confirm_quit_holds_quit_only_after_index_changes() {
+    let mut app = make_app_with_two_hunks();
+    app.confirm_quit = crate::config::ConfirmQuitMode::IfModified;
+    app.submit_review_and_quit();
+    assert!(!app.hold_quit_for_confirmation());
+
+    app.should_quit = false;
+    app.index_modified = true;
+    app.submit_review_and_quit();
+    assert!(app.hold_quit_for_confirmation());
+    assert!(!app.should_quit);
+    assert!(app.quit_confirm_pending);
+
+    app.resolve_quit_confirmation(false);
+    assert!(!app.should_quit && !app.quit_confirm_pending);
+
+    app.submit_review_and_quit();
+    assert!(app.hold_quit_for_confirmation());
+    app.resolve_quit_confirmation(true);
+    assert!(app.should_quit);
+    assert!(!app.hold_quit_for_confirmation());
+}
+
+#[test]
+fn test_path_tokens_prefers_slashed_paths() {
+    assert_eq!(
+        path_tokens(r#"import { x } from "../lib/util.ts"; // see README.md."#),
+        vec!["../lib/util.ts", "README.md"]
+    );
+    assert!(path_tokens("version 1.25 and v2.0").is_empty());
+}
+
+#[test]
+fn test_osc52_sequence_encodes_wraps_and_truncates() {
+    assert_eq!(
+        osc52_sequence("hi!", false),
+        ("\x1b]52;c;aGkh\x07".to_string(), false)
+    );
+    assert_eq!(osc52_sequence("ab", false).0, "\x1b]52;c;YWI=\x07");
+    assert_eq!(
+        osc52_sequence("a", true).0,
+        "\x1bPtmux;\x1b\x1b]52;c;YQ==\x07\x1b\\"
+    );
+
+    let long = "é".repeat(OSC52_MAX_PAYLOAD);
+    let (sequence, truncated) = osc52_sequence(&long, false);
+    assert!(truncated);
+    assert!(sequence.len() <= OSC52_MAX_PAYLOAD + 8);
+}
+
+#[test]
+fn test_goto_path_under_cursor_selects_file_in_diff() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("src/a.rs"),
+            "fn a() {}\n".to_string(),
+            "include!(\"b.rs\");\n".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("src/b.rs"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+
+    assert_eq!(app.goto_path_under_cursor(), None);
+    assert_eq!(app.multi_diff.selected_index, 1);
+    assert_eq!(app.goto_path_hint(), None);
+
+    let multi = MultiFileDiff::from_file_pairs(vec![(
+        std::path::PathBuf::from("src/a.rs"),
+        "fn a() {}\n".to_string(),
+        "include!(\"missing/nowhere.rs\");\n".to_string(),
+    )]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    assert_eq!(app.goto_path_under_cursor(), None);
+    assert_eq!(app.goto_path_hint(), Some("missing/nowhere.rs"));
+}
+
+#[test]
+fn test_toggle_old_path_only_changes_rename_display() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_patch(
+        "diff --git a/src/old.rs b/src/new.rs\n\
+         rename from src/old.rs\n\
+         rename to src/new.rs\n\
+         diff --git a/lib.rs b/lib.rs\n\
+         --- a/lib.rs\n\
+         +++ b/lib.rs\n\
+         @@ -1 +1 @@\n\
+         -a\n\
+         +b\n",
+    );
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    assert_eq!(app.file_display_name(0), "src/new.rs");
+
+    app.toggle_old_path();
+    assert_eq!(app.file_display_name(0), "src/old.rs");
+    assert_eq!(app.displayed_old_path(0).as_deref(), Some("src/old.rs"));
+    assert_eq!(app.current_file_path(), "src/new.rs");
+    assert_eq!(app.file_display_name(1), "lib.rs");
+    assert_eq!(app.displayed_old_path(1), None);
+}
+
+#[test]
+fn test_cycle_file_count_mode_wraps_through_all_modes() {
+    let mut app = make_app_with_two_hunks();
+    app.file_count_mode = FileCountMode::Active;
+
+    let mut seen = Vec::new();
+    for _ in 0..4 {
+        app.cycle_file_count_mode();
+        seen.push(app.file_count_mode);
+    }
+    assert_eq!(
+        seen,
+        vec![
+            FileCountMode::Focused,
+            FileCountMode::All,
+            FileCountMode::Off,
+            FileCountMode::Active,
+        ]
+    );
+    let parsed: crate::config::FilesConfig = toml::from_str("counts = \"always\"").unwrap();
+    assert_eq!(parsed.counts, FileCountMode::All);
+}
+
+#[test]
+fn test_cycle_diff_highlight_wraps_and_shows_hint() {
+    let mut app = make_app_with_two_hunks();
+    app.diff_highlight = DiffHighlightMode::Text;
+    assert_eq!(app.diff_highlight_hint(), None);
+
+    app.cycle_diff_highlight();
+    assert_eq!(app.diff_highlight, DiffHighlightMode::Word);
+    assert_eq!(app.diff_highlight_hint(), Some(DiffHighlightMode::Word));
+    app.cycle_diff_highlight();
+    assert_eq!(app.diff_highlight, DiffHighlightMode::None);
+    app.cycle_diff_highlight();
+    assert_eq!(app.diff_highlight, DiffHighlightMode::Text);
+
+    app.status_hint = Some((
+        HintKind::DiffHighlight,
+        Instant::now() - Duration::from_millis(1),
+    ));
+    app.tick();
+    assert_eq!(app.diff_highlight_hint(), None);
+}
+
+#[test]
+fn test_whole_word_pattern_respects_existing_anchors() {
+    let matches = |pattern: &str, text: &str| {
+        regex::Regex::new(&whole_word_pattern(pattern))
+            .unwrap()
+            .is_match(text)
+    };
+    assert!(matches("set", "let x = set(1)"));
+    assert!(!matches("set", "reset setup"));
+    assert!(matches("get|set", "x.set"));
+    assert_eq!(whole_word_pattern("^set$"), "(?:^set$)");
+    assert_eq!(whole_word_pattern("\\bset\\b"), "(?:\\bset\\b)");
+    assert_eq!(whole_word_pattern("cost\\$"), "\\b(?:cost\\$)\\b");
+}
+
+#[test]
+fn test_empty_step_mode_moves_off_blank_steps() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![(
+        std::path::PathBuf::from("new.rs"),
+        String::new(),
+        "a\n".to_string(),
+    )]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.animation_enabled = false;
+    let step = |app: &mut App| app.multi_diff.current_navigator().state().current_step;
+    app.goto_first_step();
+    assert_eq!(step(&mut app), 1);
+
+    // Default: step 0 of a new file is left blank.
+    app.prev_step();
+    assert_eq!(step(&mut app), 0);
 
+    // Advance only keeps going the way it stepped, and step 0 is the start.
+    app.empty_step = EmptyStepMode::Advance;
     app.next_step();
-    assert_eq!(app.hunk_step_info(), Some((1, 2)));
+    app.prev_step();
+    assert_eq!(step(&mut app), 0);
 
+    // Neighbor falls back to the step after it.
+    app.empty_step = EmptyStepMode::Neighbor;
     app.next_step();
-    assert_eq!(app.hunk_step_info(), Some((2, 2)));
+    app.prev_step();
+    assert_eq!(step(&mut app), 1);
+    assert!(!app.step_is_blank());
 }
 
 #[test]
-fn test_no_step_snapshot_restores_cursor_or_jumps() {
+fn test_auto_step_delay_waits_on_step_zero_and_cancels() {
     let _guard = DiffSettingsGuard::default();
-    let old_lines: Vec<String> = (1..=25).map(|i| format!("line{}", i)).collect();
-    let mut new_lines = old_lines.clone();
-    new_lines[1] = "line2-new".to_string();
-    new_lines[19] = "line20-new".to_string();
-    let old = old_lines.join("\n");
-    let new = new_lines.join("\n");
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.rs"),
+            "a\nb\n".to_string(),
+            "a\nB\n".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.rs"),
+            "c\nd\n".to_string(),
+            "C\nd\n".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("c.rs"),
+            "e\nf\n".to_string(),
+            "E\nf\n".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.animation_enabled = false;
+    app.auto_step_delay = Duration::from_millis(20);
+    let step = |app: &mut App| app.multi_diff.current_navigator().state().current_step;
 
-    let multi_diff = MultiFileDiff::from_file_pair(
-        std::path::PathBuf::from("a.txt"),
-        std::path::PathBuf::from("a.txt"),
-        old,
-        new,
-    );
-    let mut app = App::new(multi_diff, ViewMode::UnifiedPane, 0, false, None);
-    app.stepping = false;
-    app.no_step_auto_jump_on_enter = true;
-    app.enter_no_step_mode();
+    app.next_file();
+    assert_eq!(step(&mut app), 0);
+    app.tick();
+    assert_eq!(step(&mut app), 0);
+    std::thread::sleep(Duration::from_millis(30));
+    app.tick();
+    assert_eq!(step(&mut app), 1);
 
-    let idx = app.multi_diff.selected_index;
-    app.save_no_step_state_snapshot(idx);
-    app.multi_diff.current_navigator().clear_cursor_change();
-    app.multi_diff.current_navigator().set_hunk_scope(false);
+    app.next_file();
+    app.cancel_pending_auto_step();
+    std::thread::sleep(Duration::from_millis(30));
+    app.tick();
+    assert_eq!(step(&mut app), 0);
+}
 
-    assert!(app.restore_no_step_state_snapshot(idx));
-    let cursor_id = app
-        .multi_diff
-        .current_navigator()
-        .state()
-        .cursor_change
-        .expect("cursor change expected");
-    assert!(cursor_id > 0);
+#[test]
+fn test_file_filter_reveals_auto_hidden_panel() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.rs"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.rs"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    let narrow = FILE_PANEL_MIN_WIDTH;
+    assert!(!app.update_file_panel_auto_hide(narrow));
+    assert!(app.file_panel_auto_hidden);
+
+    app.start_file_filter();
+    assert!(app.update_file_panel_auto_hide(narrow));
+    app.stop_file_filter();
+    assert!(!app.update_file_panel_auto_hide(narrow));
+    assert!(app.file_panel_visible);
+
+    app.file_panel_manually_set = true;
+    app.file_panel_visible = false;
+    app.start_file_filter();
+    assert!(!app.update_file_panel_auto_hide(narrow));
 }
 
 #[test]
-fn test_no_step_cursor_stable_through_file_cycles() {
+fn test_focus_file_filter_restores_focus_on_escape() {
     let _guard = DiffSettingsGuard::default();
-    let old_lines: Vec<String> = (1..=25).map(|i| format!("line{}", i)).collect();
-    let mut new_lines = old_lines.clone();
-    new_lines[1] = "line2-new".to_string();
-    new_lines[19] = "line20-new".to_string();
-    let old = old_lines.join("\n");
-    let new = new_lines.join("\n");
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.rs"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.rs"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.file_panel_visible = false;
+    app.file_list_focused = false;
+
+    app.focus_file_filter();
+    assert!(app.file_filter_active);
+    assert!(app.file_list_focused);
+    assert!(app.file_panel_visible);
+    app.cancel_file_filter();
+    assert!(!app.file_filter_active);
+    assert!(!app.file_list_focused);
+    assert!(!app.file_panel_visible);
+
+    app.focus_file_filter();
+    app.stop_file_filter();
+    assert!(app.file_list_focused);
+    assert!(app.file_panel_visible);
+}
+
+#[test]
+fn test_sync_line_across_files_keeps_source_line() {
+    let _guard = DiffSettingsGuard::default();
+    let long_old: Vec<String> = (1..=40).map(|i| format!("line{}", i)).collect();
+    let mut long_new = long_old.clone();
+    long_new[1] = "line2-new".to_string();
+    let short_old: Vec<String> = (1..=10).map(|i| format!("short{}", i)).collect();
+    let mut short_new = short_old.clone();
+    short_new[0] = "short1-new".to_string();
 
     let multi = MultiFileDiff::from_file_pairs(vec![
-        (std::path::PathBuf::from("a.txt"), old.clone(), new.clone()),
-        (std::path::PathBuf::from("b.txt"), old.clone(), new.clone()),
-        (std::path::PathBuf::from("c.txt"), old.clone(), new.clone()),
+        (
+            std::path::PathBuf::from("a.txt"),
+            long_old.join("\n"),
+            long_new.join("\n"),
+        ),
+        (
+            std::path::PathBuf::from("b.txt"),
+            long_old.join("\n"),
+            long_new.join("\n"),
+        ),
+        (
+            std::path::PathBuf::from("c.txt"),
+            short_old.join("\n"),
+            short_new.join("\n"),
+        ),
     ]);
     let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
     app.stepping = false;
     app.no_step_auto_jump_on_enter = true;
+    app.auto_center = false;
+    app.sync_line_across_files = true;
     app.enter_no_step_mode();
 
-    app.goto_hunk_start_scroll();
-    let first_cursor = app.multi_diff.current_navigator().state().cursor_change;
+    app.goto_line_number(30);
+    assert_eq!(app.focused_source_line(), Some(30));
 
     app.next_file();
+    assert_eq!(app.focused_source_line(), Some(30));
+
+    // Beyond the end of the shorter file clamps to its last line.
     app.next_file();
-    app.prev_file();
-    app.prev_file();
+    assert_eq!(app.focused_source_line(), Some(10));
+}
 
-    let cursor_after = app.multi_diff.current_navigator().state().cursor_change;
+#[test]
+fn test_structural_files_skip_line_based_actions() {
+    let _guard = DiffSettingsGuard::default();
+    MultiFileDiff::set_structural_diff(true);
+    let multi = MultiFileDiff::from_file_pair(
+        std::path::PathBuf::from("conf.json"),
+        std::path::PathBuf::from("conf.json"),
+        "{\"b\": 1,\n \"a\": 2}\n".to_string(),
+        "{\"a\": 3,\n \"b\": 1}\n".to_string(),
+    );
+    MultiFileDiff::set_structural_diff(false);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.set_review_persist_enabled(false);
+    app.enable_review_mode();
+    assert!(app.current_file_structural());
 
-    assert_eq!(first_cursor, cursor_after);
+    app.goto_hunk_index_scroll(0);
+    app.start_line_comment();
+    assert!(!app.review_editor_active());
+    assert!(app.structural_lines_hint());
+
+    let before = app.focused_source_line();
+    app.goto_line_number(2);
+    assert_eq!(app.focused_source_line(), before);
+}
+
+#[test]
+fn test_auto_advance_file_steps_into_next_file_without_wrapping() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.txt"),
+            "a1\na2\n".to_string(),
+            "a1\na2-new\n".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.txt"),
+            "b1\nb2\n".to_string(),
+            "b1-new\nb2\n".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.auto_advance_file = true;
+    app.step_wrap = StepWrapMode::File;
+
+    app.next_step();
+    assert_eq!(app.multi_diff.selected_index, 0);
+
+    app.next_step();
+    assert_eq!(app.multi_diff.selected_index, 1);
+    assert_eq!(app.multi_diff.current_navigator().state().current_step, 1);
+
+    // The last file's last change stops instead of wrapping to the first file.
+    app.next_step();
+    assert_eq!(app.multi_diff.selected_index, 1);
+    let change_id = app
+        .multi_diff
+        .current_navigator()
+        .state()
+        .active_change
+        .unwrap();
+    assert_eq!(
+        app.step_edge_hint_for_change(change_id),
+        Some("End of review")
+    );
 }
 
 #[test]
@@ -487,6 +1851,34 @@ fn test_windowed_view_tracks_scroll_offset_in_no_step_large_file() {
     assert!(view.len() <= span.saturating_add(1));
 }
 
+#[test]
+fn test_startup_anchor_top_applies_only_to_first_positioning() {
+    let _guard = DiffSettingsGuard::default();
+    let old_lines: Vec<String> = (1..=100).map(|i| format!("line{}", i)).collect();
+    let mut new_lines = old_lines.clone();
+    new_lines[59] = "line60-new".to_string();
+    let multi = MultiFileDiff::from_file_pair(
+        std::path::PathBuf::from("a.txt"),
+        std::path::PathBuf::from("a.txt"),
+        old_lines.join("\n"),
+        new_lines.join("\n"),
+    );
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.animation_enabled = false;
+    app.auto_center = true;
+    app.startup_anchor_top = true;
+    app.next_step();
+
+    app.ensure_active_visible_if_needed(20);
+    let anchored = app.scroll_offset;
+    assert!(!app.startup_anchor_top);
+
+    app.needs_scroll_to_active = true;
+    app.ensure_active_visible_if_needed(20);
+    let centered = app.scroll_offset;
+    assert_eq!(anchored, centered + 10 - 3);
+}
+
 #[test]
 fn test_step_jump_waits_for_view_rebuild_before_scroll() {
     let _guard = DiffSettingsGuard::new(64);
@@ -670,6 +2062,51 @@ fn test_refresh_current_file_keeps_no_step_state() {
     let _ = std::fs::remove_file(path);
 }
 
+#[test]
+fn test_watch_follow_sticks_to_bottom_only_when_already_there() {
+    let _guard = DiffSettingsGuard::default();
+    let old_lines: Vec<String> = (1..=30).map(|i| format!("log {i}")).collect();
+    let old = old_lines.join("\n");
+    let grow = |count: usize| {
+        (1..=count)
+            .map(|i| format!("log {i}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let path = std::env::temp_dir().join(format!(
+        "oyo_watch_follow_test_{}_{}.txt",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("time")
+            .as_nanos()
+    ));
+    std::fs::write(&path, grow(40)).expect("write test file");
+
+    let diff = MultiFileDiff::from_file_pair(path.clone(), path.clone(), old, grow(40));
+    let mut app = App::new(diff, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.no_step_auto_jump_on_enter = false;
+    app.enter_no_step_mode();
+    app.watch_follow = true;
+    app.last_viewport_height = 10;
+
+    // At the bottom: the refresh follows the appended lines.
+    app.scroll_offset = 30;
+    std::fs::write(&path, grow(60)).expect("append");
+    app.refresh_current_file();
+    assert_eq!(app.scroll_offset, usize::MAX);
+
+    // Scrolled up: the follow pauses and the view stays put.
+    app.scroll_offset = 5;
+    std::fs::write(&path, grow(80)).expect("append");
+    app.refresh_current_file();
+    assert_eq!(app.scroll_offset, 5);
+
+    let _ = std::fs::remove_file(path);
+}
+
 #[test]
 fn test_refresh_current_file_preserves_no_step_hunk_scope() {
     let _guard = DiffSettingsGuard::default();
@@ -888,3 +2325,353 @@ fn test_diff_worker_upgrades_deferred_diff_and_updates_counts() {
     assert_eq!(file.insertions, expected.insertions);
     assert_eq!(file.deletions, expected.deletions);
 }
+
+#[test]
+fn test_expand_hunk_context_unfolds_until_gap_closes() {
+    let mut app = make_app_with_two_hunks();
+    app.set_fold_context_mode(FoldContextMode::Counts);
+    let fold_labels = |app: &mut App| -> Vec<String> {
+        app.current_view_with_frame(AnimationFrame::Idle)
+            .iter()
+            .filter(|line| is_fold_line(line))
+            .map(|line| line.content.clone())
+            .collect()
+    };
+    assert_eq!(fold_labels(&mut app), ["… 17 lines"]);
+
+    assert_eq!(app.multi_diff.current_navigator().state().current_hunk, 0);
+    app.expand_hunk_context(false, 1);
+    assert_eq!(fold_labels(&mut app), ["… 12 lines"]);
+    app.next_hunk_scroll();
+    app.expand_hunk_context(true, 1);
+    assert!(fold_labels(&mut app).is_empty());
+
+    app.toggle_fold_context();
+    app.toggle_fold_context();
+    assert_eq!(fold_labels(&mut app), ["… 17 lines"]);
+}
+
+#[test]
+fn test_config_reload_keeps_scroll_and_shows_hint() {
+    let mut app = make_app_with_two_hunks();
+    app.next_hunk_scroll();
+    let scroll = app.scroll_offset;
+
+    app.finish_config_reload(Ok(Vec::new()));
+    assert_eq!(app.scroll_offset, scroll);
+    assert_eq!(app.config_reload_hint(), Some((true, None)));
+    assert!(app.unified_render_cache.is_none());
+
+    app.finish_config_reload(Ok(vec!["bad marker".into(), "bad color".into()]));
+    assert_eq!(
+        app.config_reload_hint(),
+        Some((true, Some("bad marker (+1 more)")))
+    );
+
+    app.finish_config_reload(Err("Failed to parse config x.toml: oops".into()));
+    assert_eq!(
+        app.config_reload_hint(),
+        Some((false, Some("Failed to parse config x.toml: oops")))
+    );
+}
+
+#[test]
+fn test_set_theme_switches_mode_and_drops_render_caches() {
+    let mut app = make_app_with_two_hunks();
+    let ui = crate::config::UiConfig::default();
+    let _ = app.current_view_with_frame(AnimationFrame::Idle);
+    assert!(app.view_cache.is_some());
+
+    app.set_theme(ui.resolve_theme(true), true);
+    assert!(app.theme_is_light);
+    assert!(app.view_cache.is_none());
+    assert!(app.syntax_caches.iter().all(Option::is_none));
+
+    app.set_theme(ui.resolve_theme(false), false);
+    assert!(!app.theme_is_light);
+}
+
+#[test]
+fn test_resize_brings_active_change_back_into_view() {
+    let mut app = make_app_with_two_hunks();
+    app.next_hunk_scroll();
+    app.next_hunk_scroll();
+    app.needs_scroll_to_active = false;
+    app.scroll_offset = 0;
+
+    app.handle_resize();
+    assert!(app.needs_scroll_to_active);
+    app.ensure_active_visible_if_needed(6);
+    assert!(!app.needs_scroll_to_active);
+    assert!(app.scroll_offset > 0);
+    assert!(app.scroll_offset <= 19 && 19 < app.scroll_offset + 6);
+}
+
+#[test]
+fn test_diff_bg_toggle_is_remembered_per_view() {
+    let mut app = make_app_with_two_hunks();
+    app.set_diff_bg_default(true);
+    app.sync_diff_bg_to_view();
+
+    app.set_view_mode(ViewMode::Split);
+    app.sync_diff_bg_to_view();
+    app.toggle_diff_bg();
+    assert!(!app.diff_bg);
+
+    app.set_view_mode(ViewMode::UnifiedPane);
+    app.sync_diff_bg_to_view();
+    assert!(app.diff_bg);
+
+    app.set_view_mode(ViewMode::Split);
+    app.sync_diff_bg_to_view();
+    assert!(!app.diff_bg);
+}
+
+#[test]
+fn test_line_wrap_follows_view_defaults_until_toggled() {
+    let mut app = make_app_with_two_hunks();
+    app.set_line_wrap_defaults(true, vec![(ViewMode::Split, false)]);
+    assert!(app.line_wrap);
+
+    app.set_view_mode(ViewMode::Split);
+    app.sync_line_wrap_to_view();
+    assert!(!app.line_wrap);
+
+    app.set_view_mode(ViewMode::UnifiedPane);
+    app.sync_line_wrap_to_view();
+    assert!(app.line_wrap);
+
+    app.toggle_line_wrap();
+    app.set_view_mode(ViewMode::Split);
+    app.sync_line_wrap_to_view();
+    assert!(!app.line_wrap);
+    app.set_view_mode(ViewMode::UnifiedPane);
+    app.sync_line_wrap_to_view();
+    assert!(!app.line_wrap, "a manual toggle sticks across views");
+}
+
+#[test]
+fn test_marked_files_copy_paths_in_panel_order() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.rs"),
+            "a".to_string(),
+            "b".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.rs"),
+            "c".to_string(),
+            "d".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("c.rs"),
+            "e".to_string(),
+            "f".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    assert_eq!(app.marked_paths_text().as_deref(), Some("a.rs"));
+
+    app.select_file(2);
+    app.toggle_file_mark();
+    app.select_file(0);
+    app.toggle_file_mark();
+    app.select_file(1);
+    assert_eq!(app.marked_paths_text().as_deref(), Some("a.rs\nc.rs"));
+
+    app.select_file(0);
+    app.toggle_file_mark();
+    assert!(!app.is_file_marked(0));
+    assert_eq!(app.marked_paths_text().as_deref(), Some("c.rs"));
+}
+
+#[test]
+fn test_compare_marked_files_diffs_new_sides_in_panel_order() {
+    let _guard = DiffSettingsGuard::default();
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (
+            std::path::PathBuf::from("a.rs"),
+            "a\n".to_string(),
+            "one\n".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("b.rs"),
+            "b\n".to_string(),
+            "two\n".to_string(),
+        ),
+        (
+            std::path::PathBuf::from("c.rs"),
+            "c\n".to_string(),
+            "three\n".to_string(),
+        ),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.select_file(2);
+    app.toggle_file_mark();
+    assert!(!app.compare_marked_files());
+    assert!(app.open_compare.is_none());
+
+    app.select_file(0);
+    app.toggle_file_mark();
+    assert!(app.compare_marked_files());
+    let diff = app.open_compare.take().expect("comparison diff");
+    assert_eq!(diff.file_contents(0), Some(("one\n", "three\n")));
+    let file = &diff.files[0];
+    assert_eq!(file.path, std::path::PathBuf::from("c.rs"));
+    assert_eq!(file.old_path, Some(std::path::PathBuf::from("a.rs")));
+    assert_eq!(app.multi_diff.file_count(), 3);
+}
+
+#[test]
+fn test_auto_fold_threshold_folds_busy_files_until_unfolded() {
+    let _guard = DiffSettingsGuard::default();
+    let old: String = (1..=25).map(|i| format!("line{i}\n")).collect();
+    let busy = old
+        .replace("line2\n", "two\n")
+        .replace("line20\n", "twenty\n");
+    let quiet = old.replace("line2\n", "two\n");
+    let multi = MultiFileDiff::from_file_pairs(vec![
+        (std::path::PathBuf::from("busy.txt"), old.clone(), busy),
+        (std::path::PathBuf::from("quiet.txt"), old, quiet),
+    ]);
+    let mut app = App::new(multi, ViewMode::UnifiedPane, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.auto_fold_threshold = 1;
+    app.handle_file_enter();
+    assert!(app.fold_context.is_enabled());
+
+    app.select_file(1);
+    assert!(!app.fold_context.is_enabled());
+    app.select_file(0);
+    assert!(app.fold_context.is_enabled());
+
+    app.toggle_fold_context();
+    app.select_file(1);
+    app.select_file(0);
+    assert!(!app.fold_context.is_enabled());
+}
+
+#[test]
+fn test_hunk_separator_splits_gap_in_no_step_only() {
+    let mut app = make_app_with_two_hunks();
+    app.hunk_separator = Some("┄┄┄".to_string());
+    let separators = |app: &mut App| -> Vec<(Option<usize>, Option<usize>)> {
+        let view = app.current_view_with_frame(AnimationFrame::Idle);
+        view.iter()
+            .enumerate()
+            .filter(|(_, line)| line.content == "┄┄┄")
+            .map(|(idx, _)| (view[idx - 1].new_line, view[idx + 1].new_line))
+            .collect()
+    };
+    assert_eq!(separators(&mut app), [(Some(10), Some(11))]);
+
+    app.set_fold_context_mode(FoldContextMode::Counts);
+    assert!(separators(&mut app).is_empty());
+    app.set_fold_context_mode(FoldContextMode::Off);
+
+    app.toggle_stepping();
+    assert!(separators(&mut app).is_empty());
+}
+
+#[test]
+fn test_hunk_headers_annotate_hunks_in_no_step_and_top_bar_while_stepping() {
+    let mut app = make_app_with_two_hunks();
+    app.hunk_headers = true;
+    let headers = |app: &mut App| -> Vec<(String, Option<usize>)> {
+        let view = app.current_view_with_frame(AnimationFrame::Idle);
+        view.iter()
+            .enumerate()
+            .filter(|(_, line)| line.content.starts_with("@@"))
+            .map(|(idx, line)| (line.content.clone(), view[idx + 1].new_line))
+            .collect()
+    };
+    assert_eq!(
+        headers(&mut app),
+        [
+            ("@@ -2,1 +2,1 @@ (+1 -1)".to_string(), Some(2)),
+            ("@@ -20,1 +20,1 @@ (+1 -1)".to_string(), Some(20)),
+        ]
+    );
+    assert_eq!(app.current_hunk_header_text(), None);
+
+    app.toggle_stepping();
+    assert!(headers(&mut app).is_empty());
+    assert!(app
+        .current_hunk_header_text()
+        .is_some_and(|text| text.starts_with("@@ -")));
+}
+
+#[test]
+fn test_sync_center_split_centers_each_pane() {
+    let _guard = DiffSettingsGuard::default();
+    let old_lines: Vec<String> = (1..=60).map(|i| format!("line{}", i)).collect();
+    let mut new_lines: Vec<String> = old_lines
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !(4..34).contains(idx))
+        .map(|(_, line)| line.clone())
+        .collect();
+    new_lines[19] = "line50-new".to_string();
+    let multi_diff = MultiFileDiff::from_file_pair(
+        std::path::PathBuf::from("a.txt"),
+        std::path::PathBuf::from("a.txt"),
+        old_lines.join("\n"),
+        new_lines.join("\n"),
+    );
+    let mut app = App::new(multi_diff, ViewMode::Split, 0, false, None);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.split_align_lines = false;
+    app.last_viewport_height = 20;
+
+    app.goto_hunk_index_scroll(1);
+    app.sync_center_split(20);
+
+    // "line50" sits at row 49 of the old pane and row 19 of the new pane.
+    assert_eq!(app.scroll_offset, 49 - 10);
+    assert_eq!(app.split_new_scroll_offset(app.scroll_offset), 19 - 10);
+
+    app.center_on_active(20);
+    assert_eq!(
+        app.split_new_scroll_offset(app.scroll_offset),
+        app.scroll_offset
+    );
+}
+
+#[test]
+fn test_redraw_interval_uses_idle_tick_until_playback_starts() {
+    let _guard = DiffSettingsGuard::default();
+    let multi_diff = MultiFileDiff::from_file_pair(
+        std::path::PathBuf::from("a.txt"),
+        std::path::PathBuf::from("a.txt"),
+        "a\nb\n".to_string(),
+        "a\nc\n".to_string(),
+    );
+    let mut app = App::new(multi_diff, ViewMode::UnifiedPane, 0, false, None);
+    app.idle_tick = Duration::from_millis(1000);
+    assert_eq!(app.redraw_interval(), Duration::from_millis(1000));
+
+    app.autoplay = true;
+    assert!(app.redraw_interval() < app.idle_tick);
+}
+
+#[test]
+fn test_toggle_mouse_capture_flips_state_and_shows_hint() {
+    let mut app = make_app_with_two_hunks();
+    assert!(app.mouse_capture);
+    assert_eq!(app.mouse_capture_hint(), None);
+
+    app.toggle_mouse_capture();
+    assert!(!app.mouse_capture);
+    assert_eq!(app.mouse_capture_hint(), Some(false));
+
+    app.toggle_mouse_capture();
+    assert_eq!(app.mouse_capture_hint(), Some(true));
+
+    // A newer hint takes the status bar slot.
+    app.cycle_diff_highlight();
+    assert_eq!(app.mouse_capture_hint(), None);
+    assert!(app.diff_highlight_hint().is_some());
+}
@@ -1,16 +1,20 @@
 use crate::blame::BlameInfo;
 use crate::config::{
-    DiffExtentMarkerMode, DiffExtentMarkerScope, DiffForegroundMode, DiffHighlightMode,
-    FoldContextMode, SyntaxMode,
+    DeletionStyle, DiffExtentMarkerMode, DiffExtentMarkerScope, DiffForegroundMode,
+    DiffHighlightMode, FoldContextMode, ModifiedStepMode, SyntaxMode,
 };
 use crate::syntax::SyntaxSide;
 use oyo_core::diff::DiffResult;
-use oyo_core::{multi::BlameSource, AnimationFrame, StepDirection};
+use oyo_core::{
+    multi::{BlameSource, FileSide},
+    AnimationFrame, StepDirection, StepState, ViewLine,
+};
 use ratatui::style::Color;
 use ratatui::text::Line;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Animation phase for smooth transitions
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -73,6 +77,8 @@ pub(crate) struct HunkBounds {
 
 pub(crate) const FILE_PANEL_MIN_WIDTH: u16 = 24;
 pub(crate) const DIFF_VIEW_MIN_WIDTH: u16 = 50;
+/// Minimum diff view height for stacked (top/bottom) split panes.
+pub(crate) const DIFF_VIEW_MIN_HEIGHT: u16 = 10;
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct NoStepState {
@@ -81,10 +87,33 @@ pub(crate) struct NoStepState {
     pub(crate) last_nav_was_hunk: bool,
 }
 
+/// A jump-list entry: enough to put the view back where a jump left from.
+#[derive(Clone, Debug)]
+pub(crate) struct JumpPosition {
+    pub(crate) file_idx: usize,
+    pub(crate) stepping: bool,
+    pub(crate) step_state: StepState,
+    pub(crate) scroll_offset: usize,
+    pub(crate) horizontal_scroll: usize,
+}
+
+impl JumpPosition {
+    pub(crate) fn same_place(&self, other: &JumpPosition) -> bool {
+        self.file_idx == other.file_idx
+            && self.stepping == other.stepping
+            && self.step_state.current_step == other.step_state.current_step
+            && self.step_state.current_hunk == other.step_state.current_hunk
+            && self.step_state.cursor_change == other.step_state.cursor_change
+            && self.scroll_offset == other.scroll_offset
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum StepEdge {
     Start,
     End,
+    /// Last change of the last file while auto-advancing
+    ReviewEnd,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -94,10 +123,19 @@ pub(crate) struct StepEdgeHint {
     pub(crate) until: Instant,
 }
 
+/// Clipboard format for a snapshot of the visible diff view
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SnapshotFormat {
+    Ansi,
+    Plain,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum HunkEdge {
     First,
     Last,
+    /// Every hunk in every file has been visited
+    ReviewComplete,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -106,6 +144,127 @@ pub(crate) struct HunkEdgeHint {
     pub(crate) until: Instant,
 }
 
+/// A row of the continuous (all files) view
+#[derive(Clone, Debug)]
+pub(crate) enum ContinuousRow {
+    /// File separator header
+    Header(usize),
+    /// Diff line of a file
+    Line(usize, ViewLine),
+    /// Stand-in for a file without lines to show (binary, pending diff, ...)
+    Notice(usize, &'static str),
+}
+
+impl ContinuousRow {
+    pub(crate) fn file_index(&self) -> usize {
+        match self {
+            ContinuousRow::Header(idx)
+            | ContinuousRow::Line(idx, _)
+            | ContinuousRow::Notice(idx, _) => *idx,
+        }
+    }
+}
+
+/// Source line range the view is restricted to (`:limit <start>,<end>`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct LineLimit {
+    pub(crate) file_index: usize,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    /// Line count of the file, for the "of N" indicator
+    pub(crate) total: usize,
+}
+
+/// Hunks under review from `--hunks`, as file index -> hunk indices
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HunkSubset {
+    pub(crate) listed: FxHashMap<usize, FxHashSet<usize>>,
+    /// Files not listed have every hunk skipped instead of shown
+    pub(crate) others_hidden: bool,
+}
+
+impl HunkSubset {
+    /// Whether `--hunks` leaves this hunk out of the review.
+    pub(crate) fn skips(&self, file_idx: usize, hunk_idx: usize) -> bool {
+        match self.listed.get(&file_idx) {
+            Some(hunks) => !hunks.contains(&hunk_idx),
+            None => self.others_hidden,
+        }
+    }
+
+    /// Whether any hunk of this file is left out.
+    pub(crate) fn restricts(&self, file_idx: usize) -> bool {
+        self.listed.contains_key(&file_idx) || self.others_hidden
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ContinuousCache {
+    pub(crate) fold_context: FoldContextMode,
+    pub(crate) rows: Arc<Vec<ContinuousRow>>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ConfigReloadHint {
+    pub(crate) ok: bool,
+    /// The parse error, or the first warning from a successful reload
+    pub(crate) detail: Option<String>,
+    pub(crate) until: Instant,
+}
+
+/// Commit context shown above the first file of a git range
+#[derive(Clone, Debug)]
+pub(crate) struct CommitHeader {
+    pub(crate) title: String,
+    pub(crate) lines: Vec<String>,
+    /// Some commit in the range carries `git notes` (listed in `lines`)
+    pub(crate) has_notes: bool,
+}
+
+/// `gf` found no file for the path under the cursor
+#[derive(Clone, Debug)]
+pub(crate) struct GotoPathHint {
+    pub(crate) path: String,
+    pub(crate) until: Instant,
+}
+
+/// Short-lived status bar notice after a toggle or one-off action. Only the
+/// newest is shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum HintKind {
+    /// A yank was cut to fit OSC 52
+    ClipboardTruncated,
+    /// The inline highlight mode changed
+    DiffHighlight,
+    /// The modified step mode changed
+    ModifiedStep,
+    /// Mouse capture was toggled
+    MouseCapture,
+    /// Outcome of the last `g E` review export
+    ReviewExport { message: String, ok: bool },
+    /// A line-based action was skipped on a structurally diffed file
+    StructuralLines,
+}
+
+impl HintKind {
+    pub(crate) fn duration(&self) -> Duration {
+        let ms = match self {
+            Self::ClipboardTruncated | Self::ReviewExport { .. } | Self::StructuralLines => 3000,
+            Self::DiffHighlight | Self::ModifiedStep | Self::MouseCapture => 2000,
+        };
+        Duration::from_millis(ms)
+    }
+}
+
+/// A recent keypress shown by the keystroke overlay
+#[derive(Clone, Debug)]
+pub(crate) struct KeyLogEntry {
+    pub(crate) label: String,
+    /// Consecutive presses of the same key
+    pub(crate) count: usize,
+    pub(crate) at: Instant,
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct BlameStepHint {
     pub(crate) change_id: usize,
@@ -125,12 +284,14 @@ pub(crate) struct BlameCacheKey {
     pub(crate) path: PathBuf,
     pub(crate) line: usize,
     pub(crate) source: BlameSource,
+    pub(crate) side: FileSide,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub(crate) struct BlamePrefetchKey {
     pub(crate) path: PathBuf,
     pub(crate) source: BlameSource,
+    pub(crate) side: FileSide,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -153,6 +314,7 @@ pub(crate) struct BlameRenderKey {
     pub(crate) view_len: usize,
     pub(crate) window_start: usize,
     pub(crate) animation_frame: AnimationFrame,
+    pub(crate) peek_state: Option<PeekState>,
     pub(crate) cache_rev: u64,
     pub(crate) time_bucket: i64,
 }
@@ -194,11 +356,16 @@ pub(crate) struct UnifiedRenderKey {
     pub(crate) diff_bg: bool,
     pub(crate) diff_fg: DiffForegroundMode,
     pub(crate) diff_highlight: DiffHighlightMode,
+    pub(crate) modified_step_mode: ModifiedStepMode,
     pub(crate) diff_extent_marker: DiffExtentMarkerMode,
     pub(crate) diff_extent_marker_scope: DiffExtentMarkerScope,
     pub(crate) diff_extent_marker_context: bool,
     pub(crate) gutter_signs: bool,
-    pub(crate) strikethrough_deletions: bool,
+    pub(crate) focus_mode: bool,
+    pub(crate) mine_only: bool,
+    pub(crate) highlight_trailing_ws: bool,
+    pub(crate) review_keywords: Option<String>,
+    pub(crate) deletion_style: DeletionStyle,
     pub(crate) search_query: String,
     pub(crate) search_active: bool,
     pub(crate) syntax_mode: SyntaxMode,
@@ -207,6 +374,7 @@ pub(crate) struct UnifiedRenderKey {
     pub(crate) syntax_epoch: u64,
     pub(crate) step_edge_hint: bool,
     pub(crate) hunk_edge_hint: bool,
+    pub(crate) locate_flash: Option<usize>,
     pub(crate) blame_hunk_hint: Option<String>,
     pub(crate) review_mode: bool,
     pub(crate) review_editor_active: bool,
@@ -231,6 +399,7 @@ pub(crate) struct BlameRequest {
     pub(crate) repo_root: PathBuf,
     pub(crate) path: PathBuf,
     pub(crate) source: BlameSource,
+    pub(crate) side: FileSide,
     pub(crate) start: usize,
     pub(crate) end: usize,
 }
@@ -239,6 +408,7 @@ pub(crate) struct BlameRequest {
 pub(crate) struct BlameResponse {
     pub(crate) path: PathBuf,
     pub(crate) source: BlameSource,
+    pub(crate) side: FileSide,
     pub(crate) start: usize,
     pub(crate) end: usize,
     pub(crate) entries: Vec<(usize, BlameInfo)>,
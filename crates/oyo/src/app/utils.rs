@@ -1,9 +1,13 @@
 use super::{AnimationPhase, ViewMode};
 use crate::config::FoldContextMode;
-use oyo_core::{Change, ChangeKind, LineKind, StepDirection, ViewLine, ViewSpan, ViewSpanKind};
+use oyo_core::{
+    Change, ChangeKind, Hunk, LineKind, StepDirection, ViewLine, ViewSpan, ViewSpanKind,
+};
 use ratatui::style::Color;
 use ratatui::text::Span;
 use regex::Regex;
+use rustc_hash::FxHashMap;
+use std::collections::BTreeSet;
 use std::io::Write;
 use std::process::{Command, Stdio};
 
@@ -30,6 +34,49 @@ pub(crate) fn max_scroll(
     }
 }
 
+/// Path-like tokens on a line (`src/lib.rs`, `../util.ts`, `README.md`),
+/// tokens with a `/` first since they are the least ambiguous.
+pub(crate) fn path_tokens(line: &str) -> Vec<&str> {
+    let is_path_char =
+        |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | '~' | '@' | '+');
+    let mut tokens: Vec<&str> = line
+        .split(|c: char| !is_path_char(c))
+        .map(|token| token.trim_end_matches('.'))
+        .filter(|token| {
+            if token.contains('/') {
+                return token.chars().any(char::is_alphanumeric);
+            }
+            let Some((stem, ext)) = token.rsplit_once('.') else {
+                return false;
+            };
+            !stem.is_empty()
+                && (1..=5).contains(&ext.len())
+                && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                && !ext.chars().all(|c| c.is_ascii_digit())
+        })
+        .collect();
+    tokens.sort_by_key(|token| !token.contains('/'));
+    tokens
+}
+
+/// Resolve `.` and `..` components without touching the filesystem.
+pub(crate) fn normalize_path(path: &std::path::Path) -> std::path::PathBuf {
+    use std::path::Component;
+    let mut out = std::path::PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
 pub(crate) fn copy_to_clipboard(text: &str) -> bool {
     if text.is_empty() {
         return false;
@@ -58,6 +105,69 @@ pub(crate) fn copy_to_clipboard(text: &str) -> bool {
     }
 }
 
+/// Largest base64 payload sent in one OSC 52 sequence. xterm and tmux drop
+/// sequences beyond roughly this size, so longer text is truncated.
+pub(crate) const OSC52_MAX_PAYLOAD: usize = 100_000;
+
+/// Whether the session looks remote, where clipboard tools would copy on
+/// the wrong machine.
+pub(crate) fn in_ssh_session() -> bool {
+    std::env::var_os("SSH_TTY").is_some() || std::env::var_os("SSH_CONNECTION").is_some()
+}
+
+/// Build the OSC 52 sequence that sets the system clipboard, wrapped for
+/// tmux passthrough when asked. Returns the sequence and whether `text` had
+/// to be truncated to fit [`OSC52_MAX_PAYLOAD`].
+pub(crate) fn osc52_sequence(text: &str, tmux: bool) -> (String, bool) {
+    let mut end = text.len().min(OSC52_MAX_PAYLOAD / 4 * 3);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let payload = base64_encode(&text.as_bytes()[..end]);
+    let sequence = if tmux {
+        format!("\x1bPtmux;\x1b\x1b]52;c;{payload}\x07\x1b\\")
+    } else {
+        format!("\x1b]52;c;{payload}\x07")
+    };
+    (sequence, end < text.len())
+}
+
+/// Copy through the terminal with OSC 52. Returns false when the terminal
+/// could not be written; terminals without OSC 52 support ignore it silently.
+pub(crate) fn write_osc52(sequence: &str) -> bool {
+    use std::io::IsTerminal;
+    let mut out: Box<dyn Write> = if std::io::stdout().is_terminal() {
+        Box::new(std::io::stdout())
+    } else {
+        match std::fs::OpenOptions::new().write(true).open("/dev/tty") {
+            Ok(file) => Box::new(file),
+            Err(_) => return false,
+        }
+    };
+    out.write_all(sequence.as_bytes()).is_ok() && out.flush().is_ok()
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
 fn write_to_clipboard_cmd(cmd: &str, args: &[&str], text: &str) -> bool {
     let mut child = match Command::new(cmd).args(args).stdin(Stdio::piped()).spawn() {
         Ok(child) => child,
@@ -114,10 +224,83 @@ pub(crate) fn modified_only_text_for_change(change: &Change) -> String {
     text
 }
 
+/// Whole-word, case-sensitive pattern for `review.keywords`; `None` when no
+/// keyword is set.
+pub(crate) fn keyword_regex(keywords: &[String]) -> Option<Regex> {
+    let words: Vec<String> = keywords
+        .iter()
+        .map(|word| word.trim())
+        .filter(|word| !word.is_empty())
+        .map(regex::escape)
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    Regex::new(&whole_word_pattern(&words.join("|"))).ok()
+}
+
+/// Lines the diff introduces: keyword highlights skip context and deletions.
+pub(crate) fn is_added_line(line: &ViewLine) -> bool {
+    matches!(
+        line.kind,
+        LineKind::Inserted | LineKind::PendingInsert | LineKind::Modified | LineKind::PendingModify
+    )
+}
+
 pub(crate) fn line_has_query(text: &str, regex: &Regex) -> bool {
     regex.is_match(text)
 }
 
+/// Wrap a search pattern in word boundaries, leaving alone a side the query
+/// already anchors with `^`/`$` or `\b`.
+pub(crate) fn whole_word_pattern(pattern: &str) -> String {
+    let start = if pattern.starts_with('^') || pattern.starts_with("\\b") {
+        ""
+    } else {
+        "\\b"
+    };
+    let anchored_end =
+        (pattern.ends_with('$') && !pattern.ends_with("\\$")) || pattern.ends_with("\\b");
+    let end = if anchored_end { "" } else { "\\b" };
+    format!("{start}(?:{pattern}){end}")
+}
+
+/// Markdown fence info string for a syntect syntax name; empty for plain text
+/// and names that don't reduce to a single word.
+pub(crate) fn markdown_language_tag(syntax_name: &str) -> String {
+    let tag = match syntax_name {
+        "Plain Text" => "",
+        "C++" => "cpp",
+        "C#" => "csharp",
+        "Objective-C" => "objc",
+        "Objective-C++" => "objcpp",
+        "Bourne Again Shell (bash)" | "Shell-Unix-Generic" => "bash",
+        "JavaScript (Babel)" | "JavaScript" => "javascript",
+        "TypeScriptReact" => "tsx",
+        "Git Commit" => "gitcommit",
+        "Diff" => "diff",
+        "Dockerfile" => "dockerfile",
+        name if name.chars().all(|ch| ch.is_ascii_alphanumeric()) => {
+            return name.to_ascii_lowercase();
+        }
+        _ => "",
+    };
+    tag.to_string()
+}
+
+/// Fence `body` as a markdown code block, lengthening the fence past any
+/// backtick run inside it.
+pub(crate) fn markdown_code_block(tag: &str, body: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for ch in body.chars() {
+        run = if ch == '`' { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    let fence = "`".repeat(longest.max(2) + 1);
+    format!("{fence}{tag}\n{body}\n{fence}")
+}
+
 pub(crate) fn match_ranges(text: &str, regex: &Regex) -> Vec<(usize, usize)> {
     let mut ranges = Vec::new();
     for mat in regex.find_iter(text) {
@@ -126,6 +309,45 @@ pub(crate) fn match_ranges(text: &str, regex: &Regex) -> Vec<(usize, usize)> {
     ranges
 }
 
+/// Directory group a file is listed under in the file panel.
+pub(crate) fn file_list_group(display_name: &str) -> &str {
+    match display_name.rsplit_once('/') {
+        Some((dir, _)) => dir,
+        None => "Root Path",
+    }
+}
+
+/// Shortest path suffixes that tell the given paths apart: the basename, plus
+/// as many parent directories as same-named files need to differ.
+pub(crate) fn smart_path_labels(paths: &[&str]) -> Vec<String> {
+    let segments: Vec<Vec<&str>> = paths.iter().map(|path| path.split('/').collect()).collect();
+    let mut depths = vec![1usize; paths.len()];
+    loop {
+        let mut seen: FxHashMap<Vec<&str>, Vec<usize>> = FxHashMap::default();
+        for (idx, parts) in segments.iter().enumerate() {
+            let start = parts.len().saturating_sub(depths[idx]);
+            seen.entry(parts[start..].to_vec()).or_default().push(idx);
+        }
+        let mut grew = false;
+        for group in seen.values().filter(|group| group.len() > 1) {
+            for &idx in group {
+                if depths[idx] < segments[idx].len() {
+                    depths[idx] += 1;
+                    grew = true;
+                }
+            }
+        }
+        if !grew {
+            break;
+        }
+    }
+    segments
+        .iter()
+        .zip(depths)
+        .map(|(parts, depth)| parts[parts.len().saturating_sub(depth)..].join("/"))
+        .collect()
+}
+
 pub(crate) fn is_conflict_marker(line: &ViewLine) -> bool {
     let text = line.content.trim_start();
     text.starts_with("<<<<<<<") || text.starts_with("=======") || text.starts_with(">>>>>>>")
@@ -227,7 +449,87 @@ pub fn display_metrics(
 
 const FOLD_CONTEXT_MIN_LINES: usize = 8;
 
-pub(crate) fn fold_context_view(view: Vec<ViewLine>, mode: FoldContextMode) -> Vec<ViewLine> {
+/// Keep only lines whose old or new line number falls in `start..=end`.
+pub(crate) fn limit_lines_view(
+    view: Vec<ViewLine>,
+    range: Option<(usize, usize)>,
+) -> Vec<ViewLine> {
+    let Some((start, end)) = range else {
+        return view;
+    };
+    let in_range = |line: Option<usize>| line.is_some_and(|n| (start..=end).contains(&n));
+    view.into_iter()
+        .filter(|line| in_range(line.old_line) || in_range(line.new_line))
+        .collect()
+}
+
+/// Replace each collapsed hunk with one fold line that keeps its hunk index,
+/// so hunk navigation still lands on it. Hunks `skipped` by `--hunks` fold
+/// the same way under their own label.
+pub(crate) fn collapse_hunks_view(
+    view: Vec<ViewLine>,
+    collapsed: &[usize],
+    skipped: impl Fn(usize) -> bool,
+) -> Vec<ViewLine> {
+    if collapsed.is_empty()
+        && !view
+            .iter()
+            .any(|line| line.hunk_index.is_some_and(&skipped))
+    {
+        return view;
+    }
+    let mut out: Vec<ViewLine> = Vec::with_capacity(view.len());
+    let mut idx = 0usize;
+    while idx < view.len() {
+        let Some(hunk) = view[idx]
+            .hunk_index
+            .filter(|hunk| collapsed.contains(hunk) || skipped(*hunk))
+        else {
+            out.push(view[idx].clone());
+            idx += 1;
+            continue;
+        };
+        let start = idx;
+        while idx < view.len() && view[idx].hunk_index == Some(hunk) {
+            idx += 1;
+        }
+        let count = idx - start;
+        let label = if count == 1 { "line" } else { "lines" };
+        let state = if skipped(hunk) {
+            "not under review"
+        } else {
+            "reviewed"
+        };
+        let text = format!("… hunk {} {state} ({count} {label})", hunk + 1);
+        out.push(ViewLine {
+            content: text.clone(),
+            spans: vec![ViewSpan {
+                text,
+                kind: ViewSpanKind::Equal,
+            }],
+            kind: LineKind::Context,
+            old_line: None,
+            new_line: None,
+            is_active: false,
+            is_active_change: false,
+            is_primary_active: false,
+            show_hunk_extent: false,
+            change_id: view[start].change_id,
+            hunk_index: Some(hunk),
+            has_changes: false,
+            moved: false,
+        });
+    }
+    out
+}
+
+/// Fold long context runs outside hunks. `expand(hunk)` gives the extra
+/// `(above, below)` lines to keep unfolded next to each hunk.
+pub(crate) fn fold_context_view(
+    view: Vec<ViewLine>,
+    mode: FoldContextMode,
+    expand: impl Fn(usize) -> (usize, usize),
+) -> Vec<ViewLine> {
     if !mode.is_enabled() {
         return view;
     }
@@ -255,8 +557,17 @@ pub(crate) fn fold_context_view(view: Vec<ViewLine>, mode: FoldContextMode) -> V
                     break;
                 }
             }
-            let count = end - start;
+            let top = start
+                .checked_sub(1)
+                .and_then(|prev| view[prev].hunk_index)
+                .map_or(0, |hunk| expand(hunk).1);
+            let bottom = view
+                .get(end)
+                .and_then(|next| next.hunk_index)
+                .map_or(0, |hunk| expand(hunk).0);
+            let count = (end - start).saturating_sub(top + bottom);
             if count >= FOLD_CONTEXT_MIN_LINES {
+                out.extend_from_slice(&view[start..start + top]);
                 let text = if mode.show_counts() {
                     let label = if count == 1 { "line" } else { "lines" };
                     format!("… {count} {label}")
@@ -279,10 +590,14 @@ pub(crate) fn fold_context_view(view: Vec<ViewLine>, mode: FoldContextMode) -> V
                     change_id: 0,
                     hunk_index: None,
                     has_changes: false,
+                    moved: false,
                 });
-                idx = end;
-                continue;
+                out.extend_from_slice(&view[end - bottom..end]);
+            } else {
+                out.extend_from_slice(&view[start..end]);
             }
+            idx = end;
+            continue;
         }
         out.push(view[idx].clone());
         idx += 1;
@@ -290,9 +605,139 @@ pub(crate) fn fold_context_view(view: Vec<ViewLine>, mode: FoldContextMode) -> V
     out
 }
 
+/// Insert a `text` row halfway through the context between each pair of
+/// hunks, unless a fold line already separates them.
+pub(crate) fn hunk_separator_view(view: Vec<ViewLine>, text: &str) -> Vec<ViewLine> {
+    let mut insert_at: Vec<usize> = Vec::new();
+    let mut prev_end: Option<usize> = None;
+    for (idx, line) in view.iter().enumerate() {
+        let Some(hunk) = line.hunk_index else {
+            continue;
+        };
+        let starts_hunk = idx == 0 || view[idx - 1].hunk_index != Some(hunk);
+        if starts_hunk {
+            if let Some(end) = prev_end {
+                let gap = &view[end + 1..idx];
+                if !gap.iter().any(is_fold_line) {
+                    insert_at.push(end + 1 + gap.len() / 2);
+                }
+            }
+        }
+        prev_end = Some(idx);
+    }
+    if insert_at.is_empty() {
+        return view;
+    }
+    let mut out: Vec<ViewLine> = Vec::with_capacity(view.len() + insert_at.len());
+    let mut pending = insert_at.into_iter().peekable();
+    for (idx, line) in view.into_iter().enumerate() {
+        if pending.next_if_eq(&idx).is_some() {
+            out.push(ViewLine {
+                content: text.to_string(),
+                spans: vec![ViewSpan {
+                    text: text.to_string(),
+                    kind: ViewSpanKind::Equal,
+                }],
+                kind: LineKind::Context,
+                old_line: None,
+                new_line: None,
+                is_active: false,
+                is_active_change: false,
+                is_primary_active: false,
+                show_hunk_extent: false,
+                change_id: 0,
+                hunk_index: None,
+                has_changes: false,
+                moved: false,
+            });
+        }
+        out.push(line);
+    }
+    out
+}
+
+/// `@@ -10,5 +10,7 @@ (+3 -1)`: the old and new line ranges from the hunk's
+/// first to last changed line, then how many lines it adds and removes (a
+/// modified line counts as both).
+pub(crate) fn hunk_header_text(hunk: &Hunk, changes: &[Change]) -> String {
+    let (old_lines, new_lines) = hunk_changed_lines(hunk, changes);
+    let ((old_start, old_count), (new_start, new_count)) = hunk_ranges(hunk, changes);
+    format!(
+        "@@ -{old_start},{old_count} +{new_start},{new_count} @@ (+{} -{})",
+        new_lines.len(),
+        old_lines.len()
+    )
+}
+
+/// `(start, count)` of a hunk's old and new sides, from its first to last
+/// changed line. An empty side names the line before it, as in `git diff -U0`.
+pub(crate) fn hunk_ranges(hunk: &Hunk, changes: &[Change]) -> ((usize, usize), (usize, usize)) {
+    let (old_lines, new_lines) = hunk_changed_lines(hunk, changes);
+    let range = |lines: &BTreeSet<usize>, start: Option<usize>| match (lines.first(), lines.last())
+    {
+        (Some(first), Some(last)) => (*first, last - first + 1),
+        _ => (start.unwrap_or(1).saturating_sub(1), 0),
+    };
+    (
+        range(&old_lines, hunk.old_start),
+        range(&new_lines, hunk.new_start),
+    )
+}
+
+fn hunk_changed_lines(hunk: &Hunk, changes: &[Change]) -> (BTreeSet<usize>, BTreeSet<usize>) {
+    let mut old_lines = BTreeSet::new();
+    let mut new_lines = BTreeSet::new();
+    for span in hunk
+        .change_ids
+        .iter()
+        .filter_map(|id| changes.get(*id))
+        .flat_map(|change| &change.spans)
+    {
+        if let Some(line) = span.old_line.filter(|_| span.kind != ChangeKind::Insert) {
+            old_lines.insert(line);
+        }
+        if let Some(line) = span.new_line.filter(|_| span.kind != ChangeKind::Delete) {
+            new_lines.insert(line);
+        }
+    }
+    (old_lines, new_lines)
+}
+
+/// Insert `headers[hunk]` as a row above the first line of each hunk.
+pub(crate) fn hunk_header_view(view: Vec<ViewLine>, headers: &[String]) -> Vec<ViewLine> {
+    let mut out: Vec<ViewLine> = Vec::with_capacity(view.len() + headers.len());
+    let mut next_hunk = 0usize;
+    for line in view {
+        if let Some(hunk) = line.hunk_index.filter(|hunk| *hunk >= next_hunk) {
+            next_hunk = hunk + 1;
+            if let Some(text) = headers.get(hunk) {
+                out.push(ViewLine {
+                    content: text.clone(),
+                    spans: vec![ViewSpan {
+                        text: text.clone(),
+                        kind: ViewSpanKind::Equal,
+                    }],
+                    kind: LineKind::Context,
+                    old_line: None,
+                    new_line: None,
+                    is_active: false,
+                    is_active_change: false,
+                    is_primary_active: false,
+                    show_hunk_extent: false,
+                    change_id: 0,
+                    hunk_index: None,
+                    has_changes: false,
+                    moved: false,
+                });
+            }
+        }
+        out.push(line);
+    }
+    out
+}
+
 pub(crate) fn is_fold_line(line: &ViewLine) -> bool {
     matches!(line.kind, LineKind::Context)
-        && line.hunk_index.is_none()
         && line.old_line.is_none()
         && line.new_line.is_none()
         && !line.has_changes
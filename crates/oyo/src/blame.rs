@@ -30,6 +30,22 @@ pub fn load_git_user_name(repo_root: &Path) -> Option<String> {
     }
 }
 
+/// Full `git show` output (message and diff) for a commit.
+pub fn git_show(repo_root: &Path, commit: &str) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .arg("show")
+        .arg("--no-color")
+        .arg(commit)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 pub fn blame_line(
     repo_root: &Path,
     file_path: &Path,
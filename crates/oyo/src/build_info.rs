@@ -0,0 +1,113 @@
+//! Version, build and environment details for `--build-info` and the
+//! about popup, so bug reports say which build and config were in use
+
+use std::path::PathBuf;
+
+/// `(label, value)` rows, in display order.
+pub(crate) fn build_info(config_paths: &[PathBuf]) -> Vec<(&'static str, String)> {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    let config = if config_paths.is_empty() {
+        "none (defaults)".to_string()
+    } else {
+        config_paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    vec![
+        ("version", env!("CARGO_PKG_VERSION").to_string()),
+        ("commit", env!("OYO_BUILD_COMMIT").to_string()),
+        (
+            "build",
+            format!(
+                "{profile} ({}-{})",
+                std::env::consts::ARCH,
+                std::env::consts::OS
+            ),
+        ),
+        ("config", config),
+        ("terminal", terminal_name()),
+        ("truecolor", yes_no(truecolor_supported())),
+        (
+            "clipboard",
+            clipboard_tool().unwrap_or("unavailable").to_string(),
+        ),
+    ]
+}
+
+/// Rows as `label: value` lines, labels padded to line up.
+pub(crate) fn format_build_info(rows: &[(&'static str, String)]) -> String {
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    rows.iter()
+        .map(|(label, value)| format!("{label:<width$}  {value}\n"))
+        .collect()
+}
+
+fn terminal_name() -> String {
+    let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+    match (var("TERM_PROGRAM"), var("TERM")) {
+        (Some(program), term) => {
+            let version = var("TERM_PROGRAM_VERSION")
+                .map(|version| format!(" {version}"))
+                .unwrap_or_default();
+            match term {
+                Some(term) => format!("{program}{version} (TERM={term})"),
+                None => format!("{program}{version}"),
+            }
+        }
+        (None, Some(term)) => term,
+        (None, None) => "unknown".to_string(),
+    }
+}
+
+fn truecolor_supported() -> bool {
+    std::env::var("COLORTERM").is_ok_and(|value| matches!(value.as_str(), "truecolor" | "24bit"))
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.to_string()
+}
+
+/// The first command `copy_to_clipboard` would use that is on `PATH`.
+fn clipboard_tool() -> Option<&'static str> {
+    let candidates: &[&str] = if cfg!(target_os = "macos") {
+        &["pbcopy"]
+    } else if cfg!(target_os = "linux") {
+        &["wl-copy", "xclip", "xsel"]
+    } else if cfg!(target_os = "windows") {
+        &["clip"]
+    } else {
+        &[]
+    };
+    candidates.iter().copied().find(|tool| on_path(tool))
+}
+
+fn on_path(command: &str) -> bool {
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+    let file = format!("{command}{}", std::env::consts::EXE_SUFFIX);
+    std::env::split_paths(&path).any(|dir| dir.join(&file).is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_build_info;
+
+    #[test]
+    fn format_build_info_aligns_labels() {
+        let rows = vec![
+            ("version", "0.1.0".to_string()),
+            ("config", "none (defaults)".to_string()),
+        ];
+        assert_eq!(
+            format_build_info(&rows),
+            "version  0.1.0\nconfig   none (defaults)\n"
+        );
+    }
+}
@@ -2,25 +2,57 @@
 //!
 //! Config file location: `~/.config/oyo/config.toml` (XDG_CONFIG_HOME)
 //!
+//! A project `.oyo.toml` in the working directory or a parent (up to the
+//! repository root) is layered over it field by field. Keys that run
+//! commands or read files (`[editor]`, `ui.theme.path`, theme names given as
+//! paths) are ignored there, since a cloned repository supplies that file.
+//! `--config <path>` replaces both.
+//!
 //! Example config:
 //! ```toml
 //! [ui]
 //! zen = false
 //! topbar = true
 //! auto_center = true
+//! startup_anchor = "center" # center | top
 //! overscroll = false
 //! view_mode = "unified"
 //! line_wrap = false
+//! wrap_column = 0
+//! color_column = 0
+//! commit_message = true
+//! review_progress = true
+//! hunk_step_progress = true
+//! show_time = "off" # off | elapsed | clock | both
+//! locate_flash = true
 //! scrollbar = false
-//! strikethrough_deletions = false
+//! hscrollbar = false
+//! indent_guides = false
+//! indent_guide_width = 4
+//! confirm_quit = "never"
+//! deletion_style = "plain" # plain | strikethrough | dim | background
 //! gutter_signs = true
+//! gutter_side = "left" # left | right
+//! highlight_trailing_ws = false
+//! show_keys = false
+//! # palette = "default" # default | deuteranopia | protanopia | high_contrast
 //! # [ui.split]
 //! # align_lines = false
 //! # align_fill = "╱"
+//! # orientation = "side_by_side" # side_by_side | top_bottom
 //! primary_marker = "▶"
 //! primary_marker_right = "◀"
+//! # primary_marker_inactive = "▷"
+//! # primary_marker_right_inactive = "◁"
 //! extent_marker = "▌"
 //! extent_marker_right = "▐"
+//! # extent_marker_insert = "┃"
+//! # extent_marker_delete = "╏"
+//! # [navigation]
+//! # sync_line_across_files = false
+//! # auto_advance_file = false
+//! # collapse_reviewed_hunks = false
+//! # space = "autoplay" # autoplay | page_down (page down in no-step mode)
 //! # [navigation.wrap]
 //! # step = "none"
 //! # hunk = "none"
@@ -40,16 +72,24 @@
 //! autoplay = false
 //! animation = true
 //! auto_step_on_enter = true
+//! auto_step_delay_ms = 0
 //! auto_step_blank_files = true
+//! empty_step = "message" # message | advance | neighbor
+//! autoplay_indicator = true
+//! on_autoplay_end = "stop" # stop | loop | next_file | quit
 //!
 //! [files]
 //! panel_visible = true
 //! panel_width = 30
 //! counts = "active"
+//! sort = "none" # none | path | changes | status
+//! continuous = false
 //!
 //! [files.scan]
 //! git_ignore = "auto" # auto | true | false
 //! ignore_globs = [".git/**", ".jj/**", ".hg/**", ".svn/**"]
+//! # max_depth = 3
+//! lazy_load_mb = 256
 //!
 //! [comments.mentions]
 //! file_scope = "repo" # changed | repo
@@ -60,6 +100,14 @@
 //! # args = ["+{line}", "{file}"]
 //! open_at_line = true
 //!
+//! [search]
+//! wrap_files = false
+//!
+//! [review]
+//! comment_template = "{path}:{lines}:\n{quote}\n\n"
+//! old_header = "--- old"
+//! new_header = "+++ new"
+//!
 //! [keybindings.global]
 //! open_command_palette = ["ctrl-p"]
 //! open_file_search = ["ctrl-shift-p"]
@@ -77,6 +125,7 @@ use serde::{de, Deserialize};
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
+use unicode_width::UnicodeWidthStr;
 
 // ============================================================================
 // Theme Configuration
@@ -126,6 +175,9 @@ pub struct ThemeTokens {
 pub struct ThemeConfig {
     /// Built-in theme name (e.g., "tokyonight")
     pub name: Option<String>,
+    /// Theme file (TOML or JSON, same schema) layered over the named theme.
+    /// Relative paths are resolved against the config directory.
+    pub path: Option<PathBuf>,
     /// Theme mode: "dark" or "light"
     pub mode: Option<String>,
     /// Named color definitions (e.g., green1 = "#A3BE8C")
@@ -273,6 +325,14 @@ impl ThemeConfig {
             .and_then(|name| ThemeConfig::load_named(name, light_mode))
             .unwrap_or_default();
 
+        if let Some((file, _)) = self
+            .file_path()
+            .and_then(|path| Self::load_file(&path).ok())
+        {
+            base.defs.extend(file.defs);
+            merge_theme_tokens(&mut base.theme, &file.theme);
+        }
+
         base.name = self.name.clone();
         if self.mode.is_some() {
             base.mode = self.mode.clone();
@@ -305,6 +365,114 @@ impl ThemeConfig {
         config.name = Some(normalize_custom_theme_name(name));
         Some(config)
     }
+
+    fn file_path(&self) -> Option<PathBuf> {
+        let path = self.path.as_ref()?;
+        if path.is_relative() {
+            if let Some(dir) = Config::config_path().as_deref().and_then(Path::parent) {
+                return Some(dir.join(path));
+            }
+        }
+        Some(path.clone())
+    }
+
+    /// Load a theme file, TOML when it ends in `.toml` and JSON otherwise.
+    /// Colors that do not parse are dropped so the named theme's value (or
+    /// the built-in default) shows through; the warnings list them along
+    /// with any colors the file leaves out.
+    pub fn load_file(path: &Path) -> Result<(ThemeConfig, Vec<String>), String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read theme file {}: {}", path.display(), e))?;
+        let is_toml = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("toml"));
+        let mut config: ThemeConfig = if is_toml {
+            toml::from_str(&content).map_err(|e| e.to_string())
+        } else {
+            serde_json::from_str(&content).map_err(|e| e.to_string())
+        }
+        .map_err(|e| format!("Failed to parse theme file {}: {}", path.display(), e))?;
+
+        let defs = config.defs.clone();
+        let mut missing = Vec::new();
+        let mut invalid = Vec::new();
+        for (key, token) in config.theme.resolved_tokens_mut() {
+            let Some(value) = token.as_ref() else {
+                missing.push(key);
+                continue;
+            };
+            let valid = |value: &str| {
+                let transparent =
+                    matches!(value.trim().to_lowercase().as_str(), "transparent" | "none");
+                color::resolve_color(value, &defs).is_some()
+                    || (transparent && (key.starts_with("background") || key.ends_with("Bg")))
+            };
+            if !valid(&value.dark) || value.light.as_deref().is_some_and(|v| !valid(v)) {
+                invalid.push(key);
+                *token = None;
+            }
+        }
+
+        let mut warnings = Vec::new();
+        if !invalid.is_empty() {
+            warnings.push(format!(
+                "theme file {}: invalid colors for {}; using defaults",
+                path.display(),
+                invalid.join(", ")
+            ));
+        }
+        if !missing.is_empty() {
+            warnings.push(format!(
+                "theme file {}: no colors for {}; using defaults",
+                path.display(),
+                missing.join(", ")
+            ));
+        }
+        Ok((config, warnings))
+    }
+
+    /// Problems with `path`, for the caller to report. An unreadable file
+    /// leaves the named theme in place.
+    pub fn file_warnings(&self) -> Vec<String> {
+        let Some(path) = self.file_path() else {
+            return Vec::new();
+        };
+        match Self::load_file(&path) {
+            Ok((_, warnings)) => warnings,
+            Err(err) => vec![err],
+        }
+    }
+}
+
+impl ThemeTokens {
+    /// Tokens that feed `ResolvedTheme`, keyed as they appear in theme files.
+    fn resolved_tokens_mut(&mut self) -> [(&'static str, &mut Option<DarkLight>); 22] {
+        [
+            ("text", &mut self.text),
+            ("textMuted", &mut self.text_muted),
+            ("primary", &mut self.primary),
+            ("accent", &mut self.accent),
+            ("error", &mut self.error),
+            ("warning", &mut self.warning),
+            ("success", &mut self.success),
+            ("info", &mut self.info),
+            ("background", &mut self.background),
+            ("backgroundPanel", &mut self.background_panel),
+            ("backgroundElement", &mut self.background_element),
+            ("border", &mut self.border),
+            ("borderActive", &mut self.border_active),
+            ("borderSubtle", &mut self.border_subtle),
+            ("diffAdded", &mut self.diff_added),
+            ("diffAddedBg", &mut self.diff_added_bg),
+            ("diffRemoved", &mut self.diff_removed),
+            ("diffRemovedBg", &mut self.diff_removed_bg),
+            ("diffContext", &mut self.diff_context),
+            ("diffLineNumber", &mut self.diff_line_number),
+            ("diffExtMarker", &mut self.diff_ext_marker),
+            ("diffModifiedBg", &mut self.diff_modified_bg),
+        ]
+    }
 }
 
 /// Resolved theme — all ratatui Colors ready to use
@@ -385,6 +553,32 @@ impl ResolvedTheme {
     pub fn warning_dim(&self) -> Color {
         color::dim_color(self.warning)
     }
+
+    /// Remap insert/delete/modify colors to the given diff palette.
+    /// Gutter signs, extent markers and line backgrounds all derive from these.
+    pub fn apply_palette(&mut self, palette: DiffPalette) {
+        let Some(([insert, delete, modify], bg_alpha)) = palette.colors() else {
+            return;
+        };
+        let to_color = |hex: &str| {
+            let rgb = color::parse_hex(hex).expect("palette colors are valid hex");
+            Color::Rgb(rgb.r, rgb.g, rgb.b)
+        };
+        let (insert, delete, modify) = (to_color(insert), to_color(delete), to_color(modify));
+        self.insert = color::gradient_from_color(insert);
+        self.delete = color::gradient_from_color(delete);
+        self.modify = color::gradient_from_color(modify);
+        // Transparent themes have no base to blend against; keep their backgrounds.
+        if let Some(bg) = self
+            .background
+            .or(self.background_panel)
+            .or(self.background_element)
+        {
+            self.diff_added_bg = color::blend_colors(bg, insert, bg_alpha);
+            self.diff_removed_bg = color::blend_colors(bg, delete, bg_alpha);
+            self.diff_modified_bg = color::blend_colors(bg, modify, bg_alpha - 0.02);
+        }
+    }
 }
 
 impl Default for ResolvedTheme {
@@ -692,20 +886,67 @@ pub struct UiConfig {
     pub topbar: bool,
     /// Auto-center on active change after stepping (like vim's zz)
     pub auto_center: bool,
+    /// Where the first change lands on launch (later centering is unaffected)
+    pub startup_anchor: StartupAnchor,
     /// Allow overscroll near EOF when centering
     pub overscroll: bool,
     /// Default view mode: "unified", "split", or "evolution"
     pub view_mode: Option<String>,
     /// Enable line wrapping (default: false, uses horizontal scroll instead)
     pub line_wrap: bool,
+    /// Wrap at this column instead of the viewport edge (0 = viewport width)
+    pub wrap_column: usize,
+    /// Draw a vertical rule after this column in non-wrap mode (0 = off)
+    pub color_column: usize,
+    /// Show the commit message (or range summary) above the first file of a git range
+    pub commit_message: bool,
+    /// Show "File 3/7 · 45% reviewed" in the top bar, from the hunks visited so far
+    pub review_progress: bool,
+    /// Status bar clock: "off", "elapsed", "clock" or "both"
+    pub show_time: ShowTimeMode,
+    /// Show "step 2/5 in hunk" in the top bar while stepping
+    pub hunk_step_progress: bool,
+    /// Briefly highlight the landing line after gg, G, :goto and search jumps
+    pub locate_flash: bool,
     /// Collapse long unchanged (context) blocks ("off", "on", or "counts")
     pub fold_context: FoldContextMode,
+    /// Row drawn between hunks in no-step mode, e.g. "┄┄┄┄" ("" for a blank
+    /// row; unset for none)
+    pub hunk_separator: Option<String>,
+    /// `@@ -a,b +c,d @@ (+i -d)` rows above hunks in no-step mode; the
+    /// current hunk's goes in the top bar while stepping
+    pub hunk_headers: bool,
+    /// Subtle background across the active line's row (default: false)
+    pub cursorline: bool,
     /// Show scrollbar (default: false)
     pub scrollbar: bool,
-    /// Show strikethrough on deleted text
+    /// Show a horizontal scrollbar when long lines overflow (default: false)
+    pub hscrollbar: bool,
+    /// Faint guides at each indent level of leading whitespace (default: false)
+    pub indent_guides: bool,
+    /// Columns between indent guides for space-indented lines; tab-indented
+    /// lines use the tab width (default: 4)
+    pub indent_guide_width: usize,
+    /// Ask before quitting after oyo changed the index (default: never)
+    pub confirm_quit: ConfirmQuitMode,
+    /// Show strikethrough on deleted text (superseded by `deletion_style`)
     pub strikethrough_deletions: bool,
-    /// Show +/- sign column in the gutter (unified/evolution)
+    /// How deleted text is marked beyond its color
+    pub deletion_style: Option<DeletionStyle>,
+    /// Show +/- sign column in the gutter (unified/evolution); false removes the column
     pub gutter_signs: bool,
+    /// Edge the sign/line-number gutter sits on ("left" or "right")
+    pub gutter_side: GutterSide,
+    /// Mark trailing spaces/tabs on added lines with the error background
+    pub highlight_trailing_ws: bool,
+    /// Show recent keypresses in a corner overlay (for screencasts)
+    pub show_keys: bool,
+    /// On quit, print the last-viewed file's diff to the normal screen so it
+    /// stays in scrollback
+    pub print_on_exit: bool,
+    /// Poll interval in ms while nothing animates or runs in the background
+    /// (default: 250; animation frames stay at ~30fps)
+    pub idle_tick_ms: u64,
     /// Syntax highlighting configuration
     pub syntax: SyntaxConfig,
     /// Unified view settings
@@ -726,10 +967,20 @@ pub struct UiConfig {
     pub primary_marker: String,
     /// Marker for right pane primary line (defaults to ◀)
     pub primary_marker_right: Option<String>,
+    /// Split view: left pane primary marker while the pane lacks focus (unset = same)
+    pub primary_marker_inactive: Option<String>,
+    /// Split view: right pane primary marker while the pane lacks focus (unset = same)
+    pub primary_marker_right_inactive: Option<String>,
     /// Marker for hunk extent lines (left pane / unified pane)
     pub extent_marker: String,
     /// Marker for right pane extent lines (defaults to ▐)
     pub extent_marker_right: Option<String>,
+    /// Extent marker for inserted lines (defaults to the pane's extent marker)
+    pub extent_marker_insert: Option<String>,
+    /// Extent marker for deleted lines (defaults to the pane's extent marker)
+    pub extent_marker_delete: Option<String>,
+    /// Diff color palette applied on top of the theme
+    pub palette: DiffPalette,
     /// Theme configuration
     pub theme: ThemeConfig,
 }
@@ -740,13 +991,34 @@ impl Default for UiConfig {
             zen: false,
             topbar: true,
             auto_center: true,
+            startup_anchor: StartupAnchor::Center,
             overscroll: false,
             view_mode: None,
             line_wrap: false,
+            wrap_column: 0,
+            color_column: 0,
+            commit_message: true,
+            review_progress: true,
+            show_time: ShowTimeMode::Off,
+            hunk_step_progress: true,
+            locate_flash: true,
             fold_context: FoldContextMode::Off,
+            hunk_separator: None,
+            hunk_headers: false,
+            cursorline: false,
             scrollbar: false,
+            hscrollbar: false,
+            indent_guides: false,
+            indent_guide_width: 4,
+            confirm_quit: ConfirmQuitMode::Never,
             strikethrough_deletions: false,
+            deletion_style: None,
             gutter_signs: true,
+            gutter_side: GutterSide::Left,
+            highlight_trailing_ws: false,
+            show_keys: false,
+            print_on_exit: false,
+            idle_tick_ms: 250,
             syntax: SyntaxConfig::default(),
             unified: UnifiedViewConfig::default(),
             split: SplitViewConfig::default(),
@@ -757,13 +1029,89 @@ impl Default for UiConfig {
             stepping: true,
             primary_marker: "▶".to_string(),
             primary_marker_right: None,
+            primary_marker_inactive: None,
+            primary_marker_right_inactive: None,
             extent_marker: "▌".to_string(),
             extent_marker_right: None,
+            extent_marker_insert: None,
+            extent_marker_delete: None,
+            palette: DiffPalette::default(),
             theme: ThemeConfig::default(),
         }
     }
 }
 
+impl UiConfig {
+    /// `deletion_style`, or strikethrough for configs that only set
+    /// `strikethrough_deletions`
+    pub fn deletion_style(&self) -> DeletionStyle {
+        self.deletion_style
+            .unwrap_or(if self.strikethrough_deletions {
+                DeletionStyle::Strikethrough
+            } else {
+                DeletionStyle::Plain
+            })
+    }
+
+    /// Resolve the theme and apply the configured diff palette
+    pub fn resolve_theme(&self, light_mode: bool) -> ResolvedTheme {
+        let mut theme = self.theme.resolve(light_mode);
+        theme.apply_palette(self.palette);
+        theme
+    }
+
+    /// Reset gutter marker glyphs that are not exactly one column wide.
+    /// Wide glyphs (emoji, CJK) would shift every gutter column.
+    pub fn validate_markers(&mut self) -> Vec<String> {
+        let defaults = UiConfig::default();
+        let mut warnings = Vec::new();
+        let mut check = |name: &str, value: &mut String, fallback: &str| {
+            if UnicodeWidthStr::width(value.as_str()) != 1 {
+                warnings.push(format!(
+                    "ui.{name} = {value:?} must be one column wide; using {fallback:?}"
+                ));
+                *value = fallback.to_string();
+            }
+        };
+        check(
+            "primary_marker",
+            &mut self.primary_marker,
+            &defaults.primary_marker,
+        );
+        check(
+            "extent_marker",
+            &mut self.extent_marker,
+            &defaults.extent_marker,
+        );
+        let mut check_opt = |name: &str, value: &mut Option<String>| {
+            let Some(marker) = value.as_ref() else {
+                return;
+            };
+            if UnicodeWidthStr::width(marker.as_str()) != 1 {
+                warnings.push(format!(
+                    "ui.{name} = {marker:?} must be one column wide; using default"
+                ));
+                *value = None;
+            }
+        };
+        check_opt("primary_marker_right", &mut self.primary_marker_right);
+        check_opt("extent_marker_right", &mut self.extent_marker_right);
+        check_opt("extent_marker_insert", &mut self.extent_marker_insert);
+        check_opt("extent_marker_delete", &mut self.extent_marker_delete);
+        warnings
+    }
+}
+
+/// When quitting asks for confirmation.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmQuitMode {
+    #[default]
+    Never,
+    /// Only after staging from inside oyo changed the index
+    IfModified,
+}
+
 /// Step wrap behavior at the ends of a file.
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -774,6 +1122,34 @@ pub enum StepWrapMode {
     File,
 }
 
+/// What a step that leaves both panes empty shows.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EmptyStepMode {
+    /// "No content at this step"
+    #[default]
+    Message,
+    /// Keep stepping the same way until something shows
+    Advance,
+    /// Move to the nearest step with content, trying the step direction first
+    Neighbor,
+}
+
+/// What autoplay does once it runs out of steps.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoplayEndMode {
+    /// Turn autoplay off
+    #[default]
+    Stop,
+    /// Restart from the first change (the last, in reverse) and keep playing
+    Loop,
+    /// Continue from the first step of the next file (forward autoplay only)
+    NextFile,
+    /// Exit oy
+    Quit,
+}
+
 /// Hunk wrap behavior at the ends of a file.
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -797,6 +1173,16 @@ pub struct WrapConfig {
 #[serde(default)]
 pub struct NavigationConfig {
     pub wrap: WrapConfig,
+    /// Land on the previous file's source line when switching files
+    pub sync_line_across_files: bool,
+    /// Step from the last change of a file into the next file's first change
+    pub auto_advance_file: bool,
+    /// Fold a hunk away once `next_hunk` moves past it (prev hunk re-expands it)
+    pub collapse_reviewed_hunks: bool,
+    /// What `toggle_autoplay` (Space) does in no-step mode
+    pub space: SpaceAction,
+    /// Fold context on entering a file with more hunks than this (0 = off)
+    pub auto_fold_threshold: usize,
 }
 
 /// Split view configuration
@@ -807,6 +1193,10 @@ pub struct SplitViewConfig {
     pub align_lines: bool,
     /// Fill character for aligned blank rows (empty = no marker)
     pub align_fill: String,
+    /// Pane layout: "side_by_side" (old left) or "top_bottom" (old on top)
+    pub orientation: SplitOrientation,
+    /// Line wrap in split view (unset = `ui.line_wrap`)
+    pub line_wrap: Option<bool>,
 }
 
 impl Default for SplitViewConfig {
@@ -814,6 +1204,8 @@ impl Default for SplitViewConfig {
         Self {
             align_lines: false,
             align_fill: "╱".to_string(),
+            orientation: SplitOrientation::SideBySide,
+            line_wrap: None,
         }
     }
 }
@@ -822,14 +1214,17 @@ impl Default for SplitViewConfig {
 #[derive(Debug, Deserialize)]
 #[serde(default)]
 pub struct UnifiedViewConfig {
-    /// How modified lines render while stepping: "mixed" or "modified"
+    /// How modified lines render while stepping: "mixed", "modified" or "old"
     pub modified_step_mode: ModifiedStepMode,
+    /// Line wrap in the single pane (unset = `ui.line_wrap`)
+    pub line_wrap: Option<bool>,
 }
 
 impl Default for UnifiedViewConfig {
     fn default() -> Self {
         Self {
             modified_step_mode: ModifiedStepMode::Mixed,
+            line_wrap: None,
         }
     }
 }
@@ -840,12 +1235,15 @@ impl Default for UnifiedViewConfig {
 pub struct EvoViewConfig {
     /// Syntax scope in evolution view: "context" or "full"
     pub syntax: EvoSyntaxMode,
+    /// Line wrap in evolution view (unset = `ui.line_wrap`)
+    pub line_wrap: Option<bool>,
 }
 
 impl Default for EvoViewConfig {
     fn default() -> Self {
         Self {
             syntax: EvoSyntaxMode::Context,
+            line_wrap: None,
         }
     }
 }
@@ -869,6 +1267,9 @@ pub struct DiffConfig {
     /// Maximum file size to render with full context (bytes)
     #[serde(default = "diff_full_context_max_bytes_default")]
     pub full_context_max_bytes: u64,
+    /// Largest binary file shown as a hex diff (bytes); 0 disables the hex view
+    #[serde(default = "diff_hex_max_bytes_default")]
+    pub hex_max_bytes: u64,
     /// Defer diff computation for large files (background compute)
     #[serde(default = "diff_defer_default")]
     pub defer: bool,
@@ -884,6 +1285,17 @@ pub struct DiffConfig {
     /// Show extent markers on unchanged context lines within a hunk
     #[serde(default = "diff_extent_marker_context_default")]
     pub extent_marker_context: bool,
+    /// Color blocks moved within a file differently from real changes
+    #[serde(default = "diff_color_moved_default")]
+    pub color_moved: bool,
+    /// Merge hunks separated only by a little context (reformatting noise)
+    pub group_adjacent: bool,
+    /// Most non-blank context lines between hunks merged by `group_adjacent`
+    #[serde(default = "diff_group_adjacent_gap_default")]
+    pub group_adjacent_gap: usize,
+    /// Diff JSON/YAML files by parsed keys instead of lines (falls back on parse
+    /// errors). Line-based actions are skipped on such files.
+    pub structural: bool,
 }
 
 impl Default for DiffConfig {
@@ -894,11 +1306,16 @@ impl Default for DiffConfig {
             highlight: diff_highlight_default(),
             max_bytes: diff_max_bytes_default(),
             full_context_max_bytes: diff_full_context_max_bytes_default(),
+            hex_max_bytes: diff_hex_max_bytes_default(),
             defer: diff_defer_default(),
             idle_ms: diff_idle_ms_default(),
             extent_marker: diff_extent_marker_default(),
             extent_marker_scope: diff_extent_marker_scope_default(),
             extent_marker_context: diff_extent_marker_context_default(),
+            color_moved: diff_color_moved_default(),
+            group_adjacent: false,
+            group_adjacent_gap: diff_group_adjacent_gap_default(),
+            structural: false,
         }
     }
 }
@@ -923,6 +1340,10 @@ fn diff_full_context_max_bytes_default() -> u64 {
     2 * 1024 * 1024
 }
 
+fn diff_hex_max_bytes_default() -> u64 {
+    64 * 1024
+}
+
 fn diff_defer_default() -> bool {
     true
 }
@@ -943,6 +1364,53 @@ fn diff_extent_marker_context_default() -> bool {
     false
 }
 
+fn diff_color_moved_default() -> bool {
+    false
+}
+
+fn diff_group_adjacent_gap_default() -> usize {
+    2
+}
+
+/// Initial placement of the first change on startup
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupAnchor {
+    /// Near the top, keeping the usual scroll margin above it
+    Top,
+    /// Vertically centered when auto_center is on
+    #[default]
+    Center,
+}
+
+/// Diff color palette (insert/delete/modify)
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffPalette {
+    /// Theme colors unchanged
+    #[default]
+    Default,
+    /// Blue/orange for red-green color blindness
+    Deuteranopia,
+    /// Blue/yellow for red-weak color blindness
+    Protanopia,
+    /// Saturated colors with stronger line backgrounds
+    HighContrast,
+}
+
+impl DiffPalette {
+    /// Insert/delete/modify hex colors and background blend strength.
+    /// Returns None for the theme's own colors.
+    fn colors(self) -> Option<([&'static str; 3], f32)> {
+        match self {
+            DiffPalette::Default => None,
+            DiffPalette::Deuteranopia => Some((["#56b4e9", "#e69f00", "#cc79a7"], 0.18)),
+            DiffPalette::Protanopia => Some((["#0099e6", "#f0e442", "#cc79a7"], 0.18)),
+            DiffPalette::HighContrast => Some((["#00e05a", "#ff3b30", "#ffd60a"], 0.30)),
+        }
+    }
+}
+
 /// Context folding display mode
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -965,6 +1433,53 @@ impl FoldContextMode {
         matches!(self, FoldContextMode::Counts)
     }
 }
+/// How deleted text is marked on top of the delete color
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DeletionStyle {
+    /// Color only
+    #[default]
+    Plain,
+    /// Strike through deleted text
+    Strikethrough,
+    /// Dim deleted text
+    Dim,
+    /// Put deleted text on a background
+    Background,
+}
+
+impl DeletionStyle {
+    /// Next style for the `S` key cycle
+    pub fn next(self) -> Self {
+        match self {
+            DeletionStyle::Plain => DeletionStyle::Strikethrough,
+            DeletionStyle::Strikethrough => DeletionStyle::Dim,
+            DeletionStyle::Dim => DeletionStyle::Background,
+            DeletionStyle::Background => DeletionStyle::Plain,
+        }
+    }
+}
+
+/// Edge of each diff pane that holds the line-number gutter
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GutterSide {
+    #[default]
+    Left,
+    Right,
+}
+
+/// Split view pane layout
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitOrientation {
+    /// Old pane on the left, new pane on the right
+    #[default]
+    SideBySide,
+    /// Old pane on top, new pane below
+    TopBottom,
+}
+
 /// Evolution view syntax scope
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -980,9 +1495,32 @@ pub enum EvoSyntaxMode {
 #[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ModifiedStepMode {
+    /// Old and new text inline
     #[default]
     Mixed,
+    /// New text only
     Modified,
+    /// Old text only
+    Old,
+}
+
+impl ModifiedStepMode {
+    /// Next mode in the runtime cycle
+    pub fn next(self) -> Self {
+        match self {
+            ModifiedStepMode::Mixed => ModifiedStepMode::Modified,
+            ModifiedStepMode::Modified => ModifiedStepMode::Old,
+            ModifiedStepMode::Old => ModifiedStepMode::Mixed,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ModifiedStepMode::Mixed => "mixed",
+            ModifiedStepMode::Modified => "modified",
+            ModifiedStepMode::Old => "old",
+        }
+    }
 }
 
 /// Diff foreground rendering mode
@@ -1004,6 +1542,25 @@ pub enum DiffHighlightMode {
     None,
 }
 
+impl DiffHighlightMode {
+    /// Next mode in the runtime cycle
+    pub fn next(self) -> Self {
+        match self {
+            DiffHighlightMode::Text => DiffHighlightMode::Word,
+            DiffHighlightMode::Word => DiffHighlightMode::None,
+            DiffHighlightMode::None => DiffHighlightMode::Text,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DiffHighlightMode::Text => "text",
+            DiffHighlightMode::Word => "word",
+            DiffHighlightMode::None => "none",
+        }
+    }
+}
+
 /// Extent marker color mode
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -1040,6 +1597,12 @@ pub struct BlameConfig {
     pub enabled: bool,
     pub mode: BlameMode,
     pub hunk_hint: bool,
+    /// Blame date format: "relative" or a format string (empty = follow ui.time)
+    pub date_format: String,
+    /// Blame view gutter width in columns; text is truncated to fit (0 = 32% of the view)
+    pub width: u16,
+    /// Start with hunks not authored by the git user dimmed (`g a` toggles)
+    pub mine_only: bool,
 }
 
 impl Default for BlameConfig {
@@ -1048,6 +1611,9 @@ impl Default for BlameConfig {
             enabled: false,
             mode: BlameMode::OneShot,
             hunk_hint: true,
+            date_format: String::new(),
+            width: 0,
+            mine_only: false,
         }
     }
 }
@@ -1192,8 +1758,16 @@ pub struct PlaybackConfig {
     pub animation_duration: u64,
     /// Auto-step to first change when entering a file at step 0
     pub auto_step_on_enter: bool,
+    /// Pause on step 0 this long before the auto-step (0 = immediate)
+    pub auto_step_delay_ms: u64,
     /// Auto-step when file would be blank at step 0 (new files)
     pub auto_step_blank_files: bool,
+    /// What to do when any step leaves nothing to show
+    pub empty_step: EmptyStepMode,
+    /// Show autoplay direction and remaining replay steps in the status bar
+    pub autoplay_indicator: bool,
+    /// What autoplay does at the last change ("stop", "loop", "next_file", "quit")
+    pub on_autoplay_end: AutoplayEndMode,
 }
 
 impl Default for PlaybackConfig {
@@ -1204,7 +1778,11 @@ impl Default for PlaybackConfig {
             animation: true,
             animation_duration: 120,
             auto_step_on_enter: true,
+            auto_step_delay_ms: 0,
             auto_step_blank_files: true,
+            empty_step: EmptyStepMode::Message,
+            autoplay_indicator: true,
+            on_autoplay_end: AutoplayEndMode::Stop,
         }
     }
 }
@@ -1219,6 +1797,14 @@ pub struct FilesConfig {
     pub panel_width: u16,
     /// When to show per-file +/- counts in the file panel
     pub counts: FileCountMode,
+    /// Order of the file panel; the diff itself keeps its file order
+    pub sort: FileSortOrder,
+    /// Start in continuous mode: all files in one no-step scroll
+    pub continuous: bool,
+    /// Keep the selected file vertically centered in the panel
+    pub center_selection: bool,
+    /// How the panel labels files: grouped, full, basename or smart
+    pub path_style: FilePathStyle,
     /// Directory scan filtering configuration
     pub scan: FileScanConfig,
 }
@@ -1229,6 +1815,10 @@ impl Default for FilesConfig {
             panel_visible: true,
             panel_width: 30,
             counts: FileCountMode::Active,
+            sort: FileSortOrder::None,
+            continuous: false,
+            center_selection: false,
+            path_style: FilePathStyle::Grouped,
             scan: FileScanConfig::default(),
         }
     }
@@ -1278,6 +1868,11 @@ pub struct FileScanConfig {
     pub git_ignore: GitIgnoreMode,
     /// Glob patterns to exclude from manual directory scans.
     pub ignore_globs: Vec<String>,
+    /// Deepest level to descend into when comparing directories (unset = unlimited).
+    pub max_depth: Option<usize>,
+    /// Past this many MiB of scanned files, read each file only when it's
+    /// first selected (0 = always).
+    pub lazy_load_mb: u64,
 }
 
 impl Default for FileScanConfig {
@@ -1288,6 +1883,8 @@ impl Default for FileScanConfig {
                 .iter()
                 .map(|pattern| (*pattern).to_string())
                 .collect(),
+            max_depth: None,
+            lazy_load_mb: oyo_core::multi::DEFAULT_LAZY_LOAD_BYTES / (1024 * 1024),
         }
     }
 }
@@ -1298,16 +1895,74 @@ impl Default for FileScanConfig {
 pub struct NoStepConfig {
     /// Jump to the first hunk when entering a file in no-step mode
     pub auto_jump_on_enter: bool,
+    /// Context lines kept above the hunk start when jumping between hunks
+    pub hunk_context: usize,
 }
 
 impl Default for NoStepConfig {
     fn default() -> Self {
         Self {
             auto_jump_on_enter: true,
+            hunk_context: 0,
         }
     }
 }
 
+/// Review helpers configuration.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct ReviewConfig {
+    /// Template copied by the comment-template key. Supports {path}, {lines} and {quote}.
+    pub comment_template: String,
+    /// Label above the old side when copying a before/after block
+    pub old_header: String,
+    /// Label above the new side when copying a before/after block
+    pub new_header: String,
+    /// Format of the review comments printed on quit and exported with `g E`
+    pub output_format: ReviewOutputFormat,
+    /// Words flagged in added and modified lines, e.g. `["TODO", "FIXME"]`
+    /// (whole words, case-sensitive; empty turns it off)
+    pub keywords: Vec<String>,
+}
+
+/// Review comment output format.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewOutputFormat {
+    /// `=== Comment N ===` blocks
+    #[default]
+    Text,
+    Markdown,
+    Json,
+}
+
+impl ReviewOutputFormat {
+    /// Extension of the default export file
+    pub fn extension(self) -> &'static str {
+        match self {
+            ReviewOutputFormat::Text => "txt",
+            ReviewOutputFormat::Markdown => "md",
+            ReviewOutputFormat::Json => "json",
+        }
+    }
+}
+
+impl Default for ReviewConfig {
+    fn default() -> Self {
+        Self {
+            comment_template: DEFAULT_COMMENT_TEMPLATE.to_string(),
+            old_header: DEFAULT_OLD_HEADER.to_string(),
+            new_header: DEFAULT_NEW_HEADER.to_string(),
+            output_format: ReviewOutputFormat::Text,
+            keywords: Vec::new(),
+        }
+    }
+}
+
+pub const DEFAULT_COMMENT_TEMPLATE: &str = "{path}:{lines}:\n{quote}\n\n";
+pub const DEFAULT_OLD_HEADER: &str = "--- old";
+pub const DEFAULT_NEW_HEADER: &str = "+++ new";
+
 /// External editor configuration.
 #[derive(Debug, Deserialize)]
 #[serde(default)]
@@ -1330,17 +1985,154 @@ impl Default for EditorConfig {
     }
 }
 
+/// Clipboard configuration.
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+pub struct ClipboardConfig {
+    /// Copy through the terminal with OSC 52
+    pub osc52: Osc52Mode,
+}
+
+/// Search configuration.
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+pub struct SearchConfig {
+    /// `n`/`N` past the last match continue in the next file with a match
+    pub wrap_files: bool,
+}
+
+/// Live-refresh configuration.
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+pub struct WatchConfig {
+    /// After a file refresh, stay on the newest lines when the view was at the bottom
+    pub follow: bool,
+}
+
+/// When yanks go through the terminal's OSC 52 clipboard
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Osc52Mode {
+    /// Over SSH, or when no clipboard tool is available
+    #[default]
+    Auto,
+    /// Always, instead of clipboard tools
+    On,
+    /// Never; only clipboard tools
+    Off,
+}
+
+/// Status bar clock for timeboxed reviews
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShowTimeMode {
+    #[default]
+    Off,
+    /// Time since oy started
+    Elapsed,
+    /// Current local time
+    Clock,
+    /// Current time and elapsed time
+    Both,
+}
+
 /// File list counts display behavior
-#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum FileCountMode {
     #[default]
     Active,
     Focused,
+    /// Counts on every row, regardless of selection or focus
+    #[serde(alias = "always")]
     All,
     Off,
 }
 
+impl FileCountMode {
+    /// Next mode in the runtime cycle
+    pub fn next(self) -> Self {
+        match self {
+            FileCountMode::Active => FileCountMode::Focused,
+            FileCountMode::Focused => FileCountMode::All,
+            FileCountMode::All => FileCountMode::Off,
+            FileCountMode::Off => FileCountMode::Active,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FileCountMode::Active => "active",
+            FileCountMode::Focused => "focused",
+            FileCountMode::All => "always",
+            FileCountMode::Off => "off",
+        }
+    }
+}
+
+/// How the file panel labels files
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FilePathStyle {
+    /// Basenames under directory headers
+    #[default]
+    Grouped,
+    /// Full paths, one flat list
+    Full,
+    /// Basenames only, one flat list
+    Basename,
+    /// Basenames, with just enough parent directories to tell same-named files apart
+    Smart,
+}
+
+impl FilePathStyle {
+    /// Next style in the runtime cycle
+    pub fn next(self) -> Self {
+        match self {
+            FilePathStyle::Grouped => FilePathStyle::Full,
+            FilePathStyle::Full => FilePathStyle::Basename,
+            FilePathStyle::Basename => FilePathStyle::Smart,
+            FilePathStyle::Smart => FilePathStyle::Grouped,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FilePathStyle::Grouped => "grouped",
+            FilePathStyle::Full => "full",
+            FilePathStyle::Basename => "basename",
+            FilePathStyle::Smart => "smart",
+        }
+    }
+}
+
+/// File panel sort order
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileSortOrder {
+    /// Diff order
+    #[default]
+    None,
+    /// Alphabetical by path
+    Path,
+    /// Most inserted + deleted lines first
+    Changes,
+    /// Added, then modified, renamed and deleted
+    Status,
+}
+
+/// No-step behavior of the autoplay key
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpaceAction {
+    /// Autoplay only; nothing happens without stepping
+    #[default]
+    Autoplay,
+    /// Page down, as in `less`; stepping mode still autoplays
+    #[serde(alias = "pagedown")]
+    PageDown,
+}
+
 /// Inline mention file source scope.
 #[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -1434,6 +2226,87 @@ impl<'de> Deserialize<'de> for KeybindingsConfig {
     }
 }
 
+/// Project config file, searched from the working directory up
+const PROJECT_CONFIG_FILE: &str = ".oyo.toml";
+
+/// Nearest `.oyo.toml` from `start` up to the repository root. Outside a
+/// repository only `start` itself is checked.
+fn project_config_path(start: &Path) -> Option<PathBuf> {
+    let root = start
+        .ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .unwrap_or(start);
+    for dir in start.ancestors() {
+        let path = dir.join(PROJECT_CONFIG_FILE);
+        if path.is_file() {
+            return Some(path);
+        }
+        if dir == root {
+            break;
+        }
+    }
+    None
+}
+
+/// Remove keys from a project layer that would let a checked-out repository
+/// run commands or read arbitrary files; returns a warning per removed key.
+fn strip_untrusted_keys(path: &Path, layer: &mut toml::Table) -> Vec<String> {
+    let mut removed = Vec::new();
+    if layer.remove("editor").is_some() {
+        removed.push("editor".to_string());
+    }
+    if let Some(toml::Value::Table(ui)) = layer.get_mut("ui") {
+        if let Some(toml::Value::Table(theme)) = ui.get_mut("theme") {
+            if theme.remove("path").is_some() {
+                removed.push("ui.theme.path".to_string());
+            }
+            if theme.get("name").is_some_and(is_path_value) {
+                theme.remove("name");
+                removed.push("ui.theme.name".to_string());
+            }
+        }
+        if let Some(toml::Value::Table(syntax)) = ui.get_mut("syntax") {
+            if syntax.get("theme").is_some_and(is_path_value) {
+                syntax.remove("theme");
+                removed.push("ui.syntax.theme".to_string());
+            }
+        }
+    }
+    removed
+        .into_iter()
+        .map(|key| format!("{}: ignoring {} in a project config", path.display(), key))
+        .collect()
+}
+
+fn is_path_value(value: &toml::Value) -> bool {
+    value
+        .as_str()
+        .is_some_and(|name| name.contains(['/', '\\']) || Path::new(name).is_absolute())
+}
+
+fn parse_config_table(path: &Path, content: &str) -> Result<toml::Table, String> {
+    toml::from_str(content).map_err(|e| format!("Failed to parse config {}: {}", path.display(), e))
+}
+
+fn config_from_table(path: &Path, table: toml::Table) -> Result<Config, String> {
+    toml::Value::Table(table)
+        .try_into()
+        .map_err(|e| format!("Failed to parse config {}: {}", path.display(), e))
+}
+
+fn merge_toml_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(value)) => {
+                merge_toml_tables(existing, value);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
 /// Root configuration
 #[derive(Debug, Deserialize, Default)]
 #[serde(default)]
@@ -1445,6 +2318,10 @@ pub struct Config {
     pub no_step: NoStepConfig,
     pub comments: CommentsConfig,
     pub editor: EditorConfig,
+    pub clipboard: ClipboardConfig,
+    pub watch: WatchConfig,
+    pub search: SearchConfig,
+    pub review: ReviewConfig,
     pub keybindings: KeybindingsConfig,
 }
 
@@ -1480,20 +2357,100 @@ impl Config {
         Self::config_paths().into_iter().find(|p| p.exists())
     }
 
-    /// Load config from XDG config path
+    /// Config files [`Config::try_load`] reads, lowest priority first
+    pub fn loaded_paths(explicit: Option<&Path>) -> Vec<PathBuf> {
+        match explicit {
+            Some(path) => vec![path.to_path_buf()],
+            None => Self::config_path()
+                .into_iter()
+                .chain(
+                    std::env::current_dir()
+                        .ok()
+                        .and_then(|cwd| project_config_path(&cwd)),
+                )
+                .collect(),
+        }
+    }
+
+    /// Load config from `explicit`, or the XDG config path with any project
+    /// `.oyo.toml` layered over it
     /// Returns default config if file doesn't exist or can't be parsed
-    pub fn load() -> Self {
-        Self::config_path()
-            .and_then(|path| std::fs::read_to_string(&path).ok())
-            .and_then(|content| {
-                toml::from_str(&content)
-                    .map_err(|e| {
-                        eprintln!("Warning: Failed to parse config: {}", e);
-                        e
-                    })
-                    .ok()
-            })
-            .unwrap_or_default()
+    pub fn load(explicit: Option<&Path>) -> Self {
+        let (mut config, warnings) = Self::try_load(explicit).unwrap_or_else(|e| {
+            eprintln!("Warning: {}", e);
+            (Config::default(), Vec::new())
+        });
+        for warning in warnings.into_iter().chain(config.ui.validate_markers()) {
+            eprintln!("Warning: {}", warning);
+        }
+        config
+    }
+
+    /// Like [`Config::load`], reporting read or parse errors
+    /// Returns default config if no config file exists. A broken project
+    /// `.oyo.toml` only produces a warning; the user config still applies.
+    pub fn try_load(explicit: Option<&Path>) -> Result<(Self, Vec<String>), String> {
+        let read = |path: &Path| {
+            fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read config {}: {}", path.display(), e))
+        };
+        if let Some(path) = explicit {
+            return Self::parse_layers(Some((path, &read(path)?)), None);
+        }
+        let user = Self::config_path();
+        let user = match user.as_deref() {
+            Some(path) => Some((path, read(path)?)),
+            None => None,
+        };
+        let project = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| project_config_path(&cwd));
+        let project = project.as_deref().map(|path| (path, read(path)));
+        let user = user
+            .as_ref()
+            .map(|(path, content)| (*path, content.as_str()));
+        match project {
+            Some((path, Ok(content))) => Self::parse_layers(user, Some((path, &content))),
+            Some((_, Err(e))) => {
+                let (config, mut warnings) = Self::parse_layers(user, None)?;
+                warnings.push(format!("{}; ignoring it", e));
+                Ok((config, warnings))
+            }
+            None => Self::parse_layers(user, None),
+        }
+    }
+
+    /// Parse the user config and layer a project config over it key by key.
+    /// Errors in the user layer fail the load; errors in the project layer
+    /// are returned as warnings and the user layer is kept.
+    fn parse_layers(
+        user: Option<(&Path, &str)>,
+        project: Option<(&Path, &str)>,
+    ) -> Result<(Self, Vec<String>), String> {
+        let (base_table, base) = match user {
+            Some((path, content)) => {
+                let table = parse_config_table(path, content)?;
+                (table.clone(), config_from_table(path, table)?)
+            }
+            None => (toml::Table::new(), Config::default()),
+        };
+        let Some((path, content)) = project else {
+            return Ok((base, Vec::new()));
+        };
+        let mut warnings = Vec::new();
+        let merged = parse_config_table(path, content).and_then(|mut layer| {
+            warnings.extend(strip_untrusted_keys(path, &mut layer));
+            let mut merged = base_table;
+            merge_toml_tables(&mut merged, layer);
+            config_from_table(path, merged)
+        });
+        match merged {
+            Ok(config) => Ok((config, warnings)),
+            Err(e) => {
+                warnings.push(format!("{}; ignoring it", e));
+                Ok((base, warnings))
+            }
+        }
     }
 
     /// Parse view mode string to ViewMode enum
@@ -1507,3 +2464,184 @@ impl Config {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_markers_resets_wide_glyphs() {
+        let mut ui: UiConfig = toml::from_str(
+            r#"
+primary_marker = "👉"
+extent_marker = "|"
+extent_marker_right = "ab"
+extent_marker_insert = "+"
+extent_marker_delete = "🔥"
+"#,
+        )
+        .expect("ui config should parse");
+        let warnings = ui.validate_markers();
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(ui.primary_marker, "▶");
+        assert_eq!(ui.extent_marker, "|");
+        assert_eq!(ui.extent_marker_right, None);
+        assert_eq!(ui.extent_marker_insert.as_deref(), Some("+"));
+        assert_eq!(ui.extent_marker_delete, None);
+    }
+
+    #[test]
+    fn test_apply_palette_remaps_diff_colors() {
+        let base = ThemeConfig::default().resolve(false);
+        let mut theme = ThemeConfig::default().resolve(false);
+        theme.apply_palette(DiffPalette::Default);
+        assert_eq!(theme.insert_base(), base.insert_base());
+
+        theme.apply_palette(DiffPalette::Deuteranopia);
+        let Color::Rgb(r, g, b) = theme.insert_base() else {
+            panic!("palette colors are rgb");
+        };
+        assert!(b > r && b > g, "insert should be blue, got {r},{g},{b}");
+        assert_ne!(theme.delete_base(), base.delete_base());
+    }
+
+    #[test]
+    fn test_theme_file_overlays_named_theme() {
+        let path = std::env::temp_dir().join(format!("oyo-theme-{}.toml", std::process::id()));
+        fs::write(
+            &path,
+            "[defs]\nsea = \"#5E81AC\"\n\n[theme.primary]\ndark = \"sea\"\n\n[theme.text]\ndark = \"#zzz\"\n",
+        )
+        .unwrap();
+        let named = ThemeConfig {
+            name: Some("nord".to_string()),
+            ..ThemeConfig::default()
+        };
+        let config = ThemeConfig {
+            path: Some(path.clone()),
+            ..named.clone()
+        };
+
+        let warnings = config.file_warnings();
+        let theme = config.resolve(false);
+        let base = named.resolve(false);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(theme.primary, Color::Rgb(0x5E, 0x81, 0xAC));
+        assert_eq!(
+            theme.text, base.text,
+            "invalid colors keep the named theme's"
+        );
+        assert_eq!(theme.border, base.border);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].ends_with("invalid colors for text; using defaults"));
+        assert!(warnings[1].contains("no colors for textMuted, accent"));
+
+        let missing = ThemeConfig {
+            path: Some(path),
+            ..ThemeConfig::default()
+        };
+        assert!(missing.file_warnings()[0].starts_with("Failed to read theme file"));
+    }
+
+    #[test]
+    fn test_deletion_style_honors_legacy_strikethrough() {
+        let ui: UiConfig = toml::from_str("strikethrough_deletions = true").unwrap();
+        assert_eq!(ui.deletion_style(), DeletionStyle::Strikethrough);
+        let ui: UiConfig =
+            toml::from_str("strikethrough_deletions = true\ndeletion_style = \"dim\"").unwrap();
+        assert_eq!(ui.deletion_style(), DeletionStyle::Dim);
+        assert_eq!(UiConfig::default().deletion_style(), DeletionStyle::Plain);
+        assert_eq!(DeletionStyle::Background.next(), DeletionStyle::Plain);
+    }
+
+    fn parse_user(content: &str) -> Result<Config, String> {
+        Config::parse_layers(Some((Path::new("config.toml"), content)), None).map(|(c, _)| c)
+    }
+
+    fn parse_with_project(user: &str, project: &str) -> (Config, Vec<String>) {
+        Config::parse_layers(
+            Some((Path::new("config.toml"), user)),
+            Some((Path::new(".oyo.toml"), project)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_reports_errors() {
+        let config = parse_user("[ui]\nzen = true\n").expect("config should parse");
+        assert!(config.ui.zen);
+
+        let err = parse_user("[ui\nzen = true\n").unwrap_err();
+        assert!(
+            err.starts_with("Failed to parse config config.toml"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_broken_project_layer_keeps_user_config() {
+        let (config, warnings) = parse_with_project("[ui]\nzen = true\n", "[ui\n");
+        assert!(config.ui.zen);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains(".oyo.toml"), "{}", warnings[0]);
+
+        let (config, warnings) = parse_with_project("[ui]\nzen = true\n", "[ui]\nzen = \"yes\"\n");
+        assert!(config.ui.zen);
+        assert!(warnings[0].contains(".oyo.toml"), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn test_project_layer_cannot_set_commands_or_file_paths() {
+        let project = "[editor]\ncommand = \"sh\"\n[ui]\nzen = true\n\
+                       [ui.theme]\npath = \"/etc/passwd\"\nname = \"../x\"\n\
+                       [ui.syntax]\ntheme = \"/tmp/x.tmTheme\"\n";
+        let (config, warnings) = parse_with_project("[editor]\ncommand = \"nvim\"\n", project);
+        assert!(config.ui.zen);
+        assert_eq!(config.editor.command.as_deref(), Some("nvim"));
+        assert_eq!(config.ui.theme.path, None);
+        assert_eq!(config.ui.theme.name, None);
+        assert_eq!(warnings.len(), 4, "{warnings:?}");
+
+        let (config, warnings) = parse_with_project(
+            "",
+            "[ui.theme]\nname = \"gruvbox\"\n[ui.syntax]\ntheme = \"x\"\n",
+        );
+        assert_eq!(config.ui.theme.name.as_deref(), Some("gruvbox"));
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_navigation_space_accepts_page_down() {
+        let config = parse_user("[navigation]\nspace = \"pagedown\"\n").unwrap();
+        assert_eq!(config.navigation.space, SpaceAction::PageDown);
+        assert_eq!(Config::default().navigation.space, SpaceAction::Autoplay);
+    }
+
+    #[test]
+    fn test_project_layer_overrides_user_config_field_by_field() {
+        let user = "[ui]\nzen = true\nview_mode = \"split\"\n[files]\npanel_width = 40\n";
+        let project = "[ui]\nview_mode = \"evolution\"\n";
+        let (config, _) = parse_with_project(user, project);
+        assert!(config.ui.zen);
+        assert_eq!(config.ui.view_mode.as_deref(), Some("evolution"));
+        assert_eq!(config.files.panel_width, 40);
+    }
+
+    #[test]
+    fn test_project_config_path_stops_at_repo_root() {
+        let root = std::env::temp_dir().join(format!("oyo-project-config-{}", std::process::id()));
+        let nested = root.join("repo").join("src").join("deep");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(root.join("repo").join(".git")).unwrap();
+        fs::write(root.join(PROJECT_CONFIG_FILE), "").unwrap();
+
+        assert_eq!(project_config_path(&nested), None);
+        fs::write(root.join("repo").join(PROJECT_CONFIG_FILE), "").unwrap();
+        assert_eq!(
+            project_config_path(&nested),
+            Some(root.join("repo").join(PROJECT_CONFIG_FILE))
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -3,7 +3,7 @@
 use crate::config::ResolvedTheme;
 use crate::keybindings::{DashboardAction, Keybindings};
 use crate::time_format::TimeFormatter;
-use oyo_core::git::CommitEntry;
+use oyo_core::git::{CommitEntry, TagEntry, EMPTY_TREE_HASH};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -21,7 +21,12 @@ const MAX_CONTENT_WIDTH: u16 = 100;
 const LAYOUT_PADDING_Y: u16 = 1;
 const HEAD_REF: &str = "HEAD";
 const INDEX_REF: &str = "INDEX";
-const EMPTY_TREE_HASH: &str = "4b825dc642cb6eb9a060e54bf8d69288fbee4904";
+const DENSITY_BAR_WIDTH: usize = 8;
+const DENSITY_BAR_CHAR: &str = "■";
+/// Narrower lists leave the room to the commit summaries.
+const DENSITY_BAR_MIN_WIDTH: usize = 60;
+/// Shown before the summary of commits with `git notes`
+const NOTE_MARKER: &str = "✎";
 
 #[derive(Debug, Clone)]
 pub enum DashboardSelection {
@@ -35,6 +40,7 @@ enum EntryKind {
     WorkingTree { files: usize },
     Staged { files: usize },
     Commit(CommitEntry),
+    Tag(TagEntry),
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -64,6 +70,10 @@ pub struct Dashboard {
     scroll: usize,
     filter: String,
     filter_active: bool,
+    /// List tags instead of the working tree, index and commits
+    show_tags: bool,
+    /// Repo-relative file whose history is listed (no working tree or tags)
+    file: Option<PathBuf>,
     pinned_from: Option<String>,
     theme: ResolvedTheme,
     primary_marker: String,
@@ -78,6 +88,9 @@ pub struct DashboardConfig {
     pub repo_root: PathBuf,
     pub branch: Option<String>,
     pub commits: Vec<CommitEntry>,
+    pub tags: Vec<TagEntry>,
+    /// List only `commits` as this file's history
+    pub file: Option<PathBuf>,
     pub working_files: usize,
     pub staged_files: usize,
     pub theme: ResolvedTheme,
@@ -90,6 +103,8 @@ pub struct DashboardConfig {
 struct RenderLineContext<'a> {
     width: usize,
     stats_width: usize,
+    /// Lines changed by the largest listed commit; 0 hides the density bars
+    density_max: usize,
     detail: bool,
     range_marker: Option<RangeMarker>,
     marker_width: usize,
@@ -112,32 +127,40 @@ impl Dashboard {
             author: commit.author.clone(),
             author_time: commit.author_time,
         });
-        entries.push(DashboardEntry {
-            kind: EntryKind::WorkingTree {
-                files: config.working_files,
-            },
-        });
-        entries.push(DashboardEntry {
-            kind: EntryKind::Staged {
-                files: config.staged_files,
-            },
-        });
+        if config.file.is_none() {
+            entries.push(DashboardEntry {
+                kind: EntryKind::WorkingTree {
+                    files: config.working_files,
+                },
+            });
+            entries.push(DashboardEntry {
+                kind: EntryKind::Staged {
+                    files: config.staged_files,
+                },
+            });
+        }
         for commit in config.commits {
             entries.push(DashboardEntry {
                 kind: EntryKind::Commit(commit),
             });
         }
-        let filtered = (0..entries.len()).collect();
-        Self {
+        for tag in config.tags {
+            entries.push(DashboardEntry {
+                kind: EntryKind::Tag(tag),
+            });
+        }
+        let mut dashboard = Self {
             repo_root: config.repo_root,
             branch: config.branch,
             head_meta,
             entries,
-            filtered,
+            filtered: Vec::new(),
             selected: 0,
             scroll: 0,
             filter: String::new(),
             filter_active: false,
+            show_tags: false,
+            file: config.file,
             pinned_from: None,
             theme: config.theme,
             primary_marker: config.primary_marker,
@@ -145,7 +168,9 @@ impl Dashboard {
             last_list_area: Rect::default(),
             time_format: config.time_format,
             keybindings: config.keybindings,
-        }
+        };
+        dashboard.refresh_filter();
+        dashboard
     }
 
     pub fn filter_active(&self) -> bool {
@@ -181,6 +206,17 @@ impl Dashboard {
         self.refresh_filter();
     }
 
+    /// Switch the list between commits and tags, keeping the filter and pin.
+    pub fn toggle_tags(&mut self) {
+        if self.file.is_some() {
+            return;
+        }
+        self.show_tags = !self.show_tags;
+        self.selected = 0;
+        self.scroll = 0;
+        self.refresh_filter();
+    }
+
     pub fn move_selection(&mut self, delta: isize, view_height: usize) {
         if self.filtered.is_empty() {
             self.selected = 0;
@@ -242,6 +278,13 @@ impl Dashboard {
                     self.pinned_from = Some(INDEX_REF.to_string());
                 }
             }
+            EntryKind::Tag(tag) => {
+                if self.pinned_from.as_deref() == Some(tag.name.as_str()) {
+                    self.pinned_from = None;
+                } else {
+                    self.pinned_from = Some(tag.name.clone());
+                }
+            }
         }
     }
 
@@ -309,6 +352,16 @@ impl Dashboard {
                     .unwrap_or_else(|| EMPTY_TREE_HASH.to_string());
                 Some(DashboardSelection::Range { from, to })
             }
+            EntryKind::Tag(tag) => match self.pinned_from.clone() {
+                Some(from) if from != tag.name => Some(DashboardSelection::Range {
+                    from,
+                    to: tag.name.clone(),
+                }),
+                _ => Some(DashboardSelection::Range {
+                    from: tag.name.clone(),
+                    to: HEAD_REF.to_string(),
+                }),
+            },
         }
     }
 
@@ -354,7 +407,10 @@ impl Dashboard {
             });
             if matches!(
                 self.entries[*entry_idx].kind,
-                EntryKind::Commit(_) | EntryKind::WorkingTree { .. } | EntryKind::Staged { .. }
+                EntryKind::Commit(_)
+                    | EntryKind::WorkingTree { .. }
+                    | EntryKind::Staged { .. }
+                    | EntryKind::Tag(_)
             ) {
                 rows.push(DisplayRow::Entry {
                     idx: pos,
@@ -380,24 +436,36 @@ impl Dashboard {
         max_width
     }
 
+    /// Largest insertions + deletions among the listed commits.
+    fn max_commit_changes(&self) -> usize {
+        self.filtered
+            .iter()
+            .filter_map(|entry_idx| match &self.entries[*entry_idx].kind {
+                EntryKind::Commit(commit) => {
+                    commit.stats.map(|stats| stats.insertions + stats.deletions)
+                }
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
     fn refresh_filter(&mut self) {
         let query = self.filter.trim().to_ascii_lowercase();
-        if query.is_empty() {
-            self.filtered = (0..self.entries.len()).collect();
-        } else {
-            self.filtered = self
-                .entries
-                .iter()
-                .enumerate()
-                .filter_map(|(idx, entry)| {
-                    if entry.matches(&query) {
-                        Some(idx)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-        }
+        let show_tags = self.show_tags;
+        self.filtered = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, entry)| {
+                let is_tag = matches!(entry.kind, EntryKind::Tag(_));
+                if is_tag == show_tags && (query.is_empty() || entry.matches(&query)) {
+                    Some(idx)
+                } else {
+                    None
+                }
+            })
+            .collect();
         if self.selected >= self.filtered.len() {
             self.selected = 0;
             self.scroll = 0;
@@ -442,9 +510,13 @@ impl Dashboard {
             .branch
             .clone()
             .unwrap_or_else(|| "DETACHED".to_string());
+        let title = match &self.file {
+            Some(file) => format!("{repo_name}@{branch} • history of {}", file.display()),
+            None => format!("{repo_name}@{branch}"),
+        };
         let mut lines = vec![
             Line::from(vec![Span::styled(
-                format!("{repo_name}@{branch}"),
+                truncate_text(&title, area.width.saturating_sub(2) as usize),
                 Style::default().fg(self.theme.text_muted),
             )]),
             Line::raw(""),
@@ -454,11 +526,14 @@ impl Dashboard {
                 HEAD_REF.to_string()
             } else if from == INDEX_REF {
                 "STAGED".to_string()
+            } else if self.is_tag(from) {
+                from.clone()
             } else {
                 shorten_hash(from)
             };
             let to_label = match self.current_entry().map(|entry| &entry.kind) {
                 Some(EntryKind::Commit(commit)) => shorten_hash(&commit.id),
+                Some(EntryKind::Tag(tag)) => tag.name.clone(),
                 Some(EntryKind::WorkingTree { .. }) | Some(EntryKind::Staged { .. }) => {
                     if matches!(
                         self.current_entry().map(|entry| &entry.kind),
@@ -504,7 +579,11 @@ impl Dashboard {
     fn draw_footer(&self, frame: &mut Frame, area: Rect) {
         let filter_line = if self.filter_active {
             if self.filter.is_empty() {
-                "> Filter commits".to_string()
+                if self.show_tags {
+                    "> Filter tags".to_string()
+                } else {
+                    "> Filter commits".to_string()
+                }
             } else {
                 format!("> {}", self.filter)
             }
@@ -522,8 +601,17 @@ impl Dashboard {
         } else {
             Style::default().fg(self.theme.text_muted)
         };
+        let tags_hint = if self.file.is_some() {
+            String::new()
+        } else {
+            format!(
+                "{} {} • ",
+                self.keybindings.dashboard_keys(DashboardAction::ToggleTags),
+                if self.show_tags { "commits" } else { "tags" },
+            )
+        };
         let hint_text = format!(
-            "{} open • {} pin • {} quit",
+            "{} open • {} pin • {tags_hint}{} quit",
             self.keybindings.dashboard_keys(DashboardAction::Accept),
             self.keybindings.dashboard_keys(DashboardAction::TogglePin),
             self.keybindings.dashboard_keys(DashboardAction::Quit)
@@ -558,11 +646,25 @@ impl Dashboard {
         let marker_width = marker_width(&self.primary_marker, &self.extent_marker);
         let content_width = view_width.saturating_sub(marker_width + 1);
         let stats_width = self.stats_column_width();
+        let density_max = if content_width >= DENSITY_BAR_MIN_WIDTH {
+            self.max_commit_changes()
+        } else {
+            0
+        };
 
         if self.filtered.is_empty() {
             let mut lines = vec![Line::raw(""); height];
             if height > 0 {
-                let msg = truncate_text("No results", content_width);
+                let msg = if !self.filter.trim().is_empty() {
+                    "No results"
+                } else if self.show_tags {
+                    "No tags"
+                } else if self.file.is_some() {
+                    "No commits touch this file"
+                } else {
+                    "No results"
+                };
+                let msg = truncate_text(msg, content_width);
                 lines[height / 2] = Line::from(Span::styled(
                     msg,
                     Style::default()
@@ -596,6 +698,7 @@ impl Dashboard {
                 .iter()
                 .position(|entry_idx| match &self.entries[*entry_idx].kind {
                     EntryKind::Commit(commit) => commit.id == *pinned_id,
+                    EntryKind::Tag(tag) => tag.name == *pinned_id,
                     _ => false,
                 })
         });
@@ -642,6 +745,7 @@ impl Dashboard {
             let line = entry.render_line(RenderLineContext {
                 width: content_width,
                 stats_width,
+                density_max,
                 detail,
                 range_marker,
                 marker_width,
@@ -666,6 +770,12 @@ impl Dashboard {
         frame.render_widget(list, area);
     }
 
+    fn is_tag(&self, name: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| matches!(&entry.kind, EntryKind::Tag(tag) if tag.name == name))
+    }
+
     pub fn select_at_mouse(&mut self, y: u16) -> bool {
         let area = self.last_list_area;
         if area.height == 0 || y < area.y || y >= area.y + area.height {
@@ -696,6 +806,9 @@ impl DashboardEntry {
                 .to_ascii_lowercase();
                 haystack.contains(query)
             }
+            EntryKind::Tag(tag) => format!("{} {}", tag.name, tag.summary)
+                .to_ascii_lowercase()
+                .contains(query),
         }
     }
 
@@ -790,17 +903,27 @@ impl DashboardEntry {
                     if let Some(stats) = commit.stats {
                         right_text = format_diff_stats(stats.insertions, stats.deletions);
                     }
+                    let bar_width = if ctx.density_max == 0 {
+                        0
+                    } else {
+                        DENSITY_BAR_WIDTH + 1
+                    };
                     let right_width = if ctx.stats_width == 0 {
                         0
                     } else {
-                        ctx.stats_width.saturating_add(1)
+                        ctx.stats_width.saturating_add(1) + bar_width
                     };
                     let left_max = ctx.width.saturating_sub(right_width);
 
                     let short_width = text_width(&commit.short_id);
-                    let mut summary_width = left_max.saturating_sub(short_width + 1);
+                    let note_width = if commit.has_note {
+                        text_width(NOTE_MARKER) + 1
+                    } else {
+                        0
+                    };
+                    let mut summary_width = left_max.saturating_sub(short_width + note_width + 1);
                     if summary_width < 8 {
-                        summary_width = left_max.saturating_sub(short_width + 1);
+                        summary_width = left_max.saturating_sub(short_width + note_width + 1);
                     }
                     summary_width = summary_width.saturating_sub(4);
 
@@ -808,12 +931,32 @@ impl DashboardEntry {
                     let short_id = truncate_text(&commit.short_id, short_width);
                     spans.push(Span::styled(short_id, Style::default().fg(ctx.theme.info)));
                     spans.push(Span::raw(" "));
+                    if commit.has_note {
+                        spans.push(Span::styled(
+                            NOTE_MARKER,
+                            Style::default().fg(ctx.theme.warning),
+                        ));
+                        spans.push(Span::raw(" "));
+                    }
                     spans.push(Span::styled(summary, Style::default().fg(ctx.theme.text)));
 
                     if right_width > 0 {
                         let content_used = spans_width(&spans).saturating_sub(ctx.marker_width + 1);
                         let pad = left_max.saturating_sub(content_used);
                         spans.push(Span::raw(" ".repeat(pad)));
+                        if bar_width > 0 {
+                            let (insertions, deletions) = commit
+                                .stats
+                                .map(|stats| (stats.insertions, stats.deletions))
+                                .unwrap_or_default();
+                            spans.extend(density_bar_spans(
+                                insertions,
+                                deletions,
+                                ctx.density_max,
+                                ctx.theme,
+                            ));
+                            spans.push(Span::raw(" "));
+                        }
                         let right_text = pad_to_width(&right_text, ctx.stats_width);
                         spans.push(Span::styled(
                             right_text,
@@ -822,6 +965,30 @@ impl DashboardEntry {
                     }
                 }
             }
+            EntryKind::Tag(tag) => {
+                if ctx.detail {
+                    let date = ctx.time_format.format(tag.time, ctx.now);
+                    spans.push(Span::styled(
+                        "  ",
+                        Style::default().fg(ctx.theme.text_muted),
+                    ));
+                    spans.push(Span::styled(
+                        truncate_text(&date, ctx.width.saturating_sub(2)),
+                        Style::default()
+                            .fg(ctx.theme.text_muted)
+                            .add_modifier(Modifier::DIM),
+                    ));
+                } else {
+                    let name = truncate_text(&tag.name, ctx.width);
+                    let summary_width = ctx.width.saturating_sub(text_width(&name) + 1);
+                    spans.push(Span::styled(name, Style::default().fg(ctx.theme.info)));
+                    spans.push(Span::raw(" "));
+                    spans.push(Span::styled(
+                        truncate_text(&tag.summary, summary_width),
+                        Style::default().fg(ctx.theme.text),
+                    ));
+                }
+            }
         }
 
         Line::from(spans)
@@ -902,6 +1069,36 @@ fn format_diff_stats(insertions: usize, deletions: usize) -> String {
     )
 }
 
+/// A `DENSITY_BAR_WIDTH` bar scaled against `max` lines changed, split into
+/// insertion and deletion colors. Blank when the commit changed nothing.
+fn density_bar_spans(
+    insertions: usize,
+    deletions: usize,
+    max: usize,
+    theme: &ResolvedTheme,
+) -> Vec<Span<'static>> {
+    let total = insertions + deletions;
+    if total == 0 || max == 0 {
+        return vec![Span::raw(" ".repeat(DENSITY_BAR_WIDTH))];
+    }
+    let filled = (total * DENSITY_BAR_WIDTH)
+        .div_ceil(max)
+        .clamp(1, DENSITY_BAR_WIDTH);
+    let inserted = (filled * insertions + total / 2) / total;
+    let deleted = filled - inserted;
+    vec![
+        Span::styled(
+            DENSITY_BAR_CHAR.repeat(inserted),
+            Style::default().fg(theme.insert_base()),
+        ),
+        Span::styled(
+            DENSITY_BAR_CHAR.repeat(deleted),
+            Style::default().fg(theme.delete_base()),
+        ),
+        Span::raw(" ".repeat(DENSITY_BAR_WIDTH - filled)),
+    ]
+}
+
 fn centered_width(area: Rect, max_width: u16) -> Rect {
     let width = area.width.min(max_width);
     let x = area.x + (area.width.saturating_sub(width)) / 2;
@@ -0,0 +1,56 @@
+//! GitHub pull requests via the `gh` CLI, for `--pr <number>`
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::io;
+use std::process::Command;
+
+/// Pull request details shown in the top bar
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct PullRequest {
+    pub title: String,
+    #[serde(rename = "headRefName")]
+    pub head: String,
+}
+
+fn gh(args: &[&str]) -> Result<String> {
+    let output = match Command::new("gh").args(args).output() {
+        Ok(output) => output,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            bail!("--pr needs the GitHub CLI (`gh`) on PATH: https://cli.github.com")
+        }
+        Err(err) => return Err(err).context("Failed to run gh"),
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("gh {} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The PR's combined diff. Plain `gh pr diff` rather than `--patch`: the
+/// format-patch series repeats a file once per commit that touched it.
+pub(crate) fn pr_diff(number: u64) -> Result<String> {
+    gh(&["pr", "diff", &number.to_string(), "--color", "never"])
+}
+
+/// Title and head branch, or `None` when `gh pr view` fails.
+pub(crate) fn pr_view(number: u64) -> Option<PullRequest> {
+    let json = gh(&[
+        "pr",
+        "view",
+        &number.to_string(),
+        "--json",
+        "title,headRefName",
+    ])
+    .ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Top bar note: `PR #12: Title`.
+pub(crate) fn pr_note(number: u64, pr: Option<&PullRequest>) -> String {
+    match pr {
+        Some(pr) => format!("PR #{number}: {}", pr.title),
+        None => format!("PR #{number}"),
+    }
+}
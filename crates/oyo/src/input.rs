@@ -1,8 +1,8 @@
-use crate::app::{App, ViewMode};
+use crate::app::{App, SnapshotFormat, ViewMode};
 use crate::config;
 use crate::keybindings::{
     Dispatch, FileFilterAction, GlobalAction, HelpAction, LineInputAction, NormalAction,
-    PickerAction, ReviewEditorAction,
+    OverviewAction, PickerAction, ReviewEditorAction,
 };
 use anyhow::Result;
 use crossterm::{
@@ -10,7 +10,9 @@ use crossterm::{
     terminal,
 };
 
-use super::{coalesce_key_repeats, open_current_file_in_editor, TuiTerminal};
+use super::{
+    coalesce_key_repeats, open_current_file_in_editor, open_path_under_cursor, TuiTerminal,
+};
 
 pub(crate) fn handle_app_key(
     app: &mut App,
@@ -19,11 +21,29 @@ pub(crate) fn handle_app_key(
     terminal: &mut TuiTerminal,
     editor_config: &config::EditorConfig,
 ) -> Result<()> {
+    if app.quit_confirm_pending {
+        // Esc is a quit key too, but in a prompt it should mean "stay".
+        let confirmed = matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y'))
+            || (key.code != KeyCode::Esc
+                && matches!(
+                    app.keybindings.normal(key),
+                    Dispatch::Matched(NormalAction::Quit)
+                ));
+        app.keybindings.clear_sequence();
+        app.resolve_quit_confirmation(confirmed);
+        return Ok(());
+    }
+
     if app.show_help {
         handle_help_key(app, key);
         return Ok(());
     }
 
+    if app.show_overview {
+        handle_overview_key(app, key);
+        return Ok(());
+    }
+
     if app.review_editor_active() {
         handle_review_editor_key(app, key);
         return Ok(());
@@ -86,6 +106,51 @@ fn handle_global_key(app: &mut App, key: KeyEvent) -> bool {
     }
 }
 
+/// Human-readable key for the keystroke overlay, e.g. `Ctrl+D` or `Shift+Tab`.
+pub(crate) fn key_log_label(key: KeyEvent) -> String {
+    let mut label = String::new();
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    if ctrl {
+        label.push_str("Ctrl+");
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        label.push_str("Alt+");
+    }
+    if key.modifiers.contains(KeyModifiers::SUPER) {
+        label.push_str("Cmd+");
+    }
+    // Shifted characters already read as uppercase or symbols, except after Ctrl
+    let shift = key.modifiers.contains(KeyModifiers::SHIFT)
+        && match key.code {
+            KeyCode::Char(c) => ctrl && c.is_ascii_alphabetic(),
+            _ => true,
+        };
+    if shift || key.code == KeyCode::BackTab {
+        label.push_str("Shift+");
+    }
+    match key.code {
+        KeyCode::Char(' ') => label.push_str("Space"),
+        KeyCode::Char(c) if ctrl => label.extend(c.to_uppercase()),
+        KeyCode::Char(c) => label.push(c),
+        KeyCode::Enter => label.push_str("Enter"),
+        KeyCode::Esc => label.push_str("Esc"),
+        KeyCode::Tab | KeyCode::BackTab => label.push_str("Tab"),
+        KeyCode::Backspace => label.push_str("Backspace"),
+        KeyCode::Delete => label.push_str("Del"),
+        KeyCode::Up => label.push('↑'),
+        KeyCode::Down => label.push('↓'),
+        KeyCode::Left => label.push('←'),
+        KeyCode::Right => label.push('→'),
+        KeyCode::Home => label.push_str("Home"),
+        KeyCode::End => label.push_str("End"),
+        KeyCode::PageUp => label.push_str("PgUp"),
+        KeyCode::PageDown => label.push_str("PgDn"),
+        KeyCode::F(n) => label.push_str(&format!("F{n}")),
+        other => label.push_str(&format!("{other:?}")),
+    }
+    label
+}
+
 fn printable_char(key: KeyEvent) -> Option<char> {
     match key.code {
         KeyCode::Char(c)
@@ -107,6 +172,20 @@ fn handle_help_key(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_overview_key(app: &mut App, key: KeyEvent) {
+    match app.keybindings.overview(key) {
+        Dispatch::Matched(OverviewAction::Close) => app.close_overview(),
+        Dispatch::Matched(OverviewAction::Accept) => app.overview_accept(),
+        Dispatch::Matched(OverviewAction::SelectNext) => app.overview_move(1),
+        Dispatch::Matched(OverviewAction::SelectPrev) => app.overview_move(-1),
+        Dispatch::Matched(OverviewAction::PageDown) => app.overview_page(true),
+        Dispatch::Matched(OverviewAction::PageUp) => app.overview_page(false),
+        Dispatch::Matched(OverviewAction::SelectFirst) => app.overview_select_first(),
+        Dispatch::Matched(OverviewAction::SelectLast) => app.overview_select_last(),
+        Dispatch::Pending | Dispatch::Unmatched => {}
+    }
+}
+
 fn handle_review_editor_key(app: &mut App, key: KeyEvent) {
     match app.keybindings.review_editor(key) {
         Dispatch::Matched(ReviewEditorAction::Cancel) => {
@@ -211,7 +290,8 @@ fn handle_file_search_key(app: &mut App, key: KeyEvent) {
 
 fn handle_file_filter_key(app: &mut App, key: KeyEvent) {
     match app.keybindings.file_filter(key) {
-        Dispatch::Matched(FileFilterAction::Close) => app.stop_file_filter(),
+        Dispatch::Matched(FileFilterAction::Close) => app.cancel_file_filter(),
+        Dispatch::Matched(FileFilterAction::Accept) => app.stop_file_filter(),
         Dispatch::Matched(FileFilterAction::Backspace) => app.pop_file_filter_char(),
         Dispatch::Matched(FileFilterAction::Clear) => app.clear_file_filter(),
         Dispatch::Pending => {}
@@ -238,7 +318,7 @@ fn handle_goto_key(app: &mut App, key: KeyEvent) {
             }
         }
         Dispatch::Matched(LineInputAction::Clear) => app.clear_goto_text(),
-        Dispatch::Pending => {}
+        Dispatch::Matched(LineInputAction::ToggleWholeWord) | Dispatch::Pending => {}
         Dispatch::Unmatched => {
             if let Some(c) = printable_char(key) {
                 app.push_goto_char(c);
@@ -262,6 +342,7 @@ fn handle_search_key(app: &mut App, key: KeyEvent) {
             }
         }
         Dispatch::Matched(LineInputAction::Clear) => app.clear_search_text(),
+        Dispatch::Matched(LineInputAction::ToggleWholeWord) => app.toggle_search_whole_word(),
         Dispatch::Pending => {}
         Dispatch::Unmatched => {
             if let Some(c) = printable_char(key) {
@@ -323,6 +404,84 @@ fn handle_normal_key(
     Ok(())
 }
 
+/// Remap actions that act on a single file's cursor while in continuous mode.
+/// Returns false for actions that behave the same in both modes.
+fn dispatch_continuous_action(
+    app: &mut App,
+    action: NormalAction,
+    key: KeyEvent,
+    pending_event: &mut Option<Event>,
+) -> Result<bool> {
+    match action {
+        NormalAction::NextHunk | NormalAction::PrevHunk => {
+            let count = repeat_count(app, key, pending_event, true)?;
+            for _ in 0..count {
+                if action == NormalAction::NextHunk {
+                    app.continuous_next_hunk();
+                } else {
+                    app.continuous_prev_hunk();
+                }
+            }
+        }
+        NormalAction::GotoStart => {
+            app.reset_count();
+            app.scroll_offset = 0;
+            app.flash_locate(0);
+        }
+        NormalAction::GotoEnd => {
+            app.reset_count();
+            app.scroll_offset = usize::MAX;
+            app.flash_locate_end();
+        }
+        NormalAction::FirstStep | NormalAction::LastStep if !app.file_list_focused => {
+            app.reset_count();
+            app.scroll_offset = 0;
+            if action == NormalAction::LastStep {
+                app.scroll_offset = usize::MAX;
+                app.continuous_prev_hunk();
+            } else {
+                app.continuous_next_hunk();
+            }
+        }
+        NormalAction::HunkStart
+        | NormalAction::HunkEnd
+        | NormalAction::BlameHint
+        | NormalAction::YankBlameCommit
+        | NormalAction::TogglePeekChange
+        | NormalAction::TogglePeekHunk
+        | NormalAction::TogglePeekNext
+        | NormalAction::YankChange
+        | NormalAction::YankHunk
+        | NormalAction::YankChangePatch
+        | NormalAction::YankHunkPatch
+        | NormalAction::YankChangeBeforeAfter
+        | NormalAction::YankHunkBeforeAfter
+        | NormalAction::YankChangeMarkdown
+        | NormalAction::YankHunkMarkdown
+        | NormalAction::YankViewAnsi
+        | NormalAction::YankViewPlain
+        | NormalAction::YankCommentTemplate
+        | NormalAction::YankHunkHeader
+        | NormalAction::OpenEditor
+        | NormalAction::GotoPathUnderCursor
+        | NormalAction::JumpBack
+        | NormalAction::JumpForward
+        | NormalAction::CenterActive
+        | NormalAction::SyncCenterSplit
+        | NormalAction::NextConflict
+        | NormalAction::NextKeyword
+        | NormalAction::PrevKeyword
+        | NormalAction::PrevConflict
+        | NormalAction::LineComment
+        | NormalAction::HunkComment
+        | NormalAction::ExportComments
+        | NormalAction::RemoveLineComment
+        | NormalAction::RemoveHunkComment => app.reset_count(),
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
 fn dispatch_normal_action(
     app: &mut App,
     action: NormalAction,
@@ -331,36 +490,50 @@ fn dispatch_normal_action(
     terminal: &mut TuiTerminal,
     editor_config: &config::EditorConfig,
 ) -> Result<()> {
+    app.cancel_pending_auto_step();
+    if app.continuous_view && dispatch_continuous_action(app, action, key, pending_event)? {
+        return Ok(());
+    }
     match action {
         NormalAction::Quit => {
             app.reset_count();
             if app.show_path_popup {
                 app.show_path_popup = false;
+            } else if app.show_note_popup {
+                app.show_note_popup = false;
+            } else if app.show_build_info {
+                app.show_build_info = false;
+            } else if app.overview_return && key.code == KeyCode::Esc {
+                app.open_overview();
             } else {
                 app.submit_review_and_quit();
             }
         }
         NormalAction::StepDown => {
             let count = repeat_count(app, key, pending_event, true)?;
-            for _ in 0..count {
-                if app.file_list_focused {
-                    app.next_file();
-                } else if app.stepping {
-                    app.next_step();
-                } else {
-                    app.scroll_down();
+            if app.stepping && !app.file_list_focused {
+                app.next_steps(count);
+            } else {
+                for _ in 0..count {
+                    if app.file_list_focused {
+                        app.next_file();
+                    } else {
+                        app.scroll_down();
+                    }
                 }
             }
         }
         NormalAction::StepUp => {
             let count = repeat_count(app, key, pending_event, true)?;
-            for _ in 0..count {
-                if app.file_list_focused {
-                    app.prev_file();
-                } else if app.stepping {
-                    app.prev_step();
-                } else {
-                    app.scroll_up();
+            if app.stepping && !app.file_list_focused {
+                app.prev_steps(count);
+            } else {
+                for _ in 0..count {
+                    if app.file_list_focused {
+                        app.prev_file();
+                    } else {
+                        app.scroll_up();
+                    }
                 }
             }
         }
@@ -386,6 +559,27 @@ fn dispatch_normal_action(
                 }
             }
         }
+        NormalAction::NextUnreviewedHunk => {
+            app.reset_count();
+            app.defer_view_build_for_jump();
+            app.goto_next_unreviewed_hunk();
+        }
+        NormalAction::JumpBack => {
+            let count = repeat_count(app, key, pending_event, true)?;
+            for _ in 0..count {
+                if !app.jump_back() {
+                    break;
+                }
+            }
+        }
+        NormalAction::JumpForward => {
+            let count = repeat_count(app, key, pending_event, true)?;
+            for _ in 0..count {
+                if !app.jump_forward() {
+                    break;
+                }
+            }
+        }
         NormalAction::HunkStart => {
             app.reset_count();
             app.defer_view_build_for_jump();
@@ -410,6 +604,10 @@ fn dispatch_normal_action(
                 app.trigger_blame_hint();
             }
         }
+        NormalAction::YankBlameCommit => {
+            app.reset_count();
+            app.yank_blame_commit();
+        }
         NormalAction::TogglePeekChange => {
             app.reset_count();
             if app.stepping {
@@ -422,9 +620,17 @@ fn dispatch_normal_action(
                 app.toggle_peek_old_hunk();
             }
         }
+        NormalAction::TogglePeekNext => {
+            app.reset_count();
+            app.toggle_peek_next();
+        }
         NormalAction::YankChange => {
             app.reset_count();
-            app.yank_current_change();
+            if app.is_multi_file() && app.file_list_focused {
+                app.yank_marked_paths();
+            } else {
+                app.yank_current_change();
+            }
         }
         NormalAction::YankHunk => {
             app.reset_count();
@@ -438,40 +644,98 @@ fn dispatch_normal_action(
             app.reset_count();
             app.yank_current_hunk_patch();
         }
+        NormalAction::YankChangeBeforeAfter => {
+            app.reset_count();
+            app.yank_current_change_before_after();
+        }
+        NormalAction::YankHunkBeforeAfter => {
+            app.reset_count();
+            app.yank_current_hunk_before_after();
+        }
+        NormalAction::YankChangeMarkdown => {
+            app.reset_count();
+            app.yank_current_change_markdown();
+        }
+        NormalAction::YankHunkMarkdown => {
+            app.reset_count();
+            app.yank_current_hunk_markdown();
+        }
+        NormalAction::YankViewAnsi => {
+            app.reset_count();
+            app.yank_view_snapshot(SnapshotFormat::Ansi);
+        }
+        NormalAction::YankViewPlain => {
+            app.reset_count();
+            app.yank_view_snapshot(SnapshotFormat::Plain);
+        }
+        NormalAction::YankCommentTemplate => {
+            app.reset_count();
+            app.yank_comment_template();
+        }
+        NormalAction::YankHunkHeader => {
+            app.reset_count();
+            app.yank_hunk_header();
+        }
         NormalAction::TogglePathPopup => {
             app.reset_count();
             app.toggle_path_popup();
         }
+        NormalAction::ToggleNotePopup => {
+            app.reset_count();
+            app.toggle_note_popup();
+        }
+        NormalAction::ToggleBuildInfo => {
+            app.reset_count();
+            app.toggle_build_info();
+        }
         NormalAction::OpenEditor => {
             app.reset_count();
             open_current_file_in_editor(terminal, app, editor_config)?;
         }
+        NormalAction::GotoPathUnderCursor => {
+            app.reset_count();
+            open_path_under_cursor(terminal, app, editor_config)?;
+        }
         NormalAction::GotoStart => {
             app.reset_count();
             app.defer_view_build_for_jump();
             app.goto_start();
+            app.flash_locate(0);
         }
         NormalAction::GotoEnd => {
             app.reset_count();
             app.defer_view_build_for_jump();
             app.goto_end();
+            app.flash_locate_end();
         }
         NormalAction::FirstStep => {
             app.reset_count();
-            app.defer_view_build_for_jump();
-            if app.stepping {
-                app.goto_first_step();
+            if app.is_multi_file() && app.file_list_focused {
+                if let Ok((cols, _)) = terminal::size() {
+                    app.resize_file_panel(-2, cols);
+                }
             } else {
-                app.goto_first_hunk_scroll();
+                app.defer_view_build_for_jump();
+                if app.stepping {
+                    app.goto_first_step();
+                } else {
+                    app.goto_first_hunk_scroll();
+                }
             }
         }
         NormalAction::LastStep => {
             app.reset_count();
-            app.defer_view_build_for_jump();
-            if app.stepping {
-                app.goto_last_step();
+            if app.is_multi_file() && app.file_list_focused {
+                if let Ok((cols, _)) = terminal::size() {
+                    app.resize_file_panel(2, cols);
+                }
             } else {
-                app.goto_last_hunk_scroll();
+                app.defer_view_build_for_jump();
+                if app.stepping {
+                    app.goto_last_step();
+                } else {
+                    app.goto_last_hunk_scroll();
+                }
             }
         }
         NormalAction::PrevFile => {
@@ -488,8 +752,14 @@ fn dispatch_normal_action(
         }
         NormalAction::ToggleAutoplay => {
             app.reset_count();
-            if app.stepping {
+            if app.is_multi_file() && app.file_list_focused {
+                app.toggle_file_mark();
+            } else if app.stepping {
                 app.toggle_autoplay();
+            } else if app.space_action == config::SpaceAction::PageDown {
+                if let Ok((_, rows)) = terminal::size() {
+                    app.scroll_page_down(rows.saturating_sub(6) as usize);
+                }
             }
         }
         NormalAction::ToggleAutoplayReverse => {
@@ -567,10 +837,22 @@ fn dispatch_normal_action(
             app.reset_count();
             app.toggle_line_wrap();
         }
+        NormalAction::ToggleDiffBg => {
+            app.reset_count();
+            app.toggle_diff_bg();
+        }
         NormalAction::ToggleSyntax => {
             app.reset_count();
             app.toggle_syntax();
         }
+        NormalAction::CycleDiffHighlight => {
+            app.reset_count();
+            app.cycle_diff_highlight();
+        }
+        NormalAction::CycleModifiedStep => {
+            app.reset_count();
+            app.cycle_modified_step_mode();
+        }
         NormalAction::ToggleEvoSyntax => {
             app.reset_count();
             if app.view_mode == ViewMode::Evolution {
@@ -583,7 +865,7 @@ fn dispatch_normal_action(
         }
         NormalAction::ToggleStrikethrough => {
             app.reset_count();
-            app.toggle_strikethrough_deletions();
+            app.cycle_deletion_style();
         }
         NormalAction::ScrollLeft => {
             let count = repeat_count(app, key, pending_event, false)?;
@@ -611,10 +893,48 @@ fn dispatch_normal_action(
                 app.center_on_active(rows.saturating_sub(4) as usize);
             }
         }
+        NormalAction::SyncCenterSplit => {
+            app.reset_count();
+            if let Ok((_, rows)) = terminal::size() {
+                app.sync_center_split(rows.saturating_sub(4) as usize);
+            }
+        }
+        NormalAction::ToggleSplitFocus => {
+            app.reset_count();
+            app.toggle_split_focus();
+        }
+        NormalAction::ToggleMouseCapture => {
+            app.reset_count();
+            app.toggle_mouse_capture();
+        }
         NormalAction::ToggleZen => {
             app.reset_count();
             app.toggle_zen();
         }
+        NormalAction::ToggleFocusMode => {
+            app.reset_count();
+            app.toggle_focus_mode();
+        }
+        NormalAction::ToggleMineOnly => {
+            app.reset_count();
+            app.toggle_mine_only();
+        }
+        NormalAction::ToggleCommitHeader => {
+            app.reset_count();
+            app.toggle_commit_header();
+        }
+        NormalAction::ToggleOldPath => {
+            app.reset_count();
+            app.toggle_old_path();
+        }
+        NormalAction::CycleFileCounts => {
+            app.reset_count();
+            app.cycle_file_count_mode();
+        }
+        NormalAction::CyclePathStyle => {
+            app.reset_count();
+            app.cycle_file_path_style();
+        }
         NormalAction::ReplayStep => app.replay_step(),
         NormalAction::Refresh => {
             app.reset_count();
@@ -630,10 +950,50 @@ fn dispatch_normal_action(
                 app.toggle_file_panel();
             }
         }
+        NormalAction::ToggleOverview => {
+            app.reset_count();
+            app.toggle_overview();
+        }
+        NormalAction::ToggleContinuous => {
+            app.reset_count();
+            app.toggle_continuous_view();
+        }
+        NormalAction::ReloadConfig => {
+            app.reset_count();
+            app.config_reload_requested = true;
+        }
+        NormalAction::ToggleThemeMode => {
+            app.reset_count();
+            app.theme_mode_toggle_requested = true;
+        }
+        NormalAction::ResetFilePanelWidth => {
+            app.reset_count();
+            if app.is_multi_file() {
+                if let Ok((cols, _)) = terminal::size() {
+                    app.reset_file_panel_width(cols);
+                }
+            }
+        }
         NormalAction::ToggleFoldContext => {
             app.reset_count();
             app.toggle_fold_context();
         }
+        NormalAction::ExpandContextAbove | NormalAction::ExpandContextBelow => {
+            let count = app.take_count();
+            app.expand_hunk_context(action == NormalAction::ExpandContextAbove, count);
+        }
+        NormalAction::OpenFileHistory => {
+            app.reset_count();
+            app.request_file_history();
+        }
+        NormalAction::WalkFileCommits => {
+            app.reset_count();
+            app.request_commit_walk();
+        }
+        NormalAction::ClearLineLimit => {
+            app.reset_count();
+            app.clear_line_limit();
+        }
         NormalAction::OpenSearchOrFileFilter => {
             app.reset_count();
             if app.file_list_focused {
@@ -642,6 +1002,10 @@ fn dispatch_normal_action(
                 app.start_search();
             }
         }
+        NormalAction::FocusFileFilter => {
+            app.reset_count();
+            app.focus_file_filter();
+        }
         NormalAction::OpenGoto => {
             app.reset_count();
             if !app.file_list_focused {
@@ -658,12 +1022,22 @@ fn dispatch_normal_action(
         }
         NormalAction::NextConflict => {
             app.reset_count();
-            app.next_conflict();
+            if !(app.is_multi_file() && app.file_list_focused && app.compare_marked_files()) {
+                app.next_conflict();
+            }
         }
         NormalAction::PrevConflict => {
             app.reset_count();
             app.prev_conflict();
         }
+        NormalAction::NextKeyword => {
+            app.reset_count();
+            app.next_keyword();
+        }
+        NormalAction::PrevKeyword => {
+            app.reset_count();
+            app.prev_keyword();
+        }
         NormalAction::LineComment => {
             app.reset_count();
             app.start_line_comment();
@@ -676,6 +1050,10 @@ fn dispatch_normal_action(
             app.reset_count();
             app.clear_all_review_comments();
         }
+        NormalAction::ExportComments => {
+            app.reset_count();
+            app.export_review_comments();
+        }
         NormalAction::RemoveLineComment => {
             app.reset_count();
             app.remove_line_comment_at_cursor();
@@ -721,6 +1099,53 @@ mod tests {
         KeyEvent::new(KeyCode::Char(ch), KeyModifiers::CONTROL)
     }
 
+    #[test]
+    fn key_log_label_shows_modifiers() {
+        assert_eq!(key_log_label(key('j')), "j");
+        assert_eq!(key_log_label(ctrl('d')), "Ctrl+D");
+        assert_eq!(
+            key_log_label(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            "G"
+        );
+        assert_eq!(
+            key_log_label(KeyEvent::new(
+                KeyCode::Char('P'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )),
+            "Ctrl+Shift+P"
+        );
+        assert_eq!(
+            key_log_label(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)),
+            "Shift+Tab"
+        );
+        assert_eq!(key_log_label(key(' ')), "Space");
+    }
+
+    #[test]
+    fn key_log_collapses_repeats_and_caps_length() {
+        let diff = MultiFileDiff::from_file_pair(
+            "old.txt".into(),
+            "new.txt".into(),
+            "old\n".to_string(),
+            "new\n".to_string(),
+        );
+        let mut app = App::new(diff, ViewMode::UnifiedPane, 0, false, None);
+        app.record_key("j".to_string());
+        app.record_key("j".to_string());
+        app.record_key("k".to_string());
+        let entries: Vec<_> = app
+            .key_log_entries()
+            .map(|(label, count, _)| (label.to_string(), count))
+            .collect();
+        assert_eq!(entries, vec![("j".to_string(), 2), ("k".to_string(), 1)]);
+
+        for ch in ['a', 'b', 'c', 'd', 'e', 'f', 'g'] {
+            app.record_key(ch.to_string());
+        }
+        let labels: Vec<_> = app.key_log_entries().map(|(label, _, _)| label).collect();
+        assert_eq!(labels, vec!["b", "c", "d", "e", "f", "g"]);
+    }
+
     #[test]
     fn global_palette_binding_opens_from_search_mode() {
         let diff = MultiFileDiff::from_file_pair(
@@ -2,6 +2,7 @@ use crate::config::KeybindingsConfig;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use keymap::{parser::parse_seq, Config, Item, KeyMap, Matcher, ToKeyMap};
 use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
 use std::hash::Hash;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -9,6 +10,7 @@ pub(crate) enum KeybindingMode {
     Global,
     Normal,
     Help,
+    Overview,
     ReviewEditor,
     CommandPalette,
     FileSearch,
@@ -25,6 +27,7 @@ impl KeybindingMode {
             Self::Global => "global",
             Self::Normal => "normal",
             Self::Help => "help",
+            Self::Overview => "overview",
             Self::ReviewEditor => "review_editor",
             Self::CommandPalette => "command_palette",
             Self::FileSearch => "file_search",
@@ -52,15 +55,31 @@ pub(crate) enum NormalAction {
     PrevHunk,
     HunkStart,
     HunkEnd,
+    NextUnreviewedHunk,
+    JumpBack,
+    JumpForward,
     BlameHint,
+    YankBlameCommit,
     TogglePeekChange,
     TogglePeekHunk,
+    TogglePeekNext,
     YankChange,
     YankHunk,
     YankChangePatch,
     YankHunkPatch,
+    YankChangeBeforeAfter,
+    YankHunkBeforeAfter,
+    YankChangeMarkdown,
+    YankHunkMarkdown,
+    YankViewAnsi,
+    YankViewPlain,
+    YankCommentTemplate,
+    YankHunkHeader,
     TogglePathPopup,
+    ToggleNotePopup,
+    ToggleBuildInfo,
     OpenEditor,
+    GotoPathUnderCursor,
     GotoStart,
     GotoEnd,
     FirstStep,
@@ -80,7 +99,11 @@ pub(crate) enum NormalAction {
     DecreaseSpeed,
     ToggleAnimation,
     ToggleLineWrap,
+    ToggleDiffBg,
+    ToggleThemeMode,
     ToggleSyntax,
+    CycleDiffHighlight,
+    CycleModifiedStep,
     ToggleEvoSyntax,
     ToggleStepping,
     ToggleStrikethrough,
@@ -89,20 +112,42 @@ pub(crate) enum NormalAction {
     LineStart,
     LineEnd,
     CenterActive,
+    SyncCenterSplit,
+    ToggleSplitFocus,
+    ToggleMouseCapture,
     ToggleZen,
+    ToggleFocusMode,
+    ToggleMineOnly,
+    ToggleCommitHeader,
+    ToggleOldPath,
+    CycleFileCounts,
+    CyclePathStyle,
     ReplayStep,
     Refresh,
+    ReloadConfig,
+    ToggleContinuous,
+    ToggleOverview,
     ToggleFilePanel,
+    ResetFilePanelWidth,
     ToggleFoldContext,
+    ExpandContextAbove,
+    ExpandContextBelow,
+    OpenFileHistory,
+    WalkFileCommits,
+    ClearLineLimit,
     OpenSearchOrFileFilter,
+    FocusFileFilter,
     OpenGoto,
     SearchNext,
     SearchPrev,
     NextConflict,
     PrevConflict,
+    NextKeyword,
+    PrevKeyword,
     LineComment,
     HunkComment,
     ClearComments,
+    ExportComments,
     RemoveLineComment,
     RemoveHunkComment,
     ToggleHelp,
@@ -117,6 +162,18 @@ pub(crate) enum HelpAction {
     ScrollUp,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum OverviewAction {
+    Close,
+    Accept,
+    SelectNext,
+    SelectPrev,
+    PageDown,
+    PageUp,
+    SelectFirst,
+    SelectLast,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum ReviewEditorAction {
     Cancel,
@@ -152,11 +209,13 @@ pub(crate) enum LineInputAction {
     Accept,
     Backspace,
     Clear,
+    ToggleWholeWord,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum FileFilterAction {
     Close,
+    Accept,
     Backspace,
     Clear,
 }
@@ -167,6 +226,7 @@ pub(crate) enum DashboardAction {
     StartFilter,
     ClearPin,
     TogglePin,
+    ToggleTags,
     Accept,
     SelectNext,
     SelectPrev,
@@ -238,15 +298,31 @@ binding_action!(NormalAction, [
     PrevHunk => ("prev_hunk", "Previous hunk", ["h", "left"]),
     HunkStart => ("hunk_start", "Hunk begin", ["b"]),
     HunkEnd => ("hunk_end", "Hunk end", ["e"]),
+    NextUnreviewedHunk => ("next_unreviewed_hunk", "Next unreviewed hunk (any file)", ["g u"]),
+    JumpBack => ("jump_back", "Jump back", ["ctrl-o"]),
+    JumpForward => ("jump_forward", "Jump forward", ["ctrl-i", "ctrl-n"]),
     BlameHint => ("blame_hint", "Blame current step", ["g b"]),
+    YankBlameCommit => ("yank_blame_commit", "Copy git show of the line's blame commit", ["g C"]),
     TogglePeekChange => ("toggle_peek_change", "Peek change", ["p"]),
     TogglePeekHunk => ("toggle_peek_hunk", "Peek old hunk", ["P"]),
+    TogglePeekNext => ("toggle_peek_next", "Preview next change", ["g p"]),
     YankChange => ("yank_change", "Yank line", ["y"]),
     YankHunk => ("yank_hunk", "Yank hunk", ["Y"]),
     YankChangePatch => ("yank_change_patch", "Copy line patch", ["g y"]),
     YankHunkPatch => ("yank_hunk_patch", "Copy hunk patch", ["g Y"]),
+    YankChangeBeforeAfter => ("yank_change_before_after", "Copy line as old/new block", ["g d"]),
+    YankHunkBeforeAfter => ("yank_hunk_before_after", "Copy hunk as old/new block", ["g D"]),
+    YankChangeMarkdown => ("yank_change_markdown", "Copy line as markdown code block", ["g x"]),
+    YankHunkMarkdown => ("yank_hunk_markdown", "Copy hunk as markdown code block", ["g X"]),
+    YankViewAnsi => ("yank_view_ansi", "Copy visible view (ANSI colors)", ["g s"]),
+    YankViewPlain => ("yank_view_plain", "Copy visible view (plain text)", ["g S"]),
+    YankCommentTemplate => ("yank_comment_template", "Copy hunk review comment template", ["g c"]),
+    YankHunkHeader => ("yank_hunk_header", "Copy hunk @@ location header", ["g @"]),
     TogglePathPopup => ("toggle_path_popup", "Show full file path", ["ctrl-g"]),
+    ToggleNotePopup => ("toggle_note_popup", "Show presenter note", ["g n"]),
+    ToggleBuildInfo => ("toggle_build_info", "Show version and build info", ["g v"]),
     OpenEditor => ("open_editor", "Open file in editor", ["o", "ctrl-e"]),
+    GotoPathUnderCursor => ("goto_path_under_cursor", "Open path under cursor", ["g f"]),
     GotoStart => ("goto_start", "Go to start", ["g g", "home"]),
     GotoEnd => ("goto_end", "Go to end", ["G", "end"]),
     FirstStep => ("first_step", "First step (or hunk in no-step)", ["<"]),
@@ -266,29 +342,55 @@ binding_action!(NormalAction, [
     DecreaseSpeed => ("decrease_speed", "Decrease speed", ["-"]),
     ToggleAnimation => ("toggle_animation", "Toggle animation", ["a"]),
     ToggleLineWrap => ("toggle_line_wrap", "Toggle line wrap", ["w"]),
+    ToggleDiffBg => ("toggle_diff_bg", "Toggle line backgrounds (this view)", ["g B"]),
+    ToggleThemeMode => ("toggle_theme_mode", "Switch light/dark theme", ["g t"]),
     ToggleSyntax => ("toggle_syntax", "Toggle syntax highlight", ["t"]),
+    CycleDiffHighlight => ("cycle_diff_highlight", "Cycle inline highlight", ["g H"]),
+    CycleModifiedStep => ("cycle_modified_step", "Cycle modified line rendering", ["g M"]),
     ToggleEvoSyntax => ("toggle_evo_syntax", "Toggle evo syntax", ["E"]),
     ToggleStepping => ("toggle_stepping", "Toggle stepping", ["s"]),
-    ToggleStrikethrough => ("toggle_strikethrough", "Toggle strikethrough", ["S"]),
+    ToggleStrikethrough => ("toggle_strikethrough", "Cycle deletion style", ["S"]),
     ScrollLeft => ("scroll_left", "Scroll left", ["H"]),
     ScrollRight => ("scroll_right", "Scroll right", ["L"]),
     LineStart => ("line_start", "Scroll to line start", ["0"]),
     LineEnd => ("line_end", "Scroll to line end", ["$"]),
-    CenterActive => ("center_active", "Center on active", ["z"]),
+    CenterActive => ("center_active", "Center on active", ["z z"]),
+    SyncCenterSplit => ("sync_center_split", "Center hunk in both split panes", ["z s"]),
+    ToggleSplitFocus => ("toggle_split_focus", "Switch focused split pane", ["ctrl-w"]),
+    ToggleMouseCapture => ("toggle_mouse_capture", "Toggle mouse capture", ["z m"]),
     ToggleZen => ("toggle_zen", "Zen mode", ["Z"]),
+    ToggleFocusMode => ("toggle_focus_mode", "Focus mode (dim other hunks)", ["F"]),
+    ToggleMineOnly => ("toggle_mine_only", "Only my changes (dim others)", ["g a"]),
+    ToggleCommitHeader => ("toggle_commit_header", "Show/hide commit message", ["g i"]),
+    ToggleOldPath => ("toggle_old_path", "Show old/new name of renames", ["g o"]),
+    CycleFileCounts => ("cycle_file_counts", "Cycle file panel counts", ["g +"]),
+    CyclePathStyle => ("cycle_path_style", "Cycle file panel path style", ["g P"]),
     ReplayStep => ("replay_step", "Replay last step", ["r"]),
     Refresh => ("refresh", "Refresh files", ["R"]),
+    ReloadConfig => ("reload_config", "Reload config file", ["g R"]),
+    ToggleContinuous => ("toggle_continuous", "All files in one scroll", ["g m"]),
+    ToggleOverview => ("toggle_overview", "File overview (diff --stat)", ["O"]),
     ToggleFilePanel => ("toggle_file_panel", "Toggle file panel", ["ctrl-f"]),
+    ResetFilePanelWidth => ("reset_file_panel_width", "Reset file panel width", ["g ="]),
     ToggleFoldContext => ("toggle_fold_context", "Toggle context folding", ["f"]),
+    ExpandContextAbove => ("expand_context_above", "Unfold more context above hunk", ["g K"]),
+    ExpandContextBelow => ("expand_context_below", "Unfold more context below hunk", ["g J"]),
+    OpenFileHistory => ("open_file_history", "Browse commits touching this file", ["g h"]),
+    WalkFileCommits => ("walk_file_commits", "Step through this file's commits in the range", ["g w"]),
+    ClearLineLimit => ("clear_line_limit", "Clear :limit line range", ["g l"]),
     OpenSearchOrFileFilter => ("open_search_or_file_filter", "Search or filter files", ["/"]),
+    FocusFileFilter => ("focus_file_filter", "Filter files (from any focus)", ["g /"]),
     OpenGoto => ("open_goto", "Go to line/hunk/step", [":"]),
     SearchNext => ("search_next", "Next match", ["n"]),
     SearchPrev => ("search_prev", "Previous match", ["N"]),
     NextConflict => ("next_conflict", "Next conflict", ["c"]),
     PrevConflict => ("prev_conflict", "Previous conflict", ["C"]),
+    NextKeyword => ("next_keyword", "Next added review keyword", ["}"]),
+    PrevKeyword => ("prev_keyword", "Previous added review keyword", ["{"]),
     LineComment => ("line_comment", "Add/update line comment", ["m"]),
     HunkComment => ("hunk_comment", "Add/update hunk comment", ["M"]),
     ClearComments => ("clear_comments", "Clear all comments", ["ctrl-x"]),
+    ExportComments => ("export_comments", "Export review comments to a file", ["g E"]),
     RemoveLineComment => ("remove_line_comment", "Remove line comment", ["x"]),
     RemoveHunkComment => ("remove_hunk_comment", "Remove hunk comment", ["X"]),
     ToggleHelp => ("toggle_help", "Toggle help", ["?"]),
@@ -302,6 +404,17 @@ binding_action!(HelpAction, [
     ScrollUp => ("scroll_up", "Scroll up", ["k", "up"]),
 ]);
 
+binding_action!(OverviewAction, [
+    Close => ("close", "Close overview", ["esc", "q", "O"]),
+    Accept => ("accept", "Open file", ["enter"]),
+    SelectNext => ("select_next", "Select next", ["j", "down"]),
+    SelectPrev => ("select_prev", "Select previous", ["k", "up"]),
+    PageDown => ("page_down", "Page down", ["pagedown", "ctrl-d"]),
+    PageUp => ("page_up", "Page up", ["pageup", "ctrl-u"]),
+    SelectFirst => ("select_first", "Select first", ["g", "home"]),
+    SelectLast => ("select_last", "Select last", ["G", "end"]),
+]);
+
 binding_action!(ReviewEditorAction, [
     Cancel => ("cancel", "Cancel editor", ["esc"]),
     Save => ("save", "Save comment", ["ctrl-enter"]),
@@ -334,10 +447,12 @@ binding_action!(LineInputAction, [
     Accept => ("accept", "Accept", ["enter"]),
     Backspace => ("backspace", "Backspace", ["backspace"]),
     Clear => ("clear", "Clear query", ["ctrl-u"]),
+    ToggleWholeWord => ("toggle_whole_word", "Toggle whole-word search", ["alt-w"]),
 ]);
 
 binding_action!(FileFilterAction, [
-    Close => ("close", "Close filter", ["esc", "enter"]),
+    Close => ("close", "Close filter", ["esc"]),
+    Accept => ("accept", "Keep filter and focus file list", ["enter"]),
     Backspace => ("backspace", "Backspace", ["backspace"]),
     Clear => ("clear", "Clear filter", ["ctrl-u"]),
 ]);
@@ -347,6 +462,7 @@ binding_action!(DashboardAction, [
     StartFilter => ("start_filter", "Filter commits", ["/"]),
     ClearPin => ("clear_pin", "Clear pinned range start", ["r"]),
     TogglePin => ("toggle_pin", "Mark range start", ["space"]),
+    ToggleTags => ("toggle_tags", "Show tags / commits", ["t"]),
     Accept => ("accept", "Open selection", ["enter"]),
     SelectNext => ("select_next", "Select next", ["j", "down"]),
     SelectPrev => ("select_prev", "Select previous", ["k", "up"]),
@@ -374,6 +490,7 @@ pub(crate) struct Keybindings {
     global: ModeBindings<GlobalAction>,
     normal: ModeBindings<NormalAction>,
     help: ModeBindings<HelpAction>,
+    overview: ModeBindings<OverviewAction>,
     review_editor: ModeBindings<ReviewEditorAction>,
     command_palette: ModeBindings<PickerAction>,
     file_search: ModeBindings<PickerAction>,
@@ -422,6 +539,7 @@ impl Keybindings {
             global: ModeBindings::build(KeybindingMode::Global, config, warnings),
             normal: ModeBindings::build(KeybindingMode::Normal, config, warnings),
             help: ModeBindings::build(KeybindingMode::Help, config, warnings),
+            overview: ModeBindings::build(KeybindingMode::Overview, config, warnings),
             review_editor: ModeBindings::build(KeybindingMode::ReviewEditor, config, warnings),
             command_palette: ModeBindings::build(KeybindingMode::CommandPalette, config, warnings),
             file_search: ModeBindings::build(KeybindingMode::FileSearch, config, warnings),
@@ -443,6 +561,7 @@ impl Keybindings {
             Some(KeybindingMode::Global) => self.global.clear_sequence(),
             Some(KeybindingMode::Normal) => self.normal.clear_sequence(),
             Some(KeybindingMode::Help) => self.help.clear_sequence(),
+            Some(KeybindingMode::Overview) => self.overview.clear_sequence(),
             Some(KeybindingMode::ReviewEditor) => self.review_editor.clear_sequence(),
             Some(KeybindingMode::CommandPalette) => self.command_palette.clear_sequence(),
             Some(KeybindingMode::FileSearch) => self.file_search.clear_sequence(),
@@ -470,6 +589,11 @@ impl Keybindings {
         dispatch_mode(&mut self.active_sequence_mode, &mut self.help, key)
     }
 
+    pub(crate) fn overview(&mut self, key: KeyEvent) -> Dispatch<OverviewAction> {
+        self.prepare_mode(KeybindingMode::Overview);
+        dispatch_mode(&mut self.active_sequence_mode, &mut self.overview, key)
+    }
+
     pub(crate) fn review_editor(&mut self, key: KeyEvent) -> Dispatch<ReviewEditorAction> {
         self.prepare_mode(KeybindingMode::ReviewEditor);
         dispatch_mode(&mut self.active_sequence_mode, &mut self.review_editor, key)
@@ -530,6 +654,10 @@ impl Keybindings {
         self.help.keys_label(action)
     }
 
+    pub(crate) fn overview_keys(&self, action: OverviewAction) -> String {
+        self.overview.keys_label(action)
+    }
+
     pub(crate) fn review_editor_keys(&self, action: ReviewEditorAction) -> String {
         self.review_editor.keys_label(action)
     }
@@ -538,6 +666,39 @@ impl Keybindings {
         self.dashboard.keys_label(action)
     }
 
+    /// Effective bindings for every mode as an aligned plain-text table,
+    /// for `oy keys`.
+    pub(crate) fn reference(&self) -> String {
+        let sections = [
+            self.global.reference_rows(),
+            self.normal.reference_rows(),
+            self.help.reference_rows(),
+            self.overview.reference_rows(),
+            self.review_editor.reference_rows(),
+            self.command_palette.reference_rows(),
+            self.file_search.reference_rows(),
+            self.file_filter.reference_rows(),
+            self.goto.reference_rows(),
+            self.search.reference_rows(),
+            self.dashboard.reference_rows(),
+            self.dashboard_filter.reference_rows(),
+        ];
+        let rows = sections.iter().flat_map(|(_, rows)| rows);
+        let id_width = rows.clone().map(|row| row.0.len()).max().unwrap_or(0);
+        let keys_width = rows.map(|row| row.1.chars().count()).max().unwrap_or(0);
+        let mut out = String::new();
+        for (mode, rows) in &sections {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            let _ = writeln!(out, "[keybindings.{}]", mode.id());
+            for (id, keys, description) in rows {
+                let _ = writeln!(out, "  {id:<id_width$}  {keys:<keys_width$}  {description}");
+            }
+        }
+        out
+    }
+
     fn prepare_mode(&mut self, mode: KeybindingMode) {
         if self
             .active_sequence_mode
@@ -651,6 +812,22 @@ impl<A: BindingAction + 'static> ModeBindings<A> {
         self.buffer.clear();
     }
 
+    fn reference_rows(&self) -> (KeybindingMode, Vec<(&'static str, String, &'static str)>) {
+        let rows = A::all()
+            .iter()
+            .map(|action| {
+                let keys = self.keys_label(*action);
+                let keys = if keys.is_empty() {
+                    "(unbound)".to_string()
+                } else {
+                    keys
+                };
+                (action.id(), keys, action.description())
+            })
+            .collect();
+        (self.mode, rows)
+    }
+
     fn keys_label(&self, action: A) -> String {
         self.effective
             .get(action.id())
@@ -665,6 +842,7 @@ fn warn_unknown_modes(config: &KeybindingsConfig, warnings: &mut Vec<String>) {
             KeybindingMode::Global.id(),
             KeybindingMode::Normal.id(),
             KeybindingMode::Help.id(),
+            KeybindingMode::Overview.id(),
             KeybindingMode::ReviewEditor.id(),
             KeybindingMode::CommandPalette.id(),
             KeybindingMode::FileSearch.id(),
@@ -832,6 +1010,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reference_lists_effective_keys_per_mode() {
+        let config = KeybindingsConfig {
+            modes: BTreeMap::from([(
+                "normal".to_string(),
+                BTreeMap::from([("step_down".to_string(), vec!["v".to_string()])]),
+            )]),
+        };
+        let reference = Keybindings::from_config(&config).reference();
+        let step_down = reference
+            .lines()
+            .find(|line| line.trim_start().starts_with("step_down "))
+            .expect("step_down row");
+        assert!(step_down.contains(" v "), "{step_down}");
+        assert!(step_down.ends_with("Step forward"));
+        assert!(reference.starts_with("[keybindings.global]\n"));
+        assert!(reference.contains("\n[keybindings.dashboard_filter]\n"));
+    }
+
     #[test]
     fn sequence_prefix_waits_and_failed_sequence_retries_latest_key() {
         let mut bindings = Keybindings::default();
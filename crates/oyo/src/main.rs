@@ -2,11 +2,16 @@
 
 mod app;
 mod blame;
+mod build_info;
 mod color;
 mod config;
 mod dashboard;
+mod github;
 mod input;
 mod keybindings;
+mod notes;
+mod plain;
+mod snapshot;
 mod syntax;
 #[cfg(test)]
 mod test_utils;
@@ -15,8 +20,10 @@ mod ui;
 mod views;
 
 use crate::dashboard::{Dashboard, DashboardConfig, DashboardSelection};
-use crate::input::handle_app_key;
-use crate::keybindings::{DashboardAction, DashboardFilterAction, Dispatch, Keybindings};
+use crate::input::{handle_app_key, key_log_label};
+use crate::keybindings::{
+    DashboardAction, DashboardFilterAction, Dispatch, Keybindings, NormalAction,
+};
 use crate::syntax::{list_syntax_themes, SyntaxEngine};
 use crate::time_format::TimeFormatter;
 use anyhow::{anyhow, Context, Result};
@@ -32,8 +39,9 @@ use crossterm::{
 };
 use oyo_core::{multi::FileSide, DirectoryScanOptions, LineKind, MultiFileDiff, ViewLine};
 use ratatui::prelude::*;
+use regex::Regex;
 use std::fs::OpenOptions;
-use std::io::{self, IsTerminal};
+use std::io::{self, IsTerminal, Read};
 use std::path::{Component, Path, PathBuf};
 use std::process::Command as ProcessCommand;
 use std::time::Duration;
@@ -69,6 +77,10 @@ struct Args {
     #[arg(long)]
     autoplay: bool,
 
+    /// Config file to use instead of the user and project configs
+    #[arg(long, value_name = "PATH", global = true)]
+    config: Option<PathBuf>,
+
     /// Theme mode: dark or light
     #[arg(long, value_enum, global = true)]
     theme_mode: Option<CliThemeMode>,
@@ -77,6 +89,10 @@ struct Args {
     #[arg(long, global = true)]
     theme_name: Option<String>,
 
+    /// Theme file (TOML or JSON) layered over the theme name (overrides config)
+    #[arg(long, value_name = "PATH", global = true)]
+    theme_file: Option<PathBuf>,
+
     /// Syntax theme name or .tmTheme file (overrides config)
     #[arg(long, global = true)]
     syntax_theme: Option<String>,
@@ -85,6 +101,10 @@ struct Args {
     #[arg(long, value_name = "FILE")]
     dump_scopes: Option<PathBuf>,
 
+    /// Annotate each diff line with its change id, hunk and kind (for bug reports)
+    #[arg(long, global = true, hide = true)]
+    debug_lines: bool,
+
     /// Disable stepping (no-step diff view)
     #[arg(long, global = true)]
     no_step: bool,
@@ -93,10 +113,63 @@ struct Args {
     #[arg(long, alias = "cached", conflicts_with = "range")]
     staged: bool,
 
-    /// Diff a git range (e.g. HEAD~1..HEAD)
+    /// Diff a git range (e.g. HEAD~1..HEAD); a single branch like `main` means
+    /// merge-base(main, HEAD)..HEAD, a single commit shows that commit's changes
     #[arg(long, value_name = "RANGE", conflicts_with = "staged")]
     range: Option<String>,
 
+    /// Old-side ref for diffing one file across two refs (use with --new)
+    #[arg(
+        long = "old",
+        value_name = "REF",
+        requires = "new_ref",
+        conflicts_with_all = ["staged", "range"]
+    )]
+    old_ref: Option<String>,
+
+    /// New-side ref for diffing one file across two refs (use with --old)
+    #[arg(
+        long = "new",
+        value_name = "REF",
+        requires = "old_ref",
+        conflicts_with_all = ["staged", "range"]
+    )]
+    new_ref: Option<String>,
+
+    /// Review a GitHub pull request's diff via the `gh` CLI, without checking it out
+    #[arg(
+        long,
+        value_name = "NUMBER",
+        conflicts_with_all = ["staged", "range", "old_ref"]
+    )]
+    pr: Option<u64>,
+
+    /// Diff two pasted snippets read from stdin: the old text, a `-- >8 --`
+    /// line, then the new text (end with Ctrl-D)
+    #[arg(
+        long,
+        conflicts_with_all = ["staged", "range", "old_ref", "pr"]
+    )]
+    paste: bool,
+
+    /// Plain mode for dumb terminals and captured output: no colors, syntax,
+    /// animation or mouse capture, ASCII markers (overrides config)
+    #[arg(long, global = true)]
+    plain: bool,
+
+    /// Open on this file (by its path in the diff) and optionally line, e.g. `src/main.rs:42`
+    #[arg(long, value_name = "FILE[:LINE]")]
+    goto: Option<String>,
+
+    /// Review only these hunks, e.g. `src/main.rs:2,3` or `lib.rs:1-4` (1-based);
+    /// repeat for more files. `@FILE` reads more entries from FILE
+    #[arg(long, value_name = "FILE:HUNKS")]
+    hunks: Vec<String>,
+
+    /// With --hunks, skip every hunk of files it doesn't list (default: show them all)
+    #[arg(long, requires = "hunks")]
+    hunks_only: bool,
+
     /// Write review comments to this file on quit
     #[arg(long, value_name = "FILE", global = true)]
     review_output_file: Option<PathBuf>,
@@ -109,6 +182,14 @@ struct Args {
     #[arg(long, global = true)]
     no_review_persist: bool,
 
+    /// Show recent keypresses in a corner overlay
+    #[arg(long)]
+    show_keys: bool,
+
+    /// On quit, print the last-viewed file's diff so it stays in scrollback
+    #[arg(long)]
+    print_on_exit: bool,
+
     /// Respect git ignore files during directory scans
     #[arg(long, global = true, conflicts_with = "no_git_ignore")]
     git_ignore: bool,
@@ -117,13 +198,37 @@ struct Args {
     #[arg(long, global = true, conflicts_with = "git_ignore")]
     no_git_ignore: bool,
 
+    /// Do not exclude files matched by `.oyoignore`
+    #[arg(long, global = true)]
+    no_ignore: bool,
+
     /// Glob patterns to exclude during directory scans (pipe-separated, repeatable)
     #[arg(long, value_name = "GLOBS", global = true)]
     ignore_glob: Vec<String>,
 
+    /// Descend at most this many levels when comparing directories (1 = top-level files only)
+    #[arg(long, value_name = "DEPTH", global = true)]
+    max_depth: Option<usize>,
+
+    /// Treat lines as unchanged when they differ only in matches of this regex
+    #[arg(long, value_name = "REGEX", global = true)]
+    ignore_matching: Option<String>,
+
     /// Clear saved review session state for the current diff on startup
     #[arg(long, global = true)]
     clear_review_session: bool,
+
+    /// Exit with 1 if there are changes and 0 otherwise, without opening the viewer
+    #[arg(long)]
+    exit_code: bool,
+
+    /// Print the diff instead of opening the viewer (paged via OYO_PAGER/PAGER on a terminal)
+    #[arg(long)]
+    no_tui: bool,
+
+    /// Print version, build commit, loaded config files and terminal support, then exit
+    #[arg(long)]
+    build_info: bool,
 }
 
 #[derive(Debug, Subcommand)]
@@ -132,6 +237,8 @@ enum Command {
     Themes,
     /// List syntax themes
     SyntaxThemes,
+    /// Print every keybinding, including remaps from the config
+    Keys,
     /// Open the git range picker dashboard
     View {
         /// Number of commits to show
@@ -192,22 +299,43 @@ enum InputMode {
     GitStaged,
     /// Git range
     GitRange { from: String, to: String },
+    /// Single file compared across two git refs
+    GitRefs {
+        path: PathBuf,
+        old_ref: String,
+        new_ref: String,
+    },
+    /// GitHub pull request diff fetched with `gh`
+    GitHubPr {
+        number: u64,
+        pr: Option<github::PullRequest>,
+    },
+    /// Two snippets pasted on stdin
+    Paste { old: String, new: String },
     /// No valid input
     None,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum AppExit {
     Quit,
     OpenDashboard,
+    /// Pick a revision of this repo-relative file from its commit history
+    OpenFileHistory(PathBuf),
 }
 
 /// Detect if we're being called as a git external diff tool
 /// Git calls: oy path old-file old-hex old-mode new-file new-hex new-mode
+/// Renames and copies append: new-path xfrm-msg
+/// This is the same for `diff.external` and `GIT_EXTERNAL_DIFF`.
 fn detect_input_mode(paths: &[PathBuf]) -> InputMode {
-    if paths.len() == 7 {
+    if paths.len() == 7 || paths.len() == 9 {
         // Git external diff format
-        let display_path = paths[0].clone();
+        let display_path = if paths.len() == 9 {
+            paths[7].clone()
+        } else {
+            paths[0].clone()
+        };
         let old_file = paths[1].clone();
         let new_file = paths[4].clone();
         InputMode::GitExternal {
@@ -232,26 +360,275 @@ fn detect_input_mode(paths: &[PathBuf]) -> InputMode {
     }
 }
 
-fn parse_range(range: &str) -> Result<(String, String)> {
+/// Read git's `GIT_DIFF_PATH_COUNTER`/`GIT_DIFF_PATH_TOTAL`, set while it
+/// drives an external diff tool one file at a time.
+fn external_diff_progress() -> Option<(usize, usize)> {
+    parse_external_diff_progress(
+        std::env::var("GIT_DIFF_PATH_COUNTER").ok().as_deref(),
+        std::env::var("GIT_DIFF_PATH_TOTAL").ok().as_deref(),
+    )
+}
+
+fn parse_external_diff_progress(
+    counter: Option<&str>,
+    total: Option<&str>,
+) -> Option<(usize, usize)> {
+    let counter = counter?.trim().parse::<usize>().ok()?;
+    let total = total?.trim().parse::<usize>().ok()?;
+    if counter == 0 || total == 0 || counter > total {
+        return None;
+    }
+    Some((counter, total))
+}
+
+const RANGE_USAGE: &str = "Range must be in the form A..B, A...B, or a single ref \
+     (a branch shows merge-base(BRANCH, HEAD)..HEAD, a commit shows its own changes)";
+
+/// A parsed `--range` value.
+#[derive(Debug, PartialEq, Eq)]
+enum RangeArg {
+    /// Explicit `A..B` / `A...B`
+    Between(String, String),
+    /// Single ref: a branch means the changes on HEAD since it forked from
+    /// that branch; any other commit means that commit's own changes
+    Single(String),
+}
+
+fn parse_range(range: &str) -> Result<RangeArg> {
+    let range = range.trim();
     if let Some((from, to)) = range.split_once("...") {
         if from.is_empty() || to.is_empty() {
-            anyhow::bail!("Range must be in the form A..B or A...B");
+            anyhow::bail!(RANGE_USAGE);
         }
         if to.contains("..") {
-            anyhow::bail!("Range must be in the form A..B or A...B");
+            anyhow::bail!(RANGE_USAGE);
         }
-        return Ok((from.to_string(), to.to_string()));
+        return Ok(RangeArg::Between(from.to_string(), to.to_string()));
     }
     if let Some((from, to)) = range.split_once("..") {
         if from.is_empty() || to.is_empty() {
-            anyhow::bail!("Range must be in the form A..B or A...B");
+            anyhow::bail!(RANGE_USAGE);
         }
         if to.contains("..") {
-            anyhow::bail!("Range must be in the form A..B or A...B");
+            anyhow::bail!(RANGE_USAGE);
+        }
+        return Ok(RangeArg::Between(from.to_string(), to.to_string()));
+    }
+    if range.is_empty() || range.contains(char::is_whitespace) {
+        anyhow::bail!(RANGE_USAGE);
+    }
+    Ok(RangeArg::Single(range.to_string()))
+}
+
+/// Split `--goto` into a path and line. A suffix that isn't a line number
+/// stays part of the path.
+fn parse_goto(spec: &str) -> (&str, Option<usize>) {
+    match spec.rsplit_once(':') {
+        Some((path, line)) if !path.is_empty() => match line.parse::<usize>() {
+            Ok(line) if line > 0 => (path, Some(line)),
+            _ => (spec, None),
+        },
+        _ => (spec, None),
+    }
+}
+
+/// Index of the `--goto` file: an exact display name match first, then a
+/// file whose path ends with the given one.
+fn find_goto_file(multi_diff: &MultiFileDiff, path: &str) -> Option<usize> {
+    let path = path.strip_prefix("./").unwrap_or(path);
+    let files = &multi_diff.files;
+    files
+        .iter()
+        .position(|file| file.display_name == path)
+        .or_else(|| files.iter().position(|file| file.path.ends_with(path)))
+}
+
+/// Line separating the old and new snippets for `--paste`
+const PASTE_MARKER: &str = "-- >8 --";
+
+/// Split `--paste` input at its first marker line into (old, new). Blank
+/// input is `None`; input without a marker is an error.
+fn split_paste_input(input: &str) -> Result<Option<(String, String)>> {
+    if input.trim().is_empty() {
+        return Ok(None);
+    }
+    let mut old = String::new();
+    let mut lines = input.split_inclusive('\n');
+    for line in lines.by_ref() {
+        if line.trim_end() == PASTE_MARKER {
+            return Ok(Some((old, lines.collect())));
         }
-        return Ok((from.to_string(), to.to_string()));
+        old.push_str(line);
+    }
+    anyhow::bail!("--paste: no `{PASTE_MARKER}` line between the old and new text")
+}
+
+/// `--hunks` entries as (path, 1-based hunk numbers). An `@FILE` entry reads
+/// more entries from FILE, whitespace separated, with `#` starting a comment.
+fn parse_hunk_specs(specs: &[String]) -> Result<Vec<(String, Vec<usize>)>> {
+    let mut entries = Vec::new();
+    for spec in specs {
+        if let Some(list) = spec.strip_prefix('@') {
+            let text = std::fs::read_to_string(list)
+                .with_context(|| format!("Failed to read --hunks list {list}"))?;
+            let tokens: Vec<String> = text
+                .lines()
+                .map(|line| line.split('#').next().unwrap_or_default())
+                .flat_map(str::split_whitespace)
+                .map(str::to_string)
+                .collect();
+            entries.extend(parse_hunk_specs(&tokens)?);
+            continue;
+        }
+        let invalid = || anyhow!("Invalid --hunks entry {spec:?} (expected FILE:2,3 or FILE:1-4)");
+        let (path, list) = spec
+            .rsplit_once(':')
+            .filter(|(path, _)| !path.is_empty())
+            .ok_or_else(invalid)?;
+        let mut hunks = Vec::new();
+        for part in list.split(',') {
+            let (start, end) = part.split_once('-').unwrap_or((part, part));
+            let start: usize = start.trim().parse().map_err(|_| invalid())?;
+            let end: usize = end.trim().parse().map_err(|_| invalid())?;
+            if start == 0 || end < start {
+                return Err(invalid());
+            }
+            hunks.extend(start..=end);
+        }
+        entries.push((path.to_string(), hunks));
+    }
+    Ok(entries)
+}
+
+/// Map `--hunks` entries onto (file index, 0-based hunks), dropping paths
+/// that aren't in this diff.
+fn resolve_hunk_specs(
+    multi_diff: &MultiFileDiff,
+    specs: &[(String, Vec<usize>)],
+) -> Vec<(usize, Vec<usize>)> {
+    specs
+        .iter()
+        .filter_map(|(path, hunks)| {
+            let idx = find_goto_file(multi_diff, path)?;
+            Some((idx, hunks.iter().map(|hunk| hunk - 1).collect()))
+        })
+        .collect()
+}
+
+/// Resolve a single-ref `--range`: `merge-base(branch, HEAD)..HEAD` for a
+/// branch, `commit^..commit` (first parent) for anything else.
+fn single_ref_input_mode(reference: &str) -> Result<InputMode> {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    if !oyo_core::git::is_git_repo(&cwd) {
+        anyhow::bail!(
+            "Not in a git repository.\n\
+             \n\
+             Usage: oy --range {reference}\n\
+             \n\
+             Or run from a git repository."
+        );
+    }
+    let repo_root =
+        oyo_core::git::get_repo_root(&cwd).context("Failed to get git repository root")?;
+    if oyo_core::git::is_branch(&repo_root, reference) {
+        let from = oyo_core::git::get_merge_base(&repo_root, reference)
+            .with_context(|| format!("Failed to find merge base of {reference} and HEAD"))?;
+        return Ok(InputMode::GitRange {
+            from,
+            to: "HEAD".to_string(),
+        });
+    }
+    let parents = oyo_core::git::get_commit_parents(&repo_root, reference)
+        .with_context(|| format!("Failed to resolve commit {reference}"))?;
+    let Some(first_parent) = parents.into_iter().next() else {
+        anyhow::bail!("{reference} is a root commit and has no parent to diff against");
+    };
+    Ok(InputMode::GitRange {
+        from: first_parent,
+        to: reference.to_string(),
+    })
+}
+
+/// Top bar note when a range compares a merge commit against its first parent.
+fn merge_range_note(from: &str, to: &str) -> Option<String> {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let repo_root = oyo_core::git::get_repo_root(&cwd).ok()?;
+    let parents = oyo_core::git::get_commit_parents(&repo_root, to).ok()?;
+    if parents.len() < 2 {
+        return None;
+    }
+    let from = oyo_core::git::resolve_commit(&repo_root, from).ok()?;
+    (from == parents[0]).then(|| "merge: vs first parent".to_string())
+}
+
+/// Commit context for a git range: the full message when it spans one
+/// commit, otherwise the commit count with the first and last subjects.
+fn range_commit_header(from: &str, to: &str) -> Option<app::CommitHeader> {
+    if from == INDEX_REF || to == INDEX_REF {
+        return None;
+    }
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let repo_root = oyo_core::git::get_repo_root(&cwd).ok()?;
+    let subjects = oyo_core::git::get_range_subjects(&repo_root, from, to).ok()?;
+    match subjects.as_slice() {
+        [] => None,
+        [_] => {
+            let message = oyo_core::git::get_commit_message(&repo_root, to).ok()?;
+            let mut lines: Vec<String> = message.lines().map(ToString::to_string).collect();
+            let note = oyo_core::git::get_notes(&repo_root, to);
+            if let Some(note) = &note {
+                // Laid out like `git log` shows notes.
+                lines.push(String::new());
+                lines.push("Notes:".to_string());
+                lines.extend(note.lines().map(|line| format!("    {line}")));
+            }
+            Some(app::CommitHeader {
+                title: format!(" {to} "),
+                lines,
+                has_notes: note.is_some(),
+            })
+        }
+        [first, .., last] => {
+            let mut lines = vec![format!("first: {first}"), format!("last:  {last}")];
+            let notes = oyo_core::git::get_range_notes(&repo_root, from, to).unwrap_or_default();
+            for (hash, note) in &notes {
+                let summary = note.lines().next().unwrap_or_default();
+                lines.push(format!("note:  {hash} {summary}"));
+            }
+            Some(app::CommitHeader {
+                title: format!(" {} commits ", subjects.len()),
+                lines,
+                has_notes: !notes.is_empty(),
+            })
+        }
+    }
+}
+
+/// With no arguments and nothing uncommitted, fall back to the commits on this
+/// branch since it forked from the remote default branch (`origin/HEAD`).
+/// Whether a clean worktree may fall back to the branch diff. Only the
+/// viewer does: `--exit-code` and `--no-tui` report the worktree as asked.
+fn branch_fallback_allowed(args: &Args) -> bool {
+    !args.exit_code && !args.no_tui
+}
+
+fn default_branch_input_mode() -> Option<InputMode> {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let repo_root = oyo_core::git::get_repo_root(&cwd).ok()?;
+    let default_branch = oyo_core::git::get_default_branch(&repo_root)?;
+    let from = oyo_core::git::get_merge_base(&repo_root, &default_branch).ok()?;
+    let head = oyo_core::git::resolve_commit(&repo_root, "HEAD").ok()?;
+    (from != head).then(|| InputMode::GitRange {
+        from,
+        to: "HEAD".to_string(),
+    })
+}
+
+/// Drop `.oyoignore`d changes, reporting patterns that failed to parse.
+fn apply_oyoignore(repo_root: &Path, changes: &mut Vec<oyo_core::git::ChangedFile>) {
+    for warning in oyo_core::git::filter_oyoignore(repo_root, changes) {
+        eprintln!("Warning: {warning}");
     }
-    anyhow::bail!("Range must be in the form A..B or A...B");
 }
 
 fn split_ignore_globs(values: &[String]) -> Vec<String> {
@@ -365,10 +742,13 @@ fn directory_scan_options(
     DirectoryScanOptions {
         git_ignore,
         ignore_globs,
+        oyo_ignore: !args.no_ignore,
+        max_depth: args.max_depth.or(config.files.scan.max_depth),
+        lazy_load_bytes: Some(config.files.scan.lazy_load_mb.saturating_mul(1024 * 1024)),
     }
 }
 
-fn setup_terminal() -> Result<TuiTerminal> {
+fn setup_terminal(mouse_capture: bool) -> Result<TuiTerminal> {
     enable_raw_mode()?;
     let mut stdout: Box<dyn io::Write> = if io::stdout().is_terminal() {
         Box::new(io::stdout())
@@ -378,7 +758,10 @@ fn setup_terminal() -> Result<TuiTerminal> {
             Err(_) => Box::new(io::stdout()),
         }
     };
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    if mouse_capture {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
     Ok(terminal)
@@ -440,6 +823,8 @@ fn current_editor_target(app: &mut App, needs_line: bool) -> Result<Option<Edito
         EditorSide::New => FileSide::New,
     };
     if let Some(path) = app.multi_diff.existing_source_path(file_index, file_side) {
+        // Structural line numbers don't exist in the file; open it at the top.
+        let line = line.filter(|_| !app.skip_structural_lines());
         return Ok(Some(EditorTarget {
             path,
             line,
@@ -653,22 +1038,37 @@ fn visible_split_editor_focus(app: &App, view: &[ViewLine], target: usize) -> Op
 
 fn suspend_terminal_for_child(terminal: &mut TuiTerminal) -> Result<()> {
     disable_raw_mode()?;
+    leave_alternate_screen(terminal)?;
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+/// Always turns mouse reporting off: `z m` may have enabled it even under
+/// `--plain`, and disabling it when already off is harmless.
+fn leave_alternate_screen(terminal: &mut TuiTerminal) -> Result<()> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
         DisableMouseCapture
     )?;
-    terminal.show_cursor()?;
     Ok(())
 }
 
-fn resume_terminal_after_child(terminal: &mut TuiTerminal) -> Result<()> {
+fn set_mouse_capture(terminal: &mut TuiTerminal, enabled: bool) -> Result<()> {
+    if enabled {
+        execute!(terminal.backend_mut(), EnableMouseCapture)?;
+    } else {
+        execute!(terminal.backend_mut(), DisableMouseCapture)?;
+    }
+    Ok(())
+}
+
+fn resume_terminal_after_child(terminal: &mut TuiTerminal, mouse_capture: bool) -> Result<()> {
     enable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        EnterAlternateScreen,
-        EnableMouseCapture
-    )?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+    if mouse_capture {
+        execute!(terminal.backend_mut(), EnableMouseCapture)?;
+    }
     terminal.clear()?;
     Ok(())
 }
@@ -716,7 +1116,7 @@ fn open_current_file_in_editor(
 
     suspend_terminal_for_child(terminal)?;
     let editor_result = run_editor_command(&command, &args, target.cwd.as_deref());
-    let resume_result = resume_terminal_after_child(terminal);
+    let resume_result = resume_terminal_after_child(terminal, app.mouse_capture);
     resume_result?;
 
     if editor_result.is_ok() && target.refresh_after_edit {
@@ -725,31 +1125,192 @@ fn open_current_file_in_editor(
     Ok(())
 }
 
+/// `gf`: jump to a path on the cursor line within the diff, or open it in the
+/// editor when it is only on disk.
+fn open_path_under_cursor(
+    terminal: &mut TuiTerminal,
+    app: &mut App,
+    config: &config::EditorConfig,
+) -> Result<()> {
+    let Some(path) = app.goto_path_under_cursor() else {
+        return Ok(());
+    };
+    let command = resolve_editor_command(config);
+    let args = render_editor_args(config, None, &path);
+
+    suspend_terminal_for_child(terminal)?;
+    let _ = run_editor_command(&command, &args, app.multi_diff.repo_root());
+    resume_terminal_after_child(terminal, app.mouse_capture)
+}
+
+/// Apply CLI theme overrides and global diff limits to a loaded config.
+/// Returns whether the light theme variant should be used.
+fn apply_cli_overrides(config: &mut config::Config, args: &Args) -> bool {
+    if let Some(name) = args.theme_name.as_deref() {
+        config.ui.theme.name = Some(name.to_string());
+    }
+    if let Some(path) = args.theme_file.as_deref() {
+        config.ui.theme.path = Some(std::path::absolute(path).unwrap_or_else(|_| path.into()));
+    }
+    if let Some(name) = args.syntax_theme.as_deref() {
+        config.ui.syntax.theme = name.to_string();
+    }
+    if config.ui.syntax.theme.trim().is_empty() {
+        if let Some(name) = config.ui.theme.name.clone() {
+            config.ui.syntax.theme = name;
+        } else {
+            config.ui.syntax.theme = "ansi".to_string();
+        }
+    }
+    MultiFileDiff::set_diff_max_bytes(config.ui.diff.max_bytes);
+    MultiFileDiff::set_full_context_max_bytes(config.ui.diff.full_context_max_bytes);
+    MultiFileDiff::set_diff_defer(config.ui.diff.defer);
+    MultiFileDiff::set_color_moved(config.ui.diff.color_moved);
+    MultiFileDiff::set_structural_diff(config.ui.diff.structural);
+    MultiFileDiff::set_group_adjacent(
+        config
+            .ui
+            .diff
+            .group_adjacent
+            .then_some(config.ui.diff.group_adjacent_gap),
+    );
+    config.ui.show_keys |= args.show_keys;
+    config.ui.print_on_exit |= args.print_on_exit;
+    if args.plain {
+        config.ui.syntax.mode = config::SyntaxMode::Off;
+        config.playback.animation = false;
+        config.ui.diff.bg = false;
+        config.ui.gutter_signs = true;
+        config.ui.primary_marker = ">".to_string();
+        config.ui.primary_marker_right = Some("<".to_string());
+        config.ui.primary_marker_inactive = None;
+        config.ui.primary_marker_right_inactive = None;
+        config.ui.extent_marker = "|".to_string();
+        config.ui.extent_marker_right = Some("|".to_string());
+        config.ui.extent_marker_insert = None;
+        config.ui.extent_marker_delete = None;
+    }
+
+    // Compute theme mode: CLI overrides config, default to dark
+    match args.theme_mode {
+        Some(CliThemeMode::Light) => true,
+        Some(CliThemeMode::Dark) => false,
+        None => config.ui.theme.is_light_mode(),
+    }
+}
+
 fn apply_config_to_app(app: &mut App, config: &config::Config, args: &Args, light_mode: bool) {
-    let mut keybinding_warnings = Vec::new();
-    app.keybindings =
-        Keybindings::from_config_with_warnings(&config.keybindings, &mut keybinding_warnings);
-    for warning in keybinding_warnings {
+    for warning in apply_settings_to_app(app, config, light_mode) {
         eprintln!("Warning: {warning}");
     }
+    app.startup_anchor_top = config.ui.startup_anchor == config::StartupAnchor::Top;
+    app.show_line_debug = args.debug_lines;
+    app.plain = args.plain;
+    app.mouse_capture = !args.plain;
+    app.review_export_path = args.review_output_file.clone();
+    app.build_info = build_info::build_info(&config::Config::loaded_paths(args.config.as_deref()));
+    if app.file_sort != config::FileSortOrder::None {
+        // Start on the first file as listed rather than the first in the diff.
+        if let Some(&first) = app.filtered_file_indices().first() {
+            app.multi_diff.select_file(first);
+        }
+    }
+    let notes_dir = app
+        .multi_diff
+        .repo_root()
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::current_dir().ok());
+    app.presenter_notes = notes_dir.and_then(|dir| notes::PresenterNotes::load(&dir));
 
+    if args.no_step {
+        app.stepping = false;
+    } else {
+        app.stepping = config.ui.stepping;
+    }
+    if !app.stepping {
+        app.enter_no_step_mode();
+    }
+    app.handle_file_enter();
+    if config.files.continuous {
+        app.enter_continuous_view();
+    }
+}
+
+/// Copy config settings into a new app: the startup state of its session
+/// toggles, then everything [`apply_reloadable_settings`] covers.
+/// Returns keybinding and blame date format warnings for the caller to report.
+fn apply_settings_to_app(app: &mut App, config: &config::Config, light_mode: bool) -> Vec<String> {
     app.zen_mode = config.ui.zen;
+    app.show_keys = config.ui.show_keys;
     app.animation_enabled = config.playback.animation;
-    app.animation_duration = config.playback.animation_duration;
     app.file_panel_visible = config.files.panel_visible;
     app.file_panel_width = config.files.panel_width;
     app.file_count_mode = config.files.counts;
+    app.file_path_style = config.files.path_style;
+    app.show_commit_header = config.ui.commit_message;
+    app.set_fold_context_mode(config.ui.fold_context);
+    app.deletion_style = config.ui.deletion_style();
+    app.gutter_signs = config.ui.gutter_signs;
+    app.highlight_trailing_ws = config.ui.highlight_trailing_ws;
+    app.diff_highlight = config.ui.diff.highlight;
+    app.mine_only = config.ui.blame.mine_only;
+    app.syntax_mode = config.ui.syntax.mode;
+    app.unified_modified_step_mode = config.ui.unified.modified_step_mode;
+    app.split_orientation = config.ui.split.orientation;
+    app.evo_syntax = config.ui.evo.syntax;
+    apply_reloadable_settings(app, config, light_mode)
+}
+
+/// Copy config settings that have no session toggle, so a reload keeps
+/// wrap, panel, highlight and other changes made since startup. Wrap and
+/// line background defaults still apply to views that weren't toggled.
+/// Returns keybinding and blame date format warnings for the caller to report.
+fn apply_reloadable_settings(
+    app: &mut App,
+    config: &config::Config,
+    light_mode: bool,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+    app.keybindings = Keybindings::from_config_with_warnings(&config.keybindings, &mut warnings);
+
+    app.animation_duration = config.playback.animation_duration;
+    app.file_panel_default_width = config.files.panel_width;
+    app.file_sort = config.files.sort;
+    app.file_list_center = config.files.center_selection;
     app.auto_center = config.ui.auto_center;
     app.overscroll = config.ui.overscroll;
     app.topbar = config.ui.topbar;
-    app.line_wrap = config.ui.line_wrap;
-    app.set_fold_context_mode(config.ui.fold_context);
+    let wrap_by_view = [
+        (ViewMode::UnifiedPane, config.ui.unified.line_wrap),
+        (ViewMode::Split, config.ui.split.line_wrap),
+        (ViewMode::Evolution, config.ui.evo.line_wrap),
+    ];
+    app.set_line_wrap_defaults(
+        config.ui.line_wrap,
+        wrap_by_view
+            .into_iter()
+            .filter_map(|(mode, wrap)| wrap.map(|wrap| (mode, wrap)))
+            .collect(),
+    );
+    app.set_diff_bg_default(config.ui.diff.bg);
+    app.wrap_column = config.ui.wrap_column;
+    app.idle_tick = idle_tick(&config.ui);
+    app.color_column = config.ui.color_column;
+    app.review_progress = config.ui.review_progress;
+    app.show_time = config.ui.show_time;
+    app.hunk_step_progress = config.ui.hunk_step_progress;
+    app.locate_flash_enabled = config.ui.locate_flash;
+    app.hunk_separator = config.ui.hunk_separator.clone();
+    app.hunk_headers = config.ui.hunk_headers;
+    app.cursorline = config.ui.cursorline;
     app.scrollbar_visible = config.ui.scrollbar;
-    app.strikethrough_deletions = config.ui.strikethrough_deletions;
-    app.gutter_signs = config.ui.gutter_signs;
-    app.diff_bg = config.ui.diff.bg;
+    app.hscrollbar = config.ui.hscrollbar;
+    app.indent_guides = config.ui.indent_guides;
+    app.indent_guide_width = config.ui.indent_guide_width;
+    app.confirm_quit = config.ui.confirm_quit;
+    app.gutter_side = config.ui.gutter_side;
+    app.hex_max_bytes = config.ui.diff.hex_max_bytes;
     app.diff_fg = config.ui.diff.fg;
-    app.diff_highlight = config.ui.diff.highlight;
     app.diff_defer = config.ui.diff.defer;
     app.diff_idle_ms = config.ui.diff.idle_ms;
     app.diff_extent_marker = config.ui.diff.extent_marker;
@@ -758,49 +1319,90 @@ fn apply_config_to_app(app: &mut App, config: &config::Config, args: &Args, ligh
     app.blame_enabled = config.ui.blame.enabled;
     app.blame_mode = config.ui.blame.mode;
     app.blame_hunk_hint_enabled = config.ui.blame.hunk_hint;
-    app.blame_hunk_hint_enabled = config.ui.blame.hunk_hint;
-    app.syntax_mode = config.ui.syntax.mode;
+    app.blame_width = config.ui.blame.width;
     app.syntax_theme = config.ui.syntax.theme.clone();
     app.syntax_warmup_active_lines = config.ui.syntax.warmup.active_lines;
     app.syntax_warmup_pending_lines = config.ui.syntax.warmup.pending_lines;
     app.syntax_warmup_idle_lines = config.ui.syntax.warmup.idle_lines;
     app.syntax_warmup_debounce_ms = config.ui.syntax.warmup.debounce_ms;
-    app.unified_modified_step_mode = config.ui.unified.modified_step_mode;
     app.split_align_lines = config.ui.split.align_lines;
     app.split_align_fill = config.ui.split.align_fill.clone();
-    app.evo_syntax = config.ui.evo.syntax;
     app.auto_step_on_enter = config.playback.auto_step_on_enter;
+    app.auto_step_delay = Duration::from_millis(config.playback.auto_step_delay_ms);
     app.auto_step_blank_files = config.playback.auto_step_blank_files;
+    app.empty_step = config.playback.empty_step;
+    app.autoplay_indicator = config.playback.autoplay_indicator;
+    app.autoplay_end = config.playback.on_autoplay_end;
     app.no_step_auto_jump_on_enter = config.no_step.auto_jump_on_enter;
+    app.no_step_hunk_context = config.no_step.hunk_context;
     app.review_mention_file_scope = config.comments.mentions.file_scope;
     app.review_mention_finder = config.comments.mentions.finder;
+    app.review_comment_template = config.review.comment_template.clone();
+    app.review_old_header = config.review.old_header.clone();
+    app.review_new_header = config.review.new_header.clone();
+    app.review_output_format = config.review.output_format;
+    app.set_review_keywords(&config.review.keywords);
+    app.osc52 = config.clipboard.osc52;
+    app.watch_follow = config.watch.follow;
+    app.search_wrap_files = config.search.wrap_files;
     app.hunk_wrap = config.navigation.wrap.hunk;
     app.step_wrap = config.navigation.wrap.step;
+    app.sync_line_across_files = config.navigation.sync_line_across_files;
+    app.auto_advance_file = config.navigation.auto_advance_file;
+    app.collapse_reviewed_hunks = config.navigation.collapse_reviewed_hunks;
+    app.auto_fold_threshold = config.navigation.auto_fold_threshold;
+    app.space_action = config.navigation.space;
     app.primary_marker = config.ui.primary_marker.clone();
     app.primary_marker_right = config
         .ui
         .primary_marker_right
         .clone()
         .unwrap_or_else(|| "◀".to_string());
+    app.primary_marker_inactive = config.ui.primary_marker_inactive.clone();
+    app.primary_marker_right_inactive = config.ui.primary_marker_right_inactive.clone();
     app.extent_marker = config.ui.extent_marker.clone();
     app.extent_marker_right = config
         .ui
         .extent_marker_right
         .clone()
         .unwrap_or_else(|| "▐".to_string());
-    app.theme = config.ui.theme.resolve(light_mode);
-    app.time_format = TimeFormatter::new(&config.ui.time);
+    app.extent_marker_insert = config.ui.extent_marker_insert.clone();
+    app.extent_marker_delete = config.ui.extent_marker_delete.clone();
+    app.theme = config.ui.resolve_theme(light_mode);
+    warnings.extend(config.ui.theme.file_warnings());
+    app.time_format = TimeFormatter::for_blame(&config.ui.time, &config.ui.blame.date_format)
+        .unwrap_or_else(|warning| {
+            warnings.push(warning);
+            TimeFormatter::new(&config.ui.time)
+        });
     app.theme_is_light = light_mode;
+    warnings
+}
 
-    if args.no_step {
-        app.stepping = false;
-    } else {
-        app.stepping = config.ui.stepping;
+/// Re-read the config file and apply it to the running app. On a read or
+/// parse error the previous config stays in effect.
+fn reload_config(app: &mut App, config: &mut config::Config, args: &Args) {
+    match config::Config::try_load(args.config.as_deref()) {
+        Ok((mut next, mut warnings)) => {
+            warnings.extend(next.ui.validate_markers());
+            apply_cli_overrides(&mut next, args);
+            // Keep the light/dark mode the session is in.
+            let light_mode = app.theme_is_light;
+            warnings.extend(apply_reloadable_settings(app, &next, light_mode));
+            *config = next;
+            app.finish_config_reload(Ok(warnings));
+        }
+        Err(error) => app.finish_config_reload(Err(error)),
     }
-    if !app.stepping {
-        app.enter_no_step_mode();
+}
+
+/// The last-viewed file as `--no-tui` prints it, for `ui.print_on_exit`.
+fn exit_diff_output(app: &mut App) -> String {
+    if app.multi_diff.file_count() == 0 {
+        return String::new();
     }
-    app.handle_file_enter();
+    let idx = app.multi_diff.selected_index;
+    plain::render_file_diff(&mut app.multi_diff, idx, io::stdout().is_terminal())
 }
 
 fn emit_review_output(
@@ -985,8 +1587,11 @@ fn build_diff_from_input_mode(
 
             let repo_root =
                 oyo_core::git::get_repo_root(&cwd).context("Failed to get git repository root")?;
-            let changes = oyo_core::git::get_uncommitted_changes(&repo_root)
+            let mut changes = oyo_core::git::get_uncommitted_changes(&repo_root)
                 .context("Failed to get uncommitted changes")?;
+            if !args.no_ignore {
+                apply_oyoignore(&repo_root, &mut changes);
+            }
             if changes.is_empty() {
                 return Ok(None);
             }
@@ -1009,8 +1614,11 @@ fn build_diff_from_input_mode(
 
             let repo_root =
                 oyo_core::git::get_repo_root(&cwd).context("Failed to get git repository root")?;
-            let changes = oyo_core::git::get_staged_changes(&repo_root)
+            let mut changes = oyo_core::git::get_staged_changes(&repo_root)
                 .context("Failed to get staged changes")?;
+            if !args.no_ignore {
+                apply_oyoignore(&repo_root, &mut changes);
+            }
             if changes.is_empty() {
                 return Ok(None);
             }
@@ -1025,7 +1633,7 @@ fn build_diff_from_input_mode(
                 anyhow::bail!(
                     "Not in a git repository.\n\
                      \n\
-                     Usage: oy --range A..B\n\
+                     Usage: oy --range A..B (or --range main for this branch)\n\
                      \n\
                      Or run from a git repository."
                 );
@@ -1042,9 +1650,12 @@ fn build_diff_from_input_mode(
                     (to.clone(), false)
                 };
                 let reverse = !to_index;
-                let changes =
+                let mut changes =
                     oyo_core::git::get_changes_between_index(&repo_root, &commit, reverse)
                         .context("Failed to get index range changes")?;
+                if !args.no_ignore {
+                    apply_oyoignore(&repo_root, &mut changes);
+                }
                 if changes.is_empty() {
                     return Ok(None);
                 }
@@ -1057,8 +1668,11 @@ fn build_diff_from_input_mode(
                 .context("Failed to create diff from index range")?;
                 (changes, diff)
             } else {
-                let changes = oyo_core::git::get_changes_between(&repo_root, from, to)
+                let mut changes = oyo_core::git::get_changes_between(&repo_root, from, to)
                     .context("Failed to get range changes")?;
+                if !args.no_ignore {
+                    apply_oyoignore(&repo_root, &mut changes);
+                }
                 if changes.is_empty() {
                     return Ok(None);
                 }
@@ -1077,6 +1691,54 @@ fn build_diff_from_input_mode(
             let branch = oyo_core::git::get_current_branch(&repo_root).ok();
             (diff, branch)
         }
+        InputMode::GitRefs {
+            path,
+            old_ref,
+            new_ref,
+        } => {
+            let cwd = std::env::current_dir().unwrap_or_default();
+            if !oyo_core::git::is_git_repo(&cwd) {
+                anyhow::bail!(
+                    "Not in a git repository.\n\
+                     \n\
+                     Usage: oy --old <ref> --new <ref> <file>"
+                );
+            }
+
+            let repo_root =
+                oyo_core::git::get_repo_root(&cwd).context("Failed to get git repository root")?;
+            let abs_path = if path.is_absolute() {
+                path.clone()
+            } else {
+                cwd.join(path)
+            };
+            let rel_path = abs_path.strip_prefix(&repo_root).with_context(|| {
+                format!("Path is outside the git repository: {}", path.display())
+            })?;
+
+            let diff = MultiFileDiff::from_git_refs(
+                repo_root.clone(),
+                rel_path.to_path_buf(),
+                old_ref.clone(),
+                new_ref.clone(),
+            )
+            .context("Failed to create diff between refs")?;
+            let branch = oyo_core::git::get_current_branch(&repo_root).ok();
+            (diff, branch)
+        }
+        InputMode::GitHubPr { number, pr } => {
+            let patch = github::pr_diff(*number)?;
+            let diff = MultiFileDiff::from_patch(&patch);
+            (diff, pr.as_ref().map(|pr| pr.head.clone()))
+        }
+        InputMode::Paste { old, new } => {
+            let diff = MultiFileDiff::from_file_pair_bytes(
+                PathBuf::from("pasted"),
+                old.clone().into_bytes(),
+                new.clone().into_bytes(),
+            );
+            (diff, None)
+        }
         InputMode::None => {
             anyhow::bail!(
                 "Usage: oy <old_file> <new_file>\n\
@@ -1091,7 +1753,16 @@ fn build_diff_from_input_mode(
 }
 
 fn main() -> Result<()> {
+    time_format::init_local_offset();
     let args = Args::parse();
+    if args.build_info {
+        let config_paths = config::Config::loaded_paths(args.config.as_deref());
+        print!(
+            "{}",
+            build_info::format_build_info(&build_info::build_info(&config_paths))
+        );
+        return Ok(());
+    }
     let view_limit = match args.command {
         Some(Command::Themes) => {
             for name in config::list_ui_themes() {
@@ -1105,10 +1776,21 @@ fn main() -> Result<()> {
             }
             return Ok(());
         }
+        Some(Command::Keys) => {
+            let config = config::Config::load(args.config.as_deref());
+            let mut warnings = Vec::new();
+            let keybindings =
+                Keybindings::from_config_with_warnings(&config.keybindings, &mut warnings);
+            for warning in warnings {
+                eprintln!("Warning: {warning}");
+            }
+            print!("{}", keybindings.reference());
+            return Ok(());
+        }
         Some(Command::View { limit }) => Some(limit),
         None => None,
     };
-    let mut config = config::Config::load();
+    let mut config = config::Config::load(args.config.as_deref());
     if let Some(path) = args.dump_scopes.as_deref() {
         if let Some(name) = args.theme_name.as_deref() {
             config.ui.theme.name = Some(name.to_string());
@@ -1137,48 +1819,29 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
-    if let Some(name) = args.theme_name.as_deref() {
-        config.ui.theme.name = Some(name.to_string());
-    }
-    if let Some(name) = args.syntax_theme.as_deref() {
-        config.ui.syntax.theme = name.to_string();
-    }
-    if config.ui.syntax.theme.trim().is_empty() {
-        if let Some(name) = config.ui.theme.name.clone() {
-            config.ui.syntax.theme = name;
-        } else {
-            config.ui.syntax.theme = "ansi".to_string();
-        }
+    let light_mode = apply_cli_overrides(&mut config, &args);
+    if let Some(pattern) = args.ignore_matching.as_deref() {
+        let regex = Regex::new(pattern)
+            .with_context(|| format!("Invalid --ignore-matching pattern: {pattern}"))?;
+        MultiFileDiff::set_ignore_matching(Some(regex));
     }
-    MultiFileDiff::set_diff_max_bytes(config.ui.diff.max_bytes);
-    MultiFileDiff::set_full_context_max_bytes(config.ui.diff.full_context_max_bytes);
-    MultiFileDiff::set_diff_defer(config.ui.diff.defer);
-
-    // Compute theme mode: CLI overrides config, default to dark
-    let light_mode = match args.theme_mode {
-        Some(CliThemeMode::Light) => true,
-        Some(CliThemeMode::Dark) => false,
-        None => config.ui.theme.is_light_mode(),
-    };
 
     if let Some(limit) = view_limit {
-        let mut terminal = setup_terminal()?;
-        let mut input_mode = match run_commit_picker(&mut terminal, &config, light_mode, limit)? {
-            Some(mode) => mode,
-            None => {
-                disable_raw_mode()?;
-                execute!(
-                    terminal.backend_mut(),
-                    LeaveAlternateScreen,
-                    DisableMouseCapture
-                )?;
-                terminal.show_cursor()?;
-                return Ok(());
-            }
-        };
+        let mut terminal = setup_terminal(!args.plain)?;
+        let mut input_mode =
+            match run_commit_picker(&mut terminal, &config, light_mode, limit, None)? {
+                Some(mode) => mode,
+                None => {
+                    disable_raw_mode()?;
+                    leave_alternate_screen(&mut terminal)?;
+                    terminal.show_cursor()?;
+                    return Ok(());
+                }
+            };
 
         let mut exit_message: Option<String> = None;
         let mut review_output: Option<String> = None;
+        let mut exit_diff: Option<String> = None;
         loop {
             let empty_message = match &input_mode {
                 InputMode::GitUncommitted => Some("No uncommitted changes found.".to_string()),
@@ -1217,29 +1880,32 @@ fn main() -> Result<()> {
             app.set_review_clear_session_on_start(args.clear_review_session);
             app.enable_review_mode();
 
-            let exit = run_app(&mut terminal, &mut app, &config.editor)?;
+            let exit = run_app(&mut terminal, &mut app, &mut config, &args)?;
             if review_output.is_none() {
                 review_output = app.take_review_submission_output();
             }
-            match exit {
-                AppExit::Quit => break,
-                AppExit::OpenDashboard => {
-                    let Some(mode) = run_commit_picker(&mut terminal, &config, light_mode, limit)?
-                    else {
-                        break;
-                    };
-                    input_mode = mode;
+            let file = match exit {
+                AppExit::Quit => {
+                    exit_diff = config.ui.print_on_exit.then(|| exit_diff_output(&mut app));
+                    break;
                 }
-            }
+                AppExit::OpenDashboard => None,
+                AppExit::OpenFileHistory(file) => Some(file),
+            };
+            let Some(mode) =
+                run_commit_picker(&mut terminal, &config, light_mode, limit, file.as_deref())?
+            else {
+                break;
+            };
+            input_mode = mode;
         }
 
         disable_raw_mode()?;
-        execute!(
-            terminal.backend_mut(),
-            LeaveAlternateScreen,
-            DisableMouseCapture
-        )?;
+        leave_alternate_screen(&mut terminal)?;
         terminal.show_cursor()?;
+        if let Some(output) = exit_diff {
+            print!("{output}");
+        }
         emit_review_output(
             review_output,
             args.review_output_file.as_ref(),
@@ -1251,7 +1917,42 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
-    let mut input_mode = if args.paths.len() == 7 {
+    let mut input_mode = if args.paste {
+        if !args.paths.is_empty() {
+            anyhow::bail!("--paste cannot be used with file paths");
+        }
+        let stdin = io::stdin();
+        if stdin.is_terminal() {
+            eprintln!("Paste the old text, then a line `{PASTE_MARKER}`, then the new text; end with Ctrl-D.");
+        }
+        let mut input = String::new();
+        stdin
+            .lock()
+            .read_to_string(&mut input)
+            .context("Failed to read pasted input")?;
+        let Some((old, new)) = split_paste_input(&input)? else {
+            println!("Nothing pasted.");
+            return Ok(());
+        };
+        InputMode::Paste { old, new }
+    } else if let Some(number) = args.pr {
+        if !args.paths.is_empty() {
+            anyhow::bail!("--pr cannot be used with file paths");
+        }
+        InputMode::GitHubPr {
+            number,
+            pr: github::pr_view(number),
+        }
+    } else if let (Some(old_ref), Some(new_ref)) = (&args.old_ref, &args.new_ref) {
+        let [path] = args.paths.as_slice() else {
+            anyhow::bail!("--old/--new require exactly one file path");
+        };
+        InputMode::GitRefs {
+            path: path.clone(),
+            old_ref: old_ref.clone(),
+            new_ref: new_ref.clone(),
+        }
+    } else if matches!(args.paths.len(), 7 | 9) {
         detect_input_mode(&args.paths)
     } else if args.staged || args.range.is_some() {
         if !args.paths.is_empty() {
@@ -1261,8 +1962,10 @@ fn main() -> Result<()> {
             anyhow::bail!("--staged and --range are mutually exclusive");
         }
         if let Some(range) = args.range.as_deref() {
-            let (from, to) = parse_range(range)?;
-            InputMode::GitRange { from, to }
+            match parse_range(range)? {
+                RangeArg::Between(from, to) => InputMode::GitRange { from, to },
+                RangeArg::Single(reference) => single_ref_input_mode(&reference)?,
+            }
         } else {
             InputMode::GitStaged
         }
@@ -1276,7 +1979,19 @@ fn main() -> Result<()> {
         InputMode::GitRange { from, to } => Some(format!("No changes in range {}..{}.", from, to)),
         _ => Some("No changes found.".to_string()),
     };
-    let prefetched = match build_diff_from_input_mode(&input_mode, &config, &args)? {
+    let mut prefetched = build_diff_from_input_mode(&input_mode, &config, &args)?;
+    if prefetched.is_none()
+        && matches!(input_mode, InputMode::GitUncommitted)
+        && branch_fallback_allowed(&args)
+    {
+        if let Some(branch_mode) = default_branch_input_mode() {
+            prefetched = build_diff_from_input_mode(&branch_mode, &config, &args)?;
+            if prefetched.is_some() {
+                input_mode = branch_mode;
+            }
+        }
+    }
+    let prefetched = match prefetched {
         Some(result) => result,
         None => {
             if let Some(message) = empty_message {
@@ -1289,12 +2004,51 @@ fn main() -> Result<()> {
         println!("No changes found.");
         return Ok(());
     }
+    // With no changes the returns above already exit 0, like `git diff --exit-code`
+    if args.exit_code {
+        let file_count = prefetched.0.file_count();
+        let noun = if file_count == 1 { "file" } else { "files" };
+        println!("{file_count} {noun} changed.");
+        std::process::exit(1);
+    }
+    if args.no_tui {
+        let mut multi_diff = prefetched.0;
+        let stdout_is_tty = io::stdout().is_terminal();
+        let output = plain::render_diff(&mut multi_diff, stdout_is_tty);
+        let pager = plain::resolve_pager(
+            std::env::var("OYO_PAGER").ok(),
+            std::env::var("PAGER").ok(),
+            stdout_is_tty,
+        );
+        plain::write_paged(&output, pager.as_deref())?;
+        return Ok(());
+    }
+
+    // Resolved before the viewer starts so a miss can warn on a clean terminal.
+    let mut goto = args.goto.as_deref().and_then(|spec| {
+        let (path, line) = parse_goto(spec);
+        match find_goto_file(&prefetched.0, path) {
+            Some(idx) => Some((idx, line)),
+            None => {
+                eprintln!("Warning: --goto: {path} is not in this diff");
+                None
+            }
+        }
+    });
+
+    let hunk_specs = parse_hunk_specs(&args.hunks)?;
+    for (path, _) in &hunk_specs {
+        if find_goto_file(&prefetched.0, path).is_none() {
+            eprintln!("Warning: --hunks: {path} is not in this diff");
+        }
+    }
 
-    let mut terminal = setup_terminal()?;
+    let mut terminal = setup_terminal(!args.plain)?;
     let dashboard_limit = view_limit.unwrap_or(200);
 
     let mut exit_message: Option<String> = None;
     let mut review_output: Option<String> = None;
+    let mut exit_diff: Option<String> = None;
     let mut pending_diff = Some(prefetched);
     loop {
         let empty_message = match &input_mode {
@@ -1331,37 +2085,71 @@ fn main() -> Result<()> {
         };
         let autoplay = args.autoplay || config.playback.autoplay;
 
+        let hunk_subset =
+            (!hunk_specs.is_empty()).then(|| resolve_hunk_specs(&multi_diff, &hunk_specs));
         let mut app = App::new(multi_diff, view_mode, speed, autoplay, git_branch);
+        if matches!(input_mode, InputMode::GitExternal { .. }) {
+            app.external_diff_progress = external_diff_progress();
+        }
+        if let InputMode::GitRange { from, to } = &input_mode {
+            app.range_note = merge_range_note(from, to);
+            app.commit_header = range_commit_header(from, to);
+        }
+        if let InputMode::GitRefs {
+            old_ref, new_ref, ..
+        } = &input_mode
+        {
+            app.commit_header = range_commit_header(old_ref, new_ref);
+        }
+        if let InputMode::GitHubPr { number, pr } = &input_mode {
+            app.range_note = Some(github::pr_note(*number, pr.as_ref()));
+        }
         apply_config_to_app(&mut app, &config, &args, light_mode);
         app.set_review_persist_enabled(!args.no_review_persist);
         app.set_review_clear_session_on_start(args.clear_review_session);
         app.enable_review_mode();
+        if let Some(listed) = hunk_subset {
+            app.set_hunk_subset(listed, args.hunks_only);
+        }
+        if let Some((idx, line)) = goto.take() {
+            app.select_file(idx);
+            if let Some(line) = line {
+                app.goto_line_number(line);
+            }
+        }
 
-        let exit = run_app(&mut terminal, &mut app, &config.editor)?;
+        let exit = run_app(&mut terminal, &mut app, &mut config, &args)?;
         if review_output.is_none() {
             review_output = app.take_review_submission_output();
         }
-        match exit {
-            AppExit::Quit => break,
-            AppExit::OpenDashboard => {
-                let Some(mode) =
-                    run_commit_picker(&mut terminal, &config, light_mode, dashboard_limit)?
-                else {
-                    break;
-                };
-                input_mode = mode;
-                pending_diff = None;
+        let file = match exit {
+            AppExit::Quit => {
+                exit_diff = config.ui.print_on_exit.then(|| exit_diff_output(&mut app));
+                break;
             }
-        }
+            AppExit::OpenDashboard => None,
+            AppExit::OpenFileHistory(file) => Some(file),
+        };
+        let Some(mode) = run_commit_picker(
+            &mut terminal,
+            &config,
+            light_mode,
+            dashboard_limit,
+            file.as_deref(),
+        )?
+        else {
+            break;
+        };
+        input_mode = mode;
+        pending_diff = None;
     }
 
     disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    leave_alternate_screen(&mut terminal)?;
     terminal.show_cursor()?;
+    if let Some(output) = exit_diff {
+        print!("{output}");
+    }
     emit_review_output(
         review_output,
         args.review_output_file.as_ref(),
@@ -1377,16 +2165,25 @@ fn main() -> Result<()> {
 fn run_app(
     terminal: &mut TuiTerminal,
     app: &mut App,
-    editor_config: &config::EditorConfig,
+    config: &mut config::Config,
+    args: &Args,
 ) -> Result<AppExit> {
     let mut pending_event: Option<Event> = None;
     let mut needs_draw = true;
+    // The terminal's capture state, synced to `app.mouse_capture` (a key or
+    // palette toggle, or a fresh App after the commit picker).
+    let mut mouse_captured = None;
 
     loop {
+        if mouse_captured != Some(app.mouse_capture) {
+            set_mouse_capture(terminal, app.mouse_capture)?;
+            mouse_captured = Some(app.mouse_capture);
+        }
         if needs_draw {
-            terminal
+            let completed = terminal
                 .draw(|f| ui::draw(f, app))
                 .map_err(|e| anyhow!("{e}"))?;
+            app.copy_pending_snapshot(completed.buffer);
             needs_draw = false;
 
             // Clear active change after render (one-frame extent marker display when animation disabled)
@@ -1413,7 +2210,13 @@ fn run_app(
             needs_draw = true;
             match event {
                 Event::Mouse(me) => {
-                    if app.show_help || app.show_path_popup {
+                    if app.show_help
+                        || app.show_overview
+                        || app.show_path_popup
+                        || app.show_note_popup
+                        || app.show_build_info
+                        || app.quit_confirm_pending
+                    {
                         continue;
                     }
                     app.reset_count();
@@ -1455,6 +2258,9 @@ fn run_app(
                             if app.handle_review_preview_click(me.column, me.row) {
                                 continue;
                             }
+                            if app.handle_continuous_click(me.column, me.row) {
+                                continue;
+                            }
                             if app.handle_file_list_click(me.column, me.row) {
                                 continue;
                             }
@@ -1469,6 +2275,16 @@ fn run_app(
                         MouseEventKind::Up(MouseButton::Left) => {
                             app.end_file_panel_resize();
                         }
+                        MouseEventKind::ScrollUp
+                            if app.mouse_over_commit_header(me.column, me.row) =>
+                        {
+                            app.scroll_commit_header(-1);
+                        }
+                        MouseEventKind::ScrollDown
+                            if app.mouse_over_commit_header(me.column, me.row) =>
+                        {
+                            app.scroll_commit_header(1);
+                        }
                         MouseEventKind::ScrollUp => {
                             if app.mouse_over_file_panel(me.column, me.row) {
                                 app.prev_file();
@@ -1493,12 +2309,28 @@ fn run_app(
                 Event::Key(key)
                     if matches!(key.kind, KeyEventKind::Press | KeyEventKind::Repeat) =>
                 {
-                    handle_app_key(app, key, &mut pending_event, terminal, editor_config)?;
+                    if app.show_keys {
+                        app.record_key(key_log_label(key));
+                    }
+                    handle_app_key(app, key, &mut pending_event, terminal, &config.editor)?;
                 }
+                Event::Resize(..) => app.handle_resize(),
                 _ => {}
             }
         }
 
+        if app.config_reload_requested {
+            app.config_reload_requested = false;
+            reload_config(app, config, args);
+            needs_draw = true;
+        }
+        if app.theme_mode_toggle_requested {
+            app.theme_mode_toggle_requested = false;
+            let light_mode = !app.theme_is_light;
+            app.set_theme(config.ui.resolve_theme(light_mode), light_mode);
+            needs_draw = true;
+        }
+
         if app.tick() {
             needs_draw = true;
         }
@@ -1507,12 +2339,79 @@ fn run_app(
             app.open_dashboard = false;
             return Ok(AppExit::OpenDashboard);
         }
+        if let Some(file) = app.open_file_history.take() {
+            return Ok(AppExit::OpenFileHistory(file));
+        }
+        if let Some(diff) = app.open_compare.take() {
+            match run_compare(terminal, app, diff, "comparing files", config, args)? {
+                AppExit::Quit => {}
+                exit => return Ok(exit),
+            }
+            // The nested viewer may have left capture in its own state.
+            mouse_captured = None;
+            app.handle_resize();
+            needs_draw = true;
+        }
+        if let Some(file) = app.open_commit_walk.take() {
+            if let Some(diff) = commit_walk_diff(app, &file) {
+                let note = format!("{} · commit by commit", file.display());
+                match run_compare(terminal, app, diff, &note, config, args)? {
+                    AppExit::Quit => {}
+                    exit => return Ok(exit),
+                }
+                mouse_captured = None;
+                app.handle_resize();
+                needs_draw = true;
+            }
+        }
         if app.should_quit {
-            return Ok(AppExit::Quit);
+            if !app.hold_quit_for_confirmation() {
+                return Ok(AppExit::Quit);
+            }
+            needs_draw = true;
         }
     }
 }
 
+/// Each commit in the range that touched `file`, as one entry per commit.
+fn commit_walk_diff(app: &App, file: &Path) -> Option<MultiFileDiff> {
+    let (from, to) = app.multi_diff.git_range()?;
+    let repo_root = app.multi_diff.repo_root()?;
+    let commits = oyo_core::git::get_range_file_commits(repo_root, from, to, file).ok()?;
+    (!commits.is_empty()).then(|| MultiFileDiff::from_file_commits(repo_root, file, &commits))
+}
+
+/// View a derived diff (two marked files, a file's commits) in a nested
+/// viewer; quitting returns to `app`, which is left untouched.
+fn run_compare(
+    terminal: &mut TuiTerminal,
+    app: &App,
+    diff: MultiFileDiff,
+    note: &str,
+    config: &mut config::Config,
+    args: &Args,
+) -> Result<AppExit> {
+    let mut compare = compare_app(app, diff, note, config);
+    run_app(terminal, &mut compare, config, args)
+}
+
+/// The nested viewer's App, carrying over `app`'s session toggles.
+fn compare_app(app: &App, diff: MultiFileDiff, note: &str, config: &config::Config) -> App {
+    let mut compare = App::new(diff, app.view_mode, app.animation_speed, false, None);
+    // Warnings were already reported for the main viewer.
+    let _ = apply_settings_to_app(&mut compare, config, app.theme_is_light);
+    compare.plain = app.plain;
+    compare.mouse_capture = app.mouse_capture;
+    compare.stepping = app.stepping;
+    if !compare.stepping {
+        compare.enter_no_step_mode();
+    }
+    compare.handle_file_enter();
+    let quit_keys = compare.keybindings.normal_keys(NormalAction::Quit);
+    compare.range_note = Some(format!("{note} · {quit_keys} returns"));
+    compare
+}
+
 fn coalesce_key_repeats(
     first: KeyEvent,
     pending_event: &mut Option<Event>,
@@ -1537,11 +2436,17 @@ fn coalesce_key_repeats(
     Ok(count)
 }
 
+/// Idle poll interval from `ui.idle_tick_ms`, kept at or above one
+/// animation frame so a tiny value cannot spin the loop.
+fn idle_tick(ui: &config::UiConfig) -> Duration {
+    Duration::from_millis(ui.idle_tick_ms.max(app::MIN_IDLE_TICK_MS))
+}
+
 fn run_dashboard<B: Backend>(
     terminal: &mut Terminal<B>,
     dashboard: &mut Dashboard,
+    tick_rate: Duration,
 ) -> Result<Option<DashboardSelection>> {
-    let tick_rate = Duration::from_millis(250);
     let mut needs_draw = true;
 
     loop {
@@ -1612,6 +2517,9 @@ fn run_dashboard<B: Backend>(
                         Dispatch::Matched(DashboardAction::TogglePin) => {
                             dashboard.toggle_pin();
                         }
+                        Dispatch::Matched(DashboardAction::ToggleTags) => {
+                            dashboard.toggle_tags();
+                        }
                         Dispatch::Matched(DashboardAction::Accept) => {
                             if let Some(selection) = dashboard.selection() {
                                 return Ok(Some(selection));
@@ -1677,11 +2585,14 @@ fn printable_dashboard_char(key: KeyEvent) -> Option<char> {
     }
 }
 
+/// Pick a range from recent commits, or a revision of `file` (repo-relative)
+/// from the commits touching it.
 fn run_commit_picker<B: Backend>(
     terminal: &mut Terminal<B>,
     config: &config::Config,
     light_mode: bool,
     limit: usize,
+    file: Option<&Path>,
 ) -> Result<Option<InputMode>> {
     let cwd = std::env::current_dir().unwrap_or_default();
     if !oyo_core::git::is_git_repo(&cwd) {
@@ -1691,21 +2602,37 @@ fn run_commit_picker<B: Backend>(
     let repo_root =
         oyo_core::git::get_repo_root(&cwd).context("Failed to get git repository root")?;
     let branch = oyo_core::git::get_current_branch(&repo_root).ok();
-    let commits =
-        oyo_core::git::get_recent_commits(&repo_root, limit).context("Failed to get commits")?;
-    let working_changes = oyo_core::git::get_uncommitted_changes(&repo_root)
-        .context("Failed to get uncommitted changes")?;
-    let staged_changes =
-        oyo_core::git::get_staged_changes(&repo_root).context("Failed to get staged changes")?;
-
-    let theme = config.ui.theme.resolve(light_mode);
+    let (commits, working_files, staged_files, tags) = match file {
+        Some(file) => (
+            oyo_core::git::get_file_commits(&repo_root, file, limit)
+                .context("Failed to get file history")?,
+            0,
+            0,
+            Vec::new(),
+        ),
+        None => (
+            oyo_core::git::get_recent_commits(&repo_root, limit)
+                .context("Failed to get commits")?,
+            oyo_core::git::get_uncommitted_changes(&repo_root)
+                .context("Failed to get uncommitted changes")?
+                .len(),
+            oyo_core::git::get_staged_changes(&repo_root)
+                .context("Failed to get staged changes")?
+                .len(),
+            oyo_core::git::get_tags(&repo_root).unwrap_or_default(),
+        ),
+    };
+
+    let theme = config.ui.resolve_theme(light_mode);
     let time_format = TimeFormatter::new(&config.ui.time);
     let mut dashboard = Dashboard::new(DashboardConfig {
-        repo_root,
+        repo_root: repo_root.clone(),
         branch,
         commits,
-        working_files: working_changes.len(),
-        staged_files: staged_changes.len(),
+        tags,
+        file: file.map(Path::to_path_buf),
+        working_files,
+        staged_files,
         theme,
         primary_marker: config.ui.primary_marker.clone(),
         extent_marker: config.ui.extent_marker.clone(),
@@ -1713,12 +2640,17 @@ fn run_commit_picker<B: Backend>(
         keybindings: Keybindings::from_config(&config.keybindings),
     });
 
-    let selection = run_dashboard(terminal, &mut dashboard)?;
-    let input_mode = match selection {
-        None => return Ok(None),
-        Some(DashboardSelection::Uncommitted) => InputMode::GitUncommitted,
-        Some(DashboardSelection::Staged) => InputMode::GitStaged,
-        Some(DashboardSelection::Range { from, to }) => InputMode::GitRange { from, to },
+    let selection = run_dashboard(terminal, &mut dashboard, idle_tick(&config.ui))?;
+    let input_mode = match (selection, file) {
+        (None, _) => return Ok(None),
+        (Some(DashboardSelection::Range { from, to }), Some(file)) => InputMode::GitRefs {
+            path: repo_root.join(file),
+            old_ref: from,
+            new_ref: to,
+        },
+        (Some(DashboardSelection::Uncommitted), _) => InputMode::GitUncommitted,
+        (Some(DashboardSelection::Staged), _) => InputMode::GitStaged,
+        (Some(DashboardSelection::Range { from, to }), None) => InputMode::GitRange { from, to },
     };
 
     Ok(Some(input_mode))
@@ -1726,21 +2658,31 @@ fn run_commit_picker<B: Backend>(
 
 #[cfg(test)]
 mod tests {
-    use super::{config, detect_input_mode, parse_range, render_editor_args, InputMode};
+    use super::{
+        apply_settings_to_app, branch_fallback_allowed, compare_app, config, detect_input_mode,
+        parse_external_diff_progress, parse_goto, parse_hunk_specs, parse_range, reload_config,
+        render_editor_args, split_paste_input, Args, InputMode, RangeArg,
+    };
+    use crate::app::{App, ViewMode};
+    use crate::test_utils::TestApp;
+    use clap::Parser;
+    use oyo_core::MultiFileDiff;
     use std::path::{Path, PathBuf};
 
     #[test]
     fn parse_range_accepts_double_dot() {
-        let (from, to) = parse_range("HEAD~1..HEAD").unwrap();
-        assert_eq!(from, "HEAD~1");
-        assert_eq!(to, "HEAD");
+        assert_eq!(
+            parse_range("HEAD~1..HEAD").unwrap(),
+            RangeArg::Between("HEAD~1".to_string(), "HEAD".to_string())
+        );
     }
 
     #[test]
     fn parse_range_accepts_triple_dot() {
-        let (from, to) = parse_range("main...feature").unwrap();
-        assert_eq!(from, "main");
-        assert_eq!(to, "feature");
+        assert_eq!(
+            parse_range("main...feature").unwrap(),
+            RangeArg::Between("main".to_string(), "feature".to_string())
+        );
     }
 
     #[test]
@@ -1758,8 +2700,73 @@ mod tests {
     }
 
     #[test]
-    fn parse_range_rejects_missing_separator() {
-        assert!(parse_range("HEAD").is_err());
+    fn split_paste_input_splits_at_first_marker() {
+        let (old, new) = split_paste_input("a\nb\n-- >8 --\nb\n-- >8 --\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(old, "a\nb\n");
+        assert_eq!(new, "b\n-- >8 --\n");
+        assert!(split_paste_input(" \n\n").unwrap().is_none());
+        assert!(split_paste_input("only old\n").is_err());
+    }
+
+    #[test]
+    fn parse_hunk_specs_reads_lists_and_ranges() {
+        let specs = vec!["src/main.rs:2,3".to_string(), "lib.rs:1-3".to_string()];
+        assert_eq!(
+            parse_hunk_specs(&specs).unwrap(),
+            vec![
+                ("src/main.rs".to_string(), vec![2, 3]),
+                ("lib.rs".to_string(), vec![1, 2, 3]),
+            ]
+        );
+        for bad in ["main.rs", "main.rs:0", "main.rs:3-1", ":2", "main.rs:x"] {
+            assert!(parse_hunk_specs(&[bad.to_string()]).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn hunks_flag_takes_one_entry_and_leaves_paths_positional() {
+        let args = Args::try_parse_from([
+            "oy", "--hunks", "n.txt:1", "--hunks", "m.txt:2", "o.txt", "n.txt",
+        ])
+        .unwrap();
+        assert_eq!(args.hunks, vec!["n.txt:1", "m.txt:2"]);
+        assert_eq!(
+            args.paths,
+            vec![PathBuf::from("o.txt"), PathBuf::from("n.txt")]
+        );
+    }
+
+    #[test]
+    fn clean_worktree_falls_back_to_branch_only_in_the_viewer() {
+        let parse = |argv: &[&str]| Args::try_parse_from(argv).unwrap();
+        assert!(branch_fallback_allowed(&parse(&["oy"])));
+        assert!(!branch_fallback_allowed(&parse(&["oy", "--exit-code"])));
+        assert!(!branch_fallback_allowed(&parse(&["oy", "--no-tui"])));
+    }
+
+    #[test]
+    fn parse_goto_splits_trailing_line_number() {
+        assert_eq!(parse_goto("src/main.rs:42"), ("src/main.rs", Some(42)));
+        assert_eq!(parse_goto("src/main.rs"), ("src/main.rs", None));
+        assert_eq!(parse_goto("src/main.rs:0"), ("src/main.rs:0", None));
+        assert_eq!(parse_goto("C:notes.txt"), ("C:notes.txt", None));
+        assert_eq!(parse_goto(":7"), (":7", None));
+    }
+
+    #[test]
+    fn parse_range_accepts_single_ref() {
+        assert_eq!(
+            parse_range("main").unwrap(),
+            RangeArg::Single("main".to_string())
+        );
+        assert_eq!(
+            parse_range("abc1234").unwrap(),
+            RangeArg::Single("abc1234".to_string())
+        );
+        assert!(parse_range("").is_err());
+        assert!(parse_range("main feature").is_err());
     }
 
     #[test]
@@ -1771,6 +2778,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn detect_input_mode_git_external_rename() {
+        let paths: Vec<PathBuf> = [
+            "old.rs", "/tmp/a", "abc", "100644", "/tmp/b", "def", "100644", "new.rs", "rename",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+        match detect_input_mode(&paths) {
+            InputMode::GitExternal {
+                display_path,
+                old_file,
+                new_file,
+            } => {
+                assert_eq!(display_path, PathBuf::from("new.rs"));
+                assert_eq!(old_file, PathBuf::from("/tmp/a"));
+                assert_eq!(new_file, PathBuf::from("/tmp/b"));
+            }
+            _ => panic!("unexpected input mode"),
+        }
+    }
+
+    #[test]
+    fn external_diff_progress_requires_valid_counter() {
+        assert_eq!(
+            parse_external_diff_progress(Some("2"), Some("5")),
+            Some((2, 5))
+        );
+        assert_eq!(parse_external_diff_progress(Some("6"), Some("5")), None);
+        assert_eq!(parse_external_diff_progress(None, Some("5")), None);
+        assert_eq!(parse_external_diff_progress(Some("x"), Some("5")), None);
+    }
+
     #[test]
     fn editor_default_args_open_at_line() {
         let config = config::EditorConfig::default();
@@ -1788,4 +2828,57 @@ mod tests {
         let args = render_editor_args(&config, Some(42), Path::new("src/main.rs"));
         assert_eq!(args, vec!["--goto", "src/main.rs:42"]);
     }
+
+    #[test]
+    fn compare_app_keeps_the_session_mouse_capture() {
+        let pair = || {
+            MultiFileDiff::from_file_pair(
+                PathBuf::from("a.txt"),
+                PathBuf::from("a.txt"),
+                "one\n".to_string(),
+                "two\n".to_string(),
+            )
+        };
+        let mut app =
+            TestApp::new_default(|| App::new(pair(), ViewMode::UnifiedPane, 0, false, None));
+        let config = config::Config::default();
+        for capture in [false, true] {
+            app.plain = !capture;
+            app.mouse_capture = capture;
+            let compare = compare_app(&app, pair(), "comparing files", &config);
+            assert_eq!(compare.mouse_capture, capture);
+            assert_eq!(compare.plain, app.plain);
+        }
+    }
+
+    #[test]
+    fn reload_config_keeps_session_toggles() {
+        let path = std::env::temp_dir().join(format!("oyo_reload_{}.toml", std::process::id()));
+        std::fs::write(&path, "[ui]\nline_wrap = false\nzen = true\n").unwrap();
+        let args = Args::try_parse_from(["oy", "--config", path.to_str().unwrap()]).unwrap();
+        let mut config = config::Config::default();
+        let mut app = TestApp::new_default(|| {
+            let diff = MultiFileDiff::from_file_pair(
+                PathBuf::from("a.txt"),
+                PathBuf::from("a.txt"),
+                "one\n".to_string(),
+                "two\n".to_string(),
+            );
+            App::new(diff, ViewMode::UnifiedPane, 0, false, None)
+        });
+        let _ = apply_settings_to_app(&mut app, &config, false);
+        app.toggle_line_wrap();
+        app.toggle_file_panel();
+        app.toggle_diff_bg();
+        let (wrap, panel, bg) = (app.line_wrap, app.file_panel_visible, app.diff_bg);
+
+        reload_config(&mut app, &mut config, &args);
+        assert!(config.ui.zen);
+        assert_eq!(
+            (app.line_wrap, app.file_panel_visible, app.diff_bg),
+            (wrap, panel, bg)
+        );
+        assert!(!app.zen_mode, "zen is a session toggle");
+        let _ = std::fs::remove_file(path);
+    }
 }
@@ -0,0 +1,211 @@
+//! Presenter notes loaded from a `.oyo-notes.toml` sidecar file.
+//!
+//! Example sidecar:
+//! ```toml
+//! [[note]]
+//! file = "src/main.rs"
+//! line = 42
+//! end = 48 # optional, defaults to `line`
+//! text = "Walk through the new error handling here."
+//! ```
+
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+const NOTES_FILE_NAME: &str = ".oyo-notes.toml";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PresenterNote {
+    pub file: String,
+    pub line: usize,
+    #[serde(default)]
+    pub end: Option<usize>,
+    pub text: String,
+}
+
+impl PresenterNote {
+    pub fn start_line(&self) -> usize {
+        self.line.max(1)
+    }
+
+    pub fn end_line(&self) -> usize {
+        self.end.unwrap_or(self.line).max(self.start_line())
+    }
+
+    pub fn contains(&self, line: usize) -> bool {
+        line >= self.start_line() && line <= self.end_line()
+    }
+
+    /// Distance from `line` to the note range (0 when inside).
+    pub fn distance(&self, line: usize) -> usize {
+        if line < self.start_line() {
+            self.start_line() - line
+        } else {
+            line.saturating_sub(self.end_line())
+        }
+    }
+
+    /// Whether the note names `path`: exactly, or by trailing components
+    /// when `path` is the only file in `diff_paths` they match, so a note on
+    /// `mod.rs` doesn't attach to every `mod.rs` in the diff.
+    fn matches_path<'p>(&self, path: &Path, diff_paths: impl Iterator<Item = &'p Path>) -> bool {
+        let note_path = Path::new(self.file.trim_start_matches("./"));
+        if path == note_path {
+            return true;
+        }
+        path.ends_with(note_path)
+            && diff_paths
+                .filter(|diff_path| diff_path.ends_with(note_path))
+                .count()
+                == 1
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PresenterNotes {
+    #[serde(default, rename = "note")]
+    pub notes: Vec<PresenterNote>,
+}
+
+impl PresenterNotes {
+    /// Load the sidecar from `dir`. Returns None when the file is absent or empty.
+    pub fn load(dir: &Path) -> Option<Self> {
+        let path = dir.join(NOTES_FILE_NAME);
+        let contents = fs::read_to_string(&path).ok()?;
+        match toml::from_str::<PresenterNotes>(&contents) {
+            Ok(notes) if !notes.notes.is_empty() => Some(notes),
+            Ok(_) => None,
+            Err(e) => {
+                eprintln!("Warning: Failed to parse {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Notes for `path`, one of the files in `diff_paths`.
+    pub fn notes_for_file<'a, I>(
+        &'a self,
+        path: &'a Path,
+        diff_paths: I,
+    ) -> impl Iterator<Item = &'a PresenterNote> + 'a
+    where
+        I: Iterator<Item = &'a Path> + Clone + 'a,
+    {
+        self.notes
+            .iter()
+            .filter(move |note| note.matches_path(path, diff_paths.clone()))
+    }
+
+    /// Find the note covering `line`, falling back to the nearest note in the file
+    /// so notes keep anchoring after the surrounding lines shift.
+    pub fn note_for_line<'a, I>(
+        &'a self,
+        path: &Path,
+        line: usize,
+        diff_paths: I,
+    ) -> Option<&'a PresenterNote>
+    where
+        I: Iterator<Item = &'a Path> + Clone,
+    {
+        self.notes
+            .iter()
+            .filter(|note| note.matches_path(path, diff_paths.clone()))
+            .min_by_key(|note| note.distance(line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> PresenterNotes {
+        toml::from_str(text).expect("notes should parse")
+    }
+
+    #[test]
+    fn test_note_for_line_prefers_containing_range() {
+        let notes = parse(
+            r#"
+[[note]]
+file = "src/lib.rs"
+line = 10
+end = 12
+text = "first"
+
+[[note]]
+file = "src/lib.rs"
+line = 20
+text = "second"
+"#,
+        );
+        let path = Path::new("src/lib.rs");
+        let paths = [path];
+        assert_eq!(
+            notes
+                .note_for_line(path, 11, paths.into_iter())
+                .unwrap()
+                .text,
+            "first"
+        );
+        assert_eq!(
+            notes
+                .note_for_line(path, 20, paths.into_iter())
+                .unwrap()
+                .text,
+            "second"
+        );
+    }
+
+    #[test]
+    fn test_note_for_line_falls_back_to_nearest() {
+        let notes = parse(
+            r#"
+[[note]]
+file = "./src/lib.rs"
+line = 10
+text = "moved"
+"#,
+        );
+        let path = Path::new("crates/app/src/lib.rs");
+        let paths = [path, Path::new("src/main.rs")];
+        assert_eq!(
+            notes
+                .note_for_line(path, 14, paths.into_iter())
+                .unwrap()
+                .text,
+            "moved"
+        );
+        assert!(notes
+            .note_for_line(Path::new("src/main.rs"), 10, paths.into_iter())
+            .is_none());
+    }
+
+    #[test]
+    fn test_suffix_paths_attach_only_when_unambiguous() {
+        let notes = parse(
+            r#"
+[[note]]
+file = "mod.rs"
+line = 1
+text = "which one?"
+
+[[note]]
+file = "src/lib.rs"
+line = 1
+text = "root"
+"#,
+        );
+        let paths = [
+            Path::new("src/app/mod.rs"),
+            Path::new("src/views/mod.rs"),
+            Path::new("src/lib.rs"),
+            Path::new("crates/core/src/lib.rs"),
+        ];
+        for path in paths {
+            let found = notes.note_for_line(path, 1, paths.into_iter());
+            let expected = (path == Path::new("src/lib.rs")).then_some("root");
+            assert_eq!(found.map(|note| note.text.as_str()), expected, "{path:?}");
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Non-interactive output for `--no-tui`: a `git diff`-style ANSI listing,
+//! paged like git does when stdout is a terminal
+
+use oyo_core::multi::DiffStatus;
+use oyo_core::{Change, ChangeKind, LineKind, MultiFileDiff, ViewLine};
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+/// Unchanged lines shown around each change, as in `git diff -U3`.
+const CONTEXT_LINES: usize = 3;
+
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+/// Render every file as unified hunks; escape codes only when `color` is set.
+pub(crate) fn render_diff(multi_diff: &mut MultiFileDiff, color: bool) -> String {
+    let mut out = String::new();
+    for idx in 0..multi_diff.file_count() {
+        write_file(&mut out, multi_diff, idx, color);
+    }
+    out
+}
+
+/// Render one file as unified hunks, as in `render_diff`.
+pub(crate) fn render_file_diff(multi_diff: &mut MultiFileDiff, idx: usize, color: bool) -> String {
+    let mut out = String::new();
+    write_file(&mut out, multi_diff, idx, color);
+    out
+}
+
+fn write_file(out: &mut String, multi_diff: &mut MultiFileDiff, idx: usize, color: bool) {
+    let paint = |out: &mut String, code: &str, text: &str| {
+        if color {
+            let _ = writeln!(out, "{code}{text}{RESET}");
+        } else {
+            let _ = writeln!(out, "{text}");
+        }
+    };
+    multi_diff.ensure_loaded(idx);
+    if matches!(
+        multi_diff.diff_status(idx),
+        DiffStatus::Deferred | DiffStatus::Computing
+    ) {
+        if let Some((old, new)) = multi_diff.file_contents(idx) {
+            let diff = MultiFileDiff::compute_diff(old, new);
+            multi_diff.apply_diff_result(idx, diff);
+        }
+    }
+    let file = &multi_diff.files[idx];
+    let header = format!(
+        "{} (+{} -{})",
+        file.display_name, file.insertions, file.deletions
+    );
+    let binary = file.binary;
+    let read_error = file.read_error.clone();
+    paint(out, BOLD, &header);
+    let notice = if let Some(reason) = read_error {
+        Some(format!("Failed to read: {reason}"))
+    } else if binary {
+        Some("Binary file".to_string())
+    } else {
+        match multi_diff.diff_status(idx) {
+            DiffStatus::Failed => Some("Diff failed".to_string()),
+            DiffStatus::Disabled => Some("Diff disabled (file too large)".to_string()),
+            _ => None,
+        }
+    };
+    if let Some(notice) = notice {
+        let _ = writeln!(out, "{notice}");
+        return;
+    }
+    multi_diff.ensure_full_navigator(idx);
+    let Some(nav) = multi_diff.navigator(idx) else {
+        return;
+    };
+    let view = nav.final_view();
+    let changes = &nav.diff().changes;
+    for (start, end) in hunk_ranges(&view) {
+        let (old_start, old_len) = side_range(&view, start, end, |line| line.old_line);
+        let (new_start, new_len) = side_range(&view, start, end, |line| line.new_line);
+        let range = format!("@@ -{old_start},{old_len} +{new_start},{new_len} @@");
+        paint(out, CYAN, &range);
+        for line in &view[start..end] {
+            match line.kind {
+                LineKind::Context => {
+                    let _ = writeln!(out, " {}", line.content);
+                }
+                LineKind::Deleted | LineKind::PendingDelete => {
+                    paint(out, RED, &format!("-{}", line.content));
+                }
+                LineKind::Inserted | LineKind::PendingInsert => {
+                    paint(out, GREEN, &format!("+{}", line.content));
+                }
+                LineKind::Modified | LineKind::PendingModify => {
+                    let change = changes.iter().find(|change| change.id == line.change_id);
+                    let (old, new) = change
+                        .map(modified_sides)
+                        .unwrap_or_else(|| (String::new(), line.content.clone()));
+                    paint(out, RED, &format!("-{old}"));
+                    paint(out, GREEN, &format!("+{new}"));
+                }
+            }
+        }
+    }
+}
+
+/// Git-style start and length of one side of a hunk. An empty side starts at
+/// the line before it, as in `git diff`.
+fn side_range(
+    view: &[ViewLine],
+    start: usize,
+    end: usize,
+    line_no: impl Fn(&ViewLine) -> Option<usize>,
+) -> (usize, usize) {
+    let len = view[start..end]
+        .iter()
+        .filter(|line| line_no(line).is_some())
+        .count();
+    let first = if len > 0 {
+        view[start..end].iter().find_map(&line_no)
+    } else {
+        view[..start].iter().rev().find_map(&line_no)
+    };
+    (first.unwrap_or(0), len)
+}
+
+/// `[start, end)` view ranges covering each change plus its context, merged
+/// when they touch.
+fn hunk_ranges(view: &[ViewLine]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (idx, line) in view.iter().enumerate() {
+        if line.kind == LineKind::Context {
+            continue;
+        }
+        let start = idx.saturating_sub(CONTEXT_LINES);
+        let end = (idx + 1 + CONTEXT_LINES).min(view.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+    ranges
+}
+
+/// Old and new text of an inline-modified line.
+fn modified_sides(change: &Change) -> (String, String) {
+    let mut old = String::new();
+    let mut new = String::new();
+    for span in &change.spans {
+        match span.kind {
+            ChangeKind::Equal => {
+                old.push_str(&span.text);
+                new.push_str(&span.text);
+            }
+            ChangeKind::Delete => old.push_str(&span.text),
+            ChangeKind::Insert => new.push_str(&span.text),
+            ChangeKind::Replace => {
+                old.push_str(&span.text);
+                new.push_str(span.new_text.as_deref().unwrap_or_default());
+            }
+        }
+    }
+    (old, new)
+}
+
+/// Pager to spawn: `OYO_PAGER`, then `PAGER`, then `less -R` on a terminal.
+/// An empty value or `cat` disables paging, as with `core.pager`.
+pub(crate) fn resolve_pager(
+    oyo_pager: Option<String>,
+    pager: Option<String>,
+    stdout_is_tty: bool,
+) -> Option<String> {
+    if !stdout_is_tty {
+        return None;
+    }
+    let command = oyo_pager.or(pager).unwrap_or_else(|| "less -R".to_string());
+    let command = command.trim();
+    if command.is_empty() || command == "cat" {
+        None
+    } else {
+        Some(command.to_string())
+    }
+}
+
+/// Write `output` through `pager`, or straight to stdout when there is none
+/// or it fails to start. A pager quit early (broken pipe) is not an error.
+pub(crate) fn write_paged(output: &str, pager: Option<&str>) -> io::Result<()> {
+    let child = pager.and_then(|pager| {
+        let mut cmd = pager_command(pager);
+        if std::env::var_os("LESS").is_none() {
+            cmd.env("LESS", "FRX");
+        }
+        cmd.stdin(Stdio::piped()).spawn().ok()
+    });
+    let Some(mut child) = child else {
+        return ignore_broken_pipe(io::stdout().lock().write_all(output.as_bytes()));
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        ignore_broken_pipe(stdin.write_all(output.as_bytes()))?;
+    }
+    child.wait()?;
+    Ok(())
+}
+
+fn pager_command(pager: &str) -> Command {
+    #[cfg(windows)]
+    {
+        let mut parts = pager.split_whitespace();
+        let mut cmd = Command::new(parts.next().unwrap_or(pager));
+        cmd.args(parts);
+        cmd
+    }
+    #[cfg(not(windows))]
+    {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(pager);
+        cmd
+    }
+}
+
+fn ignore_broken_pipe(result: io::Result<()>) -> io::Result<()> {
+    match result {
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_diff, render_file_diff, resolve_pager};
+    use oyo_core::MultiFileDiff;
+    use std::path::PathBuf;
+
+    #[test]
+    fn render_diff_prints_hunks_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n".to_string();
+        let new = "a\nb\nc\nd\nE\nf\ng\nh\n".to_string();
+        let mut multi_diff = MultiFileDiff::from_file_pair(
+            PathBuf::from("old.txt"),
+            PathBuf::from("new.txt"),
+            old,
+            new,
+        );
+        let out = render_diff(&mut multi_diff, false);
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].ends_with("(+1 -1)"));
+        assert_eq!(lines[1], "@@ -2,7 +2,7 @@");
+        assert_eq!(&lines[2..5], [" b", " c", " d"]);
+        assert_eq!(&lines[5..7], ["-e", "+E"]);
+        assert_eq!(&lines[7..], [" f", " g", " h"]);
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn render_diff_counts_lines_per_side_like_git() {
+        let mut multi_diff = MultiFileDiff::from_file_pair(
+            PathBuf::from("old.txt"),
+            PathBuf::from("new.txt"),
+            "a\nb\n".to_string(),
+            "a\nb\nc\nd\n".to_string(),
+        );
+        let out = render_diff(&mut multi_diff, false);
+        assert!(out.contains("@@ -1,2 +1,4 @@\n a\n b\n+c\n+d\n"), "{out}");
+
+        let mut multi_diff = MultiFileDiff::from_file_pair(
+            PathBuf::from("old.txt"),
+            PathBuf::from("new.txt"),
+            String::new(),
+            "x\n".to_string(),
+        );
+        let out = render_diff(&mut multi_diff, false);
+        assert!(out.contains("@@ -0,0 +1,1 @@\n+x\n"), "{out}");
+    }
+
+    #[test]
+    fn render_file_diff_prints_only_that_file() {
+        let mut multi_diff = MultiFileDiff::from_file_pairs(vec![
+            (PathBuf::from("a.txt"), "a\n".to_string(), "A\n".to_string()),
+            (PathBuf::from("b.txt"), "b\n".to_string(), "B\n".to_string()),
+        ]);
+        let out = render_file_diff(&mut multi_diff, 1, false);
+        assert!(out.starts_with("b.txt (+1 -1)\n"), "{out}");
+        assert!(!out.contains("a.txt"));
+        assert!(out.contains("-b\n+B\n"));
+    }
+
+    #[test]
+    fn resolve_pager_prefers_oyo_pager_and_defaults_to_less() {
+        let set = |value: &str| Some(value.to_string());
+        assert_eq!(
+            resolve_pager(set("bat"), set("more"), true).as_deref(),
+            Some("bat")
+        );
+        assert_eq!(
+            resolve_pager(None, set("more"), true).as_deref(),
+            Some("more")
+        );
+        assert_eq!(resolve_pager(None, None, true).as_deref(), Some("less -R"));
+        assert_eq!(resolve_pager(set("cat"), None, true), None);
+        assert_eq!(resolve_pager(set(""), set("more"), true), None);
+        assert_eq!(resolve_pager(None, None, false), None);
+    }
+}
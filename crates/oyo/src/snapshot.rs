@@ -0,0 +1,146 @@
+//! Text snapshots of the rendered diff view for pasting into chats: plain
+//! text, or ANSI SGR sequences rebuilt from the buffer's cell styles
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier};
+use std::fmt::Write as _;
+use unicode_width::UnicodeWidthStr;
+
+/// Serialize `area` of a rendered buffer, one line per row. Trailing blank
+/// cells are dropped; with `ansi`, backgrounds equal to `base_bg` (the theme
+/// background) are left out so the paste follows the reader's terminal.
+pub(crate) fn buffer_region_text(
+    buf: &Buffer,
+    area: Rect,
+    ansi: bool,
+    base_bg: Option<Color>,
+) -> String {
+    let area = area.intersection(buf.area);
+    let mut rows = Vec::with_capacity(area.height as usize);
+    for y in area.y..area.y.saturating_add(area.height) {
+        let mut cells: Vec<&Cell> = Vec::new();
+        let mut x = area.x;
+        while x < area.x.saturating_add(area.width) {
+            let cell = &buf[(x, y)];
+            cells.push(cell);
+            x = x.saturating_add(cell.symbol().width().max(1) as u16);
+        }
+        let visible_bg = |cell: &Cell| ansi && cell.bg != Color::Reset && Some(cell.bg) != base_bg;
+        while cells
+            .last()
+            .is_some_and(|cell| cell.symbol().trim().is_empty() && !visible_bg(cell))
+        {
+            cells.pop();
+        }
+
+        let mut row = String::new();
+        let mut current: Option<String> = None;
+        for cell in cells {
+            if ansi {
+                let sgr = cell_sgr(cell, base_bg);
+                if current.as_deref() != Some(sgr.as_str()) {
+                    if sgr.is_empty() {
+                        row.push_str("\x1b[0m");
+                    } else {
+                        let _ = write!(row, "\x1b[0;{sgr}m");
+                    }
+                    current = Some(sgr);
+                }
+            }
+            row.push_str(cell.symbol());
+        }
+        if ansi && current.is_some() {
+            row.push_str("\x1b[0m");
+        }
+        rows.push(row);
+    }
+    while rows.last().is_some_and(String::is_empty) {
+        rows.pop();
+    }
+    rows.join("\n")
+}
+
+/// SGR parameters (without the leading reset) for one cell's style.
+fn cell_sgr(cell: &Cell, base_bg: Option<Color>) -> String {
+    let mut params: Vec<String> = Vec::new();
+    for (modifier, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if cell.modifier.contains(modifier) {
+            params.push(code.to_string());
+        }
+    }
+    if let Some(fg) = color_sgr(cell.fg, false) {
+        params.push(fg);
+    }
+    if Some(cell.bg) != base_bg {
+        if let Some(bg) = color_sgr(cell.bg, true) {
+            params.push(bg);
+        }
+    }
+    params.join(";")
+}
+
+fn color_sgr(color: Color, background: bool) -> Option<String> {
+    let base = if background { 40 } else { 30 };
+    let named = |offset: u8| Some((base + offset as u16).to_string());
+    let bright = |offset: u8| Some((base + 60 + offset as u16).to_string());
+    let extended = if background { "48" } else { "38" };
+    match color {
+        Color::Reset => None,
+        Color::Black => named(0),
+        Color::Red => named(1),
+        Color::Green => named(2),
+        Color::Yellow => named(3),
+        Color::Blue => named(4),
+        Color::Magenta => named(5),
+        Color::Cyan => named(6),
+        Color::Gray => named(7),
+        Color::DarkGray => bright(0),
+        Color::LightRed => bright(1),
+        Color::LightGreen => bright(2),
+        Color::LightYellow => bright(3),
+        Color::LightBlue => bright(4),
+        Color::LightMagenta => bright(5),
+        Color::LightCyan => bright(6),
+        Color::White => bright(7),
+        Color::Indexed(idx) => Some(format!("{extended};5;{idx}")),
+        Color::Rgb(r, g, b) => Some(format!("{extended};2;{r};{g};{b}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::buffer_region_text;
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Modifier, Style};
+
+    #[test]
+    fn buffer_region_text_serializes_plain_and_ansi() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        buf.set_string(0, 0, "gutter", Style::default());
+        buf.set_string(1, 1, "+add", Style::default().fg(Color::Green));
+        buf.set_string(
+            5,
+            1,
+            "!",
+            Style::default()
+                .fg(Color::Rgb(1, 2, 3))
+                .add_modifier(Modifier::BOLD),
+        );
+        let area = Rect::new(1, 1, 10, 2);
+
+        assert_eq!(buffer_region_text(&buf, area, false, None), "+add!");
+        assert_eq!(
+            buffer_region_text(&buf, area, true, None),
+            "\x1b[0;32m+add\x1b[0;1;38;2;1;2;3m!\x1b[0m"
+        );
+    }
+}
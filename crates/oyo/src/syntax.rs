@@ -12,7 +12,7 @@ use std::sync::OnceLock;
 use syntect::{
     easy::HighlightLines,
     highlighting::{Color, FontStyle, Style as SynStyle, Theme, ThemeSet},
-    parsing::{ParseState, ScopeStack, SyntaxReference, SyntaxSet},
+    parsing::{ParseState, ScopeStack, ScopeStackOp, SyntaxReference, SyntaxSet},
     util::LinesWithEndings,
 };
 
@@ -474,6 +474,38 @@ impl SyntaxEngine {
         scopes.into_iter().collect()
     }
 
+    /// The last line before `line_index` that declares a function, type or
+    /// module, for the function context after a hunk's `@@` header. Files
+    /// without a grammar use git's default: the last line starting with a
+    /// letter, `_` or `$`.
+    pub fn declaration_before(
+        &self,
+        content: &str,
+        file_name: &str,
+        line_index: usize,
+    ) -> Option<String> {
+        let syntax = self.syntax_for_file(file_name);
+        let plain = syntax.name == self.syntax_set.find_syntax_plain_text().name;
+        let mut state = ParseState::new(syntax);
+        let mut found = None;
+        for line in LinesWithEndings::from(content).take(line_index) {
+            let declares = if plain {
+                line.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '$')
+            } else {
+                let ops = state.parse_line(line, &self.syntax_set).unwrap_or_default();
+                ops.iter().any(|(_, op)| match op {
+                    ScopeStackOp::Push(scope) => is_declaration_scope(&scope.build_string()),
+                    _ => false,
+                })
+            };
+            if declares {
+                found = Some(line);
+            }
+        }
+        let line = found?.trim();
+        Some(line.chars().take(DECLARATION_MAX_CHARS).collect())
+    }
+
     fn syntax_for_file(&self, file_name: &str) -> &SyntaxReference {
         self.syntax_set
             .find_syntax_for_file(file_name)
@@ -483,6 +515,25 @@ impl SyntaxEngine {
     }
 }
 
+/// Longest function context kept, matching git's hunk header limit.
+const DECLARATION_MAX_CHARS: usize = 80;
+
+fn is_declaration_scope(scope: &str) -> bool {
+    const DECLARATIONS: &[&str] = &[
+        "entity.name.function",
+        "entity.name.class",
+        "entity.name.struct",
+        "entity.name.enum",
+        "entity.name.trait",
+        "entity.name.impl",
+        "entity.name.interface",
+        "entity.name.type",
+        "entity.name.namespace",
+        "entity.name.module",
+    ];
+    DECLARATIONS.iter().any(|prefix| scope.starts_with(prefix))
+}
+
 fn resolve_syntax_theme(theme_name: &str, light_mode: bool) -> (Theme, TuiColor) {
     let (mut ansi_theme, ansi_plain) = load_ansi_theme();
     strip_theme_backgrounds(&mut ansi_theme);
@@ -1418,6 +1469,25 @@ fn to_tui(color: Color) -> TuiColor {
 mod tests {
     use super::*;
 
+    #[test]
+    fn declaration_before_finds_enclosing_function() {
+        let engine = SyntaxEngine::new("aura", false);
+        let content = "struct Foo;\n\nfn run() {\n    let x = 1;\n    x\n}\n";
+        assert_eq!(
+            engine.declaration_before(content, "sample.rs", 4),
+            Some("fn run() {".to_string())
+        );
+        assert_eq!(
+            engine.declaration_before(content, "sample.rs", 2),
+            Some("struct Foo;".to_string())
+        );
+        assert_eq!(engine.declaration_before(content, "sample.rs", 0), None);
+        assert_eq!(
+            engine.declaration_before("intro\n  body\n", "notes.unknownext", 2),
+            Some("intro".to_string())
+        );
+    }
+
     #[test]
     fn lazy_cache_only_fills_requested_lines() {
         let engine = SyntaxEngine::new("aura", false);
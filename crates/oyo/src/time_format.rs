@@ -1,14 +1,28 @@
 use crate::config::{TimeConfig, TimeMode};
+use std::sync::OnceLock;
+use std::time::Duration;
 use time::format_description::{parse_owned, parse_strftime_owned, OwnedFormatItem};
-use time::OffsetDateTime;
+use time::{OffsetDateTime, UtcOffset};
 
 const DEFAULT_ABSOLUTE_FORMAT: &str = "[year]-[month]-[day] [hour]:[minute]";
+const CLOCK_FORMAT: &str = "[hour]:[minute]";
+
+static LOCAL_OFFSET: OnceLock<UtcOffset> = OnceLock::new();
+
+/// Capture the local UTC offset for the status bar clock. The offset can
+/// only be read reliably while the process is single-threaded, so call this
+/// first thing in `main`.
+pub fn init_local_offset() {
+    let offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+    let _ = LOCAL_OFFSET.set(offset);
+}
 
 #[derive(Debug, Clone)]
 pub struct TimeFormatter {
     mode: TimeMode,
     absolute_format: OwnedFormatItem,
     custom_format: Option<OwnedFormatItem>,
+    clock_format: OwnedFormatItem,
 }
 
 impl Default for TimeFormatter {
@@ -26,11 +40,34 @@ impl TimeFormatter {
             TimeMode::Custom => parse_format(&config.format),
             _ => None,
         };
+        let clock_format = parse_owned::<2>(CLOCK_FORMAT).expect("clock time format should parse");
         Self {
             mode: config.mode,
             absolute_format,
             custom_format,
+            clock_format,
+        }
+    }
+
+    /// Formatter for blame dates. `date_format` is "relative" or a format
+    /// string; an empty value follows `ui.time`.
+    pub fn for_blame(config: &TimeConfig, date_format: &str) -> Result<Self, String> {
+        let trimmed = date_format.trim();
+        if trimmed.is_empty() {
+            return Ok(Self::new(config));
+        }
+        let mut formatter = Self::new(&TimeConfig::default());
+        if trimmed.eq_ignore_ascii_case("relative") {
+            return Ok(formatter);
         }
+        let Some(format) = parse_format(trimmed) else {
+            return Err(format!(
+                "ui.blame.date_format = {date_format:?} is not a valid format; using ui.time"
+            ));
+        };
+        formatter.mode = TimeMode::Custom;
+        formatter.custom_format = Some(format);
+        Ok(formatter)
     }
 
     pub fn format(&self, epoch: Option<i64>, now: i64) -> String {
@@ -47,6 +84,26 @@ impl TimeFormatter {
             }
         }
     }
+
+    /// Wall-clock time ("14:05") in the offset captured at startup.
+    pub fn clock(&self, now: i64) -> String {
+        let offset = LOCAL_OFFSET.get().copied().unwrap_or(UtcOffset::UTC);
+        OffsetDateTime::from_unix_timestamp(now)
+            .ok()
+            .and_then(|date_time| date_time.to_offset(offset).format(&self.clock_format).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Elapsed time as "m:ss", or "h:mm:ss" past the hour.
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
 }
 
 fn parse_format(format: &str) -> Option<OwnedFormatItem> {
@@ -92,3 +149,43 @@ pub fn format_relative_age(epoch: i64, now: i64) -> String {
         format!("{years} years ago")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JAN_15_2024: i64 = 1_705_276_800;
+
+    #[test]
+    fn test_blame_date_format_variants() {
+        let config = TimeConfig::default();
+        let now = JAN_15_2024 + 21 * 86_400;
+
+        let relative = TimeFormatter::for_blame(&config, "relative").unwrap();
+        assert_eq!(relative.format(Some(JAN_15_2024), now), "21 days ago");
+
+        let iso = TimeFormatter::for_blame(&config, "%Y-%m-%d").unwrap();
+        assert_eq!(iso.format(Some(JAN_15_2024), now), "2024-01-15");
+
+        let short = TimeFormatter::for_blame(&config, "%b %d").unwrap();
+        assert_eq!(short.format(Some(JAN_15_2024), now), "Jan 15");
+
+        let follow = TimeFormatter::for_blame(&config, "").unwrap();
+        assert_eq!(follow.format(Some(JAN_15_2024), now), "21 days ago");
+
+        assert!(TimeFormatter::for_blame(&config, "[bogus]").is_err());
+    }
+
+    #[test]
+    fn test_clock_and_elapsed_formats() {
+        // No offset captured in tests, so the clock reads UTC.
+        let formatter = TimeFormatter::default();
+        assert_eq!(formatter.clock(JAN_15_2024 + 14 * 3600 + 5 * 60), "14:05");
+
+        assert_eq!(format_elapsed(Duration::from_secs(65)), "1:05");
+        assert_eq!(
+            format_elapsed(Duration::from_secs(3600 + 120 + 3)),
+            "1:02:03"
+        );
+    }
+}
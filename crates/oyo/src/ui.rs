@@ -1,16 +1,25 @@
 //! UI rendering for the TUI
 
-use crate::app::{App, ViewMode, DIFF_VIEW_MIN_WIDTH, FILE_PANEL_MIN_WIDTH};
+use crate::app::{
+    file_list_group, smart_path_labels, stat_bar_cells, App, ViewMode, DIFF_VIEW_MIN_HEIGHT,
+    KEY_LOG_TTL_MS,
+};
 use crate::color;
-use crate::keybindings::{GlobalAction, HelpAction, NormalAction, ReviewEditorAction};
-use crate::views::{render_blame, render_evolution, render_split, render_unified_pane};
+use crate::config::FilePathStyle;
+use crate::keybindings::{
+    GlobalAction, HelpAction, NormalAction, OverviewAction, ReviewEditorAction,
+};
+use crate::views::{
+    render_blame, render_continuous, render_evolution, render_split, render_unified_pane,
+    split_pane_areas,
+};
 use oyo_core::{multi::DiffStatus, FileStatus};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
-        Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar,
+        Block, Borders, Clear, List, ListItem, ListState, Padding, Paragraph, Scrollbar,
         ScrollbarOrientation, ScrollbarState,
     },
     Frame,
@@ -21,7 +30,7 @@ fn truncate_filename_keep_ext(name: &str, max_width: usize) -> String {
     if max_width == 0 {
         return String::new();
     }
-    if name.len() <= max_width {
+    if text_width(name) <= max_width {
         return name.to_string();
     }
     if max_width <= 3 {
@@ -32,22 +41,18 @@ fn truncate_filename_keep_ext(name: &str, max_width: usize) -> String {
         Some(idx) if idx > 0 && idx < name.len().saturating_sub(1) => (&name[..idx], &name[idx..]),
         _ => (name, ""),
     };
-    let ext_len = ext.len();
+    let ext_len = text_width(ext);
     if ext_len >= max_width {
         let suffix_len = max_width.saturating_sub(3);
-        return format!("…{}", &name[name.len().saturating_sub(suffix_len)..]);
+        return format!("…{}", tail_to_width(name, suffix_len));
     }
 
     if ext_len == 0 {
         let stem_keep = max_width.saturating_sub(3);
         let head_len = stem_keep.div_ceil(2);
         let tail_len = stem_keep.saturating_sub(head_len);
-        let head = &stem[..head_len.min(stem.len())];
-        let tail = if tail_len > 0 && tail_len <= stem.len() {
-            &stem[stem.len().saturating_sub(tail_len)..]
-        } else {
-            ""
-        };
+        let head = truncate_to_width(stem, head_len);
+        let tail = tail_to_width(stem, tail_len);
         return format!("{head}…{tail}");
     }
 
@@ -60,12 +65,8 @@ fn truncate_filename_keep_ext(name: &str, max_width: usize) -> String {
     let stem_keep = max_stem_len.saturating_sub(3);
     let head_len = stem_keep.div_ceil(2);
     let tail_len = stem_keep.saturating_sub(head_len);
-    let head = &stem[..head_len.min(stem.len())];
-    let tail = if tail_len > 0 && tail_len <= stem.len() {
-        &stem[stem.len().saturating_sub(tail_len)..]
-    } else {
-        ""
-    };
+    let head = truncate_to_width(stem, head_len);
+    let tail = tail_to_width(stem, tail_len);
     format!("{head}…{tail}{ext}")
 }
 
@@ -106,6 +107,21 @@ fn truncate_to_width(text: &str, max_width: usize) -> String {
     out
 }
 
+/// Longest suffix of `text` that fits in `max_width` columns.
+fn tail_to_width(text: &str, max_width: usize) -> &str {
+    let mut width = 0usize;
+    let mut start = text.len();
+    for (idx, ch) in text.char_indices().rev() {
+        let ch_width = ch.width().unwrap_or(0);
+        if width + ch_width > max_width {
+            break;
+        }
+        width += ch_width;
+        start = idx;
+    }
+    &text[start..]
+}
+
 fn wrap_editor_line(line: &str, max_width: usize) -> Vec<String> {
     if max_width == 0 {
         return vec![String::new()];
@@ -250,12 +266,31 @@ fn pad_spans_right(spans: Vec<Span>, width: usize) -> Vec<Span> {
     out
 }
 
+/// Compact byte count: `512B`, `4.2K`, `37M`.
+fn compact_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["K", "M", "G", "T"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if value < 10.0 {
+        format!("{value:.1}{}", UNITS[unit])
+    } else {
+        format!("{value:.0}{}", UNITS[unit])
+    }
+}
+
 /// Truncate a path to fit a given width, using /…/ for middle sections
 fn truncate_path(path: &str, max_width: usize) -> String {
     if max_width == 0 {
         return String::new();
     }
-    if path.len() <= max_width {
+    if text_width(path) <= max_width {
         return path.to_string();
     }
     if max_width <= 3 {
@@ -273,11 +308,11 @@ fn truncate_path(path: &str, max_width: usize) -> String {
 
     // If just first + last fits with /…/, use that
     let prefix = format!("{}/…/", first);
-    let available = max_width.saturating_sub(prefix.len());
+    let available = max_width.saturating_sub(text_width(&prefix));
     if available > 0 {
         let last_display = truncate_filename_keep_ext(last, available);
         let simple = format!("{prefix}{last_display}");
-        if simple.len() <= max_width {
+        if text_width(&simple) <= max_width {
             return simple;
         }
     }
@@ -287,7 +322,7 @@ fn truncate_path(path: &str, max_width: usize) -> String {
         return ".".repeat(max_width);
     }
     let prefix = "…/";
-    let available = max_width.saturating_sub(prefix.len());
+    let available = max_width.saturating_sub(text_width(prefix));
     if available == 0 {
         return ".".repeat(max_width);
     }
@@ -302,7 +337,7 @@ fn truncate_text(text: &str, max_width: usize) -> String {
     if max_width <= 3 {
         return ".".repeat(max_width);
     }
-    if text.len() <= max_width {
+    if text_width(text) <= max_width {
         return text.to_string();
     }
     let mut acc = String::new();
@@ -321,6 +356,9 @@ fn truncate_text(text: &str, max_width: usize) -> String {
 /// Main drawing function
 pub fn draw(frame: &mut Frame, app: &mut App) {
     app.clear_review_preview_boxes();
+    app.record_hunk_visit();
+    app.sync_diff_bg_to_view();
+    app.sync_line_wrap_to_view();
 
     if app.zen_mode {
         // Zen mode: just the content with minimal progress indicator
@@ -352,6 +390,10 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         }
     }
 
+    if app.show_overview {
+        draw_overview(frame, app);
+    }
+
     // Draw help popover if active
     if app.show_help {
         draw_help_popover(frame, app);
@@ -362,6 +404,18 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         draw_path_popup(frame, app);
     }
 
+    if app.show_note_popup {
+        draw_note_popup(frame, app);
+    }
+
+    if app.show_build_info {
+        draw_build_info_popup(frame, app);
+    }
+
+    if app.quit_confirm_pending {
+        draw_quit_confirm_popup(frame, app);
+    }
+
     if app.command_palette_active() {
         draw_command_palette_popover(frame, app);
     }
@@ -383,11 +437,88 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     } else {
         app.clear_review_preview_boxes();
     }
+
+    if app.show_keys {
+        draw_key_log(frame, app);
+    }
+
+    if app.plain {
+        strip_colors(frame);
+    }
+}
+
+/// `--plain`: drop every color from the frame, keeping text and modifiers.
+fn strip_colors(frame: &mut Frame) {
+    let area = frame.area();
+    let buffer = frame.buffer_mut();
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            buffer[(x, y)].set_fg(Color::Reset).set_bg(Color::Reset);
+        }
+    }
+}
+
+/// Recent keypresses in the bottom-right corner; entries dim in the second
+/// half of their lifetime and drop out after `KEY_LOG_TTL_MS`.
+fn draw_key_log(frame: &mut Frame, app: &App) {
+    let entries: Vec<(String, u64)> = app
+        .key_log_entries()
+        .map(|(label, count, age)| {
+            let text = if count > 1 {
+                format!("{label} ×{count}")
+            } else {
+                label.to_string()
+            };
+            (text, age)
+        })
+        .collect();
+    if entries.is_empty() {
+        return;
+    }
+    let area = frame.area();
+    let inner_width = entries
+        .iter()
+        .map(|(text, _)| text_width(text))
+        .max()
+        .unwrap_or(0)
+        .max(8) as u16;
+    let width = (inner_width + 4).min(area.width);
+    let height = (entries.len() as u16 + 2).min(area.height);
+    let bottom_margin = if app.zen_mode { 0 } else { 1 };
+    let x = area.width.saturating_sub(width + 1);
+    let y = area.height.saturating_sub(height + bottom_margin);
+    let popup_area = Rect::new(x, y, width, height);
+
+    let lines: Vec<Line> = entries
+        .into_iter()
+        .map(|(text, age)| {
+            let style = if age * 2 >= KEY_LOG_TTL_MS {
+                Style::default().fg(app.theme.text_muted)
+            } else {
+                Style::default()
+                    .fg(app.theme.text)
+                    .add_modifier(Modifier::BOLD)
+            };
+            Line::from(Span::styled(text, style)).alignment(Alignment::Right)
+        })
+        .collect();
+
+    frame.render_widget(Clear, popup_area);
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(app.theme.border_subtle))
+        .padding(Padding::horizontal(1));
+    if let Some(bg) = app.theme.background_panel.or(app.theme.background) {
+        block = block.style(Style::default().bg(bg));
+    }
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
 }
 
 fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
     let state = app.state();
     let (insertions, deletions) = app.stats();
+    let word_stats = app.word_stats();
 
     // View mode indicator
     let mode = match app.view_mode {
@@ -397,7 +528,7 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
         ViewMode::Blame => " BLAME ",
     };
 
-    let file_path = app.current_file_path();
+    let file_path = app.file_display_name(app.multi_diff.selected_index);
     let available_width = area.width as usize;
 
     let file_name = file_path.rsplit('/').next().unwrap_or(&file_path);
@@ -462,9 +593,16 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
         None
     };
 
+    // Changed-line counter (":c<num>" jumps to the Nth changed line)
+    let changed_text = app
+        .changed_line_info()
+        .map(|(current, total)| format!("chg {}", format_ratio(current, total)));
+
     // File counter (at the end)
-    let file_count = app.multi_diff.file_count();
-    let current_file = app.multi_diff.selected_index + 1;
+    let (current_file, file_count) = app.external_diff_progress.unwrap_or((
+        app.multi_diff.selected_index + 1,
+        app.multi_diff.file_count(),
+    ));
     let file_text = format!("{}/{}", current_file, file_count);
 
     // Build CENTER section: goto/search prompt or step counter
@@ -501,6 +639,12 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
             Style::default().fg(app.theme.text)
         };
         center_spans.push(Span::styled(query_text, query_style));
+        if app.search_whole_word() {
+            center_spans.push(Span::styled(
+                " [word]",
+                Style::default().fg(app.theme.text_muted),
+            ));
+        }
     } else if app.stepping {
         let autoplay_marker = if app.autoplay {
             if app.autoplay_reverse {
@@ -518,6 +662,10 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
             Style::default().fg(app.theme.text_muted),
         ));
         center_spans.push(Span::styled(step_text.clone(), step_style));
+        if let Some(status) = app.autoplay_status() {
+            center_spans.push(Span::raw(" "));
+            center_spans.push(Span::styled(status, arrow_style));
+        }
     }
 
     // Build RIGHT section: stats + hunk + file
@@ -528,6 +676,22 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
         || app.syntax_warmup_pending();
     let stats_known = insertions > 0 || deletions > 0;
     let mut right_spans = Vec::new();
+    if let Some((scroll, ..)) = app.horizontal_overflow().filter(|(scroll, ..)| *scroll > 0) {
+        right_spans.push(Span::styled(
+            format!("→ col {}", scroll + 1),
+            Style::default().fg(app.theme.text_muted),
+        ));
+        right_spans.push(Span::raw("  "));
+    }
+    if let Some((start, end, total)) = app.line_limit_info() {
+        let label = if total > 0 {
+            format!("lines {start}–{end} of {total}")
+        } else {
+            format!("lines {start}–{end}")
+        };
+        right_spans.push(Span::styled(label, Style::default().fg(app.theme.warning)));
+        right_spans.push(Span::raw("  "));
+    }
     if let Some(ref hunk) = hunk_text {
         let hunk_label = if let Some(ref hunk_step) = hunk_step_text {
             format!("{} {}", hunk_step, hunk)
@@ -540,6 +704,13 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
         ));
         right_spans.push(Span::raw("  "));
     }
+    if let Some(changed) = changed_text.filter(|_| !diff_pending) {
+        right_spans.push(Span::styled(
+            changed,
+            Style::default().fg(app.theme.text_muted),
+        ));
+        right_spans.push(Span::raw("  "));
+    }
     let spinner = if diff_pending {
         diff_spinner_frame()
     } else {
@@ -565,6 +736,10 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
             format!("-{}", deletions),
             Style::default().fg(app.theme.error),
         ));
+        if let Some((added, removed)) = word_stats {
+            right_spans.push(Span::raw("  "));
+            right_spans.extend(word_stats_spans(app, added, removed));
+        }
     }
     if app.files_changed_on_disk {
         right_spans.push(Span::raw(" "));
@@ -573,6 +748,84 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
             Style::default().fg(app.theme.warning),
         ));
     }
+    if let Some(path) = app.goto_path_hint() {
+        right_spans.push(Span::raw(" "));
+        right_spans.push(Span::styled(
+            format!("not found: {path}"),
+            Style::default().fg(app.theme.warning),
+        ));
+    }
+    if app.clipboard_truncated_hint() {
+        right_spans.push(Span::raw(" "));
+        right_spans.push(Span::styled(
+            "copy truncated",
+            Style::default().fg(app.theme.warning),
+        ));
+    }
+    if app.structural_lines_hint() {
+        right_spans.push(Span::raw(" "));
+        right_spans.push(Span::styled(
+            "structural diff: no source lines",
+            Style::default().fg(app.theme.warning),
+        ));
+    }
+    if let Some(mode) = app.diff_highlight_hint() {
+        right_spans.push(Span::raw(" "));
+        right_spans.push(Span::styled(
+            format!("highlight: {}", mode.label()),
+            Style::default().fg(app.theme.text_muted),
+        ));
+    }
+    if let Some(mode) = app.modified_step_hint() {
+        right_spans.push(Span::raw(" "));
+        right_spans.push(Span::styled(
+            format!("modified: {}", mode.label()),
+            Style::default().fg(app.theme.text_muted),
+        ));
+    }
+    if let Some(captured) = app.mouse_capture_hint() {
+        right_spans.push(Span::raw(" "));
+        let text = if captured {
+            "mouse: on"
+        } else {
+            "mouse: off (terminal selection)"
+        };
+        right_spans.push(Span::styled(
+            text,
+            Style::default().fg(app.theme.text_muted),
+        ));
+    }
+    if let Some((message, ok)) = app.review_export_hint() {
+        right_spans.push(Span::raw(" "));
+        let color = if ok {
+            app.theme.success
+        } else {
+            app.theme.warning
+        };
+        right_spans.push(Span::styled(
+            message.to_string(),
+            Style::default().fg(color),
+        ));
+    }
+    if let Some((ok, detail)) = app.config_reload_hint() {
+        right_spans.push(Span::raw(" "));
+        let (label, color) = match (ok, detail) {
+            (true, None) => ("config reloaded".to_string(), app.theme.success),
+            (true, Some(warning)) => (format!("config reloaded: {warning}"), app.theme.warning),
+            (false, detail) => (
+                format!("config error: {}", detail.unwrap_or_default()),
+                app.theme.error,
+            ),
+        };
+        right_spans.push(Span::styled(label, Style::default().fg(color)));
+    }
+    if let Some(time) = app.time_status_text() {
+        right_spans.push(Span::raw("  "));
+        right_spans.push(Span::styled(
+            time,
+            Style::default().fg(app.theme.text_muted),
+        ));
+    }
     let comment_count = app.review_comment_count();
     if comment_count > 0 || app.review_editor_active() {
         right_spans.push(Span::raw(" "));
@@ -641,6 +894,9 @@ fn draw_status_bar(frame: &mut Frame, app: &mut App, area: Rect) {
 
 fn draw_top_bar(frame: &mut Frame, app: &mut App, area: Rect) {
     let (insertions, deletions) = app.stats();
+    let word_stats = app.word_stats();
+    let hunk_header = app.current_hunk_header_text();
+    let hunk_steps = app.hunk_step_progress_text();
     let file = app.multi_diff.current_file();
     let available_width = area.width as usize;
     let diff_pending = matches!(
@@ -680,7 +936,12 @@ fn draw_top_bar(frame: &mut Frame, app: &mut App, area: Rect) {
             ]
         }
     } else {
-        vec![
+        let mut spans = Vec::new();
+        if let Some((added, removed)) = word_stats {
+            spans.extend(word_stats_spans(app, added, removed));
+            spans.push(Span::raw("  "));
+        }
+        spans.extend([
             Span::styled(
                 format!("+{}", insertions),
                 Style::default().fg(app.theme.success),
@@ -691,21 +952,56 @@ fn draw_top_bar(frame: &mut Frame, app: &mut App, area: Rect) {
                 Style::default().fg(app.theme.error),
             ),
             Span::raw(" "),
-        ]
+        ]);
+        spans
     };
     let right_width = spans_width(&right_spans);
     let left_max = available_width.saturating_sub(right_width + 2);
     let file_changed = app.file_changed_on_disk(app.multi_diff.selected_index);
     let changed_marker_len = if file_changed { 2 } else { 0 };
+    let progress_text = app
+        .external_diff_progress
+        .map(|(current, total)| format!("file {} of {}", current, total))
+        .or_else(|| app.range_note.clone());
+    let progress_text = match (progress_text, hunk_header) {
+        (Some(note), Some(header)) => Some(format!("{note} · {header}")),
+        (note, header) => note.or(header),
+    };
+    let progress_text = match (progress_text, app.has_commit_notes()) {
+        (Some(note), true) => Some(format!("{note} · ✎ notes")),
+        (None, true) => Some("✎ notes".to_string()),
+        (note, false) => note,
+    };
+    let progress_text = match (progress_text, hunk_steps) {
+        (Some(note), Some(steps)) => Some(format!("{note} · {steps}")),
+        (note, steps) => note.or(steps),
+    };
+    let progress_text = match (progress_text, app.review_progress_text()) {
+        (Some(note), Some(review)) => Some(format!("{note} · {review}")),
+        (note, review) => note.or(review),
+    };
+    let progress_len = progress_text
+        .as_ref()
+        .map(|text| text_width(text) + 2)
+        .unwrap_or(0);
 
+    let old_path_shown = app
+        .displayed_old_path(app.multi_diff.selected_index)
+        .is_some();
+    let old_marker_len = if old_path_shown { 6 } else { 0 };
+    let encoding = file
+        .and_then(|file| file.encoding)
+        .map(|encoding| encoding.label());
+    let encoding_len = encoding.map(|label| text_width(label) + 3).unwrap_or(0);
     let (name_text, status_style) = if let Some(file) = file {
-        let file_name = file
-            .display_name
-            .rsplit('/')
-            .next()
-            .unwrap_or(&file.display_name);
-        let name =
-            truncate_filename_keep_ext(file_name, left_max.saturating_sub(3 + changed_marker_len));
+        let display_name = app.file_display_name(app.multi_diff.selected_index);
+        let file_name = display_name.rsplit('/').next().unwrap_or(&display_name);
+        let name = truncate_filename_keep_ext(
+            file_name,
+            left_max.saturating_sub(
+                3 + changed_marker_len + progress_len + old_marker_len + encoding_len,
+            ),
+        );
         let status_style = match file.status {
             FileStatus::Added | FileStatus::Untracked => Style::default().fg(app.theme.success),
             FileStatus::Deleted => Style::default().fg(app.theme.error),
@@ -723,6 +1019,15 @@ fn draw_top_bar(frame: &mut Frame, app: &mut App, area: Rect) {
         Span::raw(" "),
         Span::styled(name_text, Style::default().fg(app.theme.text)),
     ];
+    if old_path_shown {
+        left_spans.push(Span::styled(" (old)", Style::default().fg(app.theme.info)));
+    }
+    if let Some(label) = encoding {
+        left_spans.push(Span::styled(
+            format!(" [{label}]"),
+            Style::default().fg(app.theme.text_muted),
+        ));
+    }
     if file_changed {
         left_spans.push(Span::raw(" "));
         left_spans.push(Span::styled(
@@ -732,6 +1037,13 @@ fn draw_top_bar(frame: &mut Frame, app: &mut App, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         ));
     }
+    if let Some(progress) = progress_text {
+        left_spans.push(Span::raw("  "));
+        left_spans.push(Span::styled(
+            progress,
+            Style::default().fg(app.theme.text_muted),
+        ));
+    }
     left_spans = clamp_spans_to_width(&left_spans, left_max);
     left_spans = pad_spans_left(left_spans, left_max);
 
@@ -749,6 +1061,16 @@ fn draw_top_bar(frame: &mut Frame, app: &mut App, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
+/// `words +N -M` for prose files, next to the line counts.
+fn word_stats_spans(app: &App, added: usize, removed: usize) -> Vec<Span<'static>> {
+    vec![
+        Span::styled("words ", Style::default().fg(app.theme.text_muted)),
+        Span::styled(format!("+{added}"), Style::default().fg(app.theme.success)),
+        Span::raw(" "),
+        Span::styled(format!("-{removed}"), Style::default().fg(app.theme.error)),
+    ]
+}
+
 fn blame_age_legend_spans(app: &App) -> Vec<Span<'static>> {
     let blocks = 10usize;
     let mut spans = Vec::with_capacity(blocks + 3);
@@ -776,23 +1098,7 @@ fn blame_age_legend_spans(app: &App) -> Vec<Span<'static>> {
 }
 
 fn draw_content(frame: &mut Frame, app: &mut App, area: Rect, show_topbar: bool) {
-    // Auto-hide file panel if viewport is too narrow (need at least 50 cols for diff view)
-    // But respect user's manual toggle preference
-    let min_width_for_panel = FILE_PANEL_MIN_WIDTH + DIFF_VIEW_MIN_WIDTH;
-
-    // Track if panel would be auto-hidden (for toggle behavior)
-    app.file_panel_auto_hidden = app.is_multi_file()
-        && app.file_panel_visible
-        && area.width < min_width_for_panel
-        && !app.file_panel_manually_set;
-
-    let show_panel = if app.file_panel_manually_set {
-        // User explicitly toggled, respect their preference
-        app.is_multi_file() && app.file_panel_visible
-    } else {
-        // Auto-hide when viewport is too narrow
-        app.is_multi_file() && app.file_panel_visible && area.width >= min_width_for_panel
-    };
+    let show_panel = app.update_file_panel_auto_hide(area.width);
 
     if show_panel {
         // Split: file list on left, diff view on right
@@ -814,19 +1120,9 @@ fn draw_content(frame: &mut Frame, app: &mut App, area: Rect, show_topbar: bool)
                 .constraints([Constraint::Length(1), Constraint::Min(0)])
                 .split(chunks[1]);
             draw_top_bar(frame, app, diff_chunks[0]);
-            app.last_viewport_height = diff_chunks[1].height as usize;
-            app.diff_view_area = Some((
-                diff_chunks[1].x,
-                diff_chunks[1].y,
-                diff_chunks[1].width,
-                diff_chunks[1].height,
-            ));
-            draw_diff_view(frame, app, diff_chunks[1]);
+            draw_diff_region(frame, app, diff_chunks[1]);
         } else {
-            app.last_viewport_height = chunks[1].height as usize;
-            app.diff_view_area =
-                Some((chunks[1].x, chunks[1].y, chunks[1].width, chunks[1].height));
-            draw_diff_view(frame, app, chunks[1]);
+            draw_diff_region(frame, app, chunks[1]);
         }
     } else {
         // Single file mode, file panel hidden, or viewport too narrow
@@ -840,18 +1136,9 @@ fn draw_content(frame: &mut Frame, app: &mut App, area: Rect, show_topbar: bool)
                 .constraints([Constraint::Length(1), Constraint::Min(0)])
                 .split(area);
             draw_top_bar(frame, app, diff_chunks[0]);
-            app.last_viewport_height = diff_chunks[1].height as usize;
-            app.diff_view_area = Some((
-                diff_chunks[1].x,
-                diff_chunks[1].y,
-                diff_chunks[1].width,
-                diff_chunks[1].height,
-            ));
-            draw_diff_view(frame, app, diff_chunks[1]);
+            draw_diff_region(frame, app, diff_chunks[1]);
         } else {
-            app.last_viewport_height = area.height as usize;
-            app.diff_view_area = Some((area.x, area.y, area.width, area.height));
-            draw_diff_view(frame, app, area);
+            draw_diff_region(frame, app, area);
         }
     }
 }
@@ -1023,17 +1310,27 @@ fn draw_file_list(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut row_map: Vec<Option<usize>> = Vec::new();
     let mut remaining = list_area.height.saturating_sub(2) as usize;
     let mut current_group: Option<String> = None;
+    let path_style = app.file_path_style;
+    let grouped = path_style == FilePathStyle::Grouped;
+    // Smart labels only need to be unique among the files actually listed.
+    let smart_labels = if path_style == FilePathStyle::Smart {
+        let names: Vec<String> = filtered_indices
+            .iter()
+            .map(|&idx| app.file_display_name(idx))
+            .collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        smart_path_labels(&names)
+    } else {
+        Vec::new()
+    };
 
     let mut idx = app.file_list_scroll;
     while idx < filtered_indices.len() && remaining > 0 {
         let file_idx = filtered_indices[idx];
         let file = &files[file_idx];
-        let group = match file.display_name.rsplit_once('/') {
-            Some((dir, _)) => dir.to_string(),
-            None => "Root Path".to_string(),
-        };
+        let group = file_list_group(&file.display_name).to_string();
 
-        if current_group.as_deref() != Some(&group) {
+        if grouped && current_group.as_deref() != Some(&group) {
             if current_group.is_some() && remaining > 0 {
                 items.push(ListItem::new(Line::raw("")));
                 row_map.push(None);
@@ -1086,7 +1383,14 @@ fn draw_file_list(frame: &mut Frame, app: &mut App, area: Rect) {
             crate::config::FileCountMode::All => true,
             crate::config::FileCountMode::Off => false,
         };
-        let show_signs = show_for_row && (file.binary || file.insertions > 0 || file.deletions > 0);
+        // Unvisited files of a lazy directory scan only know their sizes.
+        let size_text = app
+            .multi_diff
+            .unloaded_sizes(file_idx)
+            .map(|(old, new)| format!("{}→{}", compact_size(old), compact_size(new)));
+        let has_counts =
+            file.binary || file.insertions > 0 || file.deletions > 0 || size_text.is_some();
+        let show_signs = show_for_row && has_counts;
         let insert_text = if show_signs && !file.binary {
             format!("+{}", file.insertions)
         } else {
@@ -1097,37 +1401,48 @@ fn draw_file_list(frame: &mut Frame, app: &mut App, area: Rect) {
         } else {
             String::new()
         };
-        let signs_len = if show_signs {
-            if file.binary {
-                1 + "bin".len()
-            } else {
-                1 + insert_text.len() + 1 + delete_text.len()
-            }
-        } else {
+        // Reserve count width whenever counts can appear, so names don't jump
+        // as selection, focus or the count mode changes.
+        let signs_len = if app.file_count_mode == crate::config::FileCountMode::Off || !has_counts {
             0
+        } else if let Some(text) = &size_text {
+            1 + text_width(text)
+        } else if file.binary {
+            1 + "bin".len()
+        } else {
+            1 + format!("+{}", file.insertions).len() + 1 + format!("-{}", file.deletions).len()
         };
 
         let file_changed = app.file_changed_on_disk(file_idx);
         let changed_marker_len = if file_changed { 2 } else { 0 };
 
-        // Truncate filename to fit (preserve extension)
-        let file_name = file
-            .display_name
-            .rsplit('/')
-            .next()
-            .unwrap_or(&file.display_name);
+        // Truncate to fit: basenames keep their extension, paths their tail
+        let display_name = app.file_display_name(file_idx);
+        let file_name = display_name.rsplit('/').next().unwrap_or(&display_name);
         let max_name_len = list_area
             .width
             .saturating_sub(8 + signs_len as u16 + changed_marker_len as u16)
             .max(1) as usize;
-        let name = truncate_filename_keep_ext(file_name, max_name_len);
+        let name = match path_style {
+            FilePathStyle::Grouped | FilePathStyle::Basename => {
+                truncate_filename_keep_ext(file_name, max_name_len)
+            }
+            FilePathStyle::Full => truncate_path(&display_name, max_name_len),
+            FilePathStyle::Smart => truncate_path(&smart_labels[idx], max_name_len),
+        };
 
         let mut icon_style = status_style;
         if let Some(bg) = selected_bg {
             icon_style = icon_style.bg(bg);
         }
 
-        let mut name_style = Style::default().fg(app.theme.text);
+        // Files that failed to read stand out in red
+        let name_color = if file.read_error.is_some() {
+            app.theme.error
+        } else {
+            app.theme.text
+        };
+        let mut name_style = Style::default().fg(name_color);
         if is_selected {
             name_style = name_style.add_modifier(Modifier::BOLD);
         }
@@ -1135,14 +1450,23 @@ fn draw_file_list(frame: &mut Frame, app: &mut App, area: Rect) {
             name_style = name_style.bg(bg);
         }
 
+        let is_marked = app.is_file_marked(file_idx);
         let marker_style = if is_selected {
             Style::default()
                 .fg(app.theme.accent)
                 .add_modifier(Modifier::BOLD)
+        } else if is_marked {
+            Style::default().fg(app.theme.info)
         } else {
             Style::default().fg(app.theme.text_muted)
         };
-        let marker = if is_selected { "•" } else { " " };
+        let marker = if is_marked {
+            "◆"
+        } else if is_selected {
+            "•"
+        } else {
+            " "
+        };
 
         let mut line_spans = vec![
             Span::styled(marker, marker_style),
@@ -1164,7 +1488,12 @@ fn draw_file_list(frame: &mut Frame, app: &mut App, area: Rect) {
             } else {
                 Style::default().fg(app.theme.text_muted)
             };
-            if file.binary {
+            if let Some(text) = size_text {
+                line_spans.push(Span::styled(
+                    text,
+                    Style::default().fg(app.theme.text_muted),
+                ));
+            } else if file.binary {
                 line_spans.push(Span::styled("bin", sign_style));
             } else {
                 line_spans.push(Span::styled(insert_text, sign_style));
@@ -1267,7 +1596,164 @@ fn draw_file_list(frame: &mut Frame, app: &mut App, area: Rect) {
     }
 }
 
+/// Commit header rows shown before scrolling kicks in.
+const COMMIT_HEADER_MAX_ROWS: usize = 6;
+
+/// The diff view, below the commit header when the first file of a git range
+/// is selected.
+fn draw_diff_region(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.commit_header_area = None;
+    let mut area = area;
+    if let Some(lines) = commit_header_lines(app, area.width) {
+        let height = lines.len().min(COMMIT_HEADER_MAX_ROWS) as u16 + 2;
+        if area.height >= height + DIFF_VIEW_MIN_HEIGHT {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(height), Constraint::Min(0)])
+                .split(area);
+            draw_commit_header(frame, app, chunks[0], lines);
+            area = chunks[1];
+        }
+    }
+    app.last_viewport_height = area.height as usize;
+    app.diff_view_area = Some((area.x, area.y, area.width, area.height));
+    draw_diff_view(frame, app, area);
+    draw_cursorline(frame, app, area);
+    draw_hscrollbar(frame, app, area);
+}
+
+/// Thin bar along the bottom of the diff view showing the horizontal
+/// scroll position over the longest line.
+fn draw_hscrollbar(frame: &mut Frame, app: &App, area: Rect) {
+    if !app.hscrollbar || app.continuous_view || area.height < 2 {
+        return;
+    }
+    let Some((scroll, max_scroll, viewport_width)) = app.horizontal_overflow() else {
+        return;
+    };
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .track_symbol(Some("─"))
+        .thumb_symbol("━")
+        .track_style(Style::default().fg(app.theme.border_subtle))
+        .thumb_style(Style::default().fg(app.theme.text_muted));
+    let mut state = ScrollbarState::new(max_scroll + 1)
+        .position(scroll)
+        .viewport_content_length(viewport_width);
+    frame.render_stateful_widget(scrollbar, area, &mut state);
+}
+
+/// Blend a faint text-colored tint into the active row's backgrounds, so
+/// diff line backgrounds stay visible underneath.
+fn draw_cursorline(frame: &mut Frame, app: &mut App, area: Rect) {
+    const CURSORLINE_ALPHA: f32 = 0.07;
+    if !app.cursorline || app.continuous_view {
+        return;
+    }
+    let Some(idx) = app.cursorline_display_idx() else {
+        return;
+    };
+    let Some(row) = idx.checked_sub(app.render_scroll_offset()) else {
+        return;
+    };
+    if row >= area.height as usize {
+        return;
+    }
+    let base_bg = app.theme.background.or(app.theme.background_panel);
+    // Terminal-default text has no RGB value to blend with.
+    let tint = match app.theme.text {
+        Color::Rgb(..) => app.theme.text,
+        _ if app.theme_is_light => Color::Rgb(0, 0, 0),
+        _ => Color::Rgb(255, 255, 255),
+    };
+    // Transparent themes have nothing to blend into; use a flat grey.
+    let fallback = app
+        .theme
+        .background_element
+        .unwrap_or(if app.theme_is_light {
+            Color::Indexed(254)
+        } else {
+            Color::Indexed(236)
+        });
+    let blend = |bg: Color| color::blend_colors(bg, tint, CURSORLINE_ALPHA);
+    let y = area.y + row as u16;
+    let buf = frame.buffer_mut();
+    for x in area.x..area.right() {
+        let cell = &mut buf[(x, y)];
+        cell.bg = match cell.bg {
+            Color::Reset => base_bg.and_then(blend).unwrap_or(fallback),
+            bg => blend(bg).unwrap_or(bg),
+        };
+    }
+}
+
+fn commit_header_lines(app: &App, width: u16) -> Option<Vec<String>> {
+    if !app.show_commit_header || app.continuous_view || app.multi_diff.selected_index != 0 {
+        return None;
+    }
+    let header = app.commit_header.as_ref()?;
+    let text_width = width.saturating_sub(4) as usize;
+    Some(
+        header
+            .lines
+            .iter()
+            .flat_map(|line| wrap_editor_line(line, text_width))
+            .collect(),
+    )
+}
+
+fn draw_commit_header(frame: &mut Frame, app: &mut App, area: Rect, lines: Vec<String>) {
+    let rows = area.height.saturating_sub(2) as usize;
+    let max_scroll = lines.len().saturating_sub(rows);
+    app.commit_header_scroll = app.commit_header_scroll.min(max_scroll);
+    let scroll = app.commit_header_scroll;
+    app.commit_header_area = Some((area.x, area.y, area.width, area.height));
+
+    let title = app
+        .commit_header
+        .as_ref()
+        .map(|header| header.title.clone())
+        .unwrap_or_default();
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(
+            title,
+            Style::default().fg(app.theme.text_muted),
+        ))
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(app.theme.border_subtle));
+    if max_scroll > 0 {
+        let position = format!(" {}-{}/{} ", scroll + 1, scroll + rows, lines.len());
+        block = block.title_bottom(
+            Line::from(Span::styled(
+                position,
+                Style::default().fg(app.theme.text_muted),
+            ))
+            .right_aligned(),
+        );
+    }
+    if let Some(bg) = app.theme.background {
+        block = block.style(Style::default().bg(bg));
+    }
+
+    let text: Vec<Line> = lines
+        .into_iter()
+        .skip(scroll)
+        .take(rows)
+        .map(Line::from)
+        .collect();
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .style(Style::default().fg(app.theme.text));
+    frame.render_widget(paragraph, area);
+}
+
 fn draw_diff_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    if app.continuous_view {
+        render_continuous(frame, app, area);
+        return;
+    }
     match app.view_mode {
         ViewMode::UnifiedPane => render_unified_pane(frame, app, area),
         ViewMode::Split => render_split(frame, app, area),
@@ -1393,14 +1879,11 @@ fn draw_review_editor_overlay(frame: &mut Frame, app: &mut App) {
 
     let diff_area = Rect::new(x, y, width, height);
     let editor_area = if app.view_mode == ViewMode::Split {
-        let panes = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(diff_area);
+        let (old_area, new_area) = split_pane_areas(app, diff_area);
         if editor.prefer_right {
-            panes[1]
+            new_area
         } else {
-            panes[0]
+            old_area
         }
     } else {
         diff_area
@@ -1778,14 +2261,23 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
             NormalAction::TogglePeekChange,
             NormalAction::TogglePeekHunk,
         ),
+        normal(NormalAction::TogglePeekNext),
         paired(&normal, NormalAction::YankChange, NormalAction::YankHunk),
+        normal(NormalAction::YankCommentTemplate),
+        normal(NormalAction::YankHunkHeader),
         normal(NormalAction::OpenSearchOrFileFilter),
         paired(&normal, NormalAction::SearchNext, NormalAction::SearchPrev),
+        paired(&normal, NormalAction::JumpBack, NormalAction::JumpForward),
         paired(
             &normal,
             NormalAction::NextConflict,
             NormalAction::PrevConflict,
         ),
+        paired(
+            &normal,
+            NormalAction::NextKeyword,
+            NormalAction::PrevKeyword,
+        ),
         paired(
             &normal,
             NormalAction::LineComment,
@@ -1797,9 +2289,15 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
             NormalAction::RemoveHunkComment,
         ),
         normal(NormalAction::ClearComments),
+        normal(NormalAction::ExportComments),
         ":<line>".to_string(),
         ":h<num>".to_string(),
         ":s<num>".to_string(),
+        ":c<num>".to_string(),
+        ":f<num>".to_string(),
+        ":biggest".to_string(),
+        ":limit <a>,<b>".to_string(),
+        normal(NormalAction::ClearLineLimit),
         paired(&normal, NormalAction::FirstStep, NormalAction::LastStep),
         paired(&normal, NormalAction::GotoStart, NormalAction::GotoEnd),
         paired(&normal, NormalAction::ScrollDown, NormalAction::ScrollUp),
@@ -1811,10 +2309,16 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
             NormalAction::HalfPageDown,
         ),
         normal(NormalAction::TogglePathPopup),
+        normal(NormalAction::ToggleNotePopup),
         normal(NormalAction::OpenEditor),
         normal(NormalAction::CenterActive),
+        normal(NormalAction::SyncCenterSplit),
+        normal(NormalAction::ToggleSplitFocus),
+        normal(NormalAction::ToggleMouseCapture),
         normal(NormalAction::ToggleLineWrap),
         normal(NormalAction::ToggleSyntax),
+        normal(NormalAction::CycleDiffHighlight),
+        normal(NormalAction::CycleModifiedStep),
         normal(NormalAction::ToggleStepping),
         normal(NormalAction::ToggleStrikethrough),
         paired(
@@ -1830,6 +2334,12 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
         normal(NormalAction::ToggleAnimation),
         normal(NormalAction::ToggleViewMode),
         normal(NormalAction::ToggleZen),
+        normal(NormalAction::ToggleFocusMode),
+        normal(NormalAction::ToggleMineOnly),
+        normal(NormalAction::ToggleCommitHeader),
+        normal(NormalAction::ToggleOldPath),
+        normal(NormalAction::CycleFileCounts),
+        normal(NormalAction::CyclePathStyle),
         normal(NormalAction::ReplayStep),
         global(GlobalAction::OpenCommandPalette),
         global(GlobalAction::OpenFileSearch),
@@ -1841,8 +2351,13 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
             paired(&normal, NormalAction::PrevFile, NormalAction::NextFile),
             normal(NormalAction::ToggleFilePanel),
             normal(NormalAction::ToggleFileListFocus),
+            paired(&normal, NormalAction::FirstStep, NormalAction::LastStep),
+            normal(NormalAction::ResetFilePanelWidth),
+            normal(NormalAction::ToggleContinuous),
+            normal(NormalAction::ToggleOverview),
             paired(&normal, NormalAction::StepDown, NormalAction::StepUp),
             normal(NormalAction::OpenSearchOrFileFilter),
+            normal(NormalAction::FocusFileFilter),
         ]);
     }
 
@@ -1990,7 +2505,24 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
         &paired(&normal, NormalAction::HunkStart, NormalAction::HunkEnd),
         "Hunk begin/end",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::NextUnreviewedHunk),
+        "Next unreviewed hunk",
+    );
+    push_help_line(
+        &mut lines,
+        &paired(&normal, NormalAction::JumpBack, NormalAction::JumpForward),
+        "Jump back/forward",
+    );
     push_help_line(&mut lines, &normal(NormalAction::BlameHint), "Blame (step)");
+    if app.blame_enabled {
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::YankBlameCommit),
+            "Copy blame commit (git show)",
+        );
+    }
     push_help_line(
         &mut lines,
         &normal(NormalAction::TogglePeekChange),
@@ -2001,6 +2533,11 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
         &normal(NormalAction::TogglePeekHunk),
         "Peek old hunk",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::TogglePeekNext),
+        "Preview next change",
+    );
     push_help_line(
         &mut lines,
         &paired(&normal, NormalAction::YankChange, NormalAction::YankHunk),
@@ -2017,31 +2554,77 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
     );
     push_help_line(
         &mut lines,
-        &normal(NormalAction::OpenSearchOrFileFilter),
-        "Search (diff pane)",
-    );
-    push_help_line(
-        &mut lines,
-        &paired(&normal, NormalAction::SearchNext, NormalAction::SearchPrev),
-        "Next/prev match",
+        &paired(
+            &normal,
+            NormalAction::YankChangeBeforeAfter,
+            NormalAction::YankHunkBeforeAfter,
+        ),
+        "Copy old/new block (line/hunk)",
     );
     push_help_line(
         &mut lines,
         &paired(
             &normal,
-            NormalAction::NextConflict,
-            NormalAction::PrevConflict,
+            NormalAction::YankChangeMarkdown,
+            NormalAction::YankHunkMarkdown,
         ),
-        "Next/prev conflict",
+        "Copy markdown code block (line/hunk)",
     );
     push_help_line(
         &mut lines,
         &paired(
             &normal,
-            NormalAction::LineComment,
-            NormalAction::HunkComment,
+            NormalAction::YankViewAnsi,
+            NormalAction::YankViewPlain,
         ),
-        "Add/update line/hunk comment",
+        "Copy visible view (ANSI/plain)",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::YankCommentTemplate),
+        "Copy review comment template",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::YankHunkHeader),
+        "Copy hunk @@ header",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::OpenSearchOrFileFilter),
+        "Search (diff pane)",
+    );
+    push_help_line(
+        &mut lines,
+        &paired(&normal, NormalAction::SearchNext, NormalAction::SearchPrev),
+        "Next/prev match",
+    );
+    push_help_line(
+        &mut lines,
+        &paired(
+            &normal,
+            NormalAction::NextConflict,
+            NormalAction::PrevConflict,
+        ),
+        "Next/prev conflict",
+    );
+    push_help_line(
+        &mut lines,
+        &paired(
+            &normal,
+            NormalAction::NextKeyword,
+            NormalAction::PrevKeyword,
+        ),
+        "Next/prev added keyword (review.keywords)",
+    );
+    push_help_line(
+        &mut lines,
+        &paired(
+            &normal,
+            NormalAction::LineComment,
+            NormalAction::HunkComment,
+        ),
+        "Add/update line/hunk comment",
     );
     push_help_line(
         &mut lines,
@@ -2057,9 +2640,23 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
         &normal(NormalAction::ClearComments),
         "Clear all comments",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ExportComments),
+        "Export comments to a file",
+    );
     push_help_line(&mut lines, ":<line>", "Go to line");
     push_help_line(&mut lines, ":h<num>", "Go to hunk");
     push_help_line(&mut lines, ":s<num>", "Go to step");
+    push_help_line(&mut lines, ":c<num>", "Go to changed line");
+    push_help_line(&mut lines, ":f<num>", "Go to Nth file in panel");
+    push_help_line(&mut lines, ":biggest", "Go to biggest hunk (repeat: next)");
+    push_help_line(&mut lines, ":limit <a>,<b>", "Show only lines a–b");
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ClearLineLimit),
+        "Clear line range limit",
+    );
     push_help_line(
         &mut lines,
         &paired(&normal, NormalAction::FirstStep, NormalAction::LastStep),
@@ -2099,31 +2696,108 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
         &normal(NormalAction::TogglePathPopup),
         "Show full file path",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleNotePopup),
+        "Show presenter note",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleBuildInfo),
+        "Version and build info",
+    );
     push_help_line(
         &mut lines,
         &normal(NormalAction::OpenEditor),
         "Open file in editor",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::GotoPathUnderCursor),
+        "Open path under cursor",
+    );
     push_help_line(
         &mut lines,
         &normal(NormalAction::CenterActive),
         "Center on active",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::SyncCenterSplit),
+        "Center hunk in both split panes",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleSplitFocus),
+        "Switch focused split pane",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleMouseCapture),
+        if app.mouse_capture {
+            "Mouse capture (on; off to select text)"
+        } else {
+            "Mouse capture (off; on to scroll/click)"
+        },
+    );
     push_help_line(
         &mut lines,
         &normal(NormalAction::ToggleLineWrap),
         "Toggle line wrap",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleDiffBg),
+        "Toggle line backgrounds (this view)",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleThemeMode),
+        "Switch light/dark theme",
+    );
     push_help_line(
         &mut lines,
         &normal(NormalAction::ToggleFoldContext),
         "Toggle context folding",
     );
+    push_help_line(
+        &mut lines,
+        &paired(
+            &normal,
+            NormalAction::ExpandContextAbove,
+            NormalAction::ExpandContextBelow,
+        ),
+        "Unfold context above/below hunk",
+    );
+    if app.multi_diff.is_git_mode() {
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::OpenFileHistory),
+            "File history (commits touching it)",
+        );
+    }
+    if app.multi_diff.git_range().is_some() {
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::WalkFileCommits),
+            "Step through file's commits in range",
+        );
+    }
     push_help_line(
         &mut lines,
         &normal(NormalAction::ToggleSyntax),
         "Toggle syntax highlight",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::CycleDiffHighlight),
+        "Cycle inline highlight (text/word/none)",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::CycleModifiedStep),
+        "Cycle modified lines while stepping (mixed/modified/old)",
+    );
     if app.view_mode == ViewMode::Evolution {
         push_help_line(
             &mut lines,
@@ -2139,7 +2813,7 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
     push_help_line(
         &mut lines,
         &normal(NormalAction::ToggleStrikethrough),
-        "Toggle strikethrough",
+        "Cycle deletion style",
     );
     push_help_line(
         &mut lines,
@@ -2199,11 +2873,48 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
         "Cycle view mode (reverse)",
     );
     push_help_line(&mut lines, &normal(NormalAction::ToggleZen), "Zen mode");
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleFocusMode),
+        "Focus mode (dim other hunks)",
+    );
+    if app.multi_diff.is_git_mode() {
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::ToggleMineOnly),
+            "Only my changes (dim others)",
+        );
+    }
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleCommitHeader),
+        "Show/hide commit message",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ToggleOldPath),
+        "Show old/new name of renames",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::CycleFileCounts),
+        "Cycle file panel counts",
+    );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::CyclePathStyle),
+        "Cycle panel paths: grouped/full/basename/smart",
+    );
     push_help_line(
         &mut lines,
         &normal(NormalAction::Refresh),
         "Refresh all files",
     );
+    push_help_line(
+        &mut lines,
+        &normal(NormalAction::ReloadConfig),
+        "Reload config file",
+    );
 
     if app.is_multi_file() {
         lines.push(Line::from(""));
@@ -2228,11 +2939,51 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
             &paired(&normal, NormalAction::StepDown, NormalAction::StepUp),
             "Move selection (focused)",
         );
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::ToggleAutoplay),
+            "Mark file (focused)",
+        );
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::YankChange),
+            "Copy marked paths (focused)",
+        );
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::NextConflict),
+            "Compare 2 marked files (focused)",
+        );
         push_help_line(
             &mut lines,
             &normal(NormalAction::OpenSearchOrFileFilter),
             "Filter files (when focused)",
         );
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::FocusFileFilter),
+            "Filter files (from anywhere)",
+        );
+        push_help_line(
+            &mut lines,
+            &paired(&normal, NormalAction::FirstStep, NormalAction::LastStep),
+            "Narrow/widen panel (focused)",
+        );
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::ResetFilePanelWidth),
+            "Reset panel width",
+        );
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::ToggleContinuous),
+            "All files in one scroll",
+        );
+        push_help_line(
+            &mut lines,
+            &normal(NormalAction::ToggleOverview),
+            "File overview (diff --stat)",
+        );
     }
 
     lines.push(Line::from(""));
@@ -2246,7 +2997,7 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
         Span::styled(quit_label, label_style),
     ]));
 
-    let base_height = if app.is_multi_file() { 31 } else { 26 };
+    let base_height = if app.is_multi_file() { 46 } else { 36 };
     let min_height = (base_height as u16).min(area.height.saturating_sub(4));
     let needed_height = (lines.len() as u16).saturating_add(2);
     let popup_height = needed_height
@@ -2299,7 +3050,7 @@ fn draw_help_popover(frame: &mut Frame, app: &mut App) {
 
 fn draw_path_popup(frame: &mut Frame, app: &App) {
     let area = frame.area();
-    let file_path = app.current_file_path();
+    let file_path = app.file_display_name(app.multi_diff.selected_index);
 
     // Calculate popup size based on path length
     let popup_width = (file_path.len() as u16 + 6).min(area.width.saturating_sub(4));
@@ -2340,6 +3091,135 @@ fn draw_path_popup(frame: &mut Frame, app: &App) {
     frame.render_widget(path_block, popup_area);
 }
 
+fn draw_note_popup(frame: &mut Frame, app: &mut App) {
+    let Some((line, note)) = app.current_presenter_note() else {
+        app.show_note_popup = false;
+        return;
+    };
+    let area = frame.area();
+    let popup_width = 64u16.min(area.width.saturating_sub(4));
+    let text_width = popup_width.saturating_sub(4) as usize;
+    let mut lines: Vec<Line> = Vec::new();
+    for raw in note.text.trim_end().lines() {
+        for wrapped in wrap_editor_line(raw, text_width) {
+            lines.push(Line::from(wrapped));
+        }
+    }
+    let max_height = area.height.saturating_sub(4).max(3);
+    let popup_height = (lines.len() as u16).saturating_add(2).min(max_height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let range = if note.end_line() > note.start_line() {
+        format!("{}-{}", note.start_line(), note.end_line())
+    } else {
+        note.start_line().to_string()
+    };
+    let title = if note.contains(line) {
+        format!(" Note · L{} ", range)
+    } else {
+        format!(" Note · L{} (nearest) ", range)
+    };
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(app.theme.border_active));
+    if let Some(bg) = app.theme.background {
+        block = block.style(Style::default().bg(bg));
+    }
+
+    let note_block = Paragraph::new(lines)
+        .block(block)
+        .style(Style::default().fg(app.theme.text));
+
+    frame.render_widget(note_block, popup_area);
+}
+
+fn draw_build_info_popup(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let label_width = app
+        .build_info
+        .iter()
+        .map(|(label, _)| label.len())
+        .max()
+        .unwrap_or(0);
+    let popup_width = 72u16.min(area.width.saturating_sub(4));
+    let value_width = (popup_width as usize).saturating_sub(label_width + 6);
+    let mut lines: Vec<Line> = Vec::new();
+    for (label, value) in &app.build_info {
+        for (idx, wrapped) in wrap_editor_line(value, value_width).into_iter().enumerate() {
+            let label = if idx == 0 { *label } else { "" };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{label:<label_width$}  "),
+                    Style::default().fg(app.theme.text_muted),
+                ),
+                Span::styled(wrapped, Style::default().fg(app.theme.text)),
+            ]));
+        }
+    }
+    let max_height = area.height.saturating_sub(4).max(3);
+    let popup_height = (lines.len() as u16).saturating_add(2).min(max_height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(" oyo ")
+        .title_alignment(Alignment::Center)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(app.theme.border_active));
+    if let Some(bg) = app.theme.background {
+        block = block.style(Style::default().bg(bg));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+fn draw_quit_confirm_popup(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let lines = vec![
+        Line::from(Span::styled(
+            "Staged changes were made in this session.",
+            Style::default().fg(app.theme.text),
+        )),
+        Line::from(vec![
+            Span::styled("y", Style::default().fg(app.theme.accent)),
+            Span::styled(
+                " or quit again to leave, any other key to stay",
+                Style::default().fg(app.theme.text_muted),
+            ),
+        ]),
+    ];
+    let popup_width = 54u16.min(area.width.saturating_sub(4));
+    let popup_height = 4u16.min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Quit? ")
+        .title_alignment(Alignment::Center)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(app.theme.warning));
+    if let Some(bg) = app.theme.background {
+        block = block.style(Style::default().bg(bg));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
 fn draw_command_palette_popover(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
     let popup_width = 56u16.min(area.width.saturating_sub(4));
@@ -2450,6 +3330,173 @@ fn draw_command_palette_popover(frame: &mut Frame, app: &mut App) {
     frame.render_stateful_widget(list, chunks[1], &mut state);
 }
 
+/// Full-screen `git diff --stat` style file list
+fn draw_overview(frame: &mut Frame, app: &mut App) {
+    let area = frame.area();
+    frame.render_widget(Clear, area);
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(app.theme.border_active))
+        .title(Span::styled(
+            " Overview ",
+            Style::default()
+                .fg(app.theme.text)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .padding(Padding::horizontal(1));
+    if let Some(bg) = app.theme.background {
+        block = block.style(Style::default().bg(bg));
+    }
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary
+            Constraint::Min(1),    // Files
+            Constraint::Length(1), // Hints
+        ])
+        .split(inner);
+
+    let files = &app.multi_diff.files;
+    // Unvisited files of a lazy directory scan have no stats yet; they are
+    // left out of the totals and listed with their sizes instead.
+    let unloaded = (0..files.len())
+        .filter(|&idx| app.multi_diff.unloaded_sizes(idx).is_some())
+        .count();
+    let insertions: usize = files.iter().map(|file| file.insertions).sum();
+    let deletions: usize = files.iter().map(|file| file.deletions).sum();
+    let mut summary = Line::from(vec![
+        Span::styled(
+            format!(
+                "{} file{} changed, ",
+                files.len(),
+                if files.len() == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(app.theme.text),
+        ),
+        Span::styled(
+            format!("{insertions} insertions(+)"),
+            Style::default().fg(app.theme.success),
+        ),
+        Span::styled(", ", Style::default().fg(app.theme.text)),
+        Span::styled(
+            format!("{deletions} deletions(-)"),
+            Style::default().fg(app.theme.error),
+        ),
+    ]);
+    if unloaded > 0 {
+        summary.push_span(Span::styled(
+            format!(" · {unloaded} not loaded"),
+            Style::default().fg(app.theme.text_muted),
+        ));
+    }
+    frame.render_widget(Paragraph::new(summary), chunks[0]);
+
+    let max_total = files
+        .iter()
+        .map(|file| file.insertions + file.deletions)
+        .max()
+        .unwrap_or(0);
+    let count_width = max_total.to_string().len().max(3);
+    let list_width = chunks[1].width as usize;
+    let name_max = files
+        .iter()
+        .map(|file| text_width(&file.display_name))
+        .max()
+        .unwrap_or(0);
+    // Leave at least a third of the row for the count and bar.
+    let name_width = name_max.min(list_width.saturating_mul(2) / 3).max(1);
+    let bar_width = list_width
+        .saturating_sub(name_width + count_width + 4)
+        .min(60);
+
+    let list_height = chunks[1].height as usize;
+    app.overview_page_rows = list_height;
+    let selection = app.overview_selection.min(files.len().saturating_sub(1));
+    let start = (selection + 1).saturating_sub(list_height.max(1));
+    let items: Vec<ListItem> = files
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(list_height)
+        .map(|(idx, file)| {
+            let name = truncate_path(&file.display_name, name_width);
+            let name_color = match file.status {
+                FileStatus::Added | FileStatus::Untracked => app.theme.success,
+                FileStatus::Deleted => app.theme.error,
+                FileStatus::Modified | FileStatus::Renamed => app.theme.text,
+            };
+            let mut spans = vec![
+                Span::styled(
+                    format!("{name:<width$}", width = name_width),
+                    Style::default().fg(name_color),
+                ),
+                Span::styled(" | ", Style::default().fg(app.theme.border_subtle)),
+            ];
+            if let Some((old, new)) = app.multi_diff.unloaded_sizes(idx) {
+                spans.push(Span::styled(
+                    format!(
+                        "{:>count_width$} {}→{} not loaded",
+                        "?",
+                        compact_size(old),
+                        compact_size(new)
+                    ),
+                    Style::default().fg(app.theme.text_muted),
+                ));
+            } else if file.binary {
+                spans.push(Span::styled(
+                    format!("{:>count_width$}", "Bin"),
+                    Style::default().fg(app.theme.text_muted),
+                ));
+            } else {
+                let total = file.insertions + file.deletions;
+                let (plus, minus) =
+                    stat_bar_cells(file.insertions, file.deletions, max_total, bar_width);
+                spans.push(Span::styled(
+                    format!("{total:>count_width$} "),
+                    Style::default().fg(app.theme.text),
+                ));
+                spans.push(Span::styled(
+                    "+".repeat(plus),
+                    Style::default().fg(app.theme.success),
+                ));
+                spans.push(Span::styled(
+                    "-".repeat(minus),
+                    Style::default().fg(app.theme.error),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(selection - start));
+    let mut highlight_style = Style::default().add_modifier(Modifier::BOLD);
+    if let Some(bg) = app.theme.background_element.or(app.theme.background_panel) {
+        highlight_style = highlight_style.bg(bg);
+    } else {
+        highlight_style = highlight_style.add_modifier(Modifier::REVERSED);
+    }
+    let list = List::new(items).highlight_style(highlight_style);
+    frame.render_stateful_widget(list, chunks[1], &mut state);
+
+    let hint = format!(
+        "{} open • {} close",
+        app.keybindings.overview_keys(OverviewAction::Accept),
+        app.keybindings.overview_keys(OverviewAction::Close),
+    );
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            hint,
+            Style::default().fg(app.theme.text_muted),
+        )),
+        chunks[2],
+    );
+}
+
 fn draw_file_search_popover(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
     let popup_width = 60u16.min(area.width.saturating_sub(4));
@@ -2563,7 +3610,161 @@ fn draw_file_search_popover(frame: &mut Frame, app: &mut App) {
 
 #[cfg(test)]
 mod tests {
-    use super::counted_binding_label;
+    use super::{counted_binding_label, draw, text_width, truncate_filename_keep_ext};
+    use crate::app::{App, CommitHeader, ViewMode};
+    use crate::test_utils::TestApp;
+    use oyo_core::MultiFileDiff;
+    use ratatui::{backend::TestBackend, style::Color, Terminal};
+    use std::path::PathBuf;
+
+    fn draw_text(app: &mut App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).expect("terminal");
+        terminal.draw(|frame| draw(frame, app)).expect("draw");
+        let buf = terminal.backend().buffer();
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn truncate_filename_measures_wide_names_in_columns() {
+        let name = "日本語のとても長いファイル名.rs";
+        for width in 4..20 {
+            let truncated = truncate_filename_keep_ext(name, width);
+            assert!(text_width(&truncated) <= width, "{truncated} > {width}");
+        }
+        let truncated = truncate_filename_keep_ext(name, 12);
+        assert!(truncated.ends_with(".rs"));
+        assert!(truncated.contains('…'));
+        assert_eq!(truncate_filename_keep_ext("日本語", 6), "日本語");
+    }
+
+    #[test]
+    fn commit_header_scrolls_and_hides() {
+        let mut app = TestApp::new_default(|| {
+            let diff = MultiFileDiff::from_file_pair(
+                PathBuf::from("a.txt"),
+                PathBuf::from("a.txt"),
+                "one\n".to_string(),
+                "two\n".to_string(),
+            );
+            App::new(diff, ViewMode::UnifiedPane, 0, false, None)
+        });
+        app.commit_header = Some(CommitHeader {
+            title: " abc123 ".to_string(),
+            lines: (1..=10).map(|idx| format!("message line {idx}")).collect(),
+            has_notes: false,
+        });
+
+        let text = draw_text(&mut app);
+        assert!(text.contains("abc123"));
+        assert!(text.contains("message line 1 "));
+        assert!(text.contains("1-6/10"));
+
+        app.scroll_commit_header(100);
+        let text = draw_text(&mut app);
+        assert!(text.contains("5-10/10"));
+        assert!(!text.contains("message line 1 "));
+
+        app.toggle_commit_header();
+        let text = draw_text(&mut app);
+        assert!(!text.contains("abc123"));
+    }
+
+    #[test]
+    fn cursorline_tints_only_the_active_row() {
+        let mut app = TestApp::new_default(|| {
+            let diff = MultiFileDiff::from_file_pair(
+                PathBuf::from("a.txt"),
+                PathBuf::from("a.txt"),
+                "keep\none\nkeep\n".to_string(),
+                "keep\ntwo\nkeep\n".to_string(),
+            );
+            App::new(diff, ViewMode::UnifiedPane, 0, false, None)
+        });
+        app.theme.background = Some(Color::Rgb(0, 0, 0));
+        app.cursorline = true;
+        app.next_step();
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).expect("terminal");
+        terminal.draw(|frame| draw(frame, &mut app)).expect("draw");
+        let (x, y, width, height) = app.diff_view_area.expect("diff view area");
+        let buf = terminal.backend().buffer();
+        let tinted: Vec<u16> = (y..y + height)
+            .filter(|&row| (x..x + width).all(|col| buf[(col, row)].bg != Color::Rgb(0, 0, 0)))
+            .collect();
+        assert_eq!(tinted.len(), 1);
+        let row_text: String = (x..x + width)
+            .map(|col| buf[(col, tinted[0])].symbol())
+            .collect();
+        assert!(row_text.contains("two") || row_text.contains("one"));
+    }
+
+    #[test]
+    fn plain_mode_draws_without_colors() {
+        let mut app = TestApp::new_default(|| {
+            let diff = MultiFileDiff::from_file_pair(
+                PathBuf::from("a.txt"),
+                PathBuf::from("a.txt"),
+                "keep\none\nkeep\n".to_string(),
+                "keep\ntwo\nkeep\n".to_string(),
+            );
+            App::new(diff, ViewMode::UnifiedPane, 0, false, None)
+        });
+        app.plain = true;
+        app.next_step();
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).expect("terminal");
+        terminal.draw(|frame| draw(frame, &mut app)).expect("draw");
+        let buf = terminal.backend().buffer();
+        assert!(buf
+            .content()
+            .iter()
+            .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset));
+        assert!(draw_text(&mut app).contains("two"));
+    }
+
+    #[test]
+    fn hscrollbar_shows_only_when_lines_overflow() {
+        let long = "x".repeat(200);
+        let mut app = TestApp::new_default(|| {
+            let diff = MultiFileDiff::from_file_pair(
+                PathBuf::from("a.txt"),
+                PathBuf::from("a.txt"),
+                format!("keep\n{long}\n"),
+                format!("keep\n{long}y\n"),
+            );
+            App::new(diff, ViewMode::UnifiedPane, 0, false, None)
+        });
+        app.hscrollbar = true;
+        app.next_step();
+
+        // The status bar reads the text width the previous frame measured.
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).expect("terminal");
+        terminal.draw(|frame| draw(frame, &mut app)).expect("draw");
+        app.horizontal_scroll = 20;
+        terminal.draw(|frame| draw(frame, &mut app)).expect("draw");
+        let (x, y, width, height) = app.diff_view_area.expect("diff view area");
+        let buf = terminal.backend().buffer();
+        let bottom: String = (x..x + width)
+            .map(|col| buf[(col, y + height - 1)].symbol())
+            .collect();
+        assert!(bottom.contains('━'));
+        let screen: String = (0..30)
+            .flat_map(|row| (0..100).map(move |col| (col, row)))
+            .map(|pos| buf[pos].symbol())
+            .collect();
+        assert!(screen.contains("→ col 21"));
+
+        app.line_wrap = true;
+        terminal.draw(|frame| draw(frame, &mut app)).expect("draw");
+        assert_eq!(app.horizontal_overflow(), None);
+    }
 
     #[test]
     fn counted_binding_label_uses_current_binding() {
@@ -2574,4 +3775,31 @@ mod tests {
             "<count>r / <count>ctrl-r"
         );
     }
+
+    #[test]
+    fn overview_marks_unloaded_files_instead_of_zero_stats() {
+        let root = std::env::temp_dir().join(format!("oyo_overview_lazy_{}", std::process::id()));
+        let (old_dir, new_dir) = (root.join("old"), root.join("new"));
+        std::fs::create_dir_all(&old_dir).unwrap();
+        std::fs::create_dir_all(&new_dir).unwrap();
+        std::fs::write(old_dir.join("a.txt"), "one\n").unwrap();
+        std::fs::write(new_dir.join("a.txt"), "two\n").unwrap();
+        std::fs::write(old_dir.join("b.txt"), "three\n").unwrap();
+        std::fs::write(new_dir.join("b.txt"), "four\n").unwrap();
+        let mut app = TestApp::new_default(|| {
+            let options = oyo_core::DirectoryScanOptions {
+                lazy_load_bytes: Some(0),
+                ..Default::default()
+            };
+            let diff =
+                MultiFileDiff::from_directories_with_options(&old_dir, &new_dir, &options).unwrap();
+            App::new(diff, ViewMode::UnifiedPane, 0, false, None)
+        });
+        app.toggle_overview();
+
+        let text = draw_text(&mut app);
+        assert!(text.contains("1 not loaded"), "{text}");
+        assert!(text.contains("6B→5B not loaded"), "{text}");
+        let _ = std::fs::remove_dir_all(root);
+    }
 }
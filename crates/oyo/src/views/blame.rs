@@ -1,28 +1,49 @@
-use crate::app::{App, BlameDisplay};
-use crate::views::{expand_tabs_in_spans, wrap_count_for_spans, TAB_WIDTH};
+use crate::app::{App, BlameDisplay, DIFF_VIEW_MIN_WIDTH};
+use crate::views::{expand_tabs_in_spans, truncate_text, wrap_count_for_spans, TAB_WIDTH};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 use time::OffsetDateTime;
+use unicode_width::UnicodeWidthChar;
 
 const BLAME_GUTTER_PERCENT: u16 = 32;
-const CONTENT_GUTTER_WIDTH: u16 = 8;
+const BLAME_GAP_WIDTH: u16 = 2;
 const BLAME_BAR: &str = "▌";
 
+/// Blame gutter width for `area`, or `None` when the gutter would squeeze the
+/// diff below `DIFF_VIEW_MIN_WIDTH` and blame should be hidden instead.
+pub(crate) fn blame_gutter_width(configured: u16, area_width: u16) -> Option<u16> {
+    let width = if configured == 0 {
+        area_width.saturating_mul(BLAME_GUTTER_PERCENT) / 100
+    } else {
+        configured
+    };
+    let content_width = area_width.checked_sub(width.saturating_add(BLAME_GAP_WIDTH))?;
+    (width > BLAME_GAP_WIDTH && content_width >= DIFF_VIEW_MIN_WIDTH).then_some(width)
+}
+
 pub fn render_blame(frame: &mut Frame, app: &mut App, area: Rect) {
+    if let Some(reason) = app.current_read_error() {
+        super::render_read_error(frame, area, &app.theme, &reason);
+        return;
+    }
     if app.current_file_is_binary() {
-        super::render_empty_state(frame, area, &app.theme, false, true);
+        super::hex::render_binary_file(frame, app, area);
         return;
     }
+    let Some(gutter_width) = blame_gutter_width(app.blame_width, area.width) else {
+        super::render_unified_pane(frame, app, area);
+        return;
+    };
     app.poll_blame_responses();
 
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(BLAME_GUTTER_PERCENT),
-            Constraint::Length(2),
+            Constraint::Length(gutter_width),
+            Constraint::Length(BLAME_GAP_WIDTH),
             Constraint::Min(0),
         ])
         .split(area);
@@ -31,7 +52,12 @@ pub fn render_blame(frame: &mut Frame, app: &mut App, area: Rect) {
     let content_area = chunks[2];
 
     let visible_height = area.height as usize;
-    let wrap_width = content_area.width.saturating_sub(CONTENT_GUTTER_WIDTH) as usize;
+    let wrap_width = super::wrap_width(
+        app,
+        content_area
+            .width
+            .saturating_sub(super::line_gutter_width(app)) as usize,
+    );
     if !app.line_wrap {
         app.clamp_horizontal_scroll_cached(wrap_width);
     }
@@ -78,6 +104,7 @@ pub fn render_blame(frame: &mut Frame, app: &mut App, area: Rect) {
         view_len: view_lines.len(),
         window_start: app.view_window_start(),
         animation_frame,
+        peek_state: app.peek_state(),
         cache_rev: app.blame_cache_revision,
         time_bucket,
     };
@@ -123,8 +150,14 @@ pub fn render_blame(frame: &mut Frame, app: &mut App, area: Rect) {
             }
 
             let text = blame_texts[idx].clone().unwrap_or_default();
-            let wrapped = wrap_blame_text(&text, text_width);
             let group_len = end - idx;
+            let mut wrapped = wrap_blame_text(&text, text_width);
+            if app.blame_width > 0 && wrapped.len() > group_len {
+                // A fixed-width gutter keeps to the group's rows: the last
+                // row carries whatever is left, truncated.
+                let rest = wrapped.split_off(group_len - 1).concat();
+                wrapped.push(truncate_text(&rest, text_width));
+            }
             for offset in 0..group_len {
                 if let Some(segment) = wrapped.get(offset) {
                     display_texts[idx + offset] = segment.clone();
@@ -312,13 +345,15 @@ fn wrap_blame_text(text: &str, width: usize) -> Vec<String> {
     let mut current_len = 0usize;
 
     for ch in text.chars() {
-        if current_len >= width {
+        // Columns, not chars: a wide char that doesn't fit starts the next row.
+        let ch_width = ch.width().unwrap_or(0);
+        if current_len + ch_width > width && !current.is_empty() {
             lines.push(current);
             current = String::new();
             current_len = 0;
         }
         current.push(ch);
-        current_len += 1;
+        current_len += ch_width;
     }
 
     if !current.is_empty() {
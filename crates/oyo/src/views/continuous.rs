@@ -0,0 +1,187 @@
+//! Continuous mode: every file in one scroll with header rows between them
+
+use super::{
+    apply_line_bg, diff_line_bg, expand_tabs_with_indent_guides, slice_spans, spans_width,
+    view_spans_to_text,
+};
+use crate::app::{is_fold_line, App, ContinuousRow};
+use crate::config::GutterSide;
+use oyo_core::{LineKind, ViewLine, ViewSpanKind};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+pub(crate) fn render_continuous(frame: &mut Frame, app: &mut App, area: Rect) {
+    let height = area.height as usize;
+    app.handle_search_scroll_if_needed(height);
+    let rows = app.continuous_rows();
+    app.clamp_scroll(rows.len(), height, false);
+    app.sync_continuous_selection();
+    app.continuous_header_rows.clear();
+
+    let gutter_width = super::line_gutter_width(app) as usize;
+    let content_width = (area.width as usize).saturating_sub(gutter_width);
+    let search_target = app.search_target();
+    let locate_flash = app.locate_flash_line();
+    let mut lines = Vec::with_capacity(height);
+    for (row_idx, row) in rows.iter().enumerate().skip(app.scroll_offset).take(height) {
+        let line = match row {
+            ContinuousRow::Header(file_idx) => {
+                let screen_row = area.y + (row_idx - app.scroll_offset) as u16;
+                app.continuous_header_rows.push((screen_row, *file_idx));
+                header_line(app, *file_idx, area.width as usize)
+            }
+            ContinuousRow::Notice(_, text) => Line::from(vec![
+                Span::raw(" ".repeat(gutter_width)),
+                Span::styled(*text, Style::default().fg(app.theme.text_muted)),
+            ]),
+            ContinuousRow::Line(_, view_line) => diff_line(
+                app,
+                view_line,
+                gutter_width,
+                content_width,
+                search_target == Some(row_idx),
+            ),
+        };
+        let line = if locate_flash == Some(row_idx) {
+            let spans = super::locate_flash_spans(app, line.spans, area.width as usize);
+            Line::from(spans)
+        } else {
+            line
+        };
+        lines.push(line);
+    }
+
+    let mut paragraph = Paragraph::new(lines);
+    if let Some(bg) = app.theme.background {
+        paragraph = paragraph.style(Style::default().bg(bg));
+    }
+    frame.render_widget(paragraph, area);
+}
+
+fn header_line(app: &App, file_idx: usize, width: usize) -> Line<'static> {
+    let theme = &app.theme;
+    let Some(file) = app.multi_diff.files.get(file_idx) else {
+        return Line::default();
+    };
+    let collapsed = app.continuous_file_collapsed(file_idx);
+    let mut spans = vec![
+        Span::styled(
+            if collapsed { "▸ " } else { "▾ " },
+            Style::default().fg(theme.primary),
+        ),
+        Span::styled(
+            file.display_name.clone(),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("+{}", file.insertions),
+            Style::default().fg(theme.success),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("-{}", file.deletions),
+            Style::default().fg(theme.error),
+        ),
+        Span::raw(" "),
+    ];
+    let fill = width.saturating_sub(spans_width(&spans));
+    spans.push(Span::styled(
+        "─".repeat(fill),
+        Style::default().fg(theme.border_subtle),
+    ));
+    Line::from(spans)
+}
+
+fn diff_line(
+    app: &App,
+    view_line: &ViewLine,
+    gutter_width: usize,
+    content_width: usize,
+    is_search_target: bool,
+) -> Line<'static> {
+    let theme = &app.theme;
+    if is_fold_line(view_line) {
+        return Line::from(vec![
+            Span::raw(" ".repeat(gutter_width)),
+            Span::styled(
+                view_line.content.clone(),
+                Style::default().fg(theme.text_muted),
+            ),
+        ]);
+    }
+
+    let moved_fg = super::moved_line_fg(view_line, theme);
+    let (sign, sign_color) = match view_line.kind {
+        LineKind::Context => (" ", theme.diff_line_number),
+        LineKind::Inserted | LineKind::PendingInsert => ("+", theme.insert_base()),
+        LineKind::Deleted | LineKind::PendingDelete => ("-", theme.delete_base()),
+        LineKind::Modified | LineKind::PendingModify => ("~", theme.modify_base()),
+    };
+    let line_num = match view_line.kind {
+        LineKind::Deleted | LineKind::PendingDelete => view_line.old_line,
+        _ => view_line.new_line.or(view_line.old_line),
+    };
+    let line_num = line_num.map(|n| n.to_string()).unwrap_or_default();
+    let mut spans = vec![Span::styled(
+        format!(" {:>4} ", line_num),
+        Style::default().fg(moved_fg.unwrap_or(theme.diff_line_number)),
+    )];
+    if app.gutter_signs {
+        spans.push(Span::styled(
+            sign,
+            Style::default().fg(moved_fg.unwrap_or(sign_color)),
+        ));
+        spans.push(Span::raw(" "));
+    }
+
+    let mut content: Vec<Span<'static>> = view_line
+        .spans
+        .iter()
+        .map(|span| {
+            let style = match span.kind {
+                ViewSpanKind::Equal => Style::default().fg(theme.diff_context),
+                ViewSpanKind::Inserted | ViewSpanKind::PendingInsert => {
+                    Style::default().fg(theme.insert_base())
+                }
+                ViewSpanKind::Deleted | ViewSpanKind::PendingDelete => {
+                    let style = Style::default().fg(theme.delete_base());
+                    super::mark_deletion(style, app.deletion_style, theme.delete_base())
+                }
+            };
+            Span::styled(span.text.clone(), style)
+        })
+        .collect();
+    content = app.highlight_search_spans(
+        content,
+        &view_spans_to_text(&view_line.spans),
+        is_search_target,
+    );
+    super::push_line_debug(app, &mut content, view_line);
+    let content = expand_tabs_with_indent_guides(app, &content);
+    let mut content = slice_spans(&content, app.horizontal_scroll, content_width);
+    if let Some(fg) = moved_fg {
+        content = super::recolor_spans(content, fg);
+    }
+    if let Some(bg) = app
+        .diff_bg
+        .then(|| diff_line_bg(view_line.kind, theme))
+        .flatten()
+    {
+        spans = apply_line_bg(spans, bg, 0, true);
+        content = apply_line_bg(content, bg, content_width, false);
+    }
+    if app.gutter_side == GutterSide::Right {
+        let pad = content_width.saturating_sub(spans_width(&content));
+        content.push(Span::raw(" ".repeat(pad)));
+        content.extend(spans);
+        return Line::from(content);
+    }
+    spans.extend(content);
+    Line::from(spans)
+}
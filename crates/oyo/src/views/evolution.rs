@@ -10,16 +10,13 @@ use crate::app::{is_conflict_marker, is_fold_line, AnimationPhase, App};
 use crate::syntax::SyntaxSide;
 use oyo_core::{LineKind, StepDirection, ViewLine, ViewSpanKind};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
     Frame,
 };
 
-/// Width of the fixed line number gutter (marker + line num + space + blank sign + space)
-const GUTTER_WIDTH: u16 = 8; // "▶1234   " (matches single-pane width)
-
 fn hunk_overflow_wrapped_evolution(
     view_lines: &[ViewLine],
     hunk_idx: usize,
@@ -58,18 +55,26 @@ fn hunk_overflow_wrapped_evolution(
 /// Render the evolution view - file morphing without deletion markers
 pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
     let visible_height = area.height as usize;
-    let visible_width = area.width.saturating_sub(GUTTER_WIDTH) as usize;
+    // Matches the single-pane gutter so toggling views keeps content aligned.
+    let visible_width = super::wrap_width(
+        app,
+        area.width.saturating_sub(super::line_gutter_width(app)) as usize,
+    );
     if !app.line_wrap {
         app.clamp_horizontal_scroll_cached(visible_width);
     }
+    if let Some(reason) = app.current_read_error() {
+        super::render_read_error(frame, area, &app.theme, &reason);
+        return;
+    }
     if app.current_file_is_binary() {
-        render_empty_state(frame, area, &app.theme, false, true);
+        super::hex::render_binary_file(frame, app, area);
         return;
     }
 
     // Clone markers to avoid borrow conflicts
     let primary_marker = app.primary_marker.clone();
-    let extent_marker = app.extent_marker.clone();
+    let extent_markers = super::ExtentMarkers::left(app);
 
     if app.line_wrap {
         app.handle_search_scroll_if_needed(visible_height);
@@ -82,6 +87,7 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
         .current_navigator()
         .set_show_hunk_extent_while_stepping(show_extent);
     let view_lines = app.current_view_with_frame(animation_frame);
+    let note_lines = app.presenter_note_lines(&view_lines);
     let mut scroll_offset = app.render_scroll_offset();
     let debug_enabled = super::view_debug_enabled();
     if debug_enabled {
@@ -164,13 +170,8 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
     };
 
     // Split area into gutter (fixed) and content (scrollable)
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Length(GUTTER_WIDTH), Constraint::Min(0)])
-        .split(area);
-
-    let gutter_area = chunks[0];
-    let content_area = chunks[1];
+    let (gutter_area, content_area) =
+        super::gutter_layout(app, area, super::line_gutter_width(app));
 
     // Build separate gutter and content lines - skip deleted lines entirely
     let mut gutter_lines: Vec<Line> = Vec::new();
@@ -541,7 +542,7 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
             )
         } else if show_extent {
             (
-                extent_marker.as_str(),
+                extent_markers.for_kind(view_line.kind),
                 super::extent_marker_style(
                     app,
                     view_line.kind,
@@ -550,18 +551,25 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
                     view_line.new_line,
                 ),
             )
+        } else if view_line
+            .new_line
+            .or(view_line.old_line)
+            .is_some_and(|line| note_lines.contains(&line))
+        {
+            (super::NOTE_MARKER, Style::default().fg(app.theme.warning))
         } else {
             (" ", Style::default())
         };
 
         // Build gutter line (fixed, no horizontal scroll)
-        let gutter_spans = vec![
+        let mut gutter_spans = vec![
             Span::styled(active_marker, active_style),
             Span::styled(line_num_str, line_num_style),
             Span::styled(" ", Style::default()),
-            Span::styled(" ", Style::default()),
-            Span::styled(" ", Style::default()),
         ];
+        if app.gutter_signs {
+            gutter_spans.push(Span::raw("  "));
+        }
         // Evolution view ignores diff background modes to keep the morph view clean.
         gutter_lines.push(Line::from(gutter_spans));
 
@@ -632,7 +640,7 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
                     app,
                 );
                 // For deleted spans, don't strikethrough leading whitespace
-                if app.strikethrough_deletions
+                if app.strikethrough_deletions()
                     && matches!(
                         view_span.kind,
                         ViewSpanKind::Deleted | ViewSpanKind::PendingDelete
@@ -661,6 +669,9 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
         let is_active_match = app.search_target() == Some(display_idx)
             && has_query
             && line_text.to_ascii_lowercase().contains(&query);
+        content_spans =
+            super::highlight_trailing_ws(app, content_spans, view_line.kind, &line_text);
+        content_spans = app.highlight_keyword_spans(content_spans, view_line, &line_text);
         content_spans = app.highlight_search_spans(content_spans, &line_text, is_active_match);
         if is_conflict_marker(view_line) {
             content_spans = content_spans
@@ -673,7 +684,8 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
                 .collect();
         }
 
-        content_spans = expand_tabs_in_spans(&content_spans, TAB_WIDTH);
+        super::push_line_debug(app, &mut content_spans, view_line);
+        content_spans = super::expand_tabs_with_indent_guides(app, &content_spans);
 
         // Track max line width
         let line_width = spans_width(&content_spans);
@@ -692,6 +704,10 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
         if !app.line_wrap {
             display_spans = slice_spans(&display_spans, app.horizontal_scroll, visible_width);
         }
+        if app.locate_flash_line() == Some(display_idx) {
+            let width = if app.line_wrap { 0 } else { visible_width };
+            display_spans = super::locate_flash_spans(app, display_spans, width);
+        }
         content_lines.push(Line::from(display_spans));
         if app.line_wrap && wrap_count > 1 {
             for _ in 1..wrap_count {
@@ -997,6 +1013,7 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
             app.current_file_is_binary(),
         );
     } else {
+        let text_area = super::wrap_content_area(frame, app, content_area);
         let mut content_paragraph = if app.line_wrap {
             Paragraph::new(content_lines)
                 .wrap(Wrap { trim: false })
@@ -1007,7 +1024,8 @@ pub fn render_evolution(frame: &mut Frame, app: &mut App, area: Rect) {
         if let Some(style) = bg_style {
             content_paragraph = content_paragraph.style(style);
         }
-        frame.render_widget(content_paragraph, content_area);
+        frame.render_widget(content_paragraph, text_area);
+        super::render_color_column(frame, app, text_area);
 
         if app.review_mode()
             && !app.review_editor_active()
@@ -1155,7 +1173,7 @@ fn get_evolution_span_style(
                         app.animation_phase,
                         app.animation_progress,
                         app.is_backward_animation(),
-                        app.strikethrough_deletions,
+                        app.deletion_style,
                         theme.delete_base(),
                         theme.diff_context,
                         removed_bg,
@@ -0,0 +1,100 @@
+//! Side-by-side hex dump for binary files
+
+use super::render_empty_state;
+use crate::app::App;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Offset column plus the separators around both panes.
+const HEX_FIXED_WIDTH: u16 = 16;
+/// Each byte costs three hex columns and one ASCII column per side.
+const HEX_BYTE_WIDTH: u16 = 8;
+
+/// Render a binary file as a hex diff, or the "binary file" notice when the
+/// bytes are unavailable or over `ui.diff.hex_max_bytes`.
+pub(super) fn render_binary_file(frame: &mut Frame, app: &mut App, area: Rect) {
+    let Some(bytes) = app.current_binary_bytes() else {
+        render_empty_state(frame, area, &app.theme, false, true);
+        return;
+    };
+    let (old, new) = (&bytes.0, &bytes.1);
+    let per_row = hex_bytes_per_row(area.width);
+    let total_rows = old.len().max(new.len()).div_ceil(per_row);
+    let height = area.height as usize;
+    app.clamp_scroll(total_rows, height, false);
+
+    let theme = &app.theme;
+    let offset_style = Style::default().fg(theme.diff_line_number);
+    let border_style = Style::default().fg(theme.border_subtle);
+    let same_style = Style::default().fg(theme.text);
+    let diff_style = Style::default()
+        .fg(theme.modify_base())
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = Vec::with_capacity(height);
+    for row in app.scroll_offset..total_rows.min(app.scroll_offset + height) {
+        let start = row * per_row;
+        let mut spans = vec![
+            Span::styled(format!("{:08x} ", start), offset_style),
+            Span::styled("│ ", border_style),
+        ];
+        push_hex_side(&mut spans, old, new, start, per_row, same_style, diff_style);
+        spans.push(Span::styled(" │ ", border_style));
+        push_hex_side(&mut spans, new, old, start, per_row, same_style, diff_style);
+        lines.push(Line::from(spans));
+    }
+
+    let mut paragraph = Paragraph::new(lines);
+    if let Some(bg) = theme.background {
+        paragraph = paragraph.style(Style::default().bg(bg));
+    }
+    frame.render_widget(paragraph, area);
+}
+
+fn hex_bytes_per_row(width: u16) -> usize {
+    let fit = width.saturating_sub(HEX_FIXED_WIDTH) / HEX_BYTE_WIDTH;
+    (fit / 4 * 4).clamp(4, 16) as usize
+}
+
+fn push_hex_side(
+    spans: &mut Vec<Span<'static>>,
+    bytes: &[u8],
+    other: &[u8],
+    start: usize,
+    per_row: usize,
+    same_style: Style,
+    diff_style: Style,
+) {
+    let style_for = |idx: usize, byte: u8| {
+        if other.get(idx) == Some(&byte) {
+            same_style
+        } else {
+            diff_style
+        }
+    };
+    for idx in start..start + per_row {
+        match bytes.get(idx) {
+            Some(&byte) => spans.push(Span::styled(format!("{:02x} ", byte), style_for(idx, byte))),
+            None => spans.push(Span::raw("   ")),
+        }
+    }
+    spans.push(Span::raw(" "));
+    for idx in start..start + per_row {
+        match bytes.get(idx) {
+            Some(&byte) => {
+                let ch = if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                };
+                spans.push(Span::styled(ch.to_string(), style_for(idx, byte)));
+            }
+            None => spans.push(Span::raw(" ")),
+        }
+    }
+}
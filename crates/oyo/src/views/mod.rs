@@ -1,13 +1,17 @@
 //! View rendering modules
 
 mod blame;
+mod continuous;
 mod evolution;
+mod hex;
 mod split;
 mod unified_pane;
 
 pub use blame::render_blame;
+pub(crate) use continuous::render_continuous;
 pub use evolution::render_evolution;
 pub use split::render_split;
+pub(crate) use split::split_pane_areas;
 pub use unified_pane::render_unified_pane;
 
 #[cfg(test)]
@@ -24,6 +28,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use oyo_core::{LineKind, ViewLine, ViewSpan};
 use ratatui::text::Span;
+use rustc_hash::FxHashSet;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -139,6 +144,22 @@ pub(crate) fn diff_line_bg(kind: LineKind, theme: &ResolvedTheme) -> Option<Colo
     }
 }
 
+/// Foreground for lines in a moved block (`ui.diff.color_moved`). Active
+/// lines keep their animation colors.
+pub(crate) fn moved_line_fg(view_line: &ViewLine, theme: &ResolvedTheme) -> Option<Color> {
+    (view_line.moved && !view_line.is_active).then_some(theme.info)
+}
+
+pub(crate) fn recolor_spans(spans: Vec<Span<'static>>, fg: Color) -> Vec<Span<'static>> {
+    spans
+        .into_iter()
+        .map(|span| {
+            let style = span.style.fg(fg);
+            Span::styled(span.content, style)
+        })
+        .collect()
+}
+
 pub(crate) fn apply_line_bg(
     spans: Vec<Span<'static>>,
     bg: Color,
@@ -255,6 +276,40 @@ pub(crate) fn clear_leading_ws_bg(
     out
 }
 
+/// `--debug-lines`: trail the line with its change id, hunk index and kind.
+pub(crate) fn push_line_debug(app: &App, spans: &mut Vec<Span<'static>>, view_line: &ViewLine) {
+    if !app.show_line_debug {
+        return;
+    }
+    let hunk = view_line
+        .hunk_index
+        .map_or_else(|| "-".to_string(), |hunk| hunk.to_string());
+    spans.push(Span::styled(
+        format!(
+            "  [change {} hunk {} {:?}]",
+            view_line.change_id, hunk, view_line.kind
+        ),
+        Style::default().fg(app.theme.text_muted),
+    ));
+}
+
+/// Mark trailing spaces/tabs on added lines when `highlight_trailing_ws` is on.
+pub(crate) fn highlight_trailing_ws(
+    app: &App,
+    spans: Vec<Span<'static>>,
+    kind: LineKind,
+    text: &str,
+) -> Vec<Span<'static>> {
+    if !app.highlight_trailing_ws || !matches!(kind, LineKind::Inserted | LineKind::PendingInsert) {
+        return spans;
+    }
+    let content_end = text.trim_end_matches([' ', '\t']).len();
+    if content_end == text.len() {
+        return spans;
+    }
+    apply_highlight_spans(spans, &[(content_end, text.len())], app.theme.error, None)
+}
+
 pub(crate) fn replace_leading_ws_bg(
     spans: Vec<Span<'static>>,
     clear_when_fg: Option<Color>,
@@ -315,6 +370,210 @@ pub(crate) fn replace_leading_ws_bg(
 }
 
 pub(crate) const TAB_WIDTH: usize = 8;
+/// Gutter glyph for lines that carry a presenter note.
+pub(crate) const NOTE_MARKER: &str = "¶";
+/// Marker + line number + space ("▶1234 ")
+const LINE_GUTTER_BASE_WIDTH: u16 = 6;
+/// Sign + space ("+ "); collapsed when gutter signs are off
+const SIGN_COLUMN_WIDTH: u16 = 2;
+
+/// Width of the unified/evolution line number gutter.
+pub(crate) fn line_gutter_width(app: &App) -> u16 {
+    if app.gutter_signs {
+        LINE_GUTTER_BASE_WIDTH + SIGN_COLUMN_WIDTH
+    } else {
+        LINE_GUTTER_BASE_WIDTH
+    }
+}
+
+/// Split a pane into its gutter and content areas, with the gutter on the
+/// `ui.gutter_side` edge.
+pub(crate) fn gutter_layout(app: &App, area: Rect, gutter_width: u16) -> (Rect, Rect) {
+    let gutter = Constraint::Length(gutter_width);
+    let content = Constraint::Min(0);
+    let layout = Layout::default().direction(Direction::Horizontal);
+    match app.gutter_side {
+        GutterSide::Left => {
+            let chunks = layout.constraints([gutter, content]).split(area);
+            (chunks[0], chunks[1])
+        }
+        GutterSide::Right => {
+            let chunks = layout.constraints([content, gutter]).split(area);
+            (chunks[1], chunks[0])
+        }
+    }
+}
+
+/// Column wrapped lines break at: `ui.wrap_column` capped to the viewport,
+/// or the viewport width when unset.
+pub(crate) fn wrap_width(app: &App, viewport_width: usize) -> usize {
+    if app.line_wrap && app.wrap_column > 0 {
+        app.wrap_column.min(viewport_width)
+    } else {
+        viewport_width
+    }
+}
+
+/// Narrow a content area to the wrap column, painting the strip it gives up
+/// with the theme background.
+pub(crate) fn wrap_content_area(frame: &mut Frame, app: &App, area: Rect) -> Rect {
+    let width = wrap_width(app, area.width as usize) as u16;
+    if width < area.width {
+        if let Some(bg) = app.theme.background {
+            frame.render_widget(Paragraph::new("").style(Style::default().bg(bg)), area);
+        }
+    }
+    Rect { width, ..area }
+}
+
+/// Which lines render dimmed: outside the current hunk in focus mode, and
+/// in hunks without the git user's lines while `mine_only` is on.
+pub(crate) struct DimFilter {
+    focus_hunk: Option<usize>,
+    mine_hunks: Option<FxHashSet<usize>>,
+}
+
+impl DimFilter {
+    pub(crate) fn dims(&self, line_hunk: Option<usize>) -> bool {
+        self.focus_hunk.is_some_and(|hunk| line_hunk != Some(hunk))
+            || self
+                .mine_hunks
+                .as_ref()
+                .zip(line_hunk)
+                .is_some_and(|(mine, hunk)| !mine.contains(&hunk))
+    }
+}
+
+pub(crate) fn dim_filter(app: &mut App) -> DimFilter {
+    let focus_hunk = app
+        .focus_mode
+        .then(|| app.multi_diff.current_navigator().state().current_hunk);
+    DimFilter {
+        focus_hunk,
+        mine_hunks: app.mine_hunks(),
+    }
+}
+
+/// Dim spans for focus mode, dropping syntax colors to the muted text color.
+pub(crate) fn focus_dim_spans(spans: Vec<Span<'_>>, fg: Color) -> Vec<Span<'_>> {
+    spans
+        .into_iter()
+        .map(|span| {
+            let style = span.style.fg(fg).add_modifier(Modifier::DIM);
+            Span::styled(span.content, style)
+        })
+        .collect()
+}
+
+/// Dim and italicize the change previewed ahead of the current step (`g p`).
+pub(crate) fn peek_next_spans(spans: Vec<Span<'_>>) -> Vec<Span<'_>> {
+    spans
+        .into_iter()
+        .map(|span| {
+            let style = span.style.add_modifier(Modifier::DIM | Modifier::ITALIC);
+            Span::styled(span.content, style)
+        })
+        .collect()
+}
+
+/// Background pulse on the line a jump just landed on, padded to `width`.
+pub(crate) fn locate_flash_spans(
+    app: &App,
+    spans: Vec<Span<'static>>,
+    width: usize,
+) -> Vec<Span<'static>> {
+    let bg = app
+        .theme
+        .background_element
+        .unwrap_or(app.theme.border_subtle);
+    let spans = spans
+        .into_iter()
+        .map(|span| {
+            let style = span.style.bg(bg);
+            Span::styled(span.content, style)
+        })
+        .collect();
+    pad_spans_bg(spans, bg, width)
+}
+
+/// Draw the `ui.color_column` rule over rendered content, one cell past the
+/// guideline so text that overruns it sits on or beyond the rule. Skipped
+/// when wrapping, where screen columns no longer map to source columns.
+pub(crate) fn render_color_column(frame: &mut Frame, app: &App, area: Rect) {
+    if app.color_column == 0 || app.line_wrap {
+        return;
+    }
+    let Some(offset) = app.color_column.checked_sub(app.horizontal_scroll) else {
+        return;
+    };
+    if offset >= area.width as usize {
+        return;
+    }
+    let x = area.x + offset as u16;
+    let buf = frame.buffer_mut();
+    for y in area.y..area.y.saturating_add(area.height) {
+        let Some(cell) = buf.cell_mut((x, y)) else {
+            continue;
+        };
+        if cell.symbol().trim().is_empty() {
+            cell.set_symbol("│").set_fg(app.theme.border_subtle);
+        } else if let Some(bg) = app.theme.background_element {
+            cell.set_bg(bg);
+        }
+    }
+}
+
+/// Expand tabs in a line's content spans and, with `ui.indent_guides`, draw
+/// a faint `│` at each indent level of the leading whitespace. Guides are
+/// part of the line, so horizontal scroll moves them with the text; they keep
+/// the cell background so changed lines show them over diff colors.
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn expand_tabs_with_indent_guides(app: &App, spans: &[Span]) -> Vec<Span<'static>> {
+    let expanded = expand_tabs_in_spans(spans, TAB_WIDTH);
+    if !app.indent_guides {
+        return expanded;
+    }
+    let tab_indented = spans
+        .iter()
+        .flat_map(|span| span.content.chars())
+        .take_while(|ch| ch.is_whitespace())
+        .any(|ch| ch == '\t');
+    let step = if tab_indented {
+        TAB_WIDTH
+    } else {
+        app.indent_guide_width.max(1)
+    };
+    let mut out = Vec::with_capacity(expanded.len() + 4);
+    let mut col = 0usize;
+    let mut leading = true;
+    for span in expanded {
+        if !leading {
+            out.push(span);
+            continue;
+        }
+        let mut guide_style = Style::default().fg(app.theme.border_subtle);
+        if let Some(bg) = span.style.bg {
+            guide_style = guide_style.bg(bg);
+        }
+        let mut run = String::new();
+        for ch in span.content.chars() {
+            if leading && ch == ' ' && col % step == 0 {
+                if !run.is_empty() {
+                    out.push(Span::styled(std::mem::take(&mut run), span.style));
+                }
+                out.push(Span::styled("│", guide_style));
+            } else {
+                leading &= ch == ' ';
+                run.push(ch);
+            }
+            col += 1;
+        }
+        if !run.is_empty() {
+            out.push(Span::styled(run, span.style));
+        }
+    }
+    out
+}
 
 pub(crate) fn expand_tabs_in_spans(spans: &[Span], tab_width: usize) -> Vec<Span<'static>> {
     let mut out = Vec::new();
@@ -407,7 +666,14 @@ pub(crate) fn slice_spans(
             if col >= end_col {
                 break;
             }
-            buf.push_str(g);
+            if col < start_col || next_col > end_col {
+                // A wide grapheme cut by the window edge: blank the cells it
+                // keeps so everything after stays on its column.
+                let kept = next_col.min(end_col) - col.max(start_col);
+                buf.push_str(&" ".repeat(kept));
+            } else {
+                buf.push_str(g);
+            }
             col = next_col;
             if col >= end_col {
                 break;
@@ -571,18 +837,33 @@ where
 }
 
 pub(crate) fn truncate_text(text: &str, max_width: usize) -> String {
-    if max_width == 0 || text.len() <= max_width {
+    if max_width == 0 || UnicodeWidthStr::width(text) <= max_width {
         return text.to_string();
     }
-    let suffix_len = max_width.saturating_sub(3);
-    format!("{}…", &text[..suffix_len])
+    // Same budget as before, measured in columns so wide text (CJK author
+    // names) fits like ASCII.
+    let keep = max_width.saturating_sub(3);
+    let mut out = String::new();
+    let mut width = 0usize;
+    for g in text.graphemes(true) {
+        let g_width = UnicodeWidthStr::width(g);
+        if width + g_width > keep {
+            break;
+        }
+        out.push_str(g);
+        width += g_width;
+    }
+    out.push('…');
+    out
 }
 
-use crate::app::{AnimationPhase, App, ViewMode};
+use crate::app::{apply_highlight_spans, AnimationPhase, App, ViewMode};
 use crate::color;
-use crate::config::{DiffExtentMarkerMode, DiffExtentMarkerScope, ResolvedTheme};
+use crate::config::{
+    DeletionStyle, DiffExtentMarkerMode, DiffExtentMarkerScope, GutterSide, ResolvedTheme,
+};
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::Line,
     widgets::Paragraph,
@@ -621,6 +902,40 @@ pub(crate) fn extent_marker_style(
     Style::default().fg(color)
 }
 
+/// Extent marker glyphs for one pane, with optional per-kind overrides.
+pub(crate) struct ExtentMarkers {
+    base: String,
+    insert: Option<String>,
+    delete: Option<String>,
+}
+
+impl ExtentMarkers {
+    pub(crate) fn left(app: &App) -> Self {
+        Self::with_base(app, app.extent_marker.clone())
+    }
+
+    pub(crate) fn right(app: &App) -> Self {
+        Self::with_base(app, app.extent_marker_right.clone())
+    }
+
+    fn with_base(app: &App, base: String) -> Self {
+        Self {
+            base,
+            insert: app.extent_marker_insert.clone(),
+            delete: app.extent_marker_delete.clone(),
+        }
+    }
+
+    pub(crate) fn for_kind(&self, kind: LineKind) -> &str {
+        let glyph = match kind {
+            LineKind::Inserted | LineKind::PendingInsert => self.insert.as_deref(),
+            LineKind::Deleted | LineKind::PendingDelete => self.delete.as_deref(),
+            _ => None,
+        };
+        glyph.unwrap_or(self.base.as_str())
+    }
+}
+
 pub(crate) fn show_extent_marker(app: &App, view_line: &ViewLine) -> bool {
     if !view_line.show_hunk_extent {
         return false;
@@ -1258,7 +1573,7 @@ pub fn delete_style(
     phase: AnimationPhase,
     progress: f32,
     backward: bool,
-    strikethrough: bool,
+    deletion: DeletionStyle,
     base: Color,
     from: Color,
     bg: Option<Color>,
@@ -1277,13 +1592,26 @@ pub fn delete_style(
         style = style.bg(bg);
     }
 
-    // Strikethrough timing based on raw progress
-    if strikethrough && should_strikethrough(phase, progress, backward) {
-        style = style.add_modifier(Modifier::CROSSED_OUT);
+    // Marking timing based on raw progress
+    if should_mark_deletion(phase, progress, backward) {
+        style = mark_deletion(style, deletion, base);
     }
     style
 }
 
+/// Add the `ui.deletion_style` marking to a deleted span's style. The
+/// background style falls back to a dimmed delete color when the line has
+/// no diff background of its own.
+pub(crate) fn mark_deletion(style: Style, deletion: DeletionStyle, base: Color) -> Style {
+    match deletion {
+        DeletionStyle::Plain => style,
+        DeletionStyle::Strikethrough => style.add_modifier(Modifier::CROSSED_OUT),
+        DeletionStyle::Dim => style.add_modifier(Modifier::DIM),
+        DeletionStyle::Background if style.bg.is_none() => style.bg(color::dim_color(base)),
+        DeletionStyle::Background => style,
+    }
+}
+
 /// Compute animation style for modifications using a smooth fade (no pulse)
 pub fn modify_style(
     phase: AnimationPhase,
@@ -1309,8 +1637,8 @@ pub fn modify_style(
     style
 }
 
-/// Determine if strikethrough should be shown based on animation progress
-fn should_strikethrough(phase: AnimationPhase, progress: f32, backward: bool) -> bool {
+/// Determine if the deletion marking should be shown based on animation progress
+fn should_mark_deletion(phase: AnimationPhase, progress: f32, backward: bool) -> bool {
     match phase {
         AnimationPhase::Idle => true,
         AnimationPhase::FadeOut => {
@@ -1334,6 +1662,27 @@ fn should_strikethrough(phase: AnimationPhase, progress: f32, backward: bool) ->
     }
 }
 
+/// Render the "Failed to read" notice for a file whose contents couldn't be
+/// loaded, centered in area.
+fn render_read_error(frame: &mut Frame, area: Rect, theme: &ResolvedTheme, reason: &str) {
+    if let Some(bg) = theme.background {
+        let bg_fill = Paragraph::new("").style(Style::default().bg(bg));
+        frame.render_widget(bg_fill, area);
+    }
+    let line = Line::from(Span::styled(
+        format!("Failed to read: {reason}"),
+        Style::default().fg(theme.error),
+    ));
+    let centered_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(1) / 2,
+        width: area.width,
+        height: area.height.min(1),
+    };
+    let paragraph = Paragraph::new(line).alignment(Alignment::Center);
+    frame.render_widget(paragraph, centered_area);
+}
+
 /// Render empty state message centered in area.
 /// Shows hint line only if viewport has enough height and width.
 fn render_empty_state(
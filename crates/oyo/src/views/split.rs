@@ -7,10 +7,11 @@ use super::{
 };
 use crate::app::{is_conflict_marker, is_fold_line, AnimationPhase, App};
 use crate::color;
-use crate::config::{DiffForegroundMode, DiffHighlightMode};
+use crate::config::{DeletionStyle, DiffForegroundMode, DiffHighlightMode};
 use crate::syntax::SyntaxSide;
 use oyo_core::{
-    AnimationFrame, ChangeKind, LineKind, StepDirection, ViewLine, ViewSpan, ViewSpanKind,
+    multi::FileSide, AnimationFrame, ChangeKind, LineKind, StepDirection, ViewLine, ViewSpan,
+    ViewSpanKind,
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -230,11 +231,46 @@ fn add_review_preview_boxes_for_rows(
     }
 }
 
+/// Old/new pane areas for split view, plus the divider row when stacked top/bottom.
+fn split_layout(app: &App, area: Rect) -> (Rect, Rect, Option<Rect>) {
+    if app.split_stacked(area.height) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Fill(1),
+                Constraint::Length(1), // Divider
+                Constraint::Fill(1),
+            ])
+            .split(area);
+        (chunks[0], chunks[2], Some(chunks[1]))
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        (chunks[0], chunks[1], None)
+    }
+}
+
+pub(crate) fn split_pane_areas(app: &App, area: Rect) -> (Rect, Rect) {
+    let (old_area, new_area, _) = split_layout(app, area);
+    (old_area, new_area)
+}
+
 /// Render the split view
 pub fn render_split(frame: &mut Frame, app: &mut App, area: Rect) {
-    let visible_height = area.height as usize;
+    let (old_area, new_area, divider_area) = split_layout(app, area);
+    let stacked = divider_area.is_some();
+    let visible_height = old_area.height.min(new_area.height) as usize;
+    if stacked {
+        app.last_viewport_height = visible_height;
+    }
+    if let Some(reason) = app.current_read_error() {
+        super::render_read_error(frame, area, &app.theme, &reason);
+        return;
+    }
     if app.current_file_is_binary() {
-        render_empty_state(frame, area, &app.theme, false, true);
+        super::hex::render_binary_file(frame, app, area);
         return;
     }
     if app.line_wrap {
@@ -256,16 +292,11 @@ pub fn render_split(frame: &mut Frame, app: &mut App, area: Rect) {
     }
     app.begin_syntax_warmup_frame();
 
-    // Split into two panes
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(area);
-
-    let old_width = chunks[0]
+    let old_border_width = if stacked { 0 } else { OLD_BORDER_WIDTH };
+    let old_width = old_area
         .width
-        .saturating_sub(GUTTER_WIDTH + OLD_BORDER_WIDTH) as usize;
-    let new_width = chunks[1]
+        .saturating_sub(GUTTER_WIDTH + old_border_width) as usize;
+    let new_width = new_area
         .width
         .saturating_sub(NEW_GUTTER_WIDTH + NEW_MARKER_WIDTH) as usize;
     let debug_extra = if debug_enabled {
@@ -352,18 +383,28 @@ pub fn render_split(frame: &mut Frame, app: &mut App, area: Rect) {
     render_old_pane(
         frame,
         app,
-        chunks[0],
+        old_area,
+        old_border_width,
         hunk_overflow,
         show_virtual_old,
         scroll_offset,
     );
+    if let Some(divider_area) = divider_area {
+        let mut divider = Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(app.theme.border_subtle));
+        if let Some(bg) = app.theme.background {
+            divider = divider.style(Style::default().bg(bg));
+        }
+        frame.render_widget(divider, divider_area);
+    }
     render_new_pane(
         frame,
         app,
-        chunks[1],
+        new_area,
         hunk_overflow,
         show_virtual_new,
-        scroll_offset,
+        app.split_new_scroll_offset(scroll_offset),
     );
     app.commit_syntax_warmup_frame();
     if debug_enabled {
@@ -384,17 +425,22 @@ fn render_old_pane(
     frame: &mut Frame,
     app: &mut App,
     area: Rect,
+    border_width: u16,
     hunk_overflow: Option<(bool, bool)>,
     show_virtual_pane: bool,
     scroll_offset: usize,
 ) {
     // Clone markers to avoid borrow conflicts
-    let primary_marker = app.primary_marker.clone();
-    let extent_marker = app.extent_marker.clone();
+    let primary_marker = app.split_primary_marker(FileSide::Old);
+    let extent_markers = super::ExtentMarkers::left(app);
 
     let view_lines = app.current_view_with_frame(AnimationFrame::Idle);
+    let note_lines = app.presenter_note_lines(&view_lines);
     let visible_height = area.height as usize;
-    let visible_width = area.width.saturating_sub(GUTTER_WIDTH + 1) as usize; // +1 for border
+    let visible_width = super::wrap_width(
+        app,
+        area.width.saturating_sub(GUTTER_WIDTH + border_width) as usize,
+    );
     let syntax_window = if app.line_wrap {
         Some(super::syntax_highlight_window(
             scroll_offset,
@@ -537,15 +583,13 @@ fn render_old_pane(
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length(GUTTER_WIDTH),
             Constraint::Min(0),
-            Constraint::Length(1), // For border
+            Constraint::Length(border_width), // Right border (none when stacked)
         ])
         .split(area);
 
-    let gutter_area = chunks[0];
-    let content_area = chunks[1];
-    let border_area = chunks[2];
+    let (gutter_area, content_area) = super::gutter_layout(app, chunks[0], GUTTER_WIDTH);
+    let border_area = chunks[1];
 
     let mut gutter_lines: Vec<Line> = Vec::new();
     let mut content_lines: Vec<Line> = Vec::new();
@@ -583,6 +627,7 @@ fn render_old_pane(
         }
     }
 
+    let dim_filter = super::dim_filter(app);
     for (idx, view_line) in view_lines.iter().enumerate() {
         let fold_line = is_fold_line(view_line);
         let old_present = view_line.old_line.is_some() || fold_line;
@@ -603,6 +648,7 @@ fn render_old_pane(
         }
 
         let line_hunk = view_line.hunk_index;
+        let focus_dimmed = dim_filter.dims(line_hunk);
         let is_first_in_hunk = line_hunk.is_some() && prev_visible_hunk != line_hunk;
         let is_last_in_hunk = line_hunk.is_some() && next_visible_hunk[idx] != line_hunk;
 
@@ -775,7 +821,11 @@ fn render_old_pane(
                 format!("{:4}", old_line_num)
             };
             let bg_kind = split_old_bg_kind(view_line.kind);
-            let line_num_style = line_num_style_for_kind(bg_kind, app);
+            let moved_fg = super::moved_line_fg(view_line, &app.theme);
+            let mut line_num_style = line_num_style_for_kind(bg_kind, app);
+            if let Some(fg) = moved_fg {
+                line_num_style = line_num_style.fg(fg);
+            }
             let line_bg_gutter = if app.diff_bg {
                 diff_line_bg(bg_kind, &app.theme)
             } else {
@@ -793,7 +843,7 @@ fn render_old_pane(
                 )
             } else if show_extent {
                 (
-                    extent_marker.as_str(),
+                    extent_markers.for_kind(view_line.kind),
                     super::extent_marker_style(
                         app,
                         view_line.kind,
@@ -802,6 +852,13 @@ fn render_old_pane(
                         view_line.new_line,
                     ),
                 )
+            } else if view_line.new_line.is_none()
+                && view_line
+                    .old_line
+                    .is_some_and(|line| note_lines.contains(&line))
+            {
+                // Deleted lines carry their note on the old side only.
+                (super::NOTE_MARKER, Style::default().fg(app.theme.warning))
             } else {
                 (" ", Style::default())
             };
@@ -825,6 +882,9 @@ fn render_old_pane(
                     })
                     .collect();
             }
+            if focus_dimmed {
+                gutter_spans = super::focus_dim_spans(gutter_spans, app.theme.text_muted);
+            }
             gutter_lines.push(Line::from(gutter_spans));
 
             let display_idx = line_idx;
@@ -956,7 +1016,7 @@ fn render_old_pane(
                             highlight_allowed,
                         );
                         // For deleted spans, don't strikethrough leading whitespace
-                        if app.strikethrough_deletions
+                        if app.strikethrough_deletions()
                             && matches!(
                                 view_span.kind,
                                 ViewSpanKind::Deleted | ViewSpanKind::PendingDelete
@@ -982,6 +1042,9 @@ fn render_old_pane(
                 }
             }
 
+            if let Some(fg) = moved_fg {
+                content_spans = super::recolor_spans(content_spans, fg);
+            }
             let line_bg_line = if app.diff_bg {
                 diff_line_bg(bg_kind, &app.theme)
             } else {
@@ -1056,7 +1119,8 @@ fn render_old_pane(
                     .collect();
             }
 
-            content_spans = expand_tabs_in_spans(&content_spans, TAB_WIDTH);
+            super::push_line_debug(app, &mut content_spans, view_line);
+            content_spans = super::expand_tabs_with_indent_guides(app, &content_spans);
 
             let line_width = spans_width(&content_spans);
             max_line_width = max_line_width.max(line_width);
@@ -1081,12 +1145,22 @@ fn render_old_pane(
             if let Some(bg_lines) = bg_lines.as_mut() {
                 super::push_wrapped_bg_line(bg_lines, visible_width, wrap_count, line_bg_line);
             }
+            if focus_dimmed {
+                display_spans = super::focus_dim_spans(display_spans, app.theme.text_muted);
+            }
+            if app.peek_next_change() == Some(view_line.change_id) {
+                display_spans = super::peek_next_spans(display_spans);
+            }
+            if app.locate_flash_line() == Some(display_idx) {
+                let width = if app.line_wrap { 0 } else { visible_width };
+                display_spans = super::locate_flash_spans(app, display_spans, width);
+            }
             content_lines.push(Line::from(display_spans));
             display_row = display_row.saturating_add(wrap_count);
             if app.line_wrap && wrap_count > 1 {
                 let (wrap_marker, wrap_style) = if show_extent {
                     (
-                        extent_marker.as_str(),
+                        extent_markers.for_kind(view_line.kind),
                         super::extent_marker_style(
                             app,
                             view_line.kind,
@@ -1327,6 +1401,7 @@ fn render_old_pane(
             app.current_file_is_binary(),
         );
     } else {
+        let text_area = super::wrap_content_area(frame, app, content_area);
         let mut content_paragraph = if app.line_wrap {
             Paragraph::new(content_lines)
                 .wrap(Wrap { trim: false })
@@ -1340,14 +1415,15 @@ fn render_old_pane(
             if let Some(style) = bg_style {
                 bg_paragraph = bg_paragraph.style(style);
             }
-            frame.render_widget(bg_paragraph, content_area);
+            frame.render_widget(bg_paragraph, text_area);
         }
         if !has_bg_overlay {
             if let Some(style) = bg_style {
                 content_paragraph = content_paragraph.style(style);
             }
         }
-        frame.render_widget(content_paragraph, content_area);
+        frame.render_widget(content_paragraph, text_area);
+        super::render_color_column(frame, app, text_area);
     }
 
     if app.review_mode()
@@ -1378,11 +1454,12 @@ fn render_new_pane(
     scroll_offset: usize,
 ) {
     // Clone markers to avoid borrow conflicts
-    let primary_marker_right = app.primary_marker_right.clone();
-    let extent_marker_right = app.extent_marker_right.clone();
+    let primary_marker_right = app.split_primary_marker(FileSide::New);
+    let extent_markers_right = super::ExtentMarkers::right(app);
 
     let animation_frame = app.animation_frame();
     let view_lines = app.current_view_with_frame(animation_frame);
+    let note_lines = app.presenter_note_lines(&view_lines);
     let visible_height = area.height as usize;
     let syntax_window = if app.line_wrap {
         Some(super::syntax_highlight_window(
@@ -1526,16 +1603,14 @@ fn render_new_pane(
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length(5), // "1234 "
             Constraint::Min(0),
-            Constraint::Length(1), // For active marker
+            Constraint::Length(NEW_MARKER_WIDTH), // For active marker
         ])
         .split(area);
 
-    let gutter_area = chunks[0];
-    let content_area = chunks[1];
-    let marker_area = chunks[2];
-    let visible_width = content_area.width as usize;
+    let (gutter_area, content_area) = super::gutter_layout(app, chunks[0], NEW_GUTTER_WIDTH);
+    let marker_area = chunks[1];
+    let visible_width = super::wrap_width(app, content_area.width as usize);
 
     let mut gutter_lines: Vec<Line> = Vec::new();
     let mut content_lines: Vec<Line> = Vec::new();
@@ -1574,6 +1649,7 @@ fn render_new_pane(
         }
     }
 
+    let dim_filter = super::dim_filter(app);
     for (idx, view_line) in view_lines.iter().enumerate() {
         let fold_line = is_fold_line(view_line);
         let old_present = view_line.old_line.is_some() || fold_line;
@@ -1594,6 +1670,7 @@ fn render_new_pane(
         }
 
         let line_hunk = view_line.hunk_index;
+        let focus_dimmed = dim_filter.dims(line_hunk);
         let is_first_in_hunk = line_hunk.is_some() && prev_visible_hunk != line_hunk;
         let is_last_in_hunk = line_hunk.is_some() && next_visible_hunk[idx] != line_hunk;
 
@@ -1764,7 +1841,11 @@ fn render_new_pane(
                 format!("{:4}", new_line_num)
             };
             let bg_kind = split_new_bg_kind(view_line.kind);
-            let line_num_style = line_num_style_for_kind(bg_kind, app);
+            let moved_fg = super::moved_line_fg(view_line, &app.theme);
+            let mut line_num_style = line_num_style_for_kind(bg_kind, app);
+            if let Some(fg) = moved_fg {
+                line_num_style = line_num_style.fg(fg);
+            }
             let line_bg_gutter = if app.diff_bg {
                 diff_line_bg(bg_kind, &app.theme)
             } else {
@@ -1782,7 +1863,7 @@ fn render_new_pane(
                 )
             } else if show_extent {
                 (
-                    extent_marker_right.as_str(),
+                    extent_markers_right.for_kind(view_line.kind),
                     super::extent_marker_style(
                         app,
                         view_line.kind,
@@ -1791,6 +1872,11 @@ fn render_new_pane(
                         view_line.new_line,
                     ),
                 )
+            } else if view_line
+                .new_line
+                .is_some_and(|line| note_lines.contains(&line))
+            {
+                (super::NOTE_MARKER, Style::default().fg(app.theme.warning))
             } else {
                 (" ", Style::default())
             };
@@ -1806,6 +1892,9 @@ fn render_new_pane(
                     .map(|span| Span::styled(span.content, span.style.bg(bg)))
                     .collect();
             }
+            if focus_dimmed {
+                gutter_spans = super::focus_dim_spans(gutter_spans, app.theme.text_muted);
+            }
             gutter_lines.push(Line::from(gutter_spans));
 
             let display_idx = line_idx;
@@ -1962,6 +2051,9 @@ fn render_new_pane(
                     }
                 }
             }
+            if let Some(fg) = moved_fg {
+                content_spans = super::recolor_spans(content_spans, fg);
+            }
             let line_bg_line = if app.diff_bg {
                 diff_line_bg(bg_kind, &app.theme)
             } else {
@@ -2031,6 +2123,9 @@ fn render_new_pane(
             let is_active_match = app.search_target() == Some(display_idx)
                 && has_query
                 && line_text.to_ascii_lowercase().contains(&query);
+            content_spans =
+                super::highlight_trailing_ws(app, content_spans, view_line.kind, &line_text);
+            content_spans = app.highlight_keyword_spans(content_spans, view_line, &line_text);
             content_spans = app.highlight_search_spans(content_spans, &line_text, is_active_match);
             if italic_line {
                 content_spans = super::apply_italic_spans(content_spans);
@@ -2046,7 +2141,8 @@ fn render_new_pane(
                     .collect();
             }
 
-            content_spans = expand_tabs_in_spans(&content_spans, TAB_WIDTH);
+            super::push_line_debug(app, &mut content_spans, view_line);
+            content_spans = super::expand_tabs_with_indent_guides(app, &content_spans);
 
             let line_width = spans_width(&content_spans);
             max_line_width = max_line_width.max(line_width);
@@ -2071,6 +2167,16 @@ fn render_new_pane(
             if let Some(bg_lines) = bg_lines.as_mut() {
                 super::push_wrapped_bg_line(bg_lines, visible_width, wrap_count, line_bg_line);
             }
+            if focus_dimmed {
+                display_spans = super::focus_dim_spans(display_spans, app.theme.text_muted);
+            }
+            if app.peek_next_change() == Some(view_line.change_id) {
+                display_spans = super::peek_next_spans(display_spans);
+            }
+            if app.locate_flash_line() == Some(display_idx) {
+                let width = if app.line_wrap { 0 } else { visible_width };
+                display_spans = super::locate_flash_spans(app, display_spans, width);
+            }
             content_lines.push(Line::from(display_spans));
             display_row = display_row.saturating_add(wrap_count);
 
@@ -2079,7 +2185,7 @@ fn render_new_pane(
             if app.line_wrap && wrap_count > 1 {
                 let (wrap_marker, wrap_style) = if show_extent {
                     (
-                        extent_marker_right.as_str(),
+                        extent_markers_right.for_kind(view_line.kind),
                         super::extent_marker_style(
                             app,
                             view_line.kind,
@@ -2318,6 +2424,7 @@ fn render_new_pane(
             app.current_file_is_binary(),
         );
     } else {
+        let text_area = super::wrap_content_area(frame, app, content_area);
         let mut content_paragraph = if app.line_wrap {
             Paragraph::new(content_lines)
                 .wrap(Wrap { trim: false })
@@ -2331,14 +2438,15 @@ fn render_new_pane(
             if let Some(style) = bg_style {
                 bg_paragraph = bg_paragraph.style(style);
             }
-            frame.render_widget(bg_paragraph, content_area);
+            frame.render_widget(bg_paragraph, text_area);
         }
         if !has_bg_overlay {
             if let Some(style) = bg_style {
                 content_paragraph = content_paragraph.style(style);
             }
         }
-        frame.render_widget(content_paragraph, content_area);
+        frame.render_widget(content_paragraph, text_area);
+        super::render_color_column(frame, app, text_area);
     }
 
     if app.review_mode()
@@ -2614,12 +2722,12 @@ fn get_old_span_style(
         ViewSpanKind::Equal => Style::default().fg(theme.diff_context),
         ViewSpanKind::Deleted => {
             // Active delete should fade from context to delete color.
-            let mut style = if is_active {
+            let style = if is_active {
                 super::delete_style(
                     app.animation_phase,
                     app.animation_progress,
                     app.is_backward_animation(),
-                    app.strikethrough_deletions,
+                    app.deletion_style,
                     theme.delete_base(),
                     theme.diff_context,
                     removed_bg,
@@ -2629,16 +2737,13 @@ fn get_old_span_style(
                     AnimationPhase::Idle,
                     0.0,
                     false,
-                    false,
+                    DeletionStyle::Plain,
                     theme.delete_base(),
                     theme.delete_dim(),
                     removed_bg,
                 )
             };
-            if app.strikethrough_deletions {
-                style = style.add_modifier(Modifier::CROSSED_OUT);
-            }
-            style
+            super::mark_deletion(style, app.deletion_style, theme.delete_base())
         }
         ViewSpanKind::Inserted => {
             // In old pane, inserted content shouldn't appear
@@ -2650,26 +2755,23 @@ fn get_old_span_style(
                     app.animation_phase,
                     app.animation_progress,
                     app.is_backward_animation(),
-                    app.strikethrough_deletions,
+                    app.deletion_style,
                     theme.delete_base(),
                     theme.diff_context,
                     removed_bg,
                 )
             } else {
                 // Non-active pending delete: show as completed
-                let mut style = super::delete_style(
+                let style = super::delete_style(
                     AnimationPhase::Idle,
                     0.0,
                     false,
-                    false,
+                    DeletionStyle::Plain,
                     theme.delete_base(),
                     theme.delete_dim(),
                     removed_bg,
                 );
-                if app.strikethrough_deletions {
-                    style = style.add_modifier(Modifier::CROSSED_OUT);
-                }
-                style
+                super::mark_deletion(style, app.deletion_style, theme.delete_base())
             }
         }
         ViewSpanKind::PendingInsert => Style::default()
@@ -2,9 +2,11 @@ use std::path::PathBuf;
 
 use crate::app::{AnimationPhase, App, ViewMode};
 use crate::config::{
-    DiffForegroundMode, DiffHighlightMode, EvoSyntaxMode, ModifiedStepMode, SyntaxMode,
+    DiffForegroundMode, DiffHighlightMode, EvoSyntaxMode, GutterSide, ModifiedStepMode, SyntaxMode,
 };
 use crate::test_utils::TestApp;
+use crate::views::blame::blame_gutter_width;
+use crate::views::line_gutter_width;
 use crate::views::{render_blame, render_evolution, render_split, render_unified_pane};
 use oyo_core::{AnimationFrame, MultiFileDiff};
 use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
@@ -126,6 +128,92 @@ fn test_unified_peek_change_updates_render() {
     assert!(after.contains("NEWTOKEN"));
 }
 
+#[test]
+fn test_unified_modified_step_mode_cycles_active_rendering() {
+    let old = "line1\nOLDTOKEN\nline3\n";
+    let new = "line1\nNEWTOKEN\nline3\n";
+    let mut app = make_app(old, new, ViewMode::UnifiedPane);
+    app.unified_modified_step_mode = ModifiedStepMode::Mixed;
+    app.next_step();
+    let render = |app: &mut App| buffer_text(&render_buffer(app, 80, 20)).join("\n");
+
+    app.cycle_modified_step_mode();
+    assert_eq!(app.modified_step_hint(), Some(ModifiedStepMode::Modified));
+    let modified = render(&mut app);
+    assert!(!modified.contains("OLDTOKEN"));
+    assert!(modified.contains("NEWTOKEN"));
+
+    app.cycle_modified_step_mode();
+    assert_eq!(app.unified_modified_step_mode, ModifiedStepMode::Old);
+    let old_only = render(&mut app);
+    assert!(old_only.contains("OLDTOKEN"));
+    assert!(!old_only.contains("NEWTOKEN"));
+
+    app.cycle_modified_step_mode();
+    assert_eq!(app.unified_modified_step_mode, ModifiedStepMode::Mixed);
+    let mixed = render(&mut app);
+    assert!(mixed.contains("OLDTOKEN") && mixed.contains("NEWTOKEN"));
+}
+
+#[test]
+fn test_unified_gutter_signs_off_reclaims_column() {
+    let old = "line1\nOLDSIDE\nline3\n";
+    let new = "line1\nNEWSIDE\nline3\n";
+    let mut app = make_app(old, new, ViewMode::UnifiedPane);
+    let content_col = |app: &mut App| {
+        buffer_text(&render_buffer(app, 80, 20))
+            .iter()
+            .find_map(|line| line.find("line1"))
+            .expect("context line rendered")
+    };
+
+    assert_eq!(content_col(&mut app), 8);
+    app.gutter_signs = false;
+    let col = content_col(&mut app);
+    assert_eq!(col, 6);
+    let first = buffer_text(&render_buffer(&mut app, 80, 20))[0].clone();
+    assert!(first.contains('1'), "line numbers stay visible: {first:?}");
+}
+
+#[test]
+fn test_continuous_gutter_follows_gutter_signs() {
+    let mut app = make_app("line1\nold\n", "line1\nnew\n", ViewMode::UnifiedPane);
+    app.toggle_continuous_view();
+    let content_col = |app: &mut App| {
+        let backend = TestBackend::new(80, 20);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        terminal
+            .draw(|frame| super::continuous::render_continuous(frame, app, frame.area()))
+            .expect("draw");
+        buffer_text(terminal.backend().buffer())
+            .iter()
+            .find_map(|line| line.find("line1"))
+            .expect("context line rendered")
+    };
+
+    assert_eq!(content_col(&mut app), line_gutter_width(&app) as usize);
+    app.gutter_signs = false;
+    assert_eq!(content_col(&mut app), 6);
+    assert_eq!(line_gutter_width(&app), 6);
+}
+
+#[test]
+fn test_note_marker_shows_in_every_view() {
+    let mut app = make_app("a\nb\nc\n", "a\nB\nc\n", ViewMode::UnifiedPane);
+    app.presenter_notes =
+        Some(toml::from_str("[[note]]\nfile = \"new.txt\"\nline = 3\ntext = \"n\"\n").unwrap());
+    for mode in [
+        ViewMode::UnifiedPane,
+        ViewMode::Split,
+        ViewMode::Evolution,
+        ViewMode::Blame,
+    ] {
+        app.view_mode = mode;
+        let text = buffer_text(&render_buffer(&mut app, 100, 10)).join("\n");
+        assert_eq!(count_occurrences(&text, "¶"), 1, "{mode:?}:\n{text}");
+    }
+}
+
 #[test]
 fn test_split_modified_lifecycle_render() {
     let old = "line1\nOLDSPLIT\nline3\n";
@@ -143,6 +231,156 @@ fn test_split_modified_lifecycle_render() {
     assert_eq!(count_occurrences(&after, "NEWSPLIT"), 1);
 }
 
+#[test]
+fn test_split_inline_highlight_marks_changed_tokens() {
+    use ratatui::style::Color;
+
+    let old = "line1\nalpha BETA gamma\nline3\n";
+    let new = "line1\nalpha DELTA gamma\nline3\n";
+    let removed_bg = Color::Rgb(80, 20, 20);
+    let added_bg = Color::Rgb(20, 80, 20);
+    let mut app = make_app(old, new, ViewMode::Split);
+    app.theme.diff_removed_bg = Some(removed_bg);
+    app.theme.diff_added_bg = Some(added_bg);
+    app.next_step();
+    app.multi_diff.current_navigator().clear_active_change();
+
+    let cell_bg = |buf: &Buffer, needle: &str| {
+        let lines = buffer_text(buf);
+        let (y, line) = lines
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.contains(needle))
+            .expect("token rendered");
+        let x = line[..line.find(needle).unwrap()].chars().count() as u16;
+        buf[(x, y as u16)].bg
+    };
+
+    let buf = render_buffer(&mut app, 100, 20);
+    assert_eq!(cell_bg(&buf, "BETA"), removed_bg);
+    assert_eq!(cell_bg(&buf, "DELTA"), added_bg);
+    assert_ne!(cell_bg(&buf, "gamma"), removed_bg);
+
+    app.diff_highlight = DiffHighlightMode::None;
+    let buf = render_buffer(&mut app, 100, 20);
+    assert_ne!(cell_bg(&buf, "BETA"), removed_bg);
+    assert_ne!(cell_bg(&buf, "DELTA"), added_bg);
+}
+
+#[test]
+fn test_trailing_ws_highlight_marks_added_lines_only() {
+    use ratatui::style::Color;
+
+    let old = "ctx  \nline2\n";
+    let new = "ctx  \nline2\nadded  \n";
+    let marker = Color::Rgb(200, 0, 0);
+    let mut app = make_app(old, new, ViewMode::UnifiedPane);
+    app.theme.error = marker;
+    app.next_step();
+    app.multi_diff.current_navigator().clear_active_change();
+
+    let trailing_bg = |app: &mut App, needle: &str| {
+        let buf = render_buffer(app, 80, 20);
+        let lines = buffer_text(&buf);
+        let (y, line) = lines
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.contains(needle))
+            .expect("line rendered");
+        let x = line[..line.find(needle).unwrap()].chars().count() + needle.len();
+        buf[(x as u16, y as u16)].bg
+    };
+
+    assert_ne!(trailing_bg(&mut app, "added"), marker);
+    app.highlight_trailing_ws = true;
+    for mode in [
+        ViewMode::UnifiedPane,
+        ViewMode::Split,
+        ViewMode::Evolution,
+        ViewMode::Blame,
+    ] {
+        app.view_mode = mode;
+        assert_eq!(trailing_bg(&mut app, "added"), marker, "{mode:?}");
+        assert_ne!(trailing_bg(&mut app, "ctx"), marker, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_vanished_file_shows_read_error_instead_of_diff() {
+    let root = std::env::temp_dir().join(format!("oyo-read-error-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    let old_path = root.join("old.txt");
+    let new_path = root.join("new.txt");
+    std::fs::write(&old_path, "one\n").unwrap();
+    std::fs::write(&new_path, "two\n").unwrap();
+    let mut app = TestApp::new_default(|| {
+        let diff = MultiFileDiff::from_file_pair_with_sources(
+            PathBuf::from("new.txt"),
+            b"one\n".to_vec(),
+            b"two\n".to_vec(),
+            Some(old_path.clone()),
+            Some(new_path.clone()),
+        );
+        App::new(diff, ViewMode::UnifiedPane, 0, false, None)
+    });
+
+    std::fs::remove_file(&new_path).unwrap();
+    app.refresh_current_file();
+    let text = buffer_text(&render_buffer(&mut app, 80, 10)).join("\n");
+    assert!(text.contains("Failed to read:"), "{text}");
+    assert!(!text.contains("one"), "{text}");
+
+    std::fs::write(&new_path, "two\n").unwrap();
+    app.refresh_current_file();
+    let text = buffer_text(&render_buffer(&mut app, 80, 10)).join("\n");
+    assert!(!text.contains("Failed to read:"), "{text}");
+
+    let _ = std::fs::remove_dir_all(root);
+}
+
+#[test]
+fn test_binary_hex_view_marks_differing_bytes_and_respects_cap() {
+    let root = std::env::temp_dir().join(format!("oyo-hex-view-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    let old_path = root.join("old.bin");
+    let new_path = root.join("new.bin");
+    let old_bytes = b"AB\0CDEFG".to_vec();
+    let new_bytes = b"AB\0CXEFG".to_vec();
+    std::fs::write(&old_path, &old_bytes).unwrap();
+    std::fs::write(&new_path, &new_bytes).unwrap();
+
+    let mut app = TestApp::new_default(|| {
+        let diff = MultiFileDiff::from_file_pair_with_sources(
+            PathBuf::from("blob.bin"),
+            old_bytes.clone(),
+            new_bytes.clone(),
+            Some(old_path.clone()),
+            Some(new_path.clone()),
+        );
+        App::new(diff, ViewMode::UnifiedPane, 200, false, None)
+    });
+
+    let buf = render_buffer(&mut app, 100, 10);
+    let lines = buffer_text(&buf);
+    assert!(lines[0].starts_with("00000000"), "{:?}", lines[0]);
+    assert!(lines[0].contains("41 42 00 43 44"));
+    assert!(lines[0].contains("41 42 00 43 58"));
+    assert!(lines[0].contains("AB.CDEFG"));
+    let col = |needle: &str| {
+        let line = &lines[0];
+        line[..line.rfind(needle).unwrap()].chars().count() as u16
+    };
+    assert_eq!(buf[(col("58"), 0)].fg, app.theme.modify_base());
+    assert_ne!(buf[(col("41"), 0)].fg, app.theme.modify_base());
+
+    app.hex_max_bytes = 4;
+    app.refresh_current_file();
+    let text = buffer_text(&render_buffer(&mut app, 100, 10)).join("\n");
+    assert!(text.contains("Binary file"));
+
+    let _ = std::fs::remove_dir_all(root);
+}
+
 #[test]
 fn test_evolution_full_preview_no_duplicate_modified_line() {
     let old = "line1\nOLDEVO\nline3\n";
@@ -257,6 +495,166 @@ fn test_unified_wrap_hunk_hint_overflow_places_above() {
     );
 }
 
+#[test]
+fn test_unified_wrap_column_caps_wrap_width() {
+    let mut app = make_app("", "ABCDEFGHIJKLMNOPQRSTUVWXYZ\n", ViewMode::UnifiedPane);
+    app.line_wrap = true;
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.wrap_column = 10;
+
+    let lines = buffer_text(&render_buffer(&mut app, 40, 6));
+    let first = lines
+        .iter()
+        .position(|line| line.contains("ABCDEFGHIJ"))
+        .expect("first wrapped row should render");
+    assert!(
+        lines[first].trim_end().ends_with(" ABCDEFGHIJ"),
+        "content should break at the wrap column: {:?}",
+        lines[first]
+    );
+    assert!(lines[first + 1].contains("KLMNOPQRST"));
+}
+
+#[test]
+fn test_unified_color_column_follows_horizontal_scroll() {
+    let long = "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    let mut app = make_app("", &format!("{long}\n"), ViewMode::UnifiedPane);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.color_column = 5;
+
+    let lines = buffer_text(&render_buffer(&mut app, 30, 6));
+    let row = lines
+        .iter()
+        .position(|line| line.contains("ABCDEFGHIJ"))
+        .expect("content row should render");
+    let byte = lines[row].find("ABCDEFGHIJ").unwrap();
+    let start = lines[row][..byte].chars().count();
+    let rule_at = |lines: &[String], col: usize| lines[row + 1].chars().nth(col) == Some('│');
+    assert_eq!(lines[row].chars().nth(start + 5), Some('F'));
+    assert!(rule_at(&lines, start + 5), "rule should sit after column 5");
+
+    app.horizontal_scroll = 2;
+    let lines = buffer_text(&render_buffer(&mut app, 30, 6));
+    assert_eq!(app.horizontal_scroll, 2);
+    assert!(
+        rule_at(&lines, start + 3),
+        "rule should move with the scroll"
+    );
+
+    app.line_wrap = true;
+    let lines = buffer_text(&render_buffer(&mut app, 30, 6));
+    assert!(!lines[row + 1].contains('│'), "no rule while wrapping");
+}
+
+#[test]
+fn test_unified_indent_guides_mark_levels_and_scroll_with_text() {
+    // Long enough to scroll horizontally in a 40-column view.
+    let new = format!(
+        "fn a() {{\n        deep(); // {}\n\tx();\n}}\n",
+        "z".repeat(40)
+    );
+    let mut app = make_app("", &new, ViewMode::UnifiedPane);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.indent_guides = true;
+
+    let lines = buffer_text(&render_buffer(&mut app, 40, 8));
+    assert!(lines.iter().any(|line| line.contains("│   │   deep();")));
+    assert!(lines.iter().any(|line| line.contains("│       x();")));
+
+    app.horizontal_scroll = 4;
+    let lines = buffer_text(&render_buffer(&mut app, 40, 8));
+    assert_eq!(app.horizontal_scroll, 4);
+    assert!(lines.iter().any(|line| line.contains("│   deep();")));
+    assert!(!lines.iter().any(|line| line.contains("│   │   deep();")));
+
+    app.indent_guides = false;
+    app.horizontal_scroll = 0;
+    let lines = buffer_text(&render_buffer(&mut app, 40, 8));
+    assert!(lines.iter().any(|line| line.contains("        deep();")));
+}
+
+#[test]
+fn test_unified_wide_chars_keep_columns_aligned_when_scrolled() {
+    fn marker_columns(buf: &Buffer) -> Vec<u16> {
+        (0..buf.area.height)
+            .filter_map(|y| (0..buf.area.width).find(|&x| buf[(x, y)].symbol() == "#"))
+            .collect()
+    }
+
+    // Every line puts `#` at content column 6: CJK and emoji are two wide.
+    let tail = "z".repeat(60);
+    let new = format!("日本語#{tail}\nabcdef#{tail}\n😀😀😀#{tail}\n");
+    let mut app = make_app("", &new, ViewMode::UnifiedPane);
+    app.stepping = false;
+    app.enter_no_step_mode();
+
+    let columns = marker_columns(&render_buffer(&mut app, 40, 6));
+    assert_eq!(columns.len(), 3);
+    assert!(columns.iter().all(|&x| x == columns[0]), "{columns:?}");
+
+    // Scroll by one column so the first wide char on each line is cut in half.
+    app.horizontal_scroll = 1;
+    let scrolled = marker_columns(&render_buffer(&mut app, 40, 6));
+    assert_eq!(app.horizontal_scroll, 1);
+    assert_eq!(scrolled, vec![columns[0] - 1; 3]);
+}
+
+#[test]
+fn test_slice_spans_and_truncate_measure_columns() {
+    use crate::views::{slice_spans, spans_to_text, spans_width, truncate_text};
+    use ratatui::text::Span;
+
+    // Columns: a 0, 日 1-2, b 3, 😀 4-5, c 6.
+    let spans = vec![Span::raw("a日b😀c")];
+    let sliced = slice_spans(&spans, 2, 3);
+    assert_eq!(spans_to_text(&sliced), " b ");
+    assert_eq!(spans_width(&sliced), 3);
+    assert_eq!(spans_to_text(&slice_spans(&spans, 1, 6)), "日b😀c");
+
+    assert_eq!(truncate_text("abcdefghij", 8), "abcde…");
+    assert_eq!(truncate_text("山田太郎さん", 7), "山田…");
+    assert_eq!(truncate_text("山田", 4), "山田");
+}
+
+#[test]
+fn test_unified_focus_mode_dims_lines_outside_current_hunk() {
+    fn dimmed(buf: &Buffer, needle: &str) -> bool {
+        let lines = buffer_text(buf);
+        let row = lines
+            .iter()
+            .position(|line| line.contains(needle))
+            .expect("line should render");
+        let col = lines[row][..lines[row].find(needle).unwrap()]
+            .chars()
+            .count();
+        buf[(col as u16, row as u16)]
+            .modifier
+            .contains(ratatui::style::Modifier::DIM)
+    }
+
+    let old: Vec<String> = (1..=20).map(|i| format!("line{i}")).collect();
+    let mut new = old.clone();
+    new[1] = "FIRSTHUNK".to_string();
+    new[17] = "SECONDHUNK".to_string();
+    let mut app = make_app(&old.join("\n"), &new.join("\n"), ViewMode::UnifiedPane);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.focus_mode = true;
+
+    let buf = render_buffer(&mut app, 40, 30);
+    assert!(!dimmed(&buf, "FIRSTHUNK"));
+    assert!(dimmed(&buf, "SECONDHUNK"));
+    assert!(dimmed(&buf, "line10"));
+
+    app.next_hunk_scroll();
+    let buf = render_buffer(&mut app, 40, 30);
+    assert!(dimmed(&buf, "FIRSTHUNK"));
+    assert!(!dimmed(&buf, "SECONDHUNK"));
+}
+
 #[test]
 fn test_unified_wrap_end_scroll_no_bounce() {
     let long = "LONGINSERT_LONGINSERT_LONGINSERT_LONGINSERT";
@@ -306,12 +704,12 @@ fn test_blame_end_scroll_no_bounce() {
     app.last_viewport_height = 4;
     app.scroll_offset = usize::MAX;
 
-    let first = buffer_text(&render_buffer(&mut app, 30, 4));
+    let first = buffer_text(&render_buffer(&mut app, 80, 4));
     let max_scroll = app.scroll_offset;
     assert!(max_scroll > 0, "expected content to be scrollable");
 
     app.scroll_down();
-    let second = buffer_text(&render_buffer(&mut app, 30, 4));
+    let second = buffer_text(&render_buffer(&mut app, 80, 4));
 
     assert_eq!(
         app.scroll_offset, max_scroll,
@@ -320,6 +718,83 @@ fn test_blame_end_scroll_no_bounce() {
     assert_eq!(first, second, "render should not bounce at end");
 }
 
+#[test]
+fn test_blame_gutter_width_hides_on_narrow_views() {
+    assert_eq!(blame_gutter_width(0, 100), Some(32));
+    assert_eq!(blame_gutter_width(20, 100), Some(20));
+    assert_eq!(blame_gutter_width(20, 71), None);
+    assert_eq!(blame_gutter_width(0, 60), None);
+
+    let mut app = make_app("", "LONGINSERT_LONGINSERT\n", ViewMode::Blame);
+    app.blame_enabled = true;
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.blame_width = 30;
+    let text = buffer_text(&render_buffer(&mut app, 60, 4)).join("\n");
+    assert!(text.contains("LONGINSERT"), "diff should still render");
+    assert!(!text.contains('▌'), "blame gutter should be hidden");
+
+    app.blame_width = 0;
+    let text = buffer_text(&render_buffer(&mut app, 100, 4)).join("\n");
+    assert!(text.contains('▌'), "blame gutter should show on wide views");
+}
+
+#[test]
+fn test_gutter_side_right_moves_line_numbers_to_the_edge() {
+    let mut app = make_app("alpha\n", "alpha\nbeta\n", ViewMode::UnifiedPane);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.gutter_side = GutterSide::Right;
+    let lines = buffer_text(&render_buffer(&mut app, 40, 3));
+    assert!(lines[0].starts_with("alpha"), "{:?}", lines[0]);
+    assert!(lines[0].trim_end().ends_with('1'), "{:?}", lines[0]);
+
+    app.view_mode = ViewMode::Split;
+    let lines = buffer_text(&render_buffer(&mut app, 60, 3));
+    let new_pane = lines[1].split('│').nth(1).unwrap_or_default();
+    assert!(new_pane.starts_with("beta"), "{:?}", lines[1]);
+    assert!(new_pane.trim_end().ends_with("2 ◀"), "{:?}", lines[1]);
+}
+
+#[test]
+fn test_split_inactive_marker_follows_pane_focus() {
+    let old = "line1\nOLDSIDE\nline3\n";
+    let new = "line1\nNEWSIDE\nline3\n";
+    let mut app = make_app(old, new, ViewMode::Split);
+    app.primary_marker_inactive = Some("▷".to_string());
+    app.primary_marker_right_inactive = Some("◁".to_string());
+    app.next_step();
+
+    // The new pane has focus at start.
+    let text = buffer_text(&render_buffer(&mut app, 100, 20)).join("\n");
+    assert!(text.contains('▷') && text.contains('◀'), "{text}");
+    assert!(!text.contains('▶') && !text.contains('◁'), "{text}");
+
+    app.toggle_split_focus();
+    let text = buffer_text(&render_buffer(&mut app, 100, 20)).join("\n");
+    assert!(text.contains('▶') && text.contains('◁'), "{text}");
+    assert!(!text.contains('▷') && !text.contains('◀'), "{text}");
+}
+
+#[test]
+fn test_line_debug_annotates_change_and_hunk() {
+    let mut app = make_app("alpha\n", "alpha\nbeta\n", ViewMode::UnifiedPane);
+    app.stepping = false;
+    app.enter_no_step_mode();
+    app.show_line_debug = true;
+    let lines = buffer_text(&render_buffer(&mut app, 80, 3));
+    assert!(
+        lines[0].contains("[change 0 hunk - Context]"),
+        "{:?}",
+        lines[0]
+    );
+    assert!(
+        lines[1].contains("[change 1 hunk 0 Inserted]"),
+        "{:?}",
+        lines[1]
+    );
+}
+
 #[test]
 fn test_blame_large_file_end_scroll_no_empty_state() {
     let long = "LONGINSERT_LONGINSERT_LONGINSERT_LONGINSERT";
@@ -9,20 +9,17 @@ use crate::app::{
     is_conflict_marker, is_fold_line, AnimationPhase, App, UnifiedRenderKey, UnifiedRenderModel,
 };
 use crate::color;
-use crate::config::{DiffForegroundMode, DiffHighlightMode, ModifiedStepMode};
+use crate::config::{DeletionStyle, DiffForegroundMode, DiffHighlightMode, ModifiedStepMode};
 use crate::syntax::SyntaxSide;
 use oyo_core::{AnimationFrame, Change, ChangeKind, LineKind, ViewLine, ViewSpan, ViewSpanKind};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
     Frame,
 };
 
-/// Width of the fixed line number gutter (marker + line num + prefix + space)
-const GUTTER_WIDTH: u16 = 8; // "▶1234 + "
-
 fn hunk_overflow_wrapped_unified(
     view_lines: &[ViewLine],
     hunk_idx: usize,
@@ -130,7 +127,7 @@ fn build_inline_modified_spans(
         phase,
         progress,
         backward,
-        app.strikethrough_deletions,
+        app.deletion_style,
         app.theme.delete_base(),
         app.theme.diff_context,
         removed_bg,
@@ -155,7 +152,7 @@ fn build_inline_modified_spans(
             ChangeKind::Delete => {
                 has_old = true;
                 let text = &span.text;
-                if app.strikethrough_deletions {
+                if app.strikethrough_deletions() {
                     let trimmed = text.trim_start();
                     let leading_ws_len = text.len() - trimmed.len();
                     if leading_ws_len > 0 && !trimmed.is_empty() {
@@ -177,7 +174,7 @@ fn build_inline_modified_spans(
                 has_old = true;
                 has_new = true;
                 let text = &span.text;
-                if app.strikethrough_deletions {
+                if app.strikethrough_deletions() {
                     let trimmed = text.trim_start();
                     let leading_ws_len = text.len() - trimmed.len();
                     if leading_ws_len > 0 && !trimmed.is_empty() {
@@ -300,11 +297,16 @@ fn unified_render_key(
         diff_bg: app.diff_bg,
         diff_fg: app.diff_fg,
         diff_highlight: app.diff_highlight,
+        modified_step_mode: app.unified_modified_step_mode,
         diff_extent_marker: app.diff_extent_marker,
         diff_extent_marker_scope: app.diff_extent_marker_scope,
         diff_extent_marker_context: app.diff_extent_marker_context,
         gutter_signs: app.gutter_signs,
-        strikethrough_deletions: app.strikethrough_deletions,
+        focus_mode: app.focus_mode,
+        mine_only: app.mine_only,
+        highlight_trailing_ws: app.highlight_trailing_ws,
+        review_keywords: app.review_keywords_pattern().map(str::to_string),
+        deletion_style: app.deletion_style,
         search_query: app.search_query().trim().to_string(),
         search_active: app.search_active(),
         syntax_mode: app.syntax_mode,
@@ -313,6 +315,7 @@ fn unified_render_key(
         syntax_epoch: app.syntax_cache_epoch(),
         step_edge_hint: app.step_edge_hint_active(),
         hunk_edge_hint: app.hunk_edge_hint_active(),
+        locate_flash: app.locate_flash_line(),
         blame_hunk_hint: app.blame_hunk_hint_text().map(|text| text.to_string()),
         review_mode: app.review_mode(),
         review_editor_active: app.review_editor_active(),
@@ -330,7 +333,8 @@ fn build_unified_render_model(
     blame_extra_rows: Option<&[usize]>,
 ) -> UnifiedRenderModel {
     let primary_marker = app.primary_marker.clone();
-    let extent_marker = app.extent_marker.clone();
+    let extent_markers = super::ExtentMarkers::left(app);
+    let note_lines = app.presenter_note_lines(view_lines);
     let debug_target = app.syntax_scope_target(view_lines);
     let mut bg_lines: Option<Vec<Line<'static>>> = if app.line_wrap && app.diff_bg {
         Some(Vec::new())
@@ -506,6 +510,7 @@ fn build_unified_render_model(
     }
     let mut prev_visible_hunk: Option<usize> = None;
     let mut virtual_inserted = false;
+    let dim_filter = super::dim_filter(app);
     for (idx, view_line) in view_lines.iter().enumerate() {
         if !app.line_wrap && idx < scroll_offset {
             continue;
@@ -520,6 +525,7 @@ fn build_unified_render_model(
             .unwrap_or(0);
 
         let line_hunk = view_line.hunk_index;
+        let focus_dimmed = dim_filter.dims(line_hunk);
         let is_first_in_hunk = line_hunk.is_some() && prev_visible_hunk != line_hunk;
         let is_last_in_hunk = line_hunk.is_some() && next_visible_hunk[idx] != line_hunk;
 
@@ -635,7 +641,8 @@ fn build_unified_render_model(
         let delete_base = color::gradient_color(&app.theme.delete, 0.5);
         let modify_base = color::gradient_color(&app.theme.modify, 0.5);
 
-        let line_num_style = match view_line.kind {
+        let moved_fg = super::moved_line_fg(view_line, &app.theme);
+        let mut line_num_style = match view_line.kind {
             LineKind::Context => Style::default().fg(app.theme.diff_line_number),
             LineKind::Inserted | LineKind::PendingInsert => {
                 Style::default().fg(Color::Rgb(insert_base.r, insert_base.g, insert_base.b))
@@ -654,7 +661,7 @@ fn build_unified_render_model(
             None
         };
 
-        let (mut line_prefix, mut sign_style) = match view_line.kind {
+        let (line_prefix, mut sign_style) = match view_line.kind {
             LineKind::Context => (" ", Style::default().fg(app.theme.diff_line_number)),
             LineKind::Inserted | LineKind::PendingInsert => {
                 if view_line.is_active {
@@ -681,7 +688,7 @@ fn build_unified_render_model(
                             app.animation_phase,
                             app.animation_progress,
                             app.is_backward_animation(),
-                            false,
+                            DeletionStyle::Plain,
                             app.theme.delete_base(),
                             app.theme.diff_context,
                             None,
@@ -709,9 +716,10 @@ fn build_unified_render_model(
                 }
             }
         };
-        if !app.gutter_signs {
-            line_prefix = " ";
-            sign_style = Style::default();
+
+        if let Some(fg) = moved_fg {
+            line_num_style = line_num_style.fg(fg);
+            sign_style = sign_style.fg(fg);
         }
 
         let show_extent = super::show_extent_marker(app, view_line);
@@ -724,7 +732,7 @@ fn build_unified_render_model(
             )
         } else if show_extent {
             (
-                extent_marker.as_str(),
+                extent_markers.for_kind(view_line.kind),
                 super::extent_marker_style(
                     app,
                     view_line.kind,
@@ -733,6 +741,12 @@ fn build_unified_render_model(
                     view_line.new_line,
                 ),
             )
+        } else if view_line
+            .new_line
+            .or(view_line.old_line)
+            .is_some_and(|line| note_lines.contains(&line))
+        {
+            (super::NOTE_MARKER, Style::default().fg(app.theme.warning))
         } else {
             (" ", Style::default())
         };
@@ -741,9 +755,11 @@ fn build_unified_render_model(
             Span::styled(active_marker.to_string(), active_style),
             Span::styled(line_num_str, line_num_style),
             Span::styled(" ", Style::default()),
-            Span::styled(line_prefix, sign_style),
-            Span::styled(" ", Style::default()),
         ];
+        if app.gutter_signs {
+            gutter_spans.push(Span::styled(line_prefix, sign_style));
+            gutter_spans.push(Span::styled(" ", Style::default()));
+        }
         if let Some(bg) = line_bg_gutter {
             gutter_spans = gutter_spans
                 .into_iter()
@@ -757,6 +773,9 @@ fn build_unified_render_model(
                 })
                 .collect();
         }
+        if focus_dimmed {
+            gutter_spans = super::focus_dim_spans(gutter_spans, app.theme.text_muted);
+        }
         gutter_lines.push(Line::from(gutter_spans));
 
         let mut content_spans: Vec<Span<'static>> = Vec::new();
@@ -1001,7 +1020,7 @@ fn build_unified_render_model(
                         _ => {}
                     }
                 }
-                if app.strikethrough_deletions
+                if app.strikethrough_deletions()
                     && matches!(
                         view_span.kind,
                         ViewSpanKind::Deleted | ViewSpanKind::PendingDelete
@@ -1024,6 +1043,10 @@ fn build_unified_render_model(
             }
         }
 
+        if let Some(fg) = moved_fg {
+            content_spans = super::recolor_spans(content_spans, fg);
+        }
+
         let line_bg_line = if app.diff_bg {
             diff_line_bg(view_line.kind, &app.theme)
         } else {
@@ -1089,6 +1112,9 @@ fn build_unified_render_model(
         let is_active_match = app.search_target() == Some(idx)
             && has_query
             && line_text.to_ascii_lowercase().contains(&query);
+        content_spans =
+            super::highlight_trailing_ws(app, content_spans, view_line.kind, &line_text);
+        content_spans = app.highlight_keyword_spans(content_spans, view_line, &line_text);
         content_spans = app.highlight_search_spans(content_spans, &line_text, is_active_match);
         if italic_line {
             content_spans = super::apply_italic_spans(content_spans);
@@ -1113,7 +1139,8 @@ fn build_unified_render_model(
             }
         }
 
-        content_spans = expand_tabs_in_spans(&content_spans, TAB_WIDTH);
+        super::push_line_debug(app, &mut content_spans, view_line);
+        content_spans = super::expand_tabs_with_indent_guides(app, &content_spans);
 
         let line_width = spans_width(&content_spans);
         max_line_width = max_line_width.max(line_width);
@@ -1140,11 +1167,21 @@ fn build_unified_render_model(
         if let Some(bg_lines) = bg_lines.as_mut() {
             super::push_wrapped_bg_line(bg_lines, wrap_width, wrap_count, line_bg_line);
         }
+        if focus_dimmed {
+            display_spans = super::focus_dim_spans(display_spans, app.theme.text_muted);
+        }
+        if app.peek_next_change() == Some(view_line.change_id) {
+            display_spans = super::peek_next_spans(display_spans);
+        }
+        if app.locate_flash_line() == Some(idx) {
+            let width = if app.line_wrap { 0 } else { visible_width };
+            display_spans = super::locate_flash_spans(app, display_spans, width);
+        }
         content_lines.push(Line::from(display_spans));
         if app.line_wrap && wrap_count > 1 {
             let (wrap_marker, wrap_style) = if show_extent {
                 (
-                    extent_marker.as_str(),
+                    extent_markers.for_kind(view_line.kind),
                     super::extent_marker_style(
                         app,
                         view_line.kind,
@@ -1158,7 +1195,7 @@ fn build_unified_render_model(
             };
             for _ in 1..wrap_count {
                 if let Some(bg) = line_bg_gutter {
-                    let pad = " ".repeat(GUTTER_WIDTH as usize - 1);
+                    let pad = " ".repeat(super::line_gutter_width(app) as usize - 1);
                     gutter_lines.push(Line::from(vec![
                         Span::styled(wrap_marker.to_string(), wrap_style),
                         Span::styled(pad, Style::default().bg(bg)),
@@ -1463,12 +1500,19 @@ fn build_unified_render_model(
 
 fn render_unified_pane_cached(frame: &mut Frame, app: &mut App, area: Rect) {
     let visible_height = area.height as usize;
-    let visible_width = area.width.saturating_sub(GUTTER_WIDTH) as usize;
+    let visible_width = super::wrap_width(
+        app,
+        area.width.saturating_sub(super::line_gutter_width(app)) as usize,
+    );
     if !app.line_wrap {
         app.clamp_horizontal_scroll_cached(visible_width);
     }
+    if let Some(reason) = app.current_read_error() {
+        super::render_read_error(frame, area, &app.theme, &reason);
+        return;
+    }
     if app.current_file_is_binary() {
-        render_empty_state(frame, area, &app.theme, false, true);
+        super::hex::render_binary_file(frame, app, area);
         return;
     }
     if app.line_wrap {
@@ -1581,12 +1625,8 @@ fn render_unified_model(
     model: &UnifiedRenderModel,
     scroll_offset: usize,
 ) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Length(GUTTER_WIDTH), Constraint::Min(0)])
-        .split(area);
-    let gutter_area = chunks[0];
-    let content_area = chunks[1];
+    let (gutter_area, content_area) =
+        super::gutter_layout(app, area, super::line_gutter_width(app));
     let bg_style = app.theme.background.map(|bg| Style::default().bg(bg));
     let mut gutter_paragraph = if app.line_wrap {
         Paragraph::new(model.gutter_lines.clone()).scroll((scroll_offset as u16, 0))
@@ -1613,6 +1653,7 @@ fn render_unified_model(
             app.current_file_is_binary(),
         );
     } else {
+        let text_area = super::wrap_content_area(frame, app, content_area);
         let mut content_paragraph = if app.line_wrap {
             Paragraph::new(model.content_lines.clone())
                 .wrap(Wrap { trim: false })
@@ -1626,14 +1667,15 @@ fn render_unified_model(
             if let Some(style) = bg_style {
                 bg_paragraph = bg_paragraph.style(style);
             }
-            frame.render_widget(bg_paragraph, content_area);
+            frame.render_widget(bg_paragraph, text_area);
         }
         if !has_bg_overlay {
             if let Some(style) = bg_style {
                 content_paragraph = content_paragraph.style(style);
             }
         }
-        frame.render_widget(content_paragraph, content_area);
+        frame.render_widget(content_paragraph, text_area);
+        super::render_color_column(frame, app, text_area);
 
         if app.review_mode()
             && !app.review_editor_active()
@@ -1699,12 +1741,19 @@ pub fn render_unified_pane(frame: &mut Frame, app: &mut App, area: Rect) {
 
 fn render_unified_pane_uncached(frame: &mut Frame, app: &mut App, area: Rect) {
     let visible_height = area.height as usize;
-    let visible_width = area.width.saturating_sub(GUTTER_WIDTH) as usize;
+    let visible_width = super::wrap_width(
+        app,
+        area.width.saturating_sub(super::line_gutter_width(app)) as usize,
+    );
     if !app.line_wrap {
         app.clamp_horizontal_scroll_cached(visible_width);
     }
+    if let Some(reason) = app.current_read_error() {
+        super::render_read_error(frame, area, &app.theme, &reason);
+        return;
+    }
     if app.current_file_is_binary() {
-        render_empty_state(frame, area, &app.theme, false, true);
+        super::hex::render_binary_file(frame, app, area);
         return;
     }
     if app.line_wrap {
@@ -1890,7 +1939,7 @@ fn get_span_style(
                     app.animation_phase,
                     app.animation_progress,
                     backward,
-                    app.strikethrough_deletions,
+                    app.deletion_style,
                     theme.delete_base(),
                     theme.diff_context,
                     removed_bg,
@@ -1900,7 +1949,7 @@ fn get_span_style(
                     crate::app::AnimationPhase::Idle,
                     1.0,
                     false,
-                    app.strikethrough_deletions,
+                    app.deletion_style,
                     theme.delete_base(),
                     theme.diff_context,
                     removed_bg,
@@ -1965,7 +2014,7 @@ fn get_span_style(
                     app.animation_phase,
                     app.animation_progress,
                     backward,
-                    app.strikethrough_deletions,
+                    app.deletion_style,
                     theme.delete_base(),
                     theme.diff_context,
                     removed_bg,